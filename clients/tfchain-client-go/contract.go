@@ -12,6 +12,8 @@ type DeletedState struct {
 	IsCanceledByUser       bool `json:"is_canceled_by_user"`
 	IsOutOfFunds           bool `json:"is_out_of_funds"`
 	IsCanceledByCollective bool `json:"is_canceled_by_collective"`
+	IsExpired              bool `json:"is_expired"`
+	IsNodeDecommissioned   bool `json:"is_node_decommissioned"`
 }
 
 // Decode implementation for the enum type
@@ -28,6 +30,10 @@ func (r *DeletedState) Decode(decoder scale.Decoder) error {
 		r.IsOutOfFunds = true
 	case 2:
 		r.IsCanceledByCollective = true
+	case 3:
+		r.IsExpired = true
+	case 4:
+		r.IsNodeDecommissioned = true
 	default:
 		return fmt.Errorf("unknown deleted state value")
 	}
@@ -43,6 +49,10 @@ func (r DeletedState) Encode(encoder scale.Encoder) (err error) {
 		err = encoder.PushByte(1)
 	} else if r.IsCanceledByCollective {
 		err = encoder.PushByte(2)
+	} else if r.IsExpired {
+		err = encoder.PushByte(3)
+	} else if r.IsNodeDecommissioned {
+		err = encoder.PushByte(4)
 	}
 	return
 }
@@ -205,11 +205,13 @@ type ContractGracePeriodEnded struct {
 }
 
 type SolutionProvider struct {
-	SolutionProviderID types.U64  `json:"solution_provider_id"`
-	Providers          []Provider `json:"providers"`
-	Description        string     `json:"description"`
-	Link               string     `json:"link"`
-	Approved           bool       `json:"approved"`
+	SolutionProviderID types.U64       `json:"solution_provider_id"`
+	Providers          []Provider      `json:"providers"`
+	Description        string          `json:"description"`
+	Link               string          `json:"link"`
+	Approved           bool            `json:"approved"`
+	Revoked            bool            `json:"revoked"`
+	ExpiresAt          types.OptionU64 `json:"expires_at"`
 }
 
 type Provider struct {
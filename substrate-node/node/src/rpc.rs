@@ -36,9 +36,13 @@ where
     C: Send + Sync + 'static,
     C::Api: substrate_frame_rpc_system::AccountNonceApi<Block, AccountId, Nonce>,
     C::Api: pallet_transaction_payment_rpc::TransactionPaymentRuntimeApi<Block, Balance>,
+    C::Api: pallet_smart_contract_rpc::SmartContractRuntimeApi<Block, AccountId, Balance>,
+    C::Api: pallet_tfgrid_rpc::TfgridRuntimeApi<Block>,
     C::Api: BlockBuilder<Block>,
     P: TransactionPool + 'static,
 {
+    use pallet_smart_contract_rpc::{SmartContract, SmartContractApiServer};
+    use pallet_tfgrid_rpc::{Tfgrid, TfgridApiServer};
     use pallet_transaction_payment_rpc::{TransactionPayment, TransactionPaymentApiServer};
     use substrate_frame_rpc_system::{System, SystemApiServer};
 
@@ -50,13 +54,9 @@ where
     } = deps;
 
     module.merge(System::new(client.clone(), pool, deny_unsafe).into_rpc())?;
-    module.merge(TransactionPayment::new(client).into_rpc())?;
-
-    // Extend this RPC with a custom API by using the following syntax.
-    // `YourRpcStruct` should have a reference to a client, which is needed
-    // to call into the runtime.
-    // `module.merge(YourRpcTrait::into_rpc(YourRpcStruct::new(ReferenceToClient,
-    // ...)))?;`
+    module.merge(TransactionPayment::new(client.clone()).into_rpc())?;
+    module.merge(SmartContract::new(client.clone()).into_rpc())?;
+    module.merge(Tfgrid::new(client).into_rpc())?;
 
     Ok(module)
 }
@@ -368,5 +368,11 @@ impl<T: Config> ChangeNode<LocationOf<T>, InterfaceOf<T>, SerialNumberOf<T>> for
         FarmWeight::<T>::insert(node.farm_id, farm_weight);
     }
 
+    fn node_decommissioning(_node: &TfgridNode<T>, _deadline: u64) {}
+
+    fn node_decommissioned(_node: &TfgridNode<T>, _max_contracts: u32) -> Weight {
+        Weight::zero()
+    }
+
     fn node_power_state_changed(_node: &TfgridNode<T>) {}
 }
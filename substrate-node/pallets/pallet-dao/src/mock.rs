@@ -1,5 +1,7 @@
 use crate::{self as pallet_dao};
-use frame_support::{construct_runtime, parameter_types, traits::ConstU32, BoundedVec};
+use frame_support::{
+    construct_runtime, parameter_types, traits::ConstU32, weights::Weight, BoundedVec,
+};
 use frame_system::EnsureRoot;
 use pallet_collective;
 use pallet_tfgrid::{
@@ -18,7 +20,7 @@ use sp_runtime::{
 };
 use sp_std::convert::{TryFrom, TryInto};
 use tfchain_support::traits::{ChangeNode, NodeActiveContracts, PublicIpModifier};
-use tfchain_support::types::PublicIP;
+use tfchain_support::types::{PublicIP, PublicIp6Prefix};
 
 type Block = frame_system::mocking::MockBlock<TestRuntime>;
 
@@ -85,6 +87,10 @@ impl ChangeNode<Loc, Interface, Serial> for NodeChanged {
     fn node_deleted(node: &TfgridNode) {
         DaoModule::node_deleted(node);
     }
+    fn node_decommissioning(_node: &TfgridNode, _deadline: u64) {}
+    fn node_decommissioned(_node: &TfgridNode, _max_contracts: u32) -> Weight {
+        Weight::zero()
+    }
 
     fn node_power_state_changed(_node: &TfgridNode) {}
 }
@@ -92,6 +98,7 @@ impl ChangeNode<Loc, Interface, Serial> for NodeChanged {
 pub struct PublicIpModifierType;
 impl PublicIpModifier for PublicIpModifierType {
     fn ip_removed(_ip: &PublicIP) {}
+    fn ip6_prefix_removed(_farm_id: u32, _prefix: &PublicIp6Prefix) {}
 }
 
 pub struct NodeActiveContractsType;
@@ -118,6 +125,7 @@ parameter_types! {
     pub const MaxInterfacesLength: u32 = 10;
     pub const MaxFarmPublicIps: u32 = 512;
     pub const TimestampHintDrift: u64 = 60;
+    pub const NodeDecommissionPeriod: u64 = 10;
 }
 
 pub(crate) type TestTermsAndConditions = TermsAndConditions<TestRuntime>;
@@ -154,6 +162,7 @@ impl pallet_tfgrid::Config for TestRuntime {
     type Location = TestLocation;
     type SerialNumber = TestSerialNumber;
     type TimestampHintDrift = TimestampHintDrift;
+    type NodeDecommissionPeriod = NodeDecommissionPeriod;
 }
 
 impl pallet_timestamp::Config for TestRuntime {
@@ -0,0 +1,50 @@
+//! Runtime API definition for the smart contract pallet.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use parity_scale_codec::Codec;
+use sp_runtime::DispatchError;
+use sp_std::vec::Vec;
+use tfchain_support::resources::Resources;
+
+pub use pallet_smart_contract::types::{
+    ContractBill, ContractCostEstimate, ContractEarnings, ContractInGrace, DiscountLevel,
+    NameAvailability, ProviderEarnings, SolutionProviderEarnings, TwinDailyBill,
+};
+
+sp_api::decl_runtime_apis! {
+    pub trait SmartContractApi<AccountId, Balance> where
+        AccountId: Codec,
+        Balance: Codec,
+    {
+        /// Estimate the cost of a node contract with given resources and amount of public ips
+        /// on a node for one billing reference period, as would be billed to the twin given its
+        /// current balance.
+        fn estimate_contract_cost(
+            node_id: u32,
+            twin_id: u32,
+            resources: Resources,
+            public_ips: u32,
+        ) -> Result<ContractCostEstimate<Balance>, DispatchError>;
+
+        /// Get the last bills of a contract, oldest first.
+        fn contract_billing_history(contract_id: u64) -> Vec<ContractBill>;
+
+        /// Get the amount billed to a twin per day, oldest first.
+        fn twin_billing_history(twin_id: u32) -> Vec<TwinDailyBill>;
+
+        /// Get the contracts of a twin that are in grace period.
+        fn contracts_in_grace(twin_id: u32) -> Vec<ContractInGrace>;
+
+        /// Get the total amount earned by a solution provider, per provider account and per contract.
+        fn solution_provider_earnings(
+            solution_provider_id: u64,
+        ) -> SolutionProviderEarnings<AccountId, Balance>;
+
+        /// Get the solution provider shares accrued by an account that were not claimed yet.
+        fn unclaimed_solution_provider_rewards(account_id: AccountId) -> Balance;
+
+        /// Check whether a name can be registered with a name contract.
+        fn name_availability(name: Vec<u8>) -> NameAvailability;
+    }
+}
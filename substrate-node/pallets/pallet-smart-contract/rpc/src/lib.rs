@@ -0,0 +1,449 @@
+//! RPC interface for the smart contract pallet.
+
+use std::sync::Arc;
+
+use jsonrpsee::{
+    core::{async_trait, RpcResult},
+    proc_macros::rpc,
+    types::error::{CallError, ErrorObject},
+};
+use parity_scale_codec::Codec;
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+use sp_api::ProvideRuntimeApi;
+use sp_blockchain::HeaderBackend;
+use sp_runtime::traits::Block as BlockT;
+use tfchain_support::resources::Resources;
+
+pub use pallet_smart_contract_rpc_runtime_api::SmartContractApi as SmartContractRuntimeApi;
+
+/// Resources requested for a deployment.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ResourcesInput {
+    pub hru: u64,
+    pub sru: u64,
+    pub cru: u64,
+    pub mru: u64,
+}
+
+impl From<ResourcesInput> for Resources {
+    fn from(input: ResourcesInput) -> Self {
+        Resources {
+            hru: input.hru,
+            sru: input.sru,
+            cru: input.cru,
+            mru: input.mru,
+        }
+    }
+}
+
+/// Estimated cost of a deployment for one billing reference period.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ContractCostEstimate<Balance> {
+    /// Cost in mUSD.
+    pub cost_musd: u64,
+    /// Cost in TFT, discount and certification applied.
+    pub cost_tft: Balance,
+    /// Discount level the twin would get given its current balance.
+    pub discount_level: String,
+}
+
+/// A bill of a contract.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ContractBill {
+    /// Id of the billed contract.
+    pub contract_id: u64,
+    /// Time of billing in seconds since unix epoch.
+    pub timestamp: u64,
+    /// Discount level applied to the bill.
+    pub discount_level: String,
+    /// Amount billed in TFT.
+    pub amount_billed: u128,
+}
+
+/// Total amount billed to a twin on a single day.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TwinDailyBill {
+    /// Number of days since unix epoch.
+    pub day: u64,
+    /// Amount billed in TFT.
+    pub amount_billed: u128,
+}
+
+/// A contract in grace period.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ContractInGrace {
+    /// Id of the contract.
+    pub contract_id: u64,
+    /// Block at which the grace period started.
+    pub grace_period_start: u64,
+    /// Amount of blocks left before the contract gets removed.
+    pub remaining_blocks: u64,
+}
+
+/// Total amount earned by a solution provider.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SolutionProviderEarnings<AccountId, Balance> {
+    /// Id of the solution provider.
+    pub solution_provider_id: u64,
+    /// Amount earned by each provider account.
+    pub providers: Vec<ProviderEarnings<AccountId, Balance>>,
+    /// Amount earned by the providers from each contract.
+    pub contracts: Vec<ContractEarnings<Balance>>,
+}
+
+/// Amount earned by a provider account of a solution provider.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ProviderEarnings<AccountId, Balance> {
+    /// Account of the provider.
+    pub who: AccountId,
+    /// Amount earned in TFT.
+    pub earned: Balance,
+}
+
+/// Amount earned by the providers of a solution provider from a contract.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ContractEarnings<Balance> {
+    /// Id of the contract.
+    pub contract_id: u64,
+    /// Amount earned in TFT.
+    pub earned: Balance,
+}
+
+/// Availability of a name for a name contract.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct NameAvailability {
+    /// Whether the name can be registered.
+    pub available: bool,
+    /// One of Available, Invalid, Registered, Held, Reserved or Blocked.
+    pub status: String,
+    /// Id of the name contract the name is registered with, if any.
+    pub contract_id: Option<u64>,
+    /// Time in seconds since unix epoch at which the registration expires, if it has a term.
+    pub expires_at: Option<u64>,
+    /// Time in seconds since unix epoch at which a held name gets released.
+    pub released_at: Option<u64>,
+    /// Twin a reserved name is assigned to, if any.
+    pub twin_id: Option<u32>,
+}
+
+impl From<pallet_smart_contract_rpc_runtime_api::NameAvailability> for NameAvailability {
+    fn from(availability: pallet_smart_contract_rpc_runtime_api::NameAvailability) -> Self {
+        use pallet_smart_contract_rpc_runtime_api::NameAvailability as Availability;
+
+        let mut result = NameAvailability {
+            available: false,
+            status: String::new(),
+            contract_id: None,
+            expires_at: None,
+            released_at: None,
+            twin_id: None,
+        };
+        match availability {
+            Availability::Available => {
+                result.available = true;
+                result.status = "Available".into();
+            }
+            Availability::Invalid => result.status = "Invalid".into(),
+            Availability::Registered {
+                contract_id,
+                expires_at,
+            } => {
+                result.status = "Registered".into();
+                result.contract_id = Some(contract_id);
+                result.expires_at = expires_at;
+            }
+            Availability::Held {
+                contract_id,
+                released_at,
+            } => {
+                result.status = "Held".into();
+                result.contract_id = Some(contract_id);
+                result.released_at = Some(released_at);
+            }
+            Availability::Reserved { twin_id } => {
+                result.status = "Reserved".into();
+                result.twin_id = twin_id;
+            }
+            Availability::Blocked => result.status = "Blocked".into(),
+        }
+        result
+    }
+}
+
+#[rpc(client, server)]
+pub trait SmartContractApi<BlockHash, AccountId, Balance> {
+    /// Estimate the cost of a node contract before deploying it.
+    #[method(name = "smartContract_estimateContractCost")]
+    fn estimate_contract_cost(
+        &self,
+        node_id: u32,
+        twin_id: u32,
+        resources: ResourcesInput,
+        public_ips: u32,
+        at: Option<BlockHash>,
+    ) -> RpcResult<ContractCostEstimate<Balance>>;
+
+    /// Get the last bills of a contract, oldest first.
+    #[method(name = "smartContract_contractBillingHistory")]
+    fn contract_billing_history(
+        &self,
+        contract_id: u64,
+        at: Option<BlockHash>,
+    ) -> RpcResult<Vec<ContractBill>>;
+
+    /// Get the amount billed to a twin per day, oldest first.
+    #[method(name = "smartContract_twinBillingHistory")]
+    fn twin_billing_history(
+        &self,
+        twin_id: u32,
+        at: Option<BlockHash>,
+    ) -> RpcResult<Vec<TwinDailyBill>>;
+
+    /// Get the contracts of a twin that are in grace period.
+    #[method(name = "smartContract_contractsInGrace")]
+    fn contracts_in_grace(
+        &self,
+        twin_id: u32,
+        at: Option<BlockHash>,
+    ) -> RpcResult<Vec<ContractInGrace>>;
+
+    /// Get the total amount earned by a solution provider, per provider account and per contract.
+    #[method(name = "smartContract_solutionProviderEarnings")]
+    fn solution_provider_earnings(
+        &self,
+        solution_provider_id: u64,
+        at: Option<BlockHash>,
+    ) -> RpcResult<SolutionProviderEarnings<AccountId, Balance>>;
+
+    /// Get the solution provider shares accrued by an account that were not claimed yet.
+    #[method(name = "smartContract_unclaimedSolutionProviderRewards")]
+    fn unclaimed_solution_provider_rewards(
+        &self,
+        account_id: AccountId,
+        at: Option<BlockHash>,
+    ) -> RpcResult<Balance>;
+
+    /// Check whether a name can be registered with a name contract.
+    #[method(name = "smartContract_nameAvailability")]
+    fn name_availability(&self, name: String, at: Option<BlockHash>)
+        -> RpcResult<NameAvailability>;
+}
+
+/// Provides RPC methods to query smart contract related data.
+pub struct SmartContract<C, P> {
+    client: Arc<C>,
+    _marker: std::marker::PhantomData<P>,
+}
+
+impl<C, P> SmartContract<C, P> {
+    /// Creates a new instance of the SmartContract Rpc helper.
+    pub fn new(client: Arc<C>) -> Self {
+        Self {
+            client,
+            _marker: Default::default(),
+        }
+    }
+}
+
+/// Error type of this RPC api.
+pub enum Error {
+    /// The call to runtime failed.
+    RuntimeError,
+    /// The runtime could not estimate the cost.
+    EstimationError,
+}
+
+impl From<Error> for i32 {
+    fn from(e: Error) -> i32 {
+        match e {
+            Error::RuntimeError => 1,
+            Error::EstimationError => 2,
+        }
+    }
+}
+
+#[async_trait]
+impl<C, Block, AccountId, Balance>
+    SmartContractApiServer<<Block as BlockT>::Hash, AccountId, Balance> for SmartContract<C, Block>
+where
+    Block: BlockT,
+    C: ProvideRuntimeApi<Block> + HeaderBackend<Block> + Send + Sync + 'static,
+    C::Api: SmartContractRuntimeApi<Block, AccountId, Balance>,
+    AccountId: Codec + Serialize + DeserializeOwned + Send + Sync + 'static,
+    Balance: Codec + Serialize + Send + Sync + 'static,
+{
+    fn estimate_contract_cost(
+        &self,
+        node_id: u32,
+        twin_id: u32,
+        resources: ResourcesInput,
+        public_ips: u32,
+        at: Option<Block::Hash>,
+    ) -> RpcResult<ContractCostEstimate<Balance>> {
+        let api = self.client.runtime_api();
+        let at_hash = at.unwrap_or_else(|| self.client.info().best_hash);
+
+        let estimate = api
+            .estimate_contract_cost(at_hash, node_id, twin_id, resources.into(), public_ips)
+            .map_err(runtime_error_into_rpc_error)?
+            .map_err(|e| {
+                CallError::Custom(ErrorObject::owned(
+                    Error::EstimationError.into(),
+                    "Unable to estimate contract cost.",
+                    Some(format!("{:?}", e)),
+                ))
+            })?;
+
+        Ok(ContractCostEstimate {
+            cost_musd: estimate.cost_musd,
+            cost_tft: estimate.cost_tft,
+            discount_level: format!("{:?}", estimate.discount_level),
+        })
+    }
+
+    fn contract_billing_history(
+        &self,
+        contract_id: u64,
+        at: Option<Block::Hash>,
+    ) -> RpcResult<Vec<ContractBill>> {
+        let api = self.client.runtime_api();
+        let at_hash = at.unwrap_or_else(|| self.client.info().best_hash);
+
+        let history = api
+            .contract_billing_history(at_hash, contract_id)
+            .map_err(runtime_error_into_rpc_error)?;
+
+        Ok(history
+            .into_iter()
+            .map(|bill| ContractBill {
+                contract_id: bill.contract_id,
+                timestamp: bill.timestamp,
+                discount_level: format!("{:?}", bill.discount_level),
+                amount_billed: bill.amount_billed,
+            })
+            .collect())
+    }
+
+    fn twin_billing_history(
+        &self,
+        twin_id: u32,
+        at: Option<Block::Hash>,
+    ) -> RpcResult<Vec<TwinDailyBill>> {
+        let api = self.client.runtime_api();
+        let at_hash = at.unwrap_or_else(|| self.client.info().best_hash);
+
+        let history = api
+            .twin_billing_history(at_hash, twin_id)
+            .map_err(runtime_error_into_rpc_error)?;
+
+        Ok(history
+            .into_iter()
+            .map(|daily_bill| TwinDailyBill {
+                day: daily_bill.day,
+                amount_billed: daily_bill.amount_billed,
+            })
+            .collect())
+    }
+
+    fn contracts_in_grace(
+        &self,
+        twin_id: u32,
+        at: Option<Block::Hash>,
+    ) -> RpcResult<Vec<ContractInGrace>> {
+        let api = self.client.runtime_api();
+        let at_hash = at.unwrap_or_else(|| self.client.info().best_hash);
+
+        let contracts = api
+            .contracts_in_grace(at_hash, twin_id)
+            .map_err(runtime_error_into_rpc_error)?;
+
+        Ok(contracts
+            .into_iter()
+            .map(|contract| ContractInGrace {
+                contract_id: contract.contract_id,
+                grace_period_start: contract.grace_period_start,
+                remaining_blocks: contract.remaining_blocks,
+            })
+            .collect())
+    }
+
+    fn solution_provider_earnings(
+        &self,
+        solution_provider_id: u64,
+        at: Option<Block::Hash>,
+    ) -> RpcResult<SolutionProviderEarnings<AccountId, Balance>> {
+        let api = self.client.runtime_api();
+        let at_hash = at.unwrap_or_else(|| self.client.info().best_hash);
+
+        let earnings = api
+            .solution_provider_earnings(at_hash, solution_provider_id)
+            .map_err(runtime_error_into_rpc_error)?;
+
+        Ok(SolutionProviderEarnings {
+            solution_provider_id: earnings.solution_provider_id,
+            providers: earnings
+                .providers
+                .into_iter()
+                .map(|provider| ProviderEarnings {
+                    who: provider.who,
+                    earned: provider.earned,
+                })
+                .collect(),
+            contracts: earnings
+                .contracts
+                .into_iter()
+                .map(|contract| ContractEarnings {
+                    contract_id: contract.contract_id,
+                    earned: contract.earned,
+                })
+                .collect(),
+        })
+    }
+
+    fn unclaimed_solution_provider_rewards(
+        &self,
+        account_id: AccountId,
+        at: Option<Block::Hash>,
+    ) -> RpcResult<Balance> {
+        let api = self.client.runtime_api();
+        let at_hash = at.unwrap_or_else(|| self.client.info().best_hash);
+
+        let unclaimed = api
+            .unclaimed_solution_provider_rewards(at_hash, account_id)
+            .map_err(runtime_error_into_rpc_error)?;
+
+        Ok(unclaimed)
+    }
+
+    fn name_availability(
+        &self,
+        name: String,
+        at: Option<Block::Hash>,
+    ) -> RpcResult<NameAvailability> {
+        let api = self.client.runtime_api();
+        let at_hash = at.unwrap_or_else(|| self.client.info().best_hash);
+
+        let availability = api
+            .name_availability(at_hash, name.into_bytes())
+            .map_err(runtime_error_into_rpc_error)?;
+
+        Ok(availability.into())
+    }
+}
+
+/// Converts a runtime trap into an RPC error.
+fn runtime_error_into_rpc_error(err: impl std::fmt::Display) -> CallError {
+    CallError::Custom(ErrorObject::owned(
+        Error::RuntimeError.into(),
+        "Runtime error",
+        Some(err.to_string()),
+    ))
+}
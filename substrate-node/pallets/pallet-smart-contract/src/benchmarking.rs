@@ -5,7 +5,7 @@ use crate::Pallet as SmartContractModule;
 use frame_benchmarking::{account, benchmarks, whitelisted_caller};
 use frame_support::{
     assert_ok,
-    traits::{OnFinalize, OnInitialize},
+    traits::{Get, OnFinalize, OnInitialize},
     BoundedVec,
 };
 use frame_system::{pallet_prelude::BlockNumberFor, EventRecord, Pallet as System, RawOrigin};
@@ -16,7 +16,7 @@ use pallet_tfgrid::{
     LatitudeInput, LongitudeInput, Pallet as TfgridModule, PkInput, RelayInput, ResourcesInput,
 };
 use sp_runtime::{
-    traits::{Bounded, One, StaticLookup},
+    traits::{Bounded, One, StaticLookup, Zero},
     SaturatedConversion,
 };
 use sp_std::{
@@ -27,7 +27,7 @@ use sp_std::{
 };
 use tfchain_support::{
     resources::Resources,
-    types::{FarmCertification, NodeCertification, IP4},
+    types::{FarmCertification, NodeCertification, Power, IP4},
 };
 
 const GIGABYTE: u64 = 1024 * 1024 * 1024;
@@ -503,6 +503,875 @@ benchmarks! {
          }.into());
     }
 
+    // set_contract_spending_cap()
+    set_contract_spending_cap {
+        let farmer: T::AccountId = account("Alice", 0, 0);
+        _prepare_farm_with_node::<T>(farmer.clone());
+
+        let user: T::AccountId = whitelisted_caller();
+        _create_twin::<T>(user.clone());
+        _create_node_contract::<T>(user.clone());
+        let contract_id = 1;
+        let amount: BalanceOf<T> = 1000000_u64.saturated_into();
+        let period = 3600;
+        let action = types::SpendingCapAction::Pause;
+
+    }: _(RawOrigin::Signed(user), contract_id, amount, period, action)
+    verify {
+        let cap = SmartContractModule::<T>::contract_spending_cap(contract_id).unwrap();
+        assert_eq!(cap.amount, amount);
+        assert_eq!(cap.period, period);
+        assert_eq!(cap.action, action);
+    }
+
+    // clear_contract_spending_cap()
+    clear_contract_spending_cap {
+        let farmer: T::AccountId = account("Alice", 0, 0);
+        _prepare_farm_with_node::<T>(farmer.clone());
+
+        let user: T::AccountId = whitelisted_caller();
+        _create_twin::<T>(user.clone());
+        _create_node_contract::<T>(user.clone());
+        let contract_id = 1;
+        assert_ok!(SmartContractModule::<T>::set_contract_spending_cap(
+            RawOrigin::Signed(user.clone()).into(),
+            contract_id,
+            1000000_u64.saturated_into(),
+            3600,
+            types::SpendingCapAction::Grace,
+        ));
+
+    }: _(RawOrigin::Signed(user), contract_id)
+    verify {
+        assert!(SmartContractModule::<T>::contract_spending_cap(contract_id).is_none());
+        assert_last_event::<T>(Event::ContractSpendingCapCleared { contract_id }.into());
+    }
+
+    // set_twin_spending_cap()
+    set_twin_spending_cap {
+        let user: T::AccountId = whitelisted_caller();
+        _create_twin::<T>(user.clone());
+        let twin_id = 1;
+        let amount: BalanceOf<T> = 1000000_u64.saturated_into();
+        let period = 3600;
+        let action = types::SpendingCapAction::Pause;
+
+    }: _(RawOrigin::Signed(user), amount, period, action)
+    verify {
+        let cap = SmartContractModule::<T>::twin_spending_cap(twin_id).unwrap();
+        assert_eq!(cap.amount, amount);
+        assert_eq!(cap.period, period);
+        assert_eq!(cap.action, action);
+    }
+
+    // clear_twin_spending_cap()
+    clear_twin_spending_cap {
+        let user: T::AccountId = whitelisted_caller();
+        _create_twin::<T>(user.clone());
+        let twin_id = 1;
+        assert_ok!(SmartContractModule::<T>::set_twin_spending_cap(
+            RawOrigin::Signed(user.clone()).into(),
+            1000000_u64.saturated_into(),
+            3600,
+            types::SpendingCapAction::Grace,
+        ));
+
+    }: _(RawOrigin::Signed(user))
+    verify {
+        assert!(SmartContractModule::<T>::twin_spending_cap(twin_id).is_none());
+        assert_last_event::<T>(Event::TwinSpendingCapCleared { twin_id }.into());
+    }
+
+    // set_contract_kind_grace_period()
+    set_contract_kind_grace_period {
+        let kind = types::ContractKind::Node;
+        let grace_period = Some(1000);
+    }: _(RawOrigin::Root, kind.clone(), grace_period)
+    verify {
+        assert_eq!(SmartContractModule::<T>::contract_kind_grace_period(kind.clone()), grace_period);
+        assert_last_event::<T>(Event::ContractKindGracePeriodSet { kind, grace_period }.into());
+    }
+
+    // set_solution_provider_grace_period()
+    set_solution_provider_grace_period {
+        let a1: T::AccountId = account("Alice", 0, 0);
+        _create_solution_provider::<T>(a1);
+        let solution_provider_id = 1;
+        let grace_period = Some(1000);
+    }: _(RawOrigin::Root, solution_provider_id, grace_period)
+    verify {
+        assert_eq!(
+            SmartContractModule::<T>::solution_provider_grace_period(solution_provider_id),
+            grace_period
+        );
+        assert_last_event::<T>(Event::SolutionProviderGracePeriodSet {
+            solution_provider_id,
+            grace_period,
+        }.into());
+    }
+
+    // restore_contracts_from_grace()
+    restore_contracts_from_grace {
+        let n in 1 .. T::MaxContractsRestoredFromGrace::get();
+
+        let farmer: T::AccountId = account("Alice", 0, 0);
+        _prepare_farm_with_node::<T>(farmer.clone());
+
+        let user: T::AccountId = whitelisted_caller();
+        let user_lookup = T::Lookup::unlookup(user.clone());
+        let balance_init_amount = <T as pallet_balances::Config>::Balance::saturated_from(100000000000 as u128);
+        Balances::<T>::force_set_balance(RawOrigin::Root.into(), user_lookup, balance_init_amount).unwrap();
+        _create_twin::<T>(user.clone());
+        let twin_id = 2;
+
+        let current_block = System::<T>::block_number().saturated_into::<u64>();
+        for i in 0..n {
+            let name = vec![b'n', b'a', b'm', b'e', b'a' + (i / 26) as u8, b'a' + (i % 26) as u8];
+            assert_ok!(SmartContractModule::<T>::create_name_contract(
+                RawOrigin::Signed(user.clone()).into(),
+                name
+            ));
+            let mut contract = SmartContractModule::<T>::contracts(i as u64 + 1).unwrap();
+            SmartContractModule::<T>::update_contract_state(
+                &mut contract,
+                &types::ContractState::GracePeriod(current_block),
+            ).unwrap();
+        }
+
+        let now = SmartContractModule::<T>::get_current_timestamp_in_secs();
+        let then: u64 = now + 3600;
+        pallet_timestamp::Pallet::<T>::set_timestamp((then * 1000).try_into().unwrap());
+    }: _(RawOrigin::Signed(user), twin_id)
+    verify {
+        assert!(TwinContractsInGrace::<T>::iter_key_prefix(twin_id).next().is_none());
+        for i in 0..n {
+            let contract = SmartContractModule::<T>::contracts(i as u64 + 1).unwrap();
+            assert_eq!(contract.state, types::ContractState::Created);
+        }
+    }
+
+    // create_node_contracts_batch()
+    create_node_contracts_batch {
+        let n in 1 .. T::MaxNodeContractsInBatch::get();
+
+        let farmer: T::AccountId = account("Alice", 0, 0);
+        _prepare_farm_with_node::<T>(farmer);
+        let node_id = 1;
+
+        let caller: T::AccountId = whitelisted_caller();
+        _create_twin::<T>(caller.clone());
+
+        let contracts: BoundedVec<types::NodeContractInput<T>, T::MaxNodeContractsInBatch> = (0..n)
+            .map(|i| {
+                let mut deployment_hash = get_deployment_hash_input(b"858f8fb2184b15ecb8c0be8b95398c81");
+                deployment_hash[0] = i as u8;
+                types::NodeContractInput {
+                    node_id,
+                    deployment_hash,
+                    deployment_data: get_deployment_data_input::<T>(b"some_data"),
+                    public_ips: 0,
+                    resources: None,
+                }
+            })
+            .collect::<Vec<_>>()
+            .try_into()
+            .unwrap();
+    }: _(RawOrigin::Signed(caller.clone()), contracts, None)
+    verify {
+        let contract_ids: Vec<u64> = (1..=n as u64).collect();
+        for contract_id in &contract_ids {
+            assert!(SmartContractModule::<T>::contracts(contract_id).is_some());
+        }
+        assert_last_event::<T>(Event::NodeContractsBatchCreated {
+            twin_id: 2,
+            contract_ids,
+        }.into());
+    }
+
+    // create_node_contract_with_resources()
+    create_node_contract_with_resources {
+        let farmer: T::AccountId = account("Alice", 0, 0);
+        _prepare_farm_with_node::<T>(farmer);
+        let node_id = 1;
+
+        let caller: T::AccountId = whitelisted_caller();
+        _create_twin::<T>(caller.clone());
+
+        let resources = Resources {
+            hru: 0,
+            sru: 100 * GIGABYTE,
+            cru: 2,
+            mru: 4 * GIGABYTE,
+        };
+    }: _(
+        RawOrigin::Signed(caller.clone()),
+        node_id,
+        get_deployment_hash_input(b"858f8fb2184b15ecb8c0be8b95398c81"),
+        get_deployment_data_input::<T>(b"some_data"),
+        1,
+        resources.clone(),
+        None
+    )
+    verify {
+        let contract_id = 1;
+        assert!(SmartContractModule::<T>::contracts(contract_id).is_some());
+        assert_eq!(SmartContractModule::<T>::node_reserved_resources(node_id), resources);
+        let contract = SmartContractModule::<T>::contracts(contract_id).unwrap();
+        assert_last_event::<T>(Event::ContractCreated(contract).into());
+    }
+
+    // offer_contract_transfer()
+    offer_contract_transfer {
+        let farmer: T::AccountId = account("Alice", 0, 0);
+        _prepare_farm_with_node::<T>(farmer.clone());
+
+        let caller: T::AccountId = whitelisted_caller();
+        _create_twin::<T>(caller.clone());
+        _create_node_contract::<T>(caller.clone());
+        let contract_id = 1;
+
+        let recipient: T::AccountId = account("Bob", 0, 1);
+        _create_twin::<T>(recipient);
+        let to_twin_id = 3;
+    }: _(RawOrigin::Signed(caller), contract_id, to_twin_id)
+    verify {
+        assert_eq!(SmartContractModule::<T>::contract_transfer_offer(contract_id), Some(to_twin_id));
+        assert_last_event::<T>(Event::ContractTransferOffered {
+            contract_id,
+            from_twin_id: 2,
+            to_twin_id,
+        }.into());
+    }
+
+    // cancel_contract_transfer()
+    cancel_contract_transfer {
+        let farmer: T::AccountId = account("Alice", 0, 0);
+        _prepare_farm_with_node::<T>(farmer.clone());
+
+        let caller: T::AccountId = whitelisted_caller();
+        _create_twin::<T>(caller.clone());
+        _create_node_contract::<T>(caller.clone());
+        let contract_id = 1;
+
+        let recipient: T::AccountId = account("Bob", 0, 1);
+        _create_twin::<T>(recipient);
+        assert_ok!(SmartContractModule::<T>::offer_contract_transfer(
+            RawOrigin::Signed(caller.clone()).into(),
+            contract_id,
+            3
+        ));
+    }: _(RawOrigin::Signed(caller), contract_id)
+    verify {
+        assert!(SmartContractModule::<T>::contract_transfer_offer(contract_id).is_none());
+        assert_last_event::<T>(Event::ContractTransferCanceled { contract_id }.into());
+    }
+
+    // accept_contract_transfer()
+    accept_contract_transfer {
+        let farmer: T::AccountId = account("Alice", 0, 0);
+        _prepare_farm_with_node::<T>(farmer.clone());
+
+        let caller: T::AccountId = whitelisted_caller();
+        let caller_lookup = T::Lookup::unlookup(caller.clone());
+        let balance_init_amount = <T as pallet_balances::Config>::Balance::saturated_from(100000000000 as u128);
+        Balances::<T>::force_set_balance(RawOrigin::Root.into(), caller_lookup, balance_init_amount).unwrap();
+        _create_twin::<T>(caller.clone());
+        _create_node_contract::<T>(caller.clone());
+        let contract_id = 1;
+
+        let recipient: T::AccountId = account("Bob", 0, 1);
+        _create_twin::<T>(recipient.clone());
+        let to_twin_id = 3;
+        assert_ok!(SmartContractModule::<T>::offer_contract_transfer(
+            RawOrigin::Signed(caller).into(),
+            contract_id,
+            to_twin_id
+        ));
+
+        let now = SmartContractModule::<T>::get_current_timestamp_in_secs();
+        let then: u64 = now + 3600;
+        pallet_timestamp::Pallet::<T>::set_timestamp((then * 1000).try_into().unwrap());
+    }: _(RawOrigin::Signed(recipient), contract_id)
+    verify {
+        let contract = SmartContractModule::<T>::contracts(contract_id).unwrap();
+        assert_eq!(contract.twin_id, to_twin_id);
+        assert_last_event::<T>(Event::ContractTransferred {
+            contract_id,
+            from_twin_id: 2,
+            to_twin_id,
+        }.into());
+    }
+
+    // service_contract_set_dispute_window()
+    service_contract_set_dispute_window {
+        let service: T::AccountId = account("Alice", 0, 0);
+        let consumer: T::AccountId = account("Bob", 0, 1);
+        _prepare_service_contract::<T>(service.clone(), consumer);
+        let contract_id = 1;
+        let dispute_window = Some(3600);
+    }: _(RawOrigin::Signed(service), contract_id, dispute_window)
+    verify {
+        assert_eq!(SmartContractModule::<T>::service_contract_dispute_window(contract_id), dispute_window);
+        assert_last_event::<T>(Event::ServiceContractDisputeWindowSet {
+            service_contract_id: contract_id,
+            dispute_window,
+        }.into());
+    }
+
+    // service_contract_dispute_bill()
+    service_contract_dispute_bill {
+        let service: T::AccountId = account("Alice", 0, 0);
+        let consumer: T::AccountId = account("Bob", 0, 1);
+        _prepare_escrowed_service_contract_bill::<T>(service, consumer.clone());
+        let contract_id = 1;
+        let bill_id = 1;
+    }: _(RawOrigin::Signed(consumer), contract_id, bill_id)
+    verify {
+        let escrowed_bill = SmartContractModule::<T>::service_contract_escrowed_bills(contract_id, bill_id).unwrap();
+        assert!(escrowed_bill.disputed);
+        assert_last_event::<T>(Event::ServiceContractBillDisputed {
+            service_contract_id: contract_id,
+            bill_id,
+        }.into());
+    }
+
+    // service_contract_resolve_dispute()
+    service_contract_resolve_dispute {
+        let service: T::AccountId = account("Alice", 0, 0);
+        let consumer: T::AccountId = account("Bob", 0, 1);
+        _prepare_escrowed_service_contract_bill::<T>(service.clone(), consumer.clone());
+        let contract_id = 1;
+        let bill_id = 1;
+        assert_ok!(SmartContractModule::<T>::service_contract_dispute_bill(
+            RawOrigin::Signed(consumer.clone()).into(),
+            contract_id,
+            bill_id,
+        ));
+        let amount = SmartContractModule::<T>::service_contract_escrowed_bills(contract_id, bill_id).unwrap().amount;
+        let refund = amount / 2u32.into();
+        assert_ok!(SmartContractModule::<T>::service_contract_resolve_dispute(
+            RawOrigin::Signed(consumer).into(),
+            contract_id,
+            bill_id,
+            refund,
+        ));
+    }: _(RawOrigin::Signed(service), contract_id, bill_id, refund)
+    verify {
+        assert!(SmartContractModule::<T>::service_contract_escrowed_bills(contract_id, bill_id).is_none());
+        assert_last_event::<T>(Event::ServiceContractDisputeResolved {
+            service_contract_id: contract_id,
+            bill_id,
+            refund,
+            paid: amount - refund,
+        }.into());
+    }
+
+    // service_contract_force_resolve_dispute()
+    service_contract_force_resolve_dispute {
+        let service: T::AccountId = account("Alice", 0, 0);
+        let consumer: T::AccountId = account("Bob", 0, 1);
+        _prepare_escrowed_service_contract_bill::<T>(service, consumer.clone());
+        let contract_id = 1;
+        let bill_id = 1;
+        assert_ok!(SmartContractModule::<T>::service_contract_dispute_bill(
+            RawOrigin::Signed(consumer).into(),
+            contract_id,
+            bill_id,
+        ));
+        let amount = SmartContractModule::<T>::service_contract_escrowed_bills(contract_id, bill_id).unwrap().amount;
+        let refund = amount / 2u32.into();
+    }: _(RawOrigin::Root, contract_id, bill_id, refund)
+    verify {
+        assert!(SmartContractModule::<T>::service_contract_escrowed_bills(contract_id, bill_id).is_none());
+        assert_last_event::<T>(Event::ServiceContractDisputeResolved {
+            service_contract_id: contract_id,
+            bill_id,
+            refund,
+            paid: amount - refund,
+        }.into());
+    }
+
+    // service_contract_release_bill()
+    service_contract_release_bill {
+        let service: T::AccountId = account("Alice", 0, 0);
+        let consumer: T::AccountId = account("Bob", 0, 1);
+        _prepare_escrowed_service_contract_bill::<T>(service.clone(), consumer);
+        let contract_id = 1;
+        let bill_id = 1;
+        let amount = SmartContractModule::<T>::service_contract_escrowed_bills(contract_id, bill_id).unwrap().amount;
+
+        let now = SmartContractModule::<T>::get_current_timestamp_in_secs();
+        let then: u64 = now + 7200;
+        pallet_timestamp::Pallet::<T>::set_timestamp((then * 1000).try_into().unwrap());
+    }: _(RawOrigin::Signed(service), contract_id, bill_id)
+    verify {
+        assert!(SmartContractModule::<T>::service_contract_escrowed_bills(contract_id, bill_id).is_none());
+        assert_last_event::<T>(Event::ServiceContractBillReleased {
+            service_contract_id: contract_id,
+            bill_id,
+            amount,
+        }.into());
+    }
+
+    // bill_service_contract_for_block()
+    bill_service_contract_for_block {
+        let service: T::AccountId = account("Alice", 0, 0);
+        let consumer: T::AccountId = account("Bob", 0, 1);
+        let consumer_lookup = T::Lookup::unlookup(consumer.clone());
+        let balance_init_amount = <T as pallet_balances::Config>::Balance::saturated_from(100000000 as u128);
+        Balances::<T>::force_set_balance(RawOrigin::Root.into(), consumer_lookup, balance_init_amount).unwrap();
+        _prepare_and_approve_service_contract::<T>(service, consumer);
+        let contract_id = 1;
+
+        let now = SmartContractModule::<T>::get_current_timestamp_in_secs();
+        let then: u64 = now + 3600;
+        pallet_timestamp::Pallet::<T>::set_timestamp((then * 1000).try_into().unwrap());
+
+        let caller: T::AccountId = whitelisted_caller();
+    }: _(RawOrigin::Signed(caller), contract_id)
+    verify {
+        let contract = SmartContractModule::<T>::service_contracts(contract_id).unwrap();
+        assert_eq!(contract.last_bill, then);
+        let bill = types::ServiceContractBill {
+            variable_amount: 0,
+            window: 3600,
+            metadata: BoundedVec::default(),
+        };
+        let amount = contract
+            .calculate_bill_cost_tft::<T>(bill.clone())
+            .unwrap();
+        assert_last_event::<T>(Event::ServiceContractBilled {
+            service_contract: contract,
+            bill,
+            amount,
+        }.into());
+    }
+
+    // service_contract_propose_amendment()
+    service_contract_propose_amendment {
+        let service: T::AccountId = account("Alice", 0, 0);
+        let consumer: T::AccountId = account("Bob", 0, 1);
+        _prepare_and_approve_service_contract::<T>(service.clone(), consumer);
+        let contract_id = 1;
+        let base_fee = 2000;
+        let variable_fee = 2000;
+        let metadata = b"new_metadata".to_vec();
+    }: _(RawOrigin::Signed(service), contract_id, base_fee, variable_fee, metadata.clone())
+    verify {
+        let amendment = types::ServiceContractAmendment {
+            proposed_by: 1,
+            base_fee,
+            variable_fee,
+            metadata: BoundedVec::try_from(metadata).unwrap(),
+        };
+        assert_eq!(SmartContractModule::<T>::service_contract_amendment(contract_id), Some(amendment.clone()));
+        assert_last_event::<T>(Event::ServiceContractAmendmentProposed {
+            service_contract_id: contract_id,
+            amendment,
+        }.into());
+    }
+
+    // service_contract_accept_amendment()
+    service_contract_accept_amendment {
+        let service: T::AccountId = account("Alice", 0, 0);
+        let consumer: T::AccountId = account("Bob", 0, 1);
+        let consumer_lookup = T::Lookup::unlookup(consumer.clone());
+        let balance_init_amount = <T as pallet_balances::Config>::Balance::saturated_from(100000000 as u128);
+        Balances::<T>::force_set_balance(RawOrigin::Root.into(), consumer_lookup, balance_init_amount).unwrap();
+        _prepare_and_approve_service_contract::<T>(service.clone(), consumer.clone());
+        let contract_id = 1;
+        assert_ok!(SmartContractModule::<T>::service_contract_propose_amendment(
+            RawOrigin::Signed(service).into(),
+            contract_id,
+            2000,
+            2000,
+            b"new_metadata".to_vec(),
+        ));
+
+        // Old terms are billed for the elapsed time first
+        let now = SmartContractModule::<T>::get_current_timestamp_in_secs();
+        let then: u64 = now + 3600;
+        pallet_timestamp::Pallet::<T>::set_timestamp((then * 1000).try_into().unwrap());
+    }: _(RawOrigin::Signed(consumer), contract_id)
+    verify {
+        let contract = SmartContractModule::<T>::service_contracts(contract_id).unwrap();
+        assert_eq!(contract.base_fee, 2000);
+        assert_eq!(contract.last_bill, then);
+        assert!(SmartContractModule::<T>::service_contract_amendment(contract_id).is_none());
+        assert_last_event::<T>(Event::ServiceContractAmended(contract).into());
+    }
+
+    // service_contract_reject_amendment()
+    service_contract_reject_amendment {
+        let service: T::AccountId = account("Alice", 0, 0);
+        let consumer: T::AccountId = account("Bob", 0, 1);
+        _prepare_and_approve_service_contract::<T>(service.clone(), consumer.clone());
+        let contract_id = 1;
+        assert_ok!(SmartContractModule::<T>::service_contract_propose_amendment(
+            RawOrigin::Signed(service).into(),
+            contract_id,
+            2000,
+            2000,
+            b"new_metadata".to_vec(),
+        ));
+    }: _(RawOrigin::Signed(consumer), contract_id)
+    verify {
+        assert!(SmartContractModule::<T>::service_contract_amendment(contract_id).is_none());
+        assert_last_event::<T>(Event::ServiceContractAmendmentRejected {
+            service_contract_id: contract_id,
+            twin_id: 2,
+        }.into());
+    }
+
+    // service_plan_create()
+    service_plan_create {
+        let service: T::AccountId = account("Alice", 0, 0);
+        _create_twin::<T>(service.clone());
+        let metadata = b"some_metadata".to_vec();
+    }: _(RawOrigin::Signed(service), 1000, 1000, metadata.clone())
+    verify {
+        let plan_id = 1;
+        let plan = SmartContractModule::<T>::service_plans(plan_id).unwrap();
+        assert_eq!(plan.metadata, metadata);
+        assert_last_event::<T>(Event::ServicePlanCreated(plan).into());
+    }
+
+    // service_plan_update()
+    service_plan_update {
+        let service: T::AccountId = account("Alice", 0, 0);
+        _create_service_plan::<T>(service.clone());
+        let plan_id = 1;
+    }: _(RawOrigin::Signed(service), plan_id, 2000, 2000, b"new_metadata".to_vec())
+    verify {
+        let plan = SmartContractModule::<T>::service_plans(plan_id).unwrap();
+        assert_eq!(plan.base_fee, 2000);
+        assert_last_event::<T>(Event::ServicePlanUpdated(plan).into());
+    }
+
+    // service_plan_retire()
+    service_plan_retire {
+        let service: T::AccountId = account("Alice", 0, 0);
+        _create_service_plan::<T>(service.clone());
+        let plan_id = 1;
+    }: _(RawOrigin::Signed(service), plan_id)
+    verify {
+        let plan = SmartContractModule::<T>::service_plans(plan_id).unwrap();
+        assert!(plan.retired);
+        assert_last_event::<T>(Event::ServicePlanRetired { plan_id }.into());
+    }
+
+    // service_plan_subscribe()
+    service_plan_subscribe {
+        let service: T::AccountId = account("Alice", 0, 0);
+        _create_service_plan::<T>(service);
+        let plan_id = 1;
+        let consumer: T::AccountId = account("Bob", 0, 1);
+        _create_twin::<T>(consumer.clone());
+    }: _(RawOrigin::Signed(consumer), plan_id)
+    verify {
+        let service_contract_id = 1;
+        let contract = SmartContractModule::<T>::service_contracts(service_contract_id).unwrap();
+        assert!(matches!(contract.state, types::ServiceContractState::ApprovedByBoth));
+        assert_eq!(SmartContractModule::<T>::service_contract_plan(service_contract_id), Some(plan_id));
+        assert_last_event::<T>(Event::ServicePlanSubscribed {
+            plan_id,
+            service_contract_id,
+            consumer_twin_id: 2,
+        }.into());
+    }
+
+    // set_solution_provider_rewards_accrual()
+    set_solution_provider_rewards_accrual {
+        let provider: T::AccountId = account("Alice", 0, 0);
+    }: _(RawOrigin::Signed(provider.clone()), true)
+    verify {
+        assert!(SmartContractModule::<T>::solution_provider_rewards_accrual(&provider));
+        assert_last_event::<T>(Event::SolutionProviderRewardsAccrualSet {
+            account_id: provider,
+            accrue: true,
+        }.into());
+    }
+
+    // claim_solution_provider_rewards()
+    claim_solution_provider_rewards {
+        let provider: T::AccountId = account("Alice", 0, 0);
+        let rewards_account = SmartContractModule::<T>::solution_provider_rewards_account();
+        let rewards_lookup = T::Lookup::unlookup(rewards_account);
+        let balance_init_amount = <T as pallet_balances::Config>::Balance::saturated_from(200000000 as u128);
+        Balances::<T>::force_set_balance(RawOrigin::Root.into(), rewards_lookup, balance_init_amount).unwrap();
+
+        let amount = BalanceOf::<T>::saturated_from(100000000 as u128);
+        UnclaimedSolutionProviderRewards::<T>::insert(&provider, amount);
+    }: _(RawOrigin::Signed(provider.clone()))
+    verify {
+        assert_eq!(SmartContractModule::<T>::unclaimed_solution_provider_rewards(&provider), BalanceOf::<T>::zero());
+        assert_eq!(Balances::<T>::free_balance(&provider), amount);
+        assert_last_event::<T>(Event::SolutionProviderRewardsClaimed {
+            account_id: provider,
+            amount,
+        }.into());
+    }
+
+    // update_solution_provider()
+    update_solution_provider {
+        let provider: T::AccountId = account("Alice", 0, 0);
+        _create_and_approve_solution_provider::<T>(provider.clone());
+        let solution_provider_id = 1;
+        let providers = vec![super::types::Provider {
+            take: 20,
+            who: provider.clone(),
+        }];
+    }: _(
+        RawOrigin::Signed(provider),
+        solution_provider_id,
+        b"other_description".to_vec(),
+        b"other_link".to_vec(),
+        providers.clone()
+    )
+    verify {
+        let solution_provider = SmartContractModule::<T>::solution_providers(solution_provider_id).unwrap();
+        assert_eq!(solution_provider.providers, providers);
+        assert!(!solution_provider.approved);
+        assert_last_event::<T>(Event::SolutionProviderUpdated(solution_provider).into());
+    }
+
+    // revoke_solution_provider()
+    revoke_solution_provider {
+        let provider: T::AccountId = account("Alice", 0, 0);
+        _create_and_approve_solution_provider::<T>(provider);
+        let solution_provider_id = 1;
+    }: _(RawOrigin::Root, solution_provider_id)
+    verify {
+        let solution_provider = SmartContractModule::<T>::solution_providers(solution_provider_id).unwrap();
+        assert!(solution_provider.revoked);
+        assert_last_event::<T>(Event::SolutionProviderRevoked(solution_provider_id).into());
+    }
+
+    // set_solution_provider_expiry()
+    set_solution_provider_expiry {
+        let provider: T::AccountId = account("Alice", 0, 0);
+        _create_and_approve_solution_provider::<T>(provider);
+        let solution_provider_id = 1;
+        let expires_at = Some(SmartContractModule::<T>::get_current_timestamp_in_secs() + 1000);
+    }: _(RawOrigin::Root, solution_provider_id, expires_at)
+    verify {
+        let solution_provider = SmartContractModule::<T>::solution_providers(solution_provider_id).unwrap();
+        assert_eq!(solution_provider.expires_at, expires_at);
+        assert_last_event::<T>(Event::SolutionProviderExpirySet {
+            solution_provider_id,
+            expires_at,
+        }.into());
+    }
+
+    // create_name_contract_with_term()
+    create_name_contract_with_term {
+        let caller: T::AccountId = whitelisted_caller();
+        _create_twin::<T>(caller.clone());
+        let term = 1000;
+    }: _(RawOrigin::Signed(caller.clone()), b"foobar".to_vec(), term)
+    verify {
+        let contract_id = 1;
+        let contract = SmartContractModule::<T>::contracts(contract_id).unwrap();
+        let now = SmartContractModule::<T>::get_current_timestamp_in_secs();
+        assert_eq!(SmartContractModule::<T>::name_contract_expiry(contract_id), Some(now + term));
+        assert_last_event::<T>(Event::ContractCreated(contract).into());
+    }
+
+    // renew_name_contract()
+    renew_name_contract {
+        let caller: T::AccountId = whitelisted_caller();
+        _create_twin::<T>(caller.clone());
+        let term = 1000;
+        assert_ok!(SmartContractModule::<T>::create_name_contract_with_term(
+            RawOrigin::Signed(caller.clone()).into(),
+            b"foobar".to_vec(),
+            term
+        ));
+        let contract_id = 1;
+        let expires_at = SmartContractModule::<T>::name_contract_expiry(contract_id).unwrap() + term;
+    }: _(RawOrigin::Signed(caller.clone()), contract_id, term)
+    verify {
+        assert_eq!(SmartContractModule::<T>::name_contract_expiry(contract_id), Some(expires_at));
+        assert_last_event::<T>(Event::NameContractRenewed {
+            contract_id,
+            expires_at,
+        }.into());
+    }
+
+    // set_name_contract_hold_period()
+    set_name_contract_hold_period {
+        let hold_period = 1000;
+    }: _(RawOrigin::Root, hold_period)
+    verify {
+        assert_eq!(SmartContractModule::<T>::name_contract_hold_period(), hold_period);
+        assert_last_event::<T>(Event::NameContractHoldPeriodSet { hold_period }.into());
+    }
+
+    // add_name_restriction()
+    add_name_restriction {
+        let matching = types::NameMatching::Prefix;
+        let restriction = types::NameRestriction::Blocked;
+    }: _(RawOrigin::Root, b"admin".to_vec(), matching, restriction)
+    verify {
+        let pattern = T::NameContractName::try_from(b"admin".to_vec()).unwrap();
+        assert_eq!(SmartContractModule::<T>::name_restrictions(matching, &pattern), Some(restriction));
+        assert_last_event::<T>(Event::NameRestrictionAdded {
+            pattern,
+            matching,
+            restriction,
+        }.into());
+    }
+
+    // remove_name_restriction()
+    remove_name_restriction {
+        let matching = types::NameMatching::Prefix;
+        assert_ok!(SmartContractModule::<T>::add_name_restriction(
+            RawOrigin::Root.into(),
+            b"admin".to_vec(),
+            matching,
+            types::NameRestriction::Blocked
+        ));
+    }: _(RawOrigin::Root, b"admin".to_vec(), matching)
+    verify {
+        let pattern = T::NameContractName::try_from(b"admin".to_vec()).unwrap();
+        assert_eq!(SmartContractModule::<T>::name_restrictions(matching, &pattern), None);
+        assert_last_event::<T>(Event::NameRestrictionRemoved {
+            pattern,
+            matching,
+        }.into());
+    }
+
+    // assign_reserved_name()
+    assign_reserved_name {
+        let caller: T::AccountId = whitelisted_caller();
+        _create_twin::<T>(caller.clone());
+        let twin_id = Some(1);
+        let matching = types::NameMatching::Exact;
+        assert_ok!(SmartContractModule::<T>::add_name_restriction(
+            RawOrigin::Root.into(),
+            b"admin".to_vec(),
+            matching,
+            types::NameRestriction::Reserved { twin_id: None }
+        ));
+    }: _(RawOrigin::Root, b"admin".to_vec(), matching, twin_id)
+    verify {
+        let pattern = T::NameContractName::try_from(b"admin".to_vec()).unwrap();
+        assert_eq!(
+            SmartContractModule::<T>::name_restrictions(matching, &pattern),
+            Some(types::NameRestriction::Reserved { twin_id })
+        );
+        assert_last_event::<T>(Event::ReservedNameAssigned {
+            pattern,
+            matching,
+            twin_id,
+        }.into());
+    }
+
+    // create_node_contract_with_public_ips()
+    create_node_contract_with_public_ips {
+        let farmer: T::AccountId = account("Alice", 0, 0);
+        _prepare_farm_with_node::<T>(farmer);
+        let node_id = 1;
+
+        let caller: T::AccountId = whitelisted_caller();
+        _create_twin::<T>(caller.clone());
+
+        let resources = Resources {
+            hru: 0,
+            sru: 100 * GIGABYTE,
+            cru: 2,
+            mru: 4 * GIGABYTE,
+        };
+        let public_ips_request = types::PublicIpsRequest::Ips(
+            vec![get_public_ip_ip_input(b"185.206.122.34/24")].try_into().unwrap()
+        );
+    }: _(
+        RawOrigin::Signed(caller.clone()),
+        node_id,
+        get_deployment_hash_input(b"858f8fb2184b15ecb8c0be8b95398c81"),
+        get_deployment_data_input::<T>(b"some_data"),
+        public_ips_request,
+        Some(resources),
+        None
+    )
+    verify {
+        let contract_id = 1;
+        let farm = TfgridModule::<T>::farms(1).unwrap();
+        assert_eq!(farm.public_ips[1].contract_id, contract_id);
+        let contract = SmartContractModule::<T>::contracts(contract_id).unwrap();
+        assert_last_event::<T>(Event::ContractCreated(contract).into());
+    }
+
+    // set_public_ip_price_multiplier()
+    set_public_ip_price_multiplier {
+        let farmer: T::AccountId = account("Alice", 0, 0);
+        _prepare_farm_with_node::<T>(farmer.clone());
+        let farm_id = 1;
+        let ip = get_public_ip_ip_input(b"185.206.122.33/24");
+        let multiplier = 200;
+    }: _(RawOrigin::Signed(farmer), farm_id, ip.clone(), multiplier)
+    verify {
+        assert_eq!(SmartContractModule::<T>::public_ip_price_multiplier(farm_id, &ip), multiplier);
+        assert_last_event::<T>(Event::PublicIpPriceMultiplierSet {
+            farm_id,
+            ip,
+            multiplier,
+        }.into());
+    }
+
+    // create_node_contract_with_ip6_subnets()
+    create_node_contract_with_ip6_subnets {
+        let farmer: T::AccountId = account("Alice", 0, 0);
+        _prepare_farm_with_node::<T>(farmer.clone());
+        _add_farm_ip6_prefix::<T>(farmer);
+        let node_id = 1;
+
+        let caller: T::AccountId = whitelisted_caller();
+        _create_twin::<T>(caller.clone());
+    }: _(
+        RawOrigin::Signed(caller.clone()),
+        node_id,
+        get_deployment_hash_input(b"858f8fb2184b15ecb8c0be8b95398c81"),
+        get_deployment_data_input::<T>(b"some_data"),
+        0,
+        1,
+        None,
+        None
+    )
+    verify {
+        let contract_id = 1;
+        assert_eq!(SmartContractModule::<T>::contract_ip6_subnets(contract_id).len(), 1);
+        let contract = SmartContractModule::<T>::contracts(contract_id).unwrap();
+        assert_last_event::<T>(Event::ContractCreated(contract).into());
+    }
+
+    request_node_wakeup {
+        let farmer: T::AccountId = account("Alice", 0, 0);
+        _prepare_farm_with_node::<T>(farmer.clone());
+        let node_id = 1;
+
+        let caller: T::AccountId = whitelisted_caller();
+        _create_twin::<T>(caller.clone());
+        _create_rent_contract::<T>(caller.clone());
+
+        // Node goes to standby
+        assert_ok!(TfgridModule::<T>::change_power_state(
+            RawOrigin::Signed(farmer).into(),
+            Power::Down
+        ));
+    }: _(RawOrigin::Signed(caller.clone()), node_id)
+    verify {
+        let twin_id = 2;
+        let current_block = System::<T>::block_number().saturated_into::<u64>();
+        let timeout_at = current_block + T::NodeWakeupTimeout::get();
+        assert!(SmartContractModule::<T>::node_wakeup_requests(node_id).is_some());
+        assert_last_event::<T>(Event::NodeWakeupRequested {
+            node_id,
+            twin_id,
+            timeout_at,
+        }.into());
+    }
+
     // Calling the `impl_benchmark_test_suite` macro inside the `benchmarks`
     // block will generate one #[test] function per benchmark
     impl_benchmark_test_suite!(SmartContractModule, crate::mock::new_test_ext(), crate::mock::TestRuntime)
@@ -626,6 +1495,15 @@ fn _create_farm<T: Config>(source: T::AccountId) {
     ));
 }
 
+fn _add_farm_ip6_prefix<T: Config>(source: T::AccountId) {
+    assert_ok!(TfgridModule::<T>::add_farm_ip6_prefix(
+        RawOrigin::Signed(source).into(),
+        1,
+        b"2a10:b600:1::/48".to_vec().try_into().unwrap(),
+        b"2a10:b600:1::1".to_vec().try_into().unwrap(),
+    ));
+}
+
 fn _create_node<T: Config>(source: T::AccountId) {
     let resources = ResourcesInput {
         hru: 1024 * GIGABYTE,
@@ -795,6 +1673,60 @@ fn _prepare_and_approve_service_contract<T: Config>(service: T::AccountId, consu
     ));
 }
 
+fn _create_service_plan<T: Config>(service: T::AccountId) {
+    _create_twin::<T>(service.clone());
+
+    assert_ok!(SmartContractModule::<T>::service_plan_create(
+        RawOrigin::Signed(service).into(),
+        1000,
+        1000,
+        b"some_metadata".to_vec(),
+    ));
+}
+
+fn _prepare_escrowed_service_contract_bill<T: Config>(
+    service: T::AccountId,
+    consumer: T::AccountId,
+) {
+    _prepare_service_contract::<T>(service.clone(), consumer.clone());
+    let contract_id = 1;
+
+    assert_ok!(
+        SmartContractModule::<T>::service_contract_set_dispute_window(
+            RawOrigin::Signed(service.clone()).into(),
+            contract_id,
+            Some(3600),
+        )
+    );
+
+    assert_ok!(SmartContractModule::<T>::service_contract_approve(
+        RawOrigin::Signed(service.clone()).into(),
+        contract_id,
+    ));
+    assert_ok!(SmartContractModule::<T>::service_contract_approve(
+        RawOrigin::Signed(consumer.clone()).into(),
+        contract_id,
+    ));
+
+    let consumer_lookup = T::Lookup::unlookup(consumer);
+    let balance_init_amount =
+        <T as pallet_balances::Config>::Balance::saturated_from(100000000 as u128);
+    Balances::<T>::force_set_balance(RawOrigin::Root.into(), consumer_lookup, balance_init_amount)
+        .unwrap();
+
+    // Let a full billing window elapse so bill amount is not zero
+    let now = SmartContractModule::<T>::get_current_timestamp_in_secs();
+    let then: u64 = now + 3600;
+    pallet_timestamp::Pallet::<T>::set_timestamp((then * 1000).try_into().unwrap());
+
+    assert_ok!(SmartContractModule::<T>::service_contract_bill(
+        RawOrigin::Signed(service).into(),
+        contract_id,
+        0,
+        b"bill_metadata".to_vec(),
+    ));
+}
+
 pub(crate) fn get_city_name_input(city_input: &[u8]) -> CityNameInput {
     BoundedVec::try_from(city_input.to_vec()).expect("Invalid city name input.")
 }
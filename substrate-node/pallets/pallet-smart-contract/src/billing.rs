@@ -10,10 +10,11 @@ use frame_system::{
 };
 use sp_core::Get;
 use sp_runtime::{
-    traits::{CheckedAdd, CheckedSub, Convert, Zero},
+    traits::{CheckedAdd, CheckedSub, Convert, One, Zero},
     DispatchResult, Perbill, SaturatedConversion,
 };
 use sp_std::vec::Vec;
+use tfchain_support::constants::time::{SECS_PER_BLOCK, SECS_PER_DAY};
 
 impl<T: Config> Pallet<T> {
     pub fn bill_contracts_for_block(block_number: BlockNumberFor<T>) {
@@ -22,7 +23,8 @@ impl<T: Config> Pallet<T> {
         let index = Self::get_billing_loop_index_from_block_number(block_number);
 
         let contract_ids = ContractsToBillAt::<T>::get(index);
-        if contract_ids.is_empty() {
+        let service_contract_ids = ServiceContractsToBillAt::<T>::get(index);
+        if contract_ids.is_empty() && service_contract_ids.is_empty() {
             log::info!(
                 "No contracts to bill at block {:?}, index: {:?}",
                 block_number,
@@ -60,9 +62,25 @@ impl<T: Config> Pallet<T> {
                 let _res = Self::bill_contract_using_signed_transaction(contract_id);
             }
         }
+
+        for service_contract_id in service_contract_ids {
+            if let Some(service_contract) = ServiceContracts::<T>::get(service_contract_id) {
+                // Don't bill if there is no base fee to be billed
+                if service_contract.base_fee == 0 {
+                    continue;
+                }
+                let _res = Self::send_billing_transaction(Call::bill_service_contract_for_block {
+                    service_contract_id,
+                });
+            }
+        }
     }
 
     pub fn bill_contract_using_signed_transaction(contract_id: u64) -> Result<(), Error<T>> {
+        Self::send_billing_transaction(Call::bill_contract_for_block { contract_id })
+    }
+
+    fn send_billing_transaction(call: Call<T>) -> Result<(), Error<T>> {
         let signer = Signer::<T, <T as pallet::Config>::AuthorityId>::any_account();
 
         // Only allow the author of the next block to trigger the billing
@@ -70,14 +88,13 @@ impl<T: Config> Pallet<T> {
 
         if !signer.can_sign() {
             log::error!(
-                "failed billing contract {:?} account cannot be used to sign transaction",
-                contract_id,
+                "failed billing call {:?} account cannot be used to sign transaction",
+                call,
             );
             return Err(<Error<T>>::OffchainSignedTxCannotSign);
         }
 
-        let result =
-            signer.send_signed_transaction(|_acct| Call::bill_contract_for_block { contract_id });
+        let result = signer.send_signed_transaction(|_acct| call.clone());
 
         if let Some((acc, res)) = result {
             // if res is an error this means sending the transaction failed
@@ -86,8 +103,8 @@ impl<T: Config> Pallet<T> {
             // returns Err())
             if res.is_err() {
                 log::error!(
-                    "signed transaction failed for billing contract {:?} using account {:?}",
-                    contract_id,
+                    "signed transaction failed for billing call {:?} using account {:?}",
+                    call,
                     acc.id
                 );
                 return Err(<Error<T>>::OffchainSignedTxAlreadySent);
@@ -102,6 +119,7 @@ impl<T: Config> Pallet<T> {
     // Calculates how much TFT is due by the user and distributes the rewards
     pub fn bill_contract(contract_id: u64) -> DispatchResultWithPostInfo {
         let mut contract = Contracts::<T>::get(contract_id).ok_or(Error::<T>::ContractNotExists)?;
+        let now = Self::get_current_timestamp_in_secs();
 
         // Bill rent contract only if node is online
         if let types::ContractData::RentContract(rc) = &contract.contract_type {
@@ -114,6 +132,15 @@ impl<T: Config> Pallet<T> {
             }
         }
 
+        // Node and rent contracts are not billed while their node is in maintenance
+        // Name contracts have no node (id 0) so they never have a maintenance window
+        let maintenance = pallet_tfgrid::NodeMaintenance::<T>::get(contract.get_node_id());
+        if let Some(window) = maintenance.last() {
+            if window.contains(now) && !contract.is_state_delete() {
+                return Ok(().into());
+            }
+        }
+
         let twin =
             pallet_tfgrid::Twins::<T>::get(contract.twin_id).ok_or(Error::<T>::TwinNotExists)?;
         let usable_balance = Self::get_usable_balance(&twin.account_id);
@@ -122,11 +149,35 @@ impl<T: Config> Pallet<T> {
             .checked_add(&stash_balance)
             .unwrap_or(BalanceOf::<T>::zero());
 
-        let now = Self::get_current_timestamp_in_secs();
+        // A fixed-term name registration is removed once its hold period is over
+        if !contract.is_state_delete() && Self::is_name_contract_released(contract_id, now) {
+            Self::update_contract_state(
+                &mut contract,
+                &types::ContractState::Deleted(types::Cause::Expired),
+            )?;
+        }
 
         // Calculate amount of seconds elapsed based on the contract lock struct
         let mut contract_lock = ContractLock::<T>::get(contract.contract_id);
-        let seconds_elapsed = now.checked_sub(contract_lock.lock_updated).unwrap_or(0);
+        // An expired name registration is only billed up to its expiry while it is held
+        let billed_until = match NameContractExpiry::<T>::get(contract.contract_id) {
+            Some(expires_at) if now >= expires_at => {
+                if contract_lock.lock_updated < expires_at && !contract.is_state_delete() {
+                    Self::deposit_name_contract_expired_event(&contract, expires_at);
+                }
+                expires_at
+            }
+            _ => now,
+        };
+        let seconds_elapsed = billed_until
+            .checked_sub(contract_lock.lock_updated)
+            .unwrap_or(0);
+        // Time the node spent in maintenance is not billed
+        let maintenance_seconds: u64 = maintenance
+            .iter()
+            .map(|window| window.overlap(contract_lock.lock_updated, now))
+            .sum();
+        let seconds_elapsed = seconds_elapsed.saturating_sub(maintenance_seconds);
 
         // Calculate total amount due
         let (regular_amount_due, discount_received) =
@@ -162,8 +213,44 @@ impl<T: Config> Pallet<T> {
             .checked_add(&extra_lock_amount)
             .unwrap_or(BalanceOf::<T>::zero());
 
+        // Billing must not exceed the spending caps of the contract and its twin
+        let spendable_balance = match Self::get_exceeded_spending_cap(&contract, amount_due) {
+            Some((scope, action)) => {
+                if matches!(contract.state, types::ContractState::Created) {
+                    Self::deposit_event(Event::SpendingCapReached {
+                        contract_id: contract.contract_id,
+                        twin_id: contract.twin_id,
+                        scope,
+                        action,
+                    });
+                }
+                // The time a contract is paused is not billed
+                if action == types::SpendingCapAction::Pause && !contract.is_state_delete() {
+                    Self::pause_contract(&mut contract)?;
+                    contract_lock.lock_updated = now;
+                    ContractLock::<T>::insert(contract.contract_id, &contract_lock);
+                    return Ok(().into());
+                }
+                // Otherwise reaching a cap is handled as if the twin ran out of funds
+                BalanceOf::<T>::zero()
+            }
+            None => usable_balance,
+        };
+
+        // Nor does it count against the grace period of the contract, only done here as the
+        // contract lock moves past the maintenance below and it is not counted again
+        if let types::ContractState::GracePeriod(grace_start) = contract.state {
+            let maintenance_blocks = maintenance_seconds / SECS_PER_BLOCK;
+            if maintenance_blocks > 0 {
+                contract.state = types::ContractState::GracePeriod(
+                    grace_start.saturating_add(maintenance_blocks),
+                );
+                Contracts::<T>::insert(contract.contract_id, &contract);
+            }
+        }
+
         // Handle grace
-        let contract = Self::handle_grace(&mut contract, usable_balance, lock_amount)?;
+        let contract = Self::handle_grace(&mut contract, spendable_balance, lock_amount)?;
 
         // Only update contract lock in state (Created, GracePeriod)
         if !matches!(contract.state, types::ContractState::Deleted(_)) {
@@ -177,12 +264,16 @@ impl<T: Config> Pallet<T> {
         // If still in grace period, no need to continue doing locking and other stuff
         if matches!(contract.state, types::ContractState::GracePeriod(_)) {
             log::info!("contract {} is still in grace", contract.contract_id);
+            // The amount piles up in the contract lock and gets settled once out of grace,
+            // count it against the spending caps now so it is not settled past the caps
+            Self::consume_spending_caps(contract, amount_due);
             ContractLock::<T>::insert(contract.contract_id, &contract_lock);
             return Ok(().into());
         }
 
         // Handle contract lock operations
         Self::handle_lock(contract, &mut contract_lock, amount_due)?;
+        Self::consume_spending_caps(contract, amount_due);
 
         // Always emit a contract billed event
         let contract_bill = types::ContractBill {
@@ -191,6 +282,7 @@ impl<T: Config> Pallet<T> {
             discount_level: discount_received.clone(),
             amount_billed: amount_due.saturated_into::<u128>(),
         };
+        Self::record_contract_bill(contract.twin_id, &contract_bill);
         Self::deposit_event(Event::ContractBilled(contract_bill));
 
         // If the contract is in delete state, remove all associated storage
@@ -217,6 +309,77 @@ impl<T: Config> Pallet<T> {
         Ok(().into())
     }
 
+    // Keeps track of the last bills of a contract and of the
+    // daily amount billed to the twin owning the contract.
+    // Only a single slot of each history is written per bill
+    pub fn record_contract_bill(twin_id: u32, contract_bill: &types::ContractBill) {
+        let max_bills = T::MaxContractBillingHistory::get() as u64;
+        if max_bills > 0 {
+            let count = ContractBillingHistoryCount::<T>::get(contract_bill.contract_id);
+            ContractBillingHistory::<T>::insert(
+                contract_bill.contract_id,
+                (count % max_bills) as u32,
+                contract_bill,
+            );
+            ContractBillingHistoryCount::<T>::insert(contract_bill.contract_id, count + 1);
+        }
+
+        if contract_bill.amount_billed == 0 {
+            return;
+        }
+
+        let day = contract_bill.timestamp / SECS_PER_DAY;
+        let amount_billed = TwinBillingHistory::<T>::get(twin_id, day);
+
+        // First bill of the day, drop the days that fell out of the retention window
+        if amount_billed == 0 {
+            let max_days = T::MaxTwinBillingHistoryDays::get() as u64;
+            TwinBillingDays::<T>::mutate(twin_id, |days| {
+                days.retain(|d| {
+                    let keep = d.saturating_add(max_days) > day;
+                    if !keep {
+                        TwinBillingHistory::<T>::remove(twin_id, d);
+                    }
+                    keep
+                });
+                if days.is_full() && !days.is_empty() {
+                    TwinBillingHistory::<T>::remove(twin_id, days.remove(0));
+                }
+                let _ = days.try_push(day);
+            });
+        }
+
+        TwinBillingHistory::<T>::insert(
+            twin_id,
+            day,
+            amount_billed.saturating_add(contract_bill.amount_billed),
+        );
+    }
+
+    // Last bills of a contract, oldest first
+    pub fn contract_billing_history(contract_id: u64) -> Vec<types::ContractBill> {
+        let max_bills = T::MaxContractBillingHistory::get() as u64;
+        if max_bills == 0 {
+            return Vec::new();
+        }
+
+        let count = ContractBillingHistoryCount::<T>::get(contract_id);
+        (count.saturating_sub(max_bills)..count)
+            .filter_map(|i| ContractBillingHistory::<T>::get(contract_id, (i % max_bills) as u32))
+            .collect()
+    }
+
+    // Amount billed to a twin per day, oldest first
+    pub fn twin_billing_history(twin_id: u32) -> Vec<types::TwinDailyBill> {
+        TwinBillingDays::<T>::get(twin_id)
+            .into_iter()
+            .map(|day| types::TwinDailyBill {
+                day,
+                amount_billed: TwinBillingHistory::<T>::get(twin_id, day),
+            })
+            .collect()
+    }
+
     fn handle_grace(
         contract: &mut types::Contract<T>,
         usable_balance: BalanceOf<T>,
@@ -229,22 +392,26 @@ impl<T: Config> Pallet<T> {
             types::ContractState::GracePeriod(grace_start) => {
                 // if the usable balance is recharged, we can move the contract to created state again
                 if usable_balance > amount_due {
-                    Self::update_contract_state(contract, &types::ContractState::Created)?;
-                    Self::deposit_event(Event::ContractGracePeriodEnded {
-                        contract_id: contract.contract_id,
-                        node_id,
-                        twin_id: contract.twin_id,
-                    });
-                    // If the contract is a rent contract, also move state on associated node contracts
-                    Self::handle_grace_rent_contract(contract, types::ContractState::Created)?;
+                    Self::end_grace_period(contract)?;
                 } else {
                     let diff = current_block.checked_sub(grace_start).unwrap_or(0);
+                    let grace_period = Self::get_grace_period(contract);
                     // If the contract grace period ran out, we can decomission the contract
-                    if diff >= T::GracePeriod::get() {
+                    if diff >= grace_period {
                         Self::update_contract_state(
                             contract,
                             &types::ContractState::Deleted(types::Cause::OutOfFunds),
                         )?;
+                    } else {
+                        Self::handle_grace_reminder(
+                            contract,
+                            grace_start,
+                            grace_period - diff,
+                            // Grace ends once the usable balance is strictly above the amount due
+                            amount_due
+                                .saturating_sub(usable_balance)
+                                .saturating_add(One::one()),
+                        );
                     }
                 }
             }
@@ -282,6 +449,64 @@ impl<T: Config> Pallet<T> {
         Ok(contract)
     }
 
+    pub fn end_grace_period(contract: &mut types::Contract<T>) -> DispatchResultWithPostInfo {
+        Self::update_contract_state(contract, &types::ContractState::Created)?;
+        Self::deposit_event(Event::ContractGracePeriodEnded {
+            contract_id: contract.contract_id,
+            node_id: contract.get_node_id(),
+            twin_id: contract.twin_id,
+        });
+        // If the contract is a rent contract, also move state on associated node contracts
+        Self::handle_grace_rent_contract(contract, types::ContractState::Created)
+    }
+
+    // A contract paused by a spending cap is kept in grace so its workloads are stopped,
+    // its grace period restarts on every billing so it is not removed while paused
+    fn pause_contract(contract: &mut types::Contract<T>) -> DispatchResultWithPostInfo {
+        let current_block = <frame_system::Pallet<T>>::block_number().saturated_into::<u64>();
+
+        match contract.state {
+            types::ContractState::Created => {
+                // Nothing can be spent, this moves the contract into grace
+                Self::handle_grace(contract, BalanceOf::<T>::zero(), BalanceOf::<T>::zero())?;
+            }
+            types::ContractState::GracePeriod(_) => {
+                Self::update_contract_state(
+                    contract,
+                    &types::ContractState::GracePeriod(current_block),
+                )?;
+            }
+            _ => (),
+        }
+
+        Ok(().into())
+    }
+
+    // Reminds the twin that the contract is still in grace period
+    // at most once every GracePeriodReminderFrequency blocks
+    fn handle_grace_reminder(
+        contract: &types::Contract<T>,
+        grace_start: u64,
+        remaining_blocks: u64,
+        amount_needed: BalanceOf<T>,
+    ) {
+        let current_block = <frame_system::Pallet<T>>::block_number().saturated_into::<u64>();
+        let last_reminder =
+            LastGracePeriodReminder::<T>::get(contract.contract_id).unwrap_or(grace_start);
+        if current_block.saturating_sub(last_reminder) < T::GracePeriodReminderFrequency::get() {
+            return;
+        }
+
+        LastGracePeriodReminder::<T>::insert(contract.contract_id, current_block);
+        Self::deposit_event(Event::ContractGracePeriodReminder {
+            contract_id: contract.contract_id,
+            node_id: contract.get_node_id(),
+            twin_id: contract.twin_id,
+            remaining_blocks,
+            amount_needed,
+        });
+    }
+
     fn handle_grace_rent_contract(
         contract: &mut types::Contract<T>,
         state: types::ContractState,
@@ -325,8 +550,6 @@ impl<T: Config> Pallet<T> {
         contract_lock: &mut types::ContractLock<BalanceOf<T>>,
         amount_due: BalanceOf<T>,
     ) -> DispatchResultWithPostInfo {
-        let now = Self::get_current_timestamp_in_secs();
-
         // Only lock an amount from the user's balance if the contract is in create state
         // The lock is specified on the user's account, since a user can have multiple contracts
         // Just extend the lock with the amount due for this contract billing period (lock will be created if not exists)
@@ -350,90 +573,103 @@ impl<T: Config> Pallet<T> {
         // When the cultivation rewards are ready to be distributed or it's in delete state
         // Unlock all reserved balance and distribute
         if contract_lock.cycles >= T::DistributionFrequency::get() || canceled_and_not_zero {
-            // First remove the lock, calculate how much locked balance needs to be unlocked and re-lock the remaining locked balance
-            let locked_balance = Self::get_locked_balance(&twin.account_id);
-            let new_locked_balance =
-                match locked_balance.checked_sub(&contract_lock.total_amount_locked()) {
-                    Some(b) => b,
-                    None => BalanceOf::<T>::zero(),
-                };
-            <T as Config>::Currency::remove_lock(GRID_LOCK_ID, &twin.account_id);
-
-            // Fetch twin balance, if the amount locked in the contract lock exceeds the current unlocked
-            // balance we can only transfer out the remaining balance
-            // https://github.com/threefoldtech/tfchain/issues/479
-            let min_balance = <T as Config>::Currency::minimum_balance();
-            let mut twin_balance = match new_locked_balance {
-                bal if bal > min_balance => {
-                    <T as Config>::Currency::set_lock(
-                        GRID_LOCK_ID,
-                        &twin.account_id,
-                        new_locked_balance,
-                        WithdrawReasons::all(),
-                    );
-                    Self::get_usable_balance(&twin.account_id)
-                }
-                _ => Self::get_usable_balance(&twin.account_id)
-                    .checked_sub(&min_balance)
-                    .unwrap_or(BalanceOf::<T>::zero()),
-            };
+            Self::settle_contract_lock(contract, contract_lock)?;
+        }
 
-            // First, distribute extra cultivation rewards if any
-            if contract_lock.has_extra_amount_locked() {
-                log::info!(
-                    "twin balance {:?} contract lock extra amount {:?}",
-                    twin_balance,
-                    contract_lock.extra_amount_locked
-                );
+        Ok(().into())
+    }
 
-                match Self::distribute_extra_cultivation_rewards(
-                    &contract,
-                    twin_balance.min(contract_lock.extra_amount_locked),
-                ) {
-                    Ok(_) => {}
-                    Err(err) => {
-                        log::error!(
-                            "error while distributing extra cultivation rewards {:?}",
-                            err
-                        );
-                        return Err(err);
-                    }
-                };
+    // Unlocks the amount locked for the contract from the twin balance and distributes it
+    pub fn settle_contract_lock(
+        contract: &types::Contract<T>,
+        contract_lock: &mut types::ContractLock<BalanceOf<T>>,
+    ) -> DispatchResultWithPostInfo {
+        let now = Self::get_current_timestamp_in_secs();
+        let twin =
+            pallet_tfgrid::Twins::<T>::get(contract.twin_id).ok_or(Error::<T>::TwinNotExists)?;
 
-                // Update twin balance after distribution
-                twin_balance = Self::get_usable_balance(&twin.account_id);
+        // First remove the lock, calculate how much locked balance needs to be unlocked and re-lock the remaining locked balance
+        let locked_balance = Self::get_locked_balance(&twin.account_id);
+        let new_locked_balance =
+            match locked_balance.checked_sub(&contract_lock.total_amount_locked()) {
+                Some(b) => b,
+                None => BalanceOf::<T>::zero(),
+            };
+        <T as Config>::Currency::remove_lock(GRID_LOCK_ID, &twin.account_id);
+
+        // Fetch twin balance, if the amount locked in the contract lock exceeds the current unlocked
+        // balance we can only transfer out the remaining balance
+        // https://github.com/threefoldtech/tfchain/issues/479
+        let min_balance = <T as Config>::Currency::minimum_balance();
+        let mut twin_balance = match new_locked_balance {
+            bal if bal > min_balance => {
+                <T as Config>::Currency::set_lock(
+                    GRID_LOCK_ID,
+                    &twin.account_id,
+                    new_locked_balance,
+                    WithdrawReasons::all(),
+                );
+                Self::get_usable_balance(&twin.account_id)
             }
+            _ => Self::get_usable_balance(&twin.account_id)
+                .checked_sub(&min_balance)
+                .unwrap_or(BalanceOf::<T>::zero()),
+        };
 
+        // First, distribute extra cultivation rewards if any
+        if contract_lock.has_extra_amount_locked() {
             log::info!(
-                "twin balance {:?} contract lock amount {:?}",
+                "twin balance {:?} contract lock extra amount {:?}",
                 twin_balance,
-                contract_lock.amount_locked
+                contract_lock.extra_amount_locked
             );
 
-            // Fetch the default pricing policy
-            let pricing_policy = pallet_tfgrid::PricingPolicies::<T>::get(1)
-                .ok_or(Error::<T>::PricingPolicyNotExists)?;
-
-            // Then, distribute cultivation rewards
-            match Self::distribute_cultivation_rewards(
-                &contract,
-                &pricing_policy,
-                twin_balance.min(contract_lock.amount_locked),
+            match Self::distribute_extra_cultivation_rewards(
+                contract,
+                twin_balance.min(contract_lock.extra_amount_locked),
             ) {
                 Ok(_) => {}
                 Err(err) => {
-                    log::error!("error while distributing cultivation rewards {:?}", err);
+                    log::error!(
+                        "error while distributing extra cultivation rewards {:?}",
+                        err
+                    );
                     return Err(err);
                 }
             };
 
-            // Reset contract lock values
-            contract_lock.lock_updated = now;
-            contract_lock.amount_locked = BalanceOf::<T>::zero();
-            contract_lock.extra_amount_locked = BalanceOf::<T>::zero();
-            contract_lock.cycles = 0;
+            // Update twin balance after distribution
+            twin_balance = Self::get_usable_balance(&twin.account_id);
         }
 
+        log::info!(
+            "twin balance {:?} contract lock amount {:?}",
+            twin_balance,
+            contract_lock.amount_locked
+        );
+
+        // Fetch the pricing policy that applies to this contract
+        let (pricing_policy, _) = contract.get_pricing_policy_and_certification()?;
+
+        // Then, distribute cultivation rewards
+        match Self::distribute_cultivation_rewards(
+            contract,
+            &pricing_policy,
+            twin_balance.min(contract_lock.amount_locked),
+        ) {
+            Ok(_) => {}
+            Err(err) => {
+                log::error!("error while distributing cultivation rewards {:?}", err);
+                return Err(err);
+            }
+        };
+
+        // Reset contract lock values
+        contract_lock.lock_updated = now;
+        contract_lock.amount_locked = BalanceOf::<T>::zero();
+        contract_lock.extra_amount_locked = BalanceOf::<T>::zero();
+        contract_lock.cycles = 0;
+
         Ok(().into())
     }
 
@@ -544,7 +780,11 @@ impl<T: Config> Pallet<T> {
         let mut sales_share = 50;
 
         if let Some(provider_id) = contract.solution_provider_id {
-            if let Some(solution_provider) = SolutionProviders::<T>::get(provider_id) {
+            let now = Self::get_current_timestamp_in_secs();
+            // A revoked, expired or not (re)approved solution provider does not get its take
+            if let Some(solution_provider) = SolutionProviders::<T>::get(provider_id)
+                .filter(|solution_provider| solution_provider.is_active(now))
+            {
                 let total_take: u8 = solution_provider
                     .providers
                     .iter()
@@ -563,11 +803,12 @@ impl<T: Config> Pallet<T> {
                             &twin.account_id,
                             &provider.who
                         );
-                        <T as Config>::Currency::transfer(
+                        Self::pay_solution_provider_share(
+                            provider_id,
+                            contract.contract_id,
                             &twin.account_id,
                             &provider.who,
                             share,
-                            ExistenceRequirement::KeepAlive,
                         )
                     })
                     .filter(|result| result.is_err())
@@ -677,6 +918,24 @@ impl<T: Config> Pallet<T> {
         Ok(())
     }
 
+    // Inserts a service contract in the billing loop the same way as other contracts
+    pub fn insert_service_contract_in_billing_loop(service_contract_id: u64) {
+        let index = Self::get_billing_loop_index_from_contract_id(service_contract_id);
+        ServiceContractsToBillAt::<T>::mutate(index, |service_contract_ids| {
+            if !service_contract_ids.contains(&service_contract_id) {
+                service_contract_ids.push(service_contract_id);
+            }
+        });
+    }
+
+    // Removes service contract from billing loop, it is only there once approved by both
+    pub fn remove_service_contract_from_billing_loop(service_contract_id: u64) {
+        let index = Self::get_billing_loop_index_from_contract_id(service_contract_id);
+        ServiceContractsToBillAt::<T>::mutate(index, |service_contract_ids| {
+            service_contract_ids.retain(|&c| c != service_contract_id)
+        });
+    }
+
     pub fn _change_billing_frequency(frequency: u64) -> DispatchResultWithPostInfo {
         let billing_frequency = BillingFrequency::<T>::get();
         ensure!(
@@ -709,7 +968,7 @@ impl<T: Config> Pallet<T> {
         }
     }
 
-    fn get_stash_balance(twin_id: u32) -> BalanceOf<T> {
+    pub fn get_stash_balance(twin_id: u32) -> BalanceOf<T> {
         let account_id = pallet_tfgrid::TwinBoundedAccountID::<T>::get(twin_id);
         match account_id {
             Some(account) => Self::get_usable_balance(&account),
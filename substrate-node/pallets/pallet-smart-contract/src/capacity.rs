@@ -0,0 +1,69 @@
+use crate::*;
+use frame_support::{dispatch::DispatchResultWithPostInfo, ensure};
+use pallet_tfgrid::pallet::TfgridNode;
+use tfchain_support::resources::Resources;
+
+impl<T: Config> Pallet<T> {
+    // Makes sure the node can host the requested resources on top of the ones
+    // already reserved, given the overcommit ratio of its farm
+    pub fn ensure_node_has_capacity(
+        node: &TfgridNode<T>,
+        requested: &Resources,
+    ) -> DispatchResultWithPostInfo {
+        let ratio = pallet_tfgrid::FarmOvercommitRatio::<T>::get(node.farm_id) as u128;
+        let reserved = NodeReservedResources::<T>::get(node.id);
+        let fits = |reserved: u64, requested: u64, total: u64| {
+            (reserved as u128 + requested as u128) * 100 <= total as u128 * ratio
+        };
+
+        ensure!(
+            fits(reserved.cru, requested.cru, node.resources.cru)
+                && fits(reserved.mru, requested.mru, node.resources.mru)
+                && fits(reserved.sru, requested.sru, node.resources.sru)
+                && fits(reserved.hru, requested.hru, node.resources.hru),
+            Error::<T>::NodeHasNotEnoughCapacity
+        );
+
+        Ok(().into())
+    }
+
+    pub fn reserve_node_resources(node_id: u32, contract_id: u64, resources: Resources) {
+        if resources.is_empty() {
+            return;
+        }
+
+        NodeReservedResources::<T>::mutate(node_id, |reserved| {
+            *reserved = reserved.clone().saturating_add(&resources);
+        });
+        ContractReservedResources::<T>::insert(contract_id, resources);
+    }
+
+    // A contract reserves at least what its node reports it uses,
+    // so contracts created without declared resources also count against the node capacity
+    pub fn reserve_reported_resources(node_id: u32, contract_id: u64, used: &Resources) {
+        let reserved = ContractReservedResources::<T>::get(contract_id).unwrap_or_default();
+        let extra = used.clone().saturating_sub(&reserved);
+        if extra.is_empty() {
+            return;
+        }
+
+        NodeReservedResources::<T>::mutate(node_id, |node_reserved| {
+            *node_reserved = node_reserved.clone().saturating_add(&extra);
+        });
+        ContractReservedResources::<T>::insert(contract_id, reserved.saturating_add(&extra));
+    }
+
+    pub fn release_node_resources(node_id: u32, contract_id: u64) {
+        if let Some(resources) = ContractReservedResources::<T>::take(contract_id) {
+            NodeReservedResources::<T>::mutate_exists(node_id, |maybe_reserved| {
+                let reserved = maybe_reserved
+                    .take()
+                    .unwrap_or_default()
+                    .saturating_sub(&resources);
+                if !reserved.is_empty() {
+                    *maybe_reserved = Some(reserved);
+                }
+            });
+        }
+    }
+}
@@ -1,7 +1,10 @@
 use crate::*;
-use frame_support::{dispatch::DispatchErrorWithPostInfo, traits::Get};
+use frame_support::{dispatch::DispatchErrorWithPostInfo, ensure, traits::Get};
 use log;
-use sp_runtime::{traits::Zero, Percent, SaturatedConversion};
+use sp_runtime::{
+    traits::{CheckedAdd, Zero},
+    DispatchError, Percent, SaturatedConversion,
+};
 use substrate_fixed::types::U64F64;
 use tfchain_support::{
     constants::time::{SECS_PER_HOUR, SECS_PER_MONTH},
@@ -19,9 +22,8 @@ impl<T: Config> types::Contract<T> {
         balance: BalanceOf<T>,
         seconds_elapsed: u64,
     ) -> Result<(BalanceOf<T>, types::DiscountLevel), DispatchErrorWithPostInfo> {
-        // Fetch the default pricing policy and certification type
-        let pricing_policy = pallet_tfgrid::PricingPolicies::<T>::get(1).unwrap();
-        let certification_type = NodeCertification::Diy;
+        // Fetch the pricing policy and certification type that apply to this contract
+        let (pricing_policy, certification_type) = self.get_pricing_policy_and_certification()?;
 
         // Calculate the cost for a contract, can be any of:
         // - NodeContract
@@ -48,6 +50,27 @@ impl<T: Config> types::Contract<T> {
         Ok((amount_due, discount_received))
     }
 
+    // Node and rent contracts are billed following the pricing policy of the farm
+    // hosting the node and the certification of that node.
+    // Name contracts are not bound to any node so they follow the default pricing policy.
+    pub fn get_pricing_policy_and_certification(
+        &self,
+    ) -> Result<
+        (
+            pallet_tfgrid::types::PricingPolicy<T::AccountId>,
+            NodeCertification,
+        ),
+        DispatchErrorWithPostInfo,
+    > {
+        let node_id = match &self.contract_type {
+            types::ContractData::NodeContract(node_contract) => Some(node_contract.node_id),
+            types::ContractData::RentContract(rent_contract) => Some(rent_contract.node_id),
+            types::ContractData::NameContract(_) => None,
+        };
+
+        get_pricing_policy_and_certification::<T>(node_id)
+    }
+
     pub fn calculate_contract_cost_units_usd(
         &self,
         pricing_policy: &pallet_tfgrid::types::PricingPolicy<T::AccountId>,
@@ -63,9 +86,8 @@ impl<T: Config> types::Contract<T> {
                 // Get the contract billing info to view the amount unbilled for NRU (network resource units)
                 let contract_billing_info = self.get_billing_info();
                 // Make sure the node exists
-                if pallet_tfgrid::Nodes::<T>::get(node_contract.node_id).is_none() {
-                    return Err(DispatchErrorWithPostInfo::from(Error::<T>::NodeNotExists));
-                }
+                let node = pallet_tfgrid::Nodes::<T>::get(node_contract.node_id)
+                    .ok_or(Error::<T>::NodeNotExists)?;
 
                 // We know the contract is using resources, now calculate the cost for each used resource
                 let node_contract_resources =
@@ -80,7 +102,7 @@ impl<T: Config> types::Contract<T> {
 
                 let contract_cost = calculate_resources_cost_units_usd::<T>(
                     node_contract_resources.used,
-                    node_contract.public_ips,
+                    calculate_public_ips_units::<T>(node.farm_id, node_contract),
                     seconds_elapsed,
                     &pricing_policy,
                     bill_resources,
@@ -93,7 +115,7 @@ impl<T: Config> types::Contract<T> {
 
                 let contract_cost = calculate_resources_cost_units_usd::<T>(
                     node.resources,
-                    0,
+                    U64F64::from_num(0),
                     seconds_elapsed,
                     &pricing_policy,
                     true,
@@ -129,6 +151,65 @@ impl<T: Config> types::Contract<T> {
     }
 }
 
+impl<T: Config> Pallet<T> {
+    // Estimates the cost of deploying a node contract with the given resources
+    // and amount of public ips on a node for one billing reference period.
+    // Used by the runtime api to let clients know what a deployment will cost
+    // without having to reimplement the cost calculation.
+    pub fn estimate_contract_cost(
+        node_id: u32,
+        twin_id: u32,
+        resources: Resources,
+        public_ips: u32,
+    ) -> Result<types::ContractCostEstimate<BalanceOf<T>>, DispatchError> {
+        ensure!(
+            pallet_tfgrid::Nodes::<T>::contains_key(node_id),
+            Error::<T>::NodeNotExists
+        );
+        // Use the same pricing policy and certification type as billing does
+        let (pricing_policy, certification_type) =
+            get_pricing_policy_and_certification::<T>(Some(node_id)).map_err(|e| e.error)?;
+        let twin = pallet_tfgrid::Twins::<T>::get(twin_id).ok_or(Error::<T>::TwinNotExists)?;
+
+        // Resources of a node contract deployed on a rented node are not billed
+        let bill_resources = !ActiveRentContractForNode::<T>::contains_key(node_id);
+
+        let seconds_elapsed = T::BillingReferencePeriod::get();
+        let cost_units_usd = calculate_resources_cost_units_usd::<T>(
+            resources,
+            U64F64::from_num(public_ips),
+            seconds_elapsed,
+            &pricing_policy,
+            bill_resources,
+        );
+        // Convert cost from units USD to mUSD
+        let cost_musd = (U64F64::from_num(cost_units_usd) / U64F64::from_num(10000))
+            .round()
+            .to_num::<u64>();
+
+        let cost_tft_64 =
+            calculate_cost_in_tft_from_units_usd::<T>(cost_units_usd).map_err(|e| e.error)?;
+
+        // Discount is based on the balance the twin currently has available for billing
+        let total_balance = Self::get_usable_balance(&twin.account_id)
+            .checked_add(&Self::get_stash_balance(twin.id))
+            .unwrap_or(BalanceOf::<T>::zero());
+
+        let (cost_tft, discount_level) = calculate_discount_tft::<T>(
+            cost_tft_64,
+            seconds_elapsed,
+            total_balance,
+            certification_type,
+        );
+
+        Ok(types::ContractCostEstimate {
+            cost_musd,
+            cost_tft,
+            discount_level,
+        })
+    }
+}
+
 impl types::NruConsumption {
     // Calculates the total cost of a report.
     // Takes in a report for NRU (network resource units)
@@ -202,11 +283,49 @@ impl types::ServiceContract {
     }
 }
 
+// Resolves the pricing policy and certification type to apply for capacity on a node.
+// Without node, or when the node, its farm or the farm pricing policy is gone,
+// the default pricing policy is used so contracts can still be billed and removed.
+pub fn get_pricing_policy_and_certification<T: Config>(
+    node_id: Option<u32>,
+) -> Result<
+    (
+        pallet_tfgrid::types::PricingPolicy<T::AccountId>,
+        NodeCertification,
+    ),
+    DispatchErrorWithPostInfo,
+> {
+    let node_pricing = node_id
+        .and_then(pallet_tfgrid::Nodes::<T>::get)
+        .and_then(|node| {
+            let farm = pallet_tfgrid::Farms::<T>::get(node.farm_id)?;
+            let pricing_policy = pallet_tfgrid::PricingPolicies::<T>::get(farm.pricing_policy_id)?;
+            Some((pricing_policy, node.certification))
+        });
+
+    match node_pricing {
+        Some(node_pricing) => Ok(node_pricing),
+        None => {
+            // Name contracts have no node, for the others the node, farm or policy is missing
+            if let Some(node_id) = node_id {
+                log::error!(
+                    "pricing policy of node {:?} not found, billing with default pricing policy",
+                    node_id
+                );
+            }
+            let pricing_policy =
+                pallet_tfgrid::PricingPolicies::<T>::get(pallet_tfgrid::DEFAULT_PRICING_POLICY_ID)
+                    .ok_or(Error::<T>::PricingPolicyNotExists)?;
+            Ok((pricing_policy, NodeCertification::Diy))
+        }
+    }
+}
+
 // Calculates the total cost of a node contract.
 // https://library.threefold.me/info/threefold#/tfgrid/threefold__cloudunits
 pub fn calculate_resources_cost_units_usd<T: Config>(
     resources: Resources,
-    ipu: u32,
+    ipu: U64F64,
     seconds_elapsed: u64,
     pricing_policy: &pallet_tfgrid::types::PricingPolicy<T::AccountId>,
     bill_resources: bool,
@@ -239,8 +358,8 @@ pub fn calculate_resources_cost_units_usd<T: Config>(
         total_cost = su_cost + cu_cost;
     }
 
-    if ipu > 0 {
-        let total_ip_cost = U64F64::from_num(ipu)
+    if ipu > U64F64::from_num(0) {
+        let total_ip_cost = ipu
             * (U64F64::from_num(pricing_policy.ipu.value)
                 / U64F64::from_num(T::BillingReferencePeriod::get()))
             * U64F64::from_num(seconds_elapsed);
@@ -251,6 +370,27 @@ pub fn calculate_resources_cost_units_usd<T: Config>(
     return total_cost.round().to_num::<u64>();
 }
 
+// Calculates the amount of ip units of a node contract, each public ip
+// counts following the price multiplier the farm set on it.
+pub fn calculate_public_ips_units<T: Config>(
+    farm_id: u32,
+    node_contract: &types::NodeContract<T>,
+) -> U64F64 {
+    // Ips that are not in the list anymore (removed by the farm) keep the default price
+    let unlisted_ips = node_contract
+        .public_ips
+        .saturating_sub(node_contract.public_ips_list.len() as u32);
+
+    node_contract
+        .public_ips_list
+        .iter()
+        .fold(U64F64::from_num(unlisted_ips), |units, public_ip| {
+            units
+                + U64F64::from_num(PublicIpPriceMultiplier::<T>::get(farm_id, &public_ip.ip))
+                    / U64F64::from_num(100)
+        })
+}
+
 // Calculates the cost of extra fee for a dedicated node in units usd.
 pub fn calculate_extra_fee_cost_units_usd<T: Config>(node_id: u32, seconds_elapsed: u64) -> u64 {
     match DedicatedNodesExtraFee::<T>::get(node_id) {
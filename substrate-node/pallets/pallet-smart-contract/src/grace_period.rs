@@ -0,0 +1,157 @@
+use crate::{weights::WeightInfo, *};
+use frame_support::{dispatch::DispatchResultWithPostInfo, ensure, storage::with_storage_layer};
+use sp_runtime::{traits::Zero, SaturatedConversion};
+use sp_std::vec::Vec;
+
+impl<T: Config> Pallet<T> {
+    pub fn _set_contract_kind_grace_period(
+        kind: types::ContractKind,
+        grace_period: Option<u64>,
+    ) -> DispatchResultWithPostInfo {
+        ensure!(grace_period != Some(0), Error::<T>::InvalidGracePeriod);
+
+        ContractKindGracePeriod::<T>::set(kind.clone(), grace_period);
+
+        Self::deposit_event(Event::ContractKindGracePeriodSet { kind, grace_period });
+
+        Ok(().into())
+    }
+
+    pub fn _set_solution_provider_grace_period(
+        solution_provider_id: u64,
+        grace_period: Option<u64>,
+    ) -> DispatchResultWithPostInfo {
+        ensure!(grace_period != Some(0), Error::<T>::InvalidGracePeriod);
+        ensure!(
+            SolutionProviders::<T>::contains_key(solution_provider_id),
+            Error::<T>::NoSuchSolutionProvider
+        );
+
+        SolutionProviderGracePeriod::<T>::set(solution_provider_id, grace_period);
+
+        Self::deposit_event(Event::SolutionProviderGracePeriodSet {
+            solution_provider_id,
+            grace_period,
+        });
+
+        Ok(().into())
+    }
+
+    // Grace period (in blocks) of a contract, in order of precedence:
+    // the one of its solution provider, the one of its kind or the runtime default
+    pub fn get_grace_period(contract: &types::Contract<T>) -> u64 {
+        contract
+            .solution_provider_id
+            .and_then(SolutionProviderGracePeriod::<T>::get)
+            .unwrap_or_else(|| Self::get_contract_kind_grace_period(contract.get_contract_kind()))
+    }
+
+    // Grace period (in blocks) of a contract kind, or the runtime default.
+    // Service contracts have no solution provider so they only follow this one
+    pub fn get_contract_kind_grace_period(kind: types::ContractKind) -> u64 {
+        ContractKindGracePeriod::<T>::get(kind).unwrap_or_else(T::GracePeriod::get)
+    }
+
+    pub fn update_twin_contracts_in_grace(twin_id: u32, contract_id: u64, in_grace: bool) {
+        if in_grace {
+            TwinContractsInGrace::<T>::insert(twin_id, contract_id, ());
+        } else {
+            TwinContractsInGrace::<T>::remove(twin_id, contract_id);
+        }
+    }
+
+    // Contracts of a twin in grace period with the amount of blocks left before they get removed
+    pub fn get_contracts_in_grace(twin_id: u32) -> Vec<types::ContractInGrace> {
+        let current_block = <frame_system::Pallet<T>>::block_number().saturated_into::<u64>();
+
+        TwinContractsInGrace::<T>::iter_key_prefix(twin_id)
+            .filter_map(|contract_id| Contracts::<T>::get(contract_id))
+            .filter_map(|contract| match contract.state {
+                types::ContractState::GracePeriod(grace_start) => {
+                    let grace_end = grace_start.saturating_add(Self::get_grace_period(&contract));
+                    Some(types::ContractInGrace {
+                        contract_id: contract.contract_id,
+                        grace_period_start: grace_start,
+                        remaining_blocks: grace_end.saturating_sub(current_block),
+                    })
+                }
+                _ => None,
+            })
+            .collect()
+    }
+
+    pub fn _restore_contracts_from_grace(twin_id: u32) -> DispatchResultWithPostInfo {
+        ensure!(
+            pallet_tfgrid::Twins::<T>::contains_key(twin_id),
+            Error::<T>::TwinNotExists
+        );
+
+        // Restoring a contract removes it from the map, collect the ids first
+        let max_contracts = T::MaxContractsRestoredFromGrace::get();
+        let contract_ids: Vec<u64> = TwinContractsInGrace::<T>::iter_key_prefix(twin_id)
+            .take(max_contracts as usize)
+            .collect();
+        ensure!(!contract_ids.is_empty(), Error::<T>::NoContractsInGrace);
+
+        let mut processed = 0;
+        for contract_id in contract_ids {
+            processed += 1;
+            // A contract that fails to be restored must not prevent the others from being restored
+            if let Err(e) = with_storage_layer(|| Self::restore_contract_from_grace(contract_id)) {
+                log::error!(
+                    "error while restoring contract {:?} from grace period: {:?}",
+                    contract_id,
+                    e
+                );
+            }
+        }
+
+        let actual_weight = <T as Config>::WeightInfo::restore_contracts_from_grace(processed);
+        Ok(Some(actual_weight).into())
+    }
+
+    fn restore_contract_from_grace(contract_id: u64) -> DispatchResultWithPostInfo {
+        // Contract could have been restored or removed along with its rent contract
+        match Contracts::<T>::get(contract_id) {
+            Some(contract) if matches!(contract.state, types::ContractState::GracePeriod(_)) => (),
+            _ => return Ok(().into()),
+        };
+
+        // Bill what is due since last billing, the contract
+        // leaves grace period if the twin can pay for it
+        Self::bill_contract(contract_id)?;
+
+        let mut contract = match Contracts::<T>::get(contract_id) {
+            Some(contract) => contract,
+            None => return Ok(().into()),
+        };
+        let mut contract_lock = ContractLock::<T>::get(contract_id);
+
+        // Billing returns early when the contract was already billed in this block
+        // or its node is in maintenance, check the amount locked against the balance again
+        if matches!(contract.state, types::ContractState::GracePeriod(_)) {
+            let twin = pallet_tfgrid::Twins::<T>::get(contract.twin_id)
+                .ok_or(Error::<T>::TwinNotExists)?;
+            let usable_balance = Self::get_usable_balance(&twin.account_id);
+            // Amounts due in grace already count against the spending caps,
+            // a cap reached in the current period keeps the contract in grace
+            let cap_reached =
+                Self::get_exceeded_spending_cap(&contract, BalanceOf::<T>::zero()).is_some();
+            if !cap_reached && usable_balance > contract_lock.total_amount_locked() {
+                Self::end_grace_period(&mut contract)?;
+            }
+        }
+
+        if !matches!(contract.state, types::ContractState::Created) {
+            return Ok(().into());
+        }
+
+        // Pay the amount accumulated during grace period right away
+        if contract_lock.has_some_amount_locked() {
+            Self::settle_contract_lock(&contract, &mut contract_lock)?;
+            ContractLock::<T>::insert(contract_id, &contract_lock);
+        }
+
+        Ok(().into())
+    }
+}
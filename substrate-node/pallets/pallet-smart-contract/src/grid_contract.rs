@@ -1,16 +1,23 @@
-use crate::*;
+use crate::{weights::WeightInfo, *};
 use frame_support::{
     dispatch::{DispatchErrorWithPostInfo, DispatchResultWithPostInfo, Pays},
     ensure,
     pallet_prelude::TypeInfo,
+    weights::Weight,
     BoundedVec, RuntimeDebugNoBound,
 };
 use pallet_tfgrid::pallet::{InterfaceOf, LocationOf, SerialNumberOf, TfgridNode};
 use parity_scale_codec::{Decode, Encode, MaxEncodedLen};
-use sp_std::{marker::PhantomData, vec, vec::Vec};
+use sp_std::{
+    collections::{btree_map::BTreeMap, btree_set::BTreeSet},
+    marker::PhantomData,
+    vec,
+    vec::Vec,
+};
 use tfchain_support::{
+    resources::Resources,
     traits::{ChangeNode, NodeActiveContracts, PublicIpModifier},
-    types::PublicIP,
+    types::{PublicIP, PublicIp6Prefix},
 };
 
 impl<T: Config> Pallet<T> {
@@ -20,12 +27,119 @@ impl<T: Config> Pallet<T> {
         deployment_hash: types::HexHash,
         deployment_data: DeploymentDataInput<T>,
         public_ips: u32,
+        resources: Option<Resources>,
         solution_provider_id: Option<u64>,
     ) -> DispatchResultWithPostInfo {
         let twin_id = pallet_tfgrid::TwinIdByAccountID::<T>::get(&account_id)
             .ok_or(Error::<T>::TwinNotExists)?;
 
+        Self::create_node_contract_for_twin(
+            twin_id,
+            node_id,
+            deployment_hash,
+            deployment_data,
+            public_ips,
+            BoundedVec::default(),
+            0,
+            resources,
+            solution_provider_id,
+        )?;
+
+        Ok(().into())
+    }
+
+    pub fn _create_node_contracts_batch(
+        account_id: T::AccountId,
+        contracts: BoundedVec<types::NodeContractInput<T>, T::MaxNodeContractsInBatch>,
+        solution_provider_id: Option<u64>,
+    ) -> DispatchResultWithPostInfo {
+        let twin_id = pallet_tfgrid::TwinIdByAccountID::<T>::get(&account_id)
+            .ok_or(Error::<T>::TwinNotExists)?;
+
+        ensure!(!contracts.is_empty(), Error::<T>::EmptyContractsBatch);
+
+        // Validate the whole batch up front so we fail before creating anything
+        let mut deployments = BTreeSet::new();
+        let mut public_ips_by_farm: BTreeMap<u32, usize> = BTreeMap::new();
+        let mut resources_by_node: BTreeMap<u32, (TfgridNode<T>, Resources)> = BTreeMap::new();
+        for input in &contracts {
+            let node = Self::ensure_node_available_to_deploy(twin_id, input.node_id)?;
+            Self::ensure_deployment_hash_is_unique(input.node_id, &input.deployment_hash)?;
+            ensure!(
+                deployments.insert((input.node_id, input.deployment_hash)),
+                Error::<T>::ContractIsNotUnique
+            );
+
+            let requested = public_ips_by_farm.entry(node.farm_id).or_insert(0);
+            *requested = requested.saturating_add(input.public_ips as usize);
+
+            // Nodes are checked even when nothing is declared so no contract lands on a full node
+            let (_, requested) = resources_by_node
+                .entry(node.id)
+                .or_insert_with(|| (node, Resources::empty()));
+            if let Some(resources) = &input.resources {
+                *requested = requested.clone().saturating_add(resources);
+            }
+        }
+
+        for (node, requested) in resources_by_node.values() {
+            Self::ensure_node_has_capacity(node, requested)?;
+        }
+
+        for (farm_id, requested) in public_ips_by_farm {
+            let farm = pallet_tfgrid::Farms::<T>::get(farm_id).ok_or(Error::<T>::FarmNotExists)?;
+            ensure!(
+                farm.public_ips.len() >= requested,
+                Error::<T>::FarmHasNotEnoughPublicIPs
+            );
+            let free_ips = farm
+                .public_ips
+                .iter()
+                .filter(|ip| ip.contract_id == 0)
+                .count();
+            ensure!(
+                free_ips >= requested,
+                Error::<T>::FarmHasNotEnoughPublicIPsFree
+            );
+        }
+
+        let mut contract_ids = Vec::with_capacity(contracts.len());
+        for input in contracts {
+            let contract = Self::create_node_contract_for_twin(
+                twin_id,
+                input.node_id,
+                input.deployment_hash,
+                input.deployment_data,
+                input.public_ips,
+                BoundedVec::default(),
+                0,
+                input.resources,
+                solution_provider_id,
+            )?;
+            contract_ids.push(contract.contract_id);
+        }
+
+        Self::deposit_event(Event::NodeContractsBatchCreated {
+            twin_id,
+            contract_ids,
+        });
+
+        Ok(().into())
+    }
+
+    fn ensure_node_available_to_deploy(
+        twin_id: u32,
+        node_id: u32,
+    ) -> Result<TfgridNode<T>, DispatchErrorWithPostInfo> {
         let node = pallet_tfgrid::Nodes::<T>::get(node_id).ok_or(Error::<T>::NodeNotExists)?;
+        ensure!(
+            !pallet_tfgrid::DecommissioningNodes::<T>::contains_key(node_id),
+            Error::<T>::NodeIsDecommissioning
+        );
+        ensure!(
+            !pallet_tfgrid::Pallet::<T>::is_node_in_maintenance(node_id),
+            Error::<T>::NodeInMaintenance
+        );
 
         // Don't deploy if node is (or is switched to) standby
         let node_power = pallet_tfgrid::NodePower::<T>::get(node_id);
@@ -58,19 +172,45 @@ impl<T: Config> Pallet<T> {
             }
         }
 
+        Ok(node)
+    }
+
+    fn ensure_deployment_hash_is_unique(
+        node_id: u32,
+        deployment_hash: &types::HexHash,
+    ) -> DispatchResultWithPostInfo {
         // If the contract with hash and node id exists and it's in any other state then
         // contractState::Deleted then we don't allow the creation of it.
         // If it exists we allow the user to "restore" this contract
-        if ContractIDByNodeIDAndHash::<T>::contains_key(node_id, &deployment_hash) {
-            let contract_id = ContractIDByNodeIDAndHash::<T>::get(node_id, &deployment_hash);
+        if ContractIDByNodeIDAndHash::<T>::contains_key(node_id, deployment_hash) {
+            let contract_id = ContractIDByNodeIDAndHash::<T>::get(node_id, deployment_hash);
             let contract = Contracts::<T>::get(contract_id).ok_or(Error::<T>::ContractNotExists)?;
             if !contract.is_state_delete() {
                 return Err(Error::<T>::ContractIsNotUnique.into());
             }
         }
 
-        let public_ips_list: BoundedVec<PublicIP, MaxNodeContractPublicIPs<T>> =
-            vec![].try_into().unwrap();
+        Ok(().into())
+    }
+
+    // Public ips in the list (if any) are the ones to reserve,
+    // otherwise the first free public ips of the farm are reserved
+    pub fn create_node_contract_for_twin(
+        twin_id: u32,
+        node_id: u32,
+        deployment_hash: types::HexHash,
+        deployment_data: DeploymentDataInput<T>,
+        public_ips: u32,
+        public_ips_list: BoundedVec<PublicIP, MaxNodeContractPublicIPs<T>>,
+        ip6_subnets: u32,
+        resources: Option<Resources>,
+        solution_provider_id: Option<u64>,
+    ) -> Result<types::Contract<T>, DispatchErrorWithPostInfo> {
+        let node = Self::ensure_node_available_to_deploy(twin_id, node_id)?;
+        Self::ensure_deployment_hash_is_unique(node_id, &deployment_hash)?;
+        // Contracts without declared resources can not be created on a full node either
+        Self::ensure_node_has_capacity(&node, &resources.clone().unwrap_or_default())?;
+
         // Prepare NodeContract struct
         let node_contract = types::NodeContract {
             node_id,
@@ -87,6 +227,8 @@ impl<T: Config> Pallet<T> {
             solution_provider_id,
         )?;
 
+        Self::reserve_ip6(contract.contract_id, node.farm_id, ip6_subnets)?;
+
         let now = Self::get_current_timestamp_in_secs();
         let contract_billing_information = types::ContractBillingInformation {
             last_updated: now,
@@ -106,9 +248,13 @@ impl<T: Config> Pallet<T> {
         node_contracts.push(contract.contract_id);
         ActiveNodeContracts::<T>::insert(&node_contract.node_id, &node_contracts);
 
-        Self::deposit_event(Event::ContractCreated(contract));
+        if let Some(resources) = resources {
+            Self::reserve_node_resources(node_id, contract.contract_id, resources);
+        }
 
-        Ok(().into())
+        Self::deposit_event(Event::ContractCreated(contract.clone()));
+
+        Ok(contract)
     }
 
     pub fn _create_rent_contract(
@@ -126,6 +272,14 @@ impl<T: Config> Pallet<T> {
             pallet_tfgrid::Farms::<T>::contains_key(node.farm_id),
             Error::<T>::FarmNotExists
         );
+        ensure!(
+            !pallet_tfgrid::DecommissioningNodes::<T>::contains_key(node_id),
+            Error::<T>::NodeIsDecommissioning
+        );
+        ensure!(
+            !pallet_tfgrid::Pallet::<T>::is_node_in_maintenance(node_id),
+            Error::<T>::NodeInMaintenance
+        );
 
         let active_node_contracts = ActiveNodeContracts::<T>::get(node_id);
         let farm = pallet_tfgrid::Farms::<T>::get(node.farm_id).ok_or(Error::<T>::FarmNotExists)?;
@@ -151,11 +305,13 @@ impl<T: Config> Pallet<T> {
         Ok(().into())
     }
 
-    // Registers a DNS name for a Twin
+    // Registers a DNS name for a Twin, forever or for a fixed term (in seconds)
     // Ensures uniqueness and also checks if it's a valid DNS name
+    // which is not reserved for another twin nor blocked
     pub fn _create_name_contract(
         source: T::AccountId,
         name: Vec<u8>,
+        term: Option<u64>,
     ) -> DispatchResultWithPostInfo {
         ensure!(
             pallet_tfgrid::TwinIdByAccountID::<T>::contains_key(&source),
@@ -171,6 +327,10 @@ impl<T: Config> Pallet<T> {
             !ContractIDByNameRegistration::<T>::contains_key(&valid_name),
             Error::<T>::NameExists
         );
+        Self::ensure_name_not_restricted(&valid_name, twin_id)?;
+        if let Some(term) = term {
+            Self::validate_name_contract_term(term)?;
+        }
 
         let name_contract = types::NameContract {
             name: valid_name.clone(),
@@ -183,6 +343,10 @@ impl<T: Config> Pallet<T> {
         )?;
 
         ContractIDByNameRegistration::<T>::insert(valid_name, &contract.contract_id);
+        if let Some(term) = term {
+            let expires_at = Self::get_current_timestamp_in_secs() + term;
+            NameContractExpiry::<T>::insert(contract.contract_id, expires_at);
+        }
 
         Self::deposit_event(Event::ContractCreated(contract));
 
@@ -339,6 +503,9 @@ impl<T: Config> Pallet<T> {
                         }
                     }
                 }
+                if let Err(e) = Self::free_ip6(contract_id, node_contract.node_id) {
+                    log::info!("error while freeing ip6 subnets: {:?}", e);
+                }
 
                 // remove associated storage items
                 Self::remove_active_node_contract(node_contract.node_id, contract_id);
@@ -347,6 +514,7 @@ impl<T: Config> Pallet<T> {
                     &node_contract.deployment_hash,
                 );
                 NodeContractResources::<T>::remove(contract_id);
+                Self::release_node_resources(node_contract.node_id, contract_id);
                 ContractBillingInformationByID::<T>::remove(contract_id);
 
                 Self::deposit_event(Event::NodeContractCanceled {
@@ -357,6 +525,7 @@ impl<T: Config> Pallet<T> {
             }
             types::ContractData::NameContract(name_contract) => {
                 ContractIDByNameRegistration::<T>::remove(name_contract.name);
+                NameContractExpiry::<T>::remove(contract_id);
                 Self::deposit_event(Event::NameContractCanceled { contract_id });
             }
             types::ContractData::RentContract(rent_contract) => {
@@ -373,15 +542,57 @@ impl<T: Config> Pallet<T> {
         log::debug!("removing contract");
         Contracts::<T>::remove(contract_id);
         ContractLock::<T>::remove(contract_id);
+        let _ = ContractBillingHistory::<T>::clear_prefix(
+            contract_id,
+            T::MaxContractBillingHistory::get(),
+            None,
+        );
+        ContractBillingHistoryCount::<T>::remove(contract_id);
+        ContractSpendingCap::<T>::remove(contract_id);
+        ContractTransferOffers::<T>::remove(contract_id);
+        if matches!(contract.state, types::ContractState::GracePeriod(_)) {
+            LastGracePeriodReminder::<T>::remove(contract_id);
+            Self::update_twin_contracts_in_grace(contract.twin_id, contract_id, false);
+        }
 
         // Clean up contract from billing loop
         // This is the only place it should be done
         log::debug!("cleaning up deleted contract from billing loop");
         Self::remove_contract_from_billing_loop(contract_id)?;
 
+        // A node being decommissioned is deleted once its last contract is gone
+        pallet_tfgrid::Pallet::<T>::decommission_node_if_drained(contract.get_node_id());
+
         Ok(().into())
     }
 
+    // Cancels and bills the contracts on a node that goes away
+    fn cancel_node_contracts(node_id: u32, cause: types::Cause) {
+        let active_node_contracts = ActiveNodeContracts::<T>::get(node_id);
+        for node_contract_id in active_node_contracts {
+            if let Some(mut contract) = Contracts::<T>::get(node_contract_id) {
+                // Bill contract
+                let _ = Self::update_contract_state(
+                    &mut contract,
+                    &types::ContractState::Deleted(cause.clone()),
+                );
+                let _ = Self::bill_contract(node_contract_id);
+            }
+        }
+
+        // First clean up rent contract if it exists
+        if let Some(rc_id) = ActiveRentContractForNode::<T>::get(node_id) {
+            if let Some(mut contract) = Contracts::<T>::get(rc_id) {
+                // Bill contract
+                let _ = Self::update_contract_state(
+                    &mut contract,
+                    &types::ContractState::Deleted(cause),
+                );
+                let _ = Self::bill_contract(contract.contract_id);
+            }
+        }
+    }
+
     fn remove_active_node_contract(node_id: u32, contract_id: u64) {
         let mut contracts = ActiveNodeContracts::<T>::get(&node_id);
 
@@ -400,10 +611,19 @@ impl<T: Config> Pallet<T> {
         contract: &mut types::Contract<T>,
         state: &types::ContractState,
     ) -> DispatchResultWithPostInfo {
+        let was_in_grace = matches!(contract.state, types::ContractState::GracePeriod(_));
+
         // update the state and save the contract
         contract.state = state.clone();
         Contracts::<T>::insert(&contract.contract_id, contract.clone());
 
+        // keep track of the contracts in grace period of the twin
+        let in_grace = matches!(state, types::ContractState::GracePeriod(_));
+        if in_grace != was_in_grace {
+            Self::update_twin_contracts_in_grace(contract.twin_id, contract.contract_id, in_grace);
+            LastGracePeriodReminder::<T>::remove(contract.contract_id);
+        }
+
         // if the contract is a name contract, nothing to do left here
         match contract.contract_type {
             types::ContractData::NameContract(_) => return Ok(().into()),
@@ -487,12 +707,22 @@ impl<T: Config> Pallet<T> {
         );
 
         let mut ips: BoundedVec<PublicIP, MaxNodeContractPublicIPs<T>> = vec![].try_into().unwrap();
+        let requested_ips = node_contract.public_ips_list.clone();
 
         for i in 0..farm.public_ips.len() {
             if ips.len() == node_contract.public_ips as usize {
                 break;
             }
 
+            // if specific ips were requested only consider those
+            if !requested_ips.is_empty()
+                && !requested_ips
+                    .iter()
+                    .any(|ip| ip.ip == farm.public_ips[i].ip)
+            {
+                continue;
+            }
+
             // if an ip has contract id 0 it means it's not reserved
             // reserve it now
             if farm.public_ips[i].contract_id == 0 {
@@ -561,6 +791,85 @@ impl<T: Config> Pallet<T> {
         Ok(().into())
     }
 
+    fn reserve_ip6(contract_id: u64, farm_id: u32, ip6_subnets: u32) -> DispatchResultWithPostInfo {
+        if ip6_subnets == 0 {
+            return Ok(().into());
+        }
+
+        let mut prefixes = pallet_tfgrid::FarmIp6Prefixes::<T>::get(farm_id);
+        let mut subnets: BoundedVec<types::Ip6Delegation, MaxNodeContractPublicIPs<T>> =
+            BoundedVec::default();
+
+        for prefix in prefixes.iter_mut() {
+            let (first, count) = match prefix.subnets() {
+                Some(subnets) => subnets,
+                None => continue,
+            };
+
+            // Only `delegated` subnets of the prefix are taken so the free ones
+            // are found within the first `delegated + ip6_subnets` subnets
+            let mut index = 0;
+            while subnets.len() < ip6_subnets as usize && prefix.delegated < count && index < count
+            {
+                let subnet = first + index;
+                index += 1;
+
+                if DelegatedIp6Subnets::<T>::contains_key(farm_id, subnet) {
+                    continue;
+                }
+
+                subnets
+                    .try_push(types::Ip6Delegation {
+                        prefix: prefix.prefix.clone(),
+                        subnet,
+                    })
+                    .map_err(|_| Error::<T>::FailedToReserveIp6Subnets)?;
+                DelegatedIp6Subnets::<T>::insert(farm_id, subnet, contract_id);
+                prefix.delegated += 1;
+            }
+        }
+
+        ensure!(
+            subnets.len() == ip6_subnets as usize,
+            Error::<T>::FarmHasNotEnoughIp6SubnetsFree
+        );
+
+        pallet_tfgrid::FarmIp6Prefixes::<T>::insert(farm_id, prefixes);
+        ContractIp6Subnets::<T>::insert(contract_id, &subnets);
+
+        Self::deposit_event(Event::Ip6SubnetsReserved {
+            contract_id,
+            subnets,
+        });
+
+        Ok(().into())
+    }
+
+    fn free_ip6(contract_id: u64, node_id: u32) -> DispatchResultWithPostInfo {
+        let subnets = ContractIp6Subnets::<T>::take(contract_id);
+        if subnets.is_empty() {
+            return Ok(().into());
+        }
+
+        let node = pallet_tfgrid::Nodes::<T>::get(node_id).ok_or(Error::<T>::NodeNotExists)?;
+
+        let mut prefixes = pallet_tfgrid::FarmIp6Prefixes::<T>::get(node.farm_id);
+        for delegation in subnets.iter() {
+            DelegatedIp6Subnets::<T>::remove(node.farm_id, delegation.subnet);
+            if let Some(prefix) = prefixes.iter_mut().find(|p| p.prefix == delegation.prefix) {
+                prefix.delegated = prefix.delegated.saturating_sub(1);
+            }
+        }
+        pallet_tfgrid::FarmIp6Prefixes::<T>::insert(node.farm_id, prefixes);
+
+        Self::deposit_event(Event::Ip6SubnetsFreed {
+            contract_id,
+            subnets,
+        });
+
+        Ok(().into())
+    }
+
     pub fn _report_contract_resources(
         source: T::AccountId,
         contract_resources: Vec<types::ContractResources>,
@@ -589,6 +898,11 @@ impl<T: Config> Pallet<T> {
 
                 // Do insert
                 NodeContractResources::<T>::insert(contract.contract_id, &contract_resource);
+                Self::reserve_reported_resources(
+                    node_id,
+                    contract.contract_id,
+                    &contract_resource.used,
+                );
 
                 // deposit event
                 Self::deposit_event(Event::UpdatedUsedResources(contract_resource));
@@ -687,36 +1001,88 @@ impl<T: Config> PublicIpModifier for Pallet<T> {
             }
         }
     }
+
+    fn ip6_prefix_removed(farm_id: u32, prefix: &PublicIp6Prefix) {
+        let (first, count) = match prefix.subnets() {
+            Some(subnets) => subnets,
+            None => return,
+        };
+
+        let contract_ids: BTreeSet<u64> = DelegatedIp6Subnets::<T>::iter_prefix(farm_id)
+            .filter(|(subnet, _)| *subnet >= first && *subnet - first < count)
+            .map(|(_, contract_id)| contract_id)
+            .collect();
+
+        // The prefix is gone, only its delegations need to be freed
+        for contract_id in contract_ids {
+            let mut subnets = ContractIp6Subnets::<T>::get(contract_id);
+            let freed: Vec<types::Ip6Delegation> = subnets
+                .iter()
+                .filter(|delegation| delegation.prefix == prefix.prefix)
+                .cloned()
+                .collect();
+            subnets.retain(|delegation| delegation.prefix != prefix.prefix);
+
+            for delegation in freed.iter() {
+                DelegatedIp6Subnets::<T>::remove(farm_id, delegation.subnet);
+            }
+            if subnets.is_empty() {
+                ContractIp6Subnets::<T>::remove(contract_id);
+            } else {
+                ContractIp6Subnets::<T>::insert(contract_id, subnets);
+            }
+
+            Self::deposit_event(Event::Ip6SubnetsFreed {
+                contract_id,
+                subnets: BoundedVec::truncate_from(freed),
+            });
+        }
+    }
 }
 
 impl<T: Config> ChangeNode<LocationOf<T>, InterfaceOf<T>, SerialNumberOf<T>> for Pallet<T> {
     fn node_changed(_node: Option<&TfgridNode<T>>, _new_node: &TfgridNode<T>) {}
 
     fn node_deleted(node: &TfgridNode<T>) {
-        // Clean up all active contracts
-        let active_node_contracts = ActiveNodeContracts::<T>::get(node.id);
-        for node_contract_id in active_node_contracts {
-            if let Some(mut contract) = Contracts::<T>::get(node_contract_id) {
-                // Bill contract
-                let _ = Self::update_contract_state(
-                    &mut contract,
-                    &types::ContractState::Deleted(types::Cause::CanceledByUser),
-                );
-                let _ = Self::bill_contract(node_contract_id);
-            }
+        Self::cancel_node_contracts(node.id, types::Cause::CanceledByUser);
+
+        NodeWakeupRequests::<T>::remove(node.id);
+    }
+
+    fn node_decommissioning(node: &TfgridNode<T>, deadline: u64) {
+        let contract_ids = ActiveNodeContracts::<T>::get(node.id)
+            .into_iter()
+            .chain(ActiveRentContractForNode::<T>::get(node.id));
+        for contract_id in contract_ids {
+            Self::deposit_event(Event::ContractNodeDecommissioning {
+                contract_id,
+                node_id: node.id,
+                deadline,
+            });
         }
+    }
 
-        // First clean up rent contract if it exists
-        if let Some(rc_id) = ActiveRentContractForNode::<T>::get(node.id) {
-            if let Some(mut contract) = Contracts::<T>::get(rc_id) {
-                // Bill contract
+    fn node_decommissioned(node: &TfgridNode<T>, max_contracts: u32) -> Weight {
+        // Rent contract goes last so it is not canceled before the node contracts
+        let contract_ids: Vec<u64> = ActiveNodeContracts::<T>::get(node.id)
+            .into_iter()
+            .chain(ActiveRentContractForNode::<T>::get(node.id))
+            .take(max_contracts as usize)
+            .collect();
+
+        let mut weight = T::DbWeight::get().reads(2);
+        for contract_id in contract_ids {
+            if let Some(mut contract) = Contracts::<T>::get(contract_id) {
                 let _ = Self::update_contract_state(
                     &mut contract,
-                    &types::ContractState::Deleted(types::Cause::CanceledByUser),
+                    &types::ContractState::Deleted(types::Cause::NodeDecommissioned),
                 );
-                let _ = Self::bill_contract(contract.contract_id);
+                let _ = Self::bill_contract(contract_id);
             }
+            weight.saturating_accrue(<T as Config>::WeightInfo::cancel_contract());
         }
+
+        weight
     }
 
     fn node_power_state_changed(node: &TfgridNode<T>) {
@@ -724,6 +1090,8 @@ impl<T: Config> ChangeNode<LocationOf<T>, InterfaceOf<T>, SerialNumberOf<T>> for
         // So update contract lock timestamp when node power state comes back to Up
         let node_power = pallet_tfgrid::NodePower::<T>::get(node.id);
         if !node_power.is_standby() {
+            Self::node_wakeup_completed(node.id);
+
             if let Some(rc_id) = ActiveRentContractForNode::<T>::get(node.id) {
                 let mut contract_lock = ContractLock::<T>::get(rc_id);
                 let now = Self::get_current_timestamp_in_secs();
@@ -1,11 +1,19 @@
 #![cfg_attr(not(feature = "std"), no_std)]
 
 pub mod billing;
+pub mod capacity;
 pub mod cost;
+pub mod grace_period;
 pub mod grid_contract;
 pub mod migrations;
+pub mod name_contract;
+pub mod node_wakeup;
+pub mod public_ip;
 pub mod service_contract;
+pub mod service_plan;
 pub mod solution_provider;
+pub mod spending_cap;
+pub mod transfer;
 pub mod types;
 pub mod weights;
 
@@ -63,7 +71,11 @@ pub mod pallet {
     use super::*;
     use frame_support::{
         pallet_prelude::*,
-        traits::{Currency, Get, Hooks, LockIdentifier, LockableCurrency, OnUnbalanced},
+        traits::{
+            Currency, Get, Hooks, LockIdentifier, LockableCurrency, OnUnbalanced,
+            ReservableCurrency,
+        },
+        PalletId,
     };
     use frame_system::{
         self as system, ensure_signed,
@@ -77,7 +89,7 @@ pub mod pallet {
         fmt::Debug,
         vec::Vec,
     };
-    use tfchain_support::traits::PublicIpModifier;
+    use tfchain_support::{resources::Resources, traits::PublicIpModifier};
 
     pub type BalanceOf<T> =
         <<T as Config>::Currency as Currency<<T as system::Config>::AccountId>>::Balance;
@@ -85,6 +97,8 @@ pub mod pallet {
         <<T as Config>::Currency as Currency<<T as system::Config>::AccountId>>::NegativeImbalance;
 
     pub const GRID_LOCK_ID: LockIdentifier = *b"gridlock";
+    // Account holding the accrued solution provider shares until they are claimed
+    pub const SOLUTION_PROVIDER_REWARDS_ID: PalletId = PalletId(*b"tf/sprwd");
     use tfchain_support::types::PublicIP;
 
     #[pallet::pallet]
@@ -144,6 +158,35 @@ pub mod pallet {
     pub type ContractIDByNameRegistration<T: Config> =
         StorageMap<_, Blake2_128Concat, T::NameContractName, u64, ValueQuery>;
 
+    // Time (in seconds) at which a fixed-term name registration expires
+    #[pallet::storage]
+    #[pallet::getter(fn name_contract_expiry)]
+    pub type NameContractExpiry<T> = StorageMap<_, Blake2_128Concat, u64, u64, OptionQuery>;
+
+    #[pallet::type_value]
+    pub fn DefaultNameContractHoldPeriod() -> u64 {
+        types::DEFAULT_NAME_CONTRACT_HOLD_PERIOD
+    }
+
+    // Time (in seconds) an expired name is held for its owner before the name contract is removed
+    #[pallet::storage]
+    #[pallet::getter(fn name_contract_hold_period)]
+    pub type NameContractHoldPeriod<T> =
+        StorageValue<_, u64, ValueQuery, DefaultNameContractHoldPeriod>;
+
+    // Names reserved or blocked by the council, by exact name or prefix
+    #[pallet::storage]
+    #[pallet::getter(fn name_restrictions)]
+    pub type NameRestrictions<T: Config> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        types::NameMatching,
+        Blake2_128Concat,
+        T::NameContractName,
+        types::NameRestriction,
+        OptionQuery,
+    >;
+
     #[pallet::storage]
     #[pallet::getter(fn active_rent_contracts)]
     // A mapping between a Node ID and Contract ID
@@ -186,6 +229,70 @@ pub mod pallet {
     #[pallet::getter(fn service_contract_id)]
     pub type ServiceContractID<T> = StorageValue<_, u64, ValueQuery>;
 
+    // Dispute window (in seconds) of the service contracts billed in escrow mode
+    #[pallet::storage]
+    #[pallet::getter(fn service_contract_dispute_window)]
+    pub type ServiceContractDisputeWindow<T> =
+        StorageMap<_, Blake2_128Concat, u64, u64, OptionQuery>;
+
+    #[pallet::storage]
+    #[pallet::getter(fn service_contract_escrowed_bills)]
+    pub type ServiceContractEscrowedBills<T: Config> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        u64,
+        Blake2_128Concat,
+        u64,
+        EscrowedServiceContractBill<BalanceOf<T>>,
+        OptionQuery,
+    >;
+
+    #[pallet::storage]
+    #[pallet::getter(fn service_contract_bill_id)]
+    pub type ServiceContractBillID<T> = StorageValue<_, u64, ValueQuery>;
+
+    // Service contracts whose base fee is billed at a billing loop index
+    #[pallet::storage]
+    #[pallet::getter(fn service_contract_to_bill_at_block)]
+    pub type ServiceContractsToBillAt<T> =
+        StorageMap<_, Blake2_128Concat, u64, Vec<u64>, ValueQuery>;
+
+    // Last time (in seconds) the variable amount of a service contract was billed
+    #[pallet::storage]
+    #[pallet::getter(fn service_contract_last_variable_bill)]
+    pub type ServiceContractLastVariableBill<T> =
+        StorageMap<_, Blake2_128Concat, u64, u64, OptionQuery>;
+
+    // Variable amount left to bill with the terms before the last accepted amendment
+    #[pallet::storage]
+    #[pallet::getter(fn service_contract_pending_variable_bill)]
+    pub type ServiceContractPendingVariableBill<T> =
+        StorageMap<_, Blake2_128Concat, u64, types::PendingVariableBill, OptionQuery>;
+
+    // Block at which a service contract entered grace period
+    #[pallet::storage]
+    #[pallet::getter(fn service_contract_grace_period_start)]
+    pub type ServiceContractGracePeriodStart<T> =
+        StorageMap<_, Blake2_128Concat, u64, u64, OptionQuery>;
+
+    #[pallet::storage]
+    #[pallet::getter(fn service_contract_amendment)]
+    pub type ServiceContractAmendments<T> =
+        StorageMap<_, Blake2_128Concat, u64, ServiceContractAmendment, OptionQuery>;
+
+    #[pallet::storage]
+    #[pallet::getter(fn service_plans)]
+    pub type ServicePlans<T> = StorageMap<_, Blake2_128Concat, u64, ServicePlan, OptionQuery>;
+
+    #[pallet::storage]
+    #[pallet::getter(fn service_plan_id)]
+    pub type ServicePlanID<T> = StorageValue<_, u64, ValueQuery>;
+
+    // Plan a service contract was created from by subscribing to it
+    #[pallet::storage]
+    #[pallet::getter(fn service_contract_plan)]
+    pub type ServiceContractPlan<T> = StorageMap<_, Blake2_128Concat, u64, u64, OptionQuery>;
+
     /// The current migration's stage, if any.
     #[pallet::storage]
     #[pallet::getter(fn current_migration_stage)]
@@ -195,6 +302,162 @@ pub mod pallet {
     #[pallet::getter(fn dedicated_nodes_extra_fee)]
     pub type DedicatedNodesExtraFee<T> = StorageMap<_, Blake2_128Concat, u32, u64, ValueQuery>;
 
+    #[pallet::type_value]
+    pub fn DefaultPublicIpPriceMultiplier() -> u32 {
+        types::DEFAULT_PUBLIC_IP_PRICE_MULTIPLIER
+    }
+
+    // Price multiplier (in percent) a farm set on one of its public ips
+    #[pallet::storage]
+    #[pallet::getter(fn public_ip_price_multiplier)]
+    pub type PublicIpPriceMultiplier<T> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        u32,
+        Blake2_128Concat,
+        pallet_tfgrid::Ip4Input,
+        u32,
+        ValueQuery,
+        DefaultPublicIpPriceMultiplier,
+    >;
+
+    // Contract a /64 subnet of a farm is delegated to
+    #[pallet::storage]
+    #[pallet::getter(fn delegated_ip6_subnets)]
+    pub type DelegatedIp6Subnets<T> =
+        StorageDoubleMap<_, Blake2_128Concat, u32, Blake2_128Concat, u64, u64, OptionQuery>;
+
+    // /64 subnets delegated to a node contract
+    #[pallet::storage]
+    #[pallet::getter(fn contract_ip6_subnets)]
+    pub type ContractIp6Subnets<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        u64,
+        BoundedVec<types::Ip6Delegation, MaxNodeContractPublicIPs<T>>,
+        ValueQuery,
+    >;
+
+    // Last bills of a contract, kept in a ring of `MaxContractBillingHistory` slots
+    // so the oldest bill gets overwritten first
+    #[pallet::storage]
+    pub type ContractBillingHistory<T: Config> =
+        StorageDoubleMap<_, Blake2_128Concat, u64, Twox64Concat, u32, ContractBill, OptionQuery>;
+
+    // Amount of bills recorded for a contract, the next bill goes in
+    // slot `count % MaxContractBillingHistory`
+    #[pallet::storage]
+    #[pallet::getter(fn contract_billing_history_count)]
+    pub type ContractBillingHistoryCount<T: Config> =
+        StorageMap<_, Blake2_128Concat, u64, u64, ValueQuery>;
+
+    // Amount billed to a twin per day (days since unix epoch)
+    #[pallet::storage]
+    pub type TwinBillingHistory<T: Config> =
+        StorageDoubleMap<_, Blake2_128Concat, u32, Twox64Concat, u64, u128, ValueQuery>;
+
+    // Days a twin was billed on, oldest first. Days out of the retention window are dropped
+    #[pallet::storage]
+    #[pallet::getter(fn twin_billing_days)]
+    pub type TwinBillingDays<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        u32,
+        BoundedVec<u64, T::MaxTwinBillingHistoryDays>,
+        ValueQuery,
+    >;
+
+    #[pallet::storage]
+    #[pallet::getter(fn contract_spending_cap)]
+    pub type ContractSpendingCap<T: Config> =
+        StorageMap<_, Blake2_128Concat, u64, types::SpendingCap<BalanceOf<T>>, OptionQuery>;
+
+    #[pallet::storage]
+    #[pallet::getter(fn twin_spending_cap)]
+    pub type TwinSpendingCap<T: Config> =
+        StorageMap<_, Blake2_128Concat, u32, types::SpendingCap<BalanceOf<T>>, OptionQuery>;
+
+    // Grace period (in blocks) overriding the runtime default for a contract kind
+    #[pallet::storage]
+    #[pallet::getter(fn contract_kind_grace_period)]
+    pub type ContractKindGracePeriod<T> =
+        StorageMap<_, Blake2_128Concat, types::ContractKind, u64, OptionQuery>;
+
+    // Grace period (in blocks) for contracts of a solution provider,
+    // takes precedence over the grace period of the contract kind
+    #[pallet::storage]
+    #[pallet::getter(fn solution_provider_grace_period)]
+    pub type SolutionProviderGracePeriod<T> =
+        StorageMap<_, Blake2_128Concat, u64, u64, OptionQuery>;
+
+    // Total amount a provider account earned from the contracts of a solution provider
+    #[pallet::storage]
+    #[pallet::getter(fn solution_provider_account_earnings)]
+    pub type SolutionProviderAccountEarnings<T: Config> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        u64,
+        Blake2_128Concat,
+        T::AccountId,
+        BalanceOf<T>,
+        ValueQuery,
+    >;
+
+    // Total amount the providers of a solution provider earned from a contract
+    #[pallet::storage]
+    #[pallet::getter(fn solution_provider_contract_earnings)]
+    pub type SolutionProviderContractEarnings<T: Config> =
+        StorageDoubleMap<_, Blake2_128Concat, u64, Blake2_128Concat, u64, BalanceOf<T>, ValueQuery>;
+
+    // Provider accounts that accrue their shares instead of receiving them on every bill
+    #[pallet::storage]
+    #[pallet::getter(fn solution_provider_rewards_accrual)]
+    pub type SolutionProviderRewardsAccrual<T: Config> =
+        StorageMap<_, Blake2_128Concat, T::AccountId, bool, ValueQuery>;
+
+    // Shares accrued by a provider account that were not claimed yet
+    #[pallet::storage]
+    #[pallet::getter(fn unclaimed_solution_provider_rewards)]
+    pub type UnclaimedSolutionProviderRewards<T: Config> =
+        StorageMap<_, Blake2_128Concat, T::AccountId, BalanceOf<T>, ValueQuery>;
+
+    // Contracts of a twin that are in grace period
+    #[pallet::storage]
+    pub type TwinContractsInGrace<T> =
+        StorageDoubleMap<_, Blake2_128Concat, u32, Blake2_128Concat, u64, (), OptionQuery>;
+
+    // Block at which the last grace period reminder of a contract was emitted
+    #[pallet::storage]
+    #[pallet::getter(fn last_grace_period_reminder)]
+    pub type LastGracePeriodReminder<T> = StorageMap<_, Blake2_128Concat, u64, u64, OptionQuery>;
+
+    // Resources reserved on a node by the node contracts deployed on it
+    #[pallet::storage]
+    #[pallet::getter(fn node_reserved_resources)]
+    pub type NodeReservedResources<T> = StorageMap<_, Blake2_128Concat, u32, Resources, ValueQuery>;
+
+    // Resources a node contract reserved on its node at creation
+    #[pallet::storage]
+    #[pallet::getter(fn contract_reserved_resources)]
+    pub type ContractReservedResources<T> =
+        StorageMap<_, Blake2_128Concat, u64, Resources, OptionQuery>;
+
+    // Pending transfer of a contract, to the twin that can accept it
+    #[pallet::storage]
+    #[pallet::getter(fn contract_transfer_offer)]
+    pub type ContractTransferOffers<T> = StorageMap<_, Blake2_128Concat, u64, u32, OptionQuery>;
+
+    // Pending wake-up request of a standby node
+    #[pallet::storage]
+    #[pallet::getter(fn node_wakeup_requests)]
+    pub type NodeWakeupRequests<T> =
+        StorageMap<_, Blake2_128Concat, u32, types::NodeWakeupRequest, OptionQuery>;
+
+    // Nodes of which the wake-up request times out at a block
+    #[pallet::storage]
+    pub type NodeWakeupTimeouts<T> =
+        StorageDoubleMap<_, Blake2_128Concat, u64, Blake2_128Concat, u32, (), OptionQuery>;
+
     #[pallet::config]
     pub trait Config:
         CreateSignedTransaction<Call<Self>>
@@ -207,7 +470,7 @@ pub mod pallet {
         + pallet_session::Config
     {
         type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
-        type Currency: LockableCurrency<Self::AccountId>;
+        type Currency: LockableCurrency<Self::AccountId> + ReservableCurrency<Self::AccountId>;
         /// Handler for the unbalanced decrement when slashing (burning collateral)
         type Burn: OnUnbalanced<NegativeImbalanceOf<Self>>;
         type StakingPoolAccount: Get<Self::AccountId>;
@@ -215,6 +478,14 @@ pub mod pallet {
         type BillingReferencePeriod: Get<u64>;
         type DistributionFrequency: Get<u16>;
         type GracePeriod: Get<u64>;
+        /// Amount of blocks between two reminders for a contract in grace period
+        type GracePeriodReminderFrequency: Get<u64>;
+        /// Maximum number of contracts restored from grace period in a single call
+        #[pallet::constant]
+        type MaxContractsRestoredFromGrace: Get<u32>;
+        /// Maximum number of node contracts created in a single batch
+        #[pallet::constant]
+        type MaxNodeContractsInBatch: Get<u32>;
         type WeightInfo: weights::WeightInfo;
         type PublicIpModifier: PublicIpModifier;
         type AuthorityId: AppCrypto<Self::Public, Self::Signature>;
@@ -229,6 +500,18 @@ pub mod pallet {
         #[pallet::constant]
         type MaxNodeContractPublicIps: Get<u32>;
 
+        /// Maximum number of bills kept in the billing history of a contract
+        #[pallet::constant]
+        type MaxContractBillingHistory: Get<u32>;
+
+        /// Maximum number of days kept in the billing history of a twin
+        #[pallet::constant]
+        type MaxTwinBillingHistoryDays: Get<u32>;
+
+        /// Amount of blocks a standby node has to come back up after a wake-up request
+        #[pallet::constant]
+        type NodeWakeupTimeout: Get<u64>;
+
         /// The type of a name contract name.
         type NameContractName: FullCodec
             + Debug
@@ -302,6 +585,15 @@ pub mod pallet {
             node_id: u32,
             twin_id: u32,
         },
+        /// A Contract is still in grace period, the amount needed should be
+        /// added to the twin balance before the remaining blocks run out
+        ContractGracePeriodReminder {
+            contract_id: u64,
+            node_id: u32,
+            twin_id: u32,
+            remaining_blocks: u64,
+            amount_needed: BalanceOf<T>,
+        },
         SolutionProviderCreated(types::SolutionProvider<T::AccountId>),
         SolutionProviderApproved(u64, bool),
         /// A Service contract is created
@@ -328,6 +620,227 @@ pub mod pallet {
             node_id: u32,
             extra_fee: u64,
         },
+        /// A spending cap was set on a contract
+        ContractSpendingCapSet {
+            contract_id: u64,
+            amount: BalanceOf<T>,
+            period: u64,
+            action: types::SpendingCapAction,
+        },
+        /// A spending cap was removed from a contract
+        ContractSpendingCapCleared {
+            contract_id: u64,
+        },
+        /// A spending cap was set on a twin
+        TwinSpendingCapSet {
+            twin_id: u32,
+            amount: BalanceOf<T>,
+            period: u64,
+            action: types::SpendingCapAction,
+        },
+        /// A spending cap was removed from a twin
+        TwinSpendingCapCleared {
+            twin_id: u32,
+        },
+        /// Billing a contract would exceed a spending cap, the contract is moved to grace or paused
+        SpendingCapReached {
+            contract_id: u64,
+            twin_id: u32,
+            scope: types::SpendingCapScope,
+            action: types::SpendingCapAction,
+        },
+        /// Grace period of a contract kind was changed, `None` means the default is used
+        ContractKindGracePeriodSet {
+            kind: types::ContractKind,
+            grace_period: Option<u64>,
+        },
+        /// Grace period of a solution provider was changed, `None` means the contract kind one is used
+        SolutionProviderGracePeriodSet {
+            solution_provider_id: u64,
+            grace_period: Option<u64>,
+        },
+        /// A batch of node contracts was created, ids are in the order of the batch
+        NodeContractsBatchCreated {
+            twin_id: u32,
+            contract_ids: Vec<u64>,
+        },
+        /// The owner of a contract offered to transfer it to another twin
+        ContractTransferOffered {
+            contract_id: u64,
+            from_twin_id: u32,
+            to_twin_id: u32,
+        },
+        /// The owner of a contract withdrew its transfer offer
+        ContractTransferCanceled {
+            contract_id: u64,
+        },
+        /// A contract was transferred to the twin it was offered to
+        ContractTransferred {
+            contract_id: u64,
+            from_twin_id: u32,
+            to_twin_id: u32,
+        },
+        /// Dispute window of a service contract was changed, `None` disables escrow mode
+        ServiceContractDisputeWindowSet {
+            service_contract_id: u64,
+            dispute_window: Option<u64>,
+        },
+        /// A Service contract bill is held in escrow until its dispute deadline
+        ServiceContractBillEscrowed {
+            service_contract_id: u64,
+            bill_id: u64,
+            amount: BalanceOf<T>,
+            dispute_deadline: u64,
+        },
+        /// A Service contract bill in escrow is disputed by the consumer
+        ServiceContractBillDisputed {
+            service_contract_id: u64,
+            bill_id: u64,
+        },
+        /// A party of a Service contract proposed a refund to settle a dispute
+        ServiceContractDisputeRefundProposed {
+            service_contract_id: u64,
+            bill_id: u64,
+            twin_id: u32,
+            refund: BalanceOf<T>,
+        },
+        /// A dispute is settled, the refund goes back to the consumer and the rest to the service
+        ServiceContractDisputeResolved {
+            service_contract_id: u64,
+            bill_id: u64,
+            refund: BalanceOf<T>,
+            paid: BalanceOf<T>,
+        },
+        /// A Service contract bill in escrow is paid to the service
+        ServiceContractBillReleased {
+            service_contract_id: u64,
+            bill_id: u64,
+            amount: BalanceOf<T>,
+        },
+        /// Consumer of a Service contract can not pay its base fee anymore
+        ServiceContractGracePeriodStarted {
+            service_contract_id: u64,
+            block_number: u64,
+        },
+        /// Consumer of a Service contract paid its base fee again
+        ServiceContractGracePeriodEnded {
+            service_contract_id: u64,
+        },
+        /// New terms are proposed for a Service contract
+        ServiceContractAmendmentProposed {
+            service_contract_id: u64,
+            amendment: types::ServiceContractAmendment,
+        },
+        /// Proposed terms of a Service contract are rejected or withdrawn
+        ServiceContractAmendmentRejected {
+            service_contract_id: u64,
+            twin_id: u32,
+        },
+        /// Proposed terms of a Service contract are accepted and apply from now on
+        ServiceContractAmended(types::ServiceContract),
+        /// A Service plan is published
+        ServicePlanCreated(types::ServicePlan),
+        /// Terms of a Service plan are changed for new subscriptions
+        ServicePlanUpdated(types::ServicePlan),
+        /// A Service plan does not accept new subscriptions anymore
+        ServicePlanRetired {
+            plan_id: u64,
+        },
+        /// A consumer subscribed to a Service plan
+        ServicePlanSubscribed {
+            plan_id: u64,
+            service_contract_id: u64,
+            consumer_twin_id: u32,
+        },
+        /// A provider account opted in or out of accruing its solution provider shares
+        SolutionProviderRewardsAccrualSet {
+            account_id: T::AccountId,
+            accrue: bool,
+        },
+        /// A provider account claimed its accrued solution provider shares
+        SolutionProviderRewardsClaimed {
+            account_id: T::AccountId,
+            amount: BalanceOf<T>,
+        },
+        SolutionProviderUpdated(types::SolutionProvider<T::AccountId>),
+        SolutionProviderRevoked(u64),
+        /// Expiry of a solution provider was changed, `None` means it does not expire
+        SolutionProviderExpirySet {
+            solution_provider_id: u64,
+            expires_at: Option<u64>,
+        },
+        /// A fixed-term name registration was renewed until the given time (in seconds)
+        NameContractRenewed {
+            contract_id: u64,
+            expires_at: u64,
+        },
+        /// Time (in seconds) expired names are held for their owner was changed
+        NameContractHoldPeriodSet {
+            hold_period: u64,
+        },
+        /// A fixed-term name registration expired, the name is held for its owner
+        /// and no longer usable until it is renewed or released at the given time (in seconds)
+        NameContractExpired {
+            contract_id: u64,
+            name: T::NameContractName,
+            released_at: u64,
+        },
+        /// Names matching the pattern are reserved or blocked
+        NameRestrictionAdded {
+            pattern: T::NameContractName,
+            matching: types::NameMatching,
+            restriction: types::NameRestriction,
+        },
+        /// Names matching the pattern are no longer reserved or blocked
+        NameRestrictionRemoved {
+            pattern: T::NameContractName,
+            matching: types::NameMatching,
+        },
+        /// Reserved names matching the pattern are assigned to a twin, `None` means to no twin
+        ReservedNameAssigned {
+            pattern: T::NameContractName,
+            matching: types::NameMatching,
+            twin_id: Option<u32>,
+        },
+        /// A farm set the price multiplier (in percent) of one of its public ips
+        PublicIpPriceMultiplierSet {
+            farm_id: u32,
+            ip: pallet_tfgrid::Ip4Input,
+            multiplier: u32,
+        },
+        /// IPv6 /64 subnets got delegated to a Node contract
+        Ip6SubnetsReserved {
+            contract_id: u64,
+            subnets: BoundedVec<types::Ip6Delegation, MaxNodeContractPublicIPs<T>>,
+        },
+        /// IPv6 /64 subnets got freed by a Node contract
+        Ip6SubnetsFreed {
+            contract_id: u64,
+            subnets: BoundedVec<types::Ip6Delegation, MaxNodeContractPublicIPs<T>>,
+        },
+        /// A twin with contracts on a standby node asked for the node to be woken up
+        NodeWakeupRequested {
+            node_id: u32,
+            twin_id: u32,
+            timeout_at: u64,
+        },
+        /// A node came back up after a wake-up request
+        NodeWakeupSucceeded {
+            node_id: u32,
+            twin_id: u32,
+        },
+        /// A node did not come back up in time after a wake-up request
+        NodeWakeupTimedOut {
+            node_id: u32,
+            twin_id: u32,
+        },
+        /// The node of a contract is being decommissioned, the contract is canceled
+        /// at the deadline (block) if it did not move off the node
+        ContractNodeDecommissioning {
+            contract_id: u64,
+            node_id: u32,
+            deadline: u64,
+        },
     }
 
     #[pallet::error]
@@ -384,6 +897,57 @@ pub mod pallet {
         WrongAuthority,
         UnauthorizedToChangeSolutionProviderId,
         UnauthorizedToSetExtraFee,
+        InvalidSpendingCapPeriod,
+        SpendingCapNotExists,
+        InvalidGracePeriod,
+        NoContractsInGrace,
+        EmptyContractsBatch,
+        NodeHasNotEnoughCapacity,
+        CannotTransferContractInGraceState,
+        CannotTransferContractToSameTwin,
+        ContractTransferNotOffered,
+        InvalidDisputeWindow,
+        ServiceContractBillNotInEscrow,
+        ServiceContractDisputeWindowClosed,
+        ServiceContractDisputeWindowNotClosed,
+        ServiceContractBillDisputed,
+        ServiceContractBillNotDisputed,
+        InvalidServiceContractBillRefund,
+        ServiceContractAmendmentNotAllowed,
+        InvalidServiceContractAmendment,
+        ServiceContractAmendmentNotProposed,
+        CannotAmendServiceContractInGracePeriod,
+        ServicePlanNotExists,
+        ServicePlanIsRetired,
+        InvalidServicePlan,
+        NoSolutionProviderRewardsToClaim,
+        UnauthorizedToUpdateSolutionProvider,
+        SolutionProviderRevoked,
+        SolutionProviderExpired,
+        InvalidSolutionProviderExpiry,
+        InvalidNameContractTerm,
+        NameContractHasNoTerm,
+        NameContractExpired,
+        NameReserved,
+        NameBlocked,
+        NameRestrictionExists,
+        NameRestrictionNotExists,
+        NameRestrictionNotReserved,
+        InvalidPublicIpsRequest,
+        PublicIpNotExists,
+        PublicIpNotAvailable,
+        PublicIpsBlockNotAvailable,
+        UnauthorizedToSetPublicIpPriceMultiplier,
+        InvalidPublicIpPriceMultiplier,
+        PublicIpIsReserved,
+        FarmHasNotEnoughIp6SubnetsFree,
+        FailedToReserveIp6Subnets,
+        TwinNotAuthorizedToWakeUpNode,
+        NodeNotInStandby,
+        NodeWakeupAlreadyRequested,
+        NodeIsDecommissioning,
+        NodeInMaintenance,
+        ServiceContractEscrowedAmountNotReserved,
     }
 
     #[pallet::genesis_config]
@@ -419,6 +983,7 @@ pub mod pallet {
                 deployment_hash,
                 deployment_data,
                 public_ips,
+                None,
                 solution_provider_id,
             )
         }
@@ -452,7 +1017,7 @@ pub mod pallet {
             name: Vec<u8>,
         ) -> DispatchResultWithPostInfo {
             let account_id = ensure_signed(origin)?;
-            Self::_create_name_contract(account_id, name)
+            Self::_create_name_contract(account_id, name, None)
         }
 
         #[pallet::call_index(5)]
@@ -645,11 +1210,508 @@ pub mod pallet {
             <T as Config>::RestrictedOrigin::ensure_origin(origin)?;
             Self::_cancel_contract_collective(contract_id, types::Cause::CanceledByCollective)
         }
+
+        #[pallet::call_index(22)]
+        #[pallet::weight(<T as Config>::WeightInfo::set_contract_spending_cap())]
+        pub fn set_contract_spending_cap(
+            origin: OriginFor<T>,
+            contract_id: u64,
+            amount: BalanceOf<T>,
+            period: u64,
+            action: types::SpendingCapAction,
+        ) -> DispatchResultWithPostInfo {
+            let account_id = ensure_signed(origin)?;
+            Self::_set_contract_spending_cap(account_id, contract_id, amount, period, action)
+        }
+
+        #[pallet::call_index(23)]
+        #[pallet::weight(<T as Config>::WeightInfo::clear_contract_spending_cap())]
+        pub fn clear_contract_spending_cap(
+            origin: OriginFor<T>,
+            contract_id: u64,
+        ) -> DispatchResultWithPostInfo {
+            let account_id = ensure_signed(origin)?;
+            Self::_clear_contract_spending_cap(account_id, contract_id)
+        }
+
+        #[pallet::call_index(24)]
+        #[pallet::weight(<T as Config>::WeightInfo::set_twin_spending_cap())]
+        pub fn set_twin_spending_cap(
+            origin: OriginFor<T>,
+            amount: BalanceOf<T>,
+            period: u64,
+            action: types::SpendingCapAction,
+        ) -> DispatchResultWithPostInfo {
+            let account_id = ensure_signed(origin)?;
+            Self::_set_twin_spending_cap(account_id, amount, period, action)
+        }
+
+        #[pallet::call_index(25)]
+        #[pallet::weight(<T as Config>::WeightInfo::clear_twin_spending_cap())]
+        pub fn clear_twin_spending_cap(origin: OriginFor<T>) -> DispatchResultWithPostInfo {
+            let account_id = ensure_signed(origin)?;
+            Self::_clear_twin_spending_cap(account_id)
+        }
+
+        #[pallet::call_index(26)]
+        #[pallet::weight(<T as Config>::WeightInfo::set_contract_kind_grace_period())]
+        pub fn set_contract_kind_grace_period(
+            origin: OriginFor<T>,
+            kind: types::ContractKind,
+            grace_period: Option<u64>,
+        ) -> DispatchResultWithPostInfo {
+            <T as Config>::RestrictedOrigin::ensure_origin(origin)?;
+            Self::_set_contract_kind_grace_period(kind, grace_period)
+        }
+
+        #[pallet::call_index(27)]
+        #[pallet::weight(<T as Config>::WeightInfo::set_solution_provider_grace_period())]
+        pub fn set_solution_provider_grace_period(
+            origin: OriginFor<T>,
+            solution_provider_id: u64,
+            grace_period: Option<u64>,
+        ) -> DispatchResultWithPostInfo {
+            <T as Config>::RestrictedOrigin::ensure_origin(origin)?;
+            Self::_set_solution_provider_grace_period(solution_provider_id, grace_period)
+        }
+
+        #[pallet::call_index(28)]
+        #[pallet::weight(<T as Config>::WeightInfo::restore_contracts_from_grace(
+            T::MaxContractsRestoredFromGrace::get()
+        ))]
+        pub fn restore_contracts_from_grace(
+            origin: OriginFor<T>,
+            twin_id: u32,
+        ) -> DispatchResultWithPostInfo {
+            let _account_id = ensure_signed(origin)?;
+            Self::_restore_contracts_from_grace(twin_id)
+        }
+
+        #[pallet::call_index(29)]
+        #[pallet::weight(<T as Config>::WeightInfo::create_node_contracts_batch(
+            contracts.len() as u32
+        ))]
+        pub fn create_node_contracts_batch(
+            origin: OriginFor<T>,
+            contracts: BoundedVec<types::NodeContractInput<T>, T::MaxNodeContractsInBatch>,
+            solution_provider_id: Option<u64>,
+        ) -> DispatchResultWithPostInfo {
+            let account_id = ensure_signed(origin)?;
+            Self::_create_node_contracts_batch(account_id, contracts, solution_provider_id)
+        }
+
+        #[pallet::call_index(30)]
+        #[pallet::weight(<T as Config>::WeightInfo::create_node_contract_with_resources())]
+        pub fn create_node_contract_with_resources(
+            origin: OriginFor<T>,
+            node_id: u32,
+            deployment_hash: HexHash,
+            deployment_data: DeploymentDataInput<T>,
+            public_ips: u32,
+            resources: Resources,
+            solution_provider_id: Option<u64>,
+        ) -> DispatchResultWithPostInfo {
+            let account_id = ensure_signed(origin)?;
+            Self::_create_node_contract(
+                account_id,
+                node_id,
+                deployment_hash,
+                deployment_data,
+                public_ips,
+                Some(resources),
+                solution_provider_id,
+            )
+        }
+
+        #[pallet::call_index(31)]
+        #[pallet::weight(<T as Config>::WeightInfo::offer_contract_transfer())]
+        pub fn offer_contract_transfer(
+            origin: OriginFor<T>,
+            contract_id: u64,
+            to_twin_id: u32,
+        ) -> DispatchResultWithPostInfo {
+            let account_id = ensure_signed(origin)?;
+            Self::_offer_contract_transfer(account_id, contract_id, to_twin_id)
+        }
+
+        #[pallet::call_index(32)]
+        #[pallet::weight(<T as Config>::WeightInfo::cancel_contract_transfer())]
+        pub fn cancel_contract_transfer(
+            origin: OriginFor<T>,
+            contract_id: u64,
+        ) -> DispatchResultWithPostInfo {
+            let account_id = ensure_signed(origin)?;
+            Self::_cancel_contract_transfer(account_id, contract_id)
+        }
+
+        #[pallet::call_index(33)]
+        #[pallet::weight(<T as Config>::WeightInfo::accept_contract_transfer())]
+        pub fn accept_contract_transfer(
+            origin: OriginFor<T>,
+            contract_id: u64,
+        ) -> DispatchResultWithPostInfo {
+            let account_id = ensure_signed(origin)?;
+            Self::_accept_contract_transfer(account_id, contract_id)
+        }
+
+        #[pallet::call_index(34)]
+        #[pallet::weight(<T as Config>::WeightInfo::service_contract_set_dispute_window())]
+        pub fn service_contract_set_dispute_window(
+            origin: OriginFor<T>,
+            service_contract_id: u64,
+            dispute_window: Option<u64>,
+        ) -> DispatchResultWithPostInfo {
+            let account_id = ensure_signed(origin)?;
+            Self::_service_contract_set_dispute_window(
+                account_id,
+                service_contract_id,
+                dispute_window,
+            )
+        }
+
+        #[pallet::call_index(35)]
+        #[pallet::weight(<T as Config>::WeightInfo::service_contract_dispute_bill())]
+        pub fn service_contract_dispute_bill(
+            origin: OriginFor<T>,
+            service_contract_id: u64,
+            bill_id: u64,
+        ) -> DispatchResultWithPostInfo {
+            let account_id = ensure_signed(origin)?;
+            Self::_service_contract_dispute_bill(account_id, service_contract_id, bill_id)
+        }
+
+        #[pallet::call_index(36)]
+        #[pallet::weight(<T as Config>::WeightInfo::service_contract_resolve_dispute())]
+        pub fn service_contract_resolve_dispute(
+            origin: OriginFor<T>,
+            service_contract_id: u64,
+            bill_id: u64,
+            refund: BalanceOf<T>,
+        ) -> DispatchResultWithPostInfo {
+            let account_id = ensure_signed(origin)?;
+            Self::_service_contract_resolve_dispute(
+                account_id,
+                service_contract_id,
+                bill_id,
+                refund,
+            )
+        }
+
+        #[pallet::call_index(37)]
+        #[pallet::weight(<T as Config>::WeightInfo::service_contract_force_resolve_dispute())]
+        pub fn service_contract_force_resolve_dispute(
+            origin: OriginFor<T>,
+            service_contract_id: u64,
+            bill_id: u64,
+            refund: BalanceOf<T>,
+        ) -> DispatchResultWithPostInfo {
+            <T as Config>::RestrictedOrigin::ensure_origin(origin)?;
+            Self::_service_contract_force_resolve_dispute(service_contract_id, bill_id, refund)
+        }
+
+        #[pallet::call_index(38)]
+        #[pallet::weight(<T as Config>::WeightInfo::service_contract_release_bill())]
+        pub fn service_contract_release_bill(
+            origin: OriginFor<T>,
+            service_contract_id: u64,
+            bill_id: u64,
+        ) -> DispatchResultWithPostInfo {
+            let account_id = ensure_signed(origin)?;
+            Self::_service_contract_release_bill(account_id, service_contract_id, bill_id)
+        }
+
+        #[pallet::call_index(39)]
+        #[pallet::weight(<T as Config>::WeightInfo::bill_service_contract_for_block())]
+        pub fn bill_service_contract_for_block(
+            origin: OriginFor<T>,
+            service_contract_id: u64,
+        ) -> DispatchResultWithPostInfo {
+            let _account_id = ensure_signed(origin)?;
+            Self::bill_service_contract(service_contract_id)
+        }
+
+        #[pallet::call_index(40)]
+        #[pallet::weight(<T as Config>::WeightInfo::service_contract_propose_amendment())]
+        pub fn service_contract_propose_amendment(
+            origin: OriginFor<T>,
+            service_contract_id: u64,
+            base_fee: u64,
+            variable_fee: u64,
+            metadata: Vec<u8>,
+        ) -> DispatchResultWithPostInfo {
+            let account_id = ensure_signed(origin)?;
+            Self::_service_contract_propose_amendment(
+                account_id,
+                service_contract_id,
+                base_fee,
+                variable_fee,
+                metadata,
+            )
+        }
+
+        #[pallet::call_index(41)]
+        #[pallet::weight(<T as Config>::WeightInfo::service_contract_accept_amendment())]
+        pub fn service_contract_accept_amendment(
+            origin: OriginFor<T>,
+            service_contract_id: u64,
+        ) -> DispatchResultWithPostInfo {
+            let account_id = ensure_signed(origin)?;
+            Self::_service_contract_accept_amendment(account_id, service_contract_id)
+        }
+
+        #[pallet::call_index(42)]
+        #[pallet::weight(<T as Config>::WeightInfo::service_contract_reject_amendment())]
+        pub fn service_contract_reject_amendment(
+            origin: OriginFor<T>,
+            service_contract_id: u64,
+        ) -> DispatchResultWithPostInfo {
+            let account_id = ensure_signed(origin)?;
+            Self::_service_contract_reject_amendment(account_id, service_contract_id)
+        }
+
+        #[pallet::call_index(43)]
+        #[pallet::weight(<T as Config>::WeightInfo::service_plan_create())]
+        pub fn service_plan_create(
+            origin: OriginFor<T>,
+            base_fee: u64,
+            variable_fee: u64,
+            metadata: Vec<u8>,
+        ) -> DispatchResultWithPostInfo {
+            let account_id = ensure_signed(origin)?;
+            Self::_service_plan_create(account_id, base_fee, variable_fee, metadata)
+        }
+
+        #[pallet::call_index(44)]
+        #[pallet::weight(<T as Config>::WeightInfo::service_plan_update())]
+        pub fn service_plan_update(
+            origin: OriginFor<T>,
+            plan_id: u64,
+            base_fee: u64,
+            variable_fee: u64,
+            metadata: Vec<u8>,
+        ) -> DispatchResultWithPostInfo {
+            let account_id = ensure_signed(origin)?;
+            Self::_service_plan_update(account_id, plan_id, base_fee, variable_fee, metadata)
+        }
+
+        #[pallet::call_index(45)]
+        #[pallet::weight(<T as Config>::WeightInfo::service_plan_retire())]
+        pub fn service_plan_retire(
+            origin: OriginFor<T>,
+            plan_id: u64,
+        ) -> DispatchResultWithPostInfo {
+            let account_id = ensure_signed(origin)?;
+            Self::_service_plan_retire(account_id, plan_id)
+        }
+
+        #[pallet::call_index(46)]
+        #[pallet::weight(<T as Config>::WeightInfo::service_plan_subscribe())]
+        pub fn service_plan_subscribe(
+            origin: OriginFor<T>,
+            plan_id: u64,
+        ) -> DispatchResultWithPostInfo {
+            let account_id = ensure_signed(origin)?;
+            Self::_service_plan_subscribe(account_id, plan_id)
+        }
+
+        #[pallet::call_index(47)]
+        #[pallet::weight(<T as Config>::WeightInfo::set_solution_provider_rewards_accrual())]
+        pub fn set_solution_provider_rewards_accrual(
+            origin: OriginFor<T>,
+            accrue: bool,
+        ) -> DispatchResultWithPostInfo {
+            let account_id = ensure_signed(origin)?;
+            Self::_set_solution_provider_rewards_accrual(account_id, accrue)
+        }
+
+        #[pallet::call_index(48)]
+        #[pallet::weight(<T as Config>::WeightInfo::claim_solution_provider_rewards())]
+        pub fn claim_solution_provider_rewards(origin: OriginFor<T>) -> DispatchResultWithPostInfo {
+            let account_id = ensure_signed(origin)?;
+            Self::_claim_solution_provider_rewards(account_id)
+        }
+
+        #[pallet::call_index(49)]
+        #[pallet::weight(<T as Config>::WeightInfo::update_solution_provider())]
+        pub fn update_solution_provider(
+            origin: OriginFor<T>,
+            solution_provider_id: u64,
+            description: Vec<u8>,
+            link: Vec<u8>,
+            providers: Vec<types::Provider<T::AccountId>>,
+        ) -> DispatchResultWithPostInfo {
+            let account_id = ensure_signed(origin)?;
+            Self::_update_solution_provider(
+                account_id,
+                solution_provider_id,
+                description,
+                link,
+                providers,
+            )
+        }
+
+        #[pallet::call_index(50)]
+        #[pallet::weight(<T as Config>::WeightInfo::revoke_solution_provider())]
+        pub fn revoke_solution_provider(
+            origin: OriginFor<T>,
+            solution_provider_id: u64,
+        ) -> DispatchResultWithPostInfo {
+            <T as Config>::RestrictedOrigin::ensure_origin(origin)?;
+            Self::_revoke_solution_provider(solution_provider_id)
+        }
+
+        #[pallet::call_index(51)]
+        #[pallet::weight(<T as Config>::WeightInfo::set_solution_provider_expiry())]
+        pub fn set_solution_provider_expiry(
+            origin: OriginFor<T>,
+            solution_provider_id: u64,
+            expires_at: Option<u64>,
+        ) -> DispatchResultWithPostInfo {
+            <T as Config>::RestrictedOrigin::ensure_origin(origin)?;
+            Self::_set_solution_provider_expiry(solution_provider_id, expires_at)
+        }
+
+        #[pallet::call_index(52)]
+        #[pallet::weight(<T as Config>::WeightInfo::create_name_contract_with_term())]
+        pub fn create_name_contract_with_term(
+            origin: OriginFor<T>,
+            name: Vec<u8>,
+            term: u64,
+        ) -> DispatchResultWithPostInfo {
+            let account_id = ensure_signed(origin)?;
+            Self::_create_name_contract(account_id, name, Some(term))
+        }
+
+        #[pallet::call_index(53)]
+        #[pallet::weight(<T as Config>::WeightInfo::renew_name_contract())]
+        pub fn renew_name_contract(
+            origin: OriginFor<T>,
+            contract_id: u64,
+            term: u64,
+        ) -> DispatchResultWithPostInfo {
+            let account_id = ensure_signed(origin)?;
+            Self::_renew_name_contract(account_id, contract_id, term)
+        }
+
+        #[pallet::call_index(54)]
+        #[pallet::weight(<T as Config>::WeightInfo::set_name_contract_hold_period())]
+        pub fn set_name_contract_hold_period(
+            origin: OriginFor<T>,
+            hold_period: u64,
+        ) -> DispatchResultWithPostInfo {
+            <T as Config>::RestrictedOrigin::ensure_origin(origin)?;
+            Self::_set_name_contract_hold_period(hold_period)
+        }
+
+        #[pallet::call_index(55)]
+        #[pallet::weight(<T as Config>::WeightInfo::add_name_restriction())]
+        pub fn add_name_restriction(
+            origin: OriginFor<T>,
+            pattern: Vec<u8>,
+            matching: types::NameMatching,
+            restriction: types::NameRestriction,
+        ) -> DispatchResultWithPostInfo {
+            <T as Config>::RestrictedOrigin::ensure_origin(origin)?;
+            Self::_add_name_restriction(pattern, matching, restriction)
+        }
+
+        #[pallet::call_index(56)]
+        #[pallet::weight(<T as Config>::WeightInfo::remove_name_restriction())]
+        pub fn remove_name_restriction(
+            origin: OriginFor<T>,
+            pattern: Vec<u8>,
+            matching: types::NameMatching,
+        ) -> DispatchResultWithPostInfo {
+            <T as Config>::RestrictedOrigin::ensure_origin(origin)?;
+            Self::_remove_name_restriction(pattern, matching)
+        }
+
+        #[pallet::call_index(57)]
+        #[pallet::weight(<T as Config>::WeightInfo::assign_reserved_name())]
+        pub fn assign_reserved_name(
+            origin: OriginFor<T>,
+            pattern: Vec<u8>,
+            matching: types::NameMatching,
+            twin_id: Option<u32>,
+        ) -> DispatchResultWithPostInfo {
+            <T as Config>::RestrictedOrigin::ensure_origin(origin)?;
+            Self::_assign_reserved_name(pattern, matching, twin_id)
+        }
+
+        #[pallet::call_index(58)]
+        #[pallet::weight(<T as Config>::WeightInfo::create_node_contract_with_public_ips())]
+        pub fn create_node_contract_with_public_ips(
+            origin: OriginFor<T>,
+            node_id: u32,
+            deployment_hash: HexHash,
+            deployment_data: DeploymentDataInput<T>,
+            public_ips_request: types::PublicIpsRequest<T>,
+            resources: Option<Resources>,
+            solution_provider_id: Option<u64>,
+        ) -> DispatchResultWithPostInfo {
+            let account_id = ensure_signed(origin)?;
+            Self::_create_node_contract_with_public_ips(
+                account_id,
+                node_id,
+                deployment_hash,
+                deployment_data,
+                public_ips_request,
+                resources,
+                solution_provider_id,
+            )
+        }
+
+        #[pallet::call_index(59)]
+        #[pallet::weight(<T as Config>::WeightInfo::set_public_ip_price_multiplier())]
+        pub fn set_public_ip_price_multiplier(
+            origin: OriginFor<T>,
+            farm_id: u32,
+            ip: pallet_tfgrid::Ip4Input,
+            multiplier: u32,
+        ) -> DispatchResultWithPostInfo {
+            let account_id = ensure_signed(origin)?;
+            Self::_set_public_ip_price_multiplier(account_id, farm_id, ip, multiplier)
+        }
+
+        #[pallet::call_index(60)]
+        #[pallet::weight(<T as Config>::WeightInfo::create_node_contract_with_ip6_subnets())]
+        pub fn create_node_contract_with_ip6_subnets(
+            origin: OriginFor<T>,
+            node_id: u32,
+            deployment_hash: HexHash,
+            deployment_data: DeploymentDataInput<T>,
+            public_ips: u32,
+            ip6_subnets: u32,
+            resources: Option<Resources>,
+            solution_provider_id: Option<u64>,
+        ) -> DispatchResultWithPostInfo {
+            let account_id = ensure_signed(origin)?;
+            Self::_create_node_contract_with_ip6_subnets(
+                account_id,
+                node_id,
+                deployment_hash,
+                deployment_data,
+                public_ips,
+                ip6_subnets,
+                resources,
+                solution_provider_id,
+            )
+        }
+
+        #[pallet::call_index(61)]
+        #[pallet::weight(<T as Config>::WeightInfo::request_node_wakeup())]
+        pub fn request_node_wakeup(
+            origin: OriginFor<T>,
+            node_id: u32,
+        ) -> DispatchResultWithPostInfo {
+            let account_id = ensure_signed(origin)?;
+            Self::_request_node_wakeup(account_id, node_id)
+        }
     }
 
     #[pallet::hooks]
     impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
-        fn on_initialize(_n: BlockNumberFor<T>) -> Weight {
+        fn on_initialize(n: BlockNumberFor<T>) -> Weight {
             let mut weight_used = Weight::zero();
             if let Some(migration_stage) = CurrentMigrationStage::<T>::get() {
                 let (w, new_migration_stage) =
@@ -657,6 +1719,7 @@ pub mod pallet {
                 CurrentMigrationStage::<T>::set(new_migration_stage);
                 weight_used.saturating_accrue(w);
             }
+            weight_used.saturating_accrue(Self::time_out_node_wakeup_requests(n));
             weight_used
         }
 
@@ -3,5 +3,9 @@ pub mod v6;
 // v7 was a failed migration and was reworked to v8
 pub mod v10;
 pub mod v11;
+pub mod v12;
+pub mod v13;
+pub mod v14;
+pub mod v15;
 pub mod v8;
 pub mod v9;
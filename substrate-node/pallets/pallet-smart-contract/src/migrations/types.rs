@@ -46,3 +46,19 @@ pub mod v11 {
         pub cycles: u16,
     }
 }
+
+pub mod v13 {
+    use crate::types::Provider;
+    use parity_scale_codec::{Decode, Encode};
+    use scale_info::TypeInfo;
+    use sp_std::vec::Vec;
+
+    #[derive(PartialEq, Eq, PartialOrd, Ord, Clone, Encode, Decode, Default, Debug, TypeInfo)]
+    pub struct SolutionProvider<AccountId> {
+        pub solution_provider_id: u64,
+        pub providers: Vec<Provider<AccountId>>,
+        pub description: Vec<u8>,
+        pub link: Vec<u8>,
+        pub approved: bool,
+    }
+}
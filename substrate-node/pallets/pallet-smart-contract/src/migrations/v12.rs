@@ -0,0 +1,106 @@
+use crate::*;
+use frame_support::{traits::OnRuntimeUpgrade, weights::Weight};
+use log::{debug, info};
+use sp_core::Get;
+use sp_std::marker::PhantomData;
+
+#[cfg(feature = "try-runtime")]
+use frame_support::{dispatch::DispatchError, ensure};
+#[cfg(feature = "try-runtime")]
+use sp_std::{vec, vec::Vec};
+
+pub struct IndexContractsInGrace<T: Config>(PhantomData<T>);
+impl<T: Config> OnRuntimeUpgrade for IndexContractsInGrace<T> {
+    #[cfg(feature = "try-runtime")]
+    fn pre_upgrade() -> Result<Vec<u8>, sp_runtime::TryRuntimeError> {
+        debug!("current pallet version: {:?}", PalletVersion::<T>::get());
+        ensure!(
+            PalletVersion::<T>::get() >= types::StorageVersion::V11,
+            DispatchError::Other("Unexpected pallet version")
+        );
+
+        debug!("👥  Smart Contract pallet to V12 passes PRE migrate checks ✅",);
+        Ok(vec![])
+    }
+
+    fn on_runtime_upgrade() -> Weight {
+        if PalletVersion::<T>::get() == types::StorageVersion::V11 {
+            migrate_to_version_12::<T>()
+        } else {
+            info!(" >>> Unused Smart Contract pallet V12 migration");
+            Weight::zero()
+        }
+    }
+
+    #[cfg(feature = "try-runtime")]
+    fn post_upgrade(_: Vec<u8>) -> Result<(), sp_runtime::TryRuntimeError> {
+        debug!("current pallet version: {:?}", PalletVersion::<T>::get());
+        ensure!(
+            PalletVersion::<T>::get() >= types::StorageVersion::V12,
+            DispatchError::Other("Unexpected pallet version")
+        );
+
+        check_contracts_in_grace_v12::<T>()
+    }
+}
+
+pub fn migrate_to_version_12<T: Config>() -> frame_support::weights::Weight {
+    debug!(
+        " >>> Starting contract pallet migration, pallet version: {:?}",
+        PalletVersion::<T>::get()
+    );
+
+    let mut r = 0;
+    let mut w = 0;
+
+    // index contracts in grace period by twin
+    for contract in Contracts::<T>::iter_values() {
+        r += 1;
+        if matches!(contract.state, types::ContractState::GracePeriod(_)) {
+            debug!("Indexing contract in grace {:?}", contract.contract_id);
+            Pallet::<T>::update_twin_contracts_in_grace(
+                contract.twin_id,
+                contract.contract_id,
+                true,
+            );
+            r += 1;
+            w += 1;
+        }
+    }
+
+    // Set the new storage version
+    PalletVersion::<T>::put(types::StorageVersion::V12);
+    w += 1;
+
+    T::DbWeight::get().reads_writes(r, w)
+}
+
+#[cfg(feature = "try-runtime")]
+pub fn check_contracts_in_grace_v12<T: Config>() -> Result<(), sp_runtime::TryRuntimeError> {
+    debug!(
+        "🔎  Smart Contract pallet {:?} checking TwinContractsInGrace storage map START",
+        PalletVersion::<T>::get()
+    );
+
+    // Check each contract in grace is indexed
+    for (contract_id, contract) in Contracts::<T>::iter() {
+        if matches!(contract.state, types::ContractState::GracePeriod(_)) {
+            ensure!(
+                TwinContractsInGrace::<T>::contains_key(contract.twin_id, contract_id),
+                DispatchError::Other("Contract in grace not indexed")
+            );
+        }
+    }
+
+    debug!(
+        "🏁  Smart Contract pallet {:?} checking TwinContractsInGrace storage map END",
+        PalletVersion::<T>::get()
+    );
+
+    debug!(
+        "👥  Smart Contract pallet to {:?} passes POST migrate checks ✅",
+        PalletVersion::<T>::get()
+    );
+
+    Ok(())
+}
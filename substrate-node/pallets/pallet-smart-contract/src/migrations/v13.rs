@@ -0,0 +1,123 @@
+use crate::*;
+use frame_support::{traits::OnRuntimeUpgrade, weights::Weight};
+use log::{debug, info};
+use sp_core::Get;
+use sp_std::marker::PhantomData;
+
+#[cfg(feature = "try-runtime")]
+use frame_support::{dispatch::DispatchError, ensure};
+#[cfg(feature = "try-runtime")]
+use sp_std::{vec, vec::Vec};
+
+pub struct InsertServiceContractsInBillingLoop<T: Config>(PhantomData<T>);
+impl<T: Config> OnRuntimeUpgrade for InsertServiceContractsInBillingLoop<T> {
+    #[cfg(feature = "try-runtime")]
+    fn pre_upgrade() -> Result<Vec<u8>, sp_runtime::TryRuntimeError> {
+        debug!("current pallet version: {:?}", PalletVersion::<T>::get());
+        ensure!(
+            PalletVersion::<T>::get() >= types::StorageVersion::V12,
+            DispatchError::Other("Unexpected pallet version")
+        );
+
+        debug!("👥  Smart Contract pallet to V13 passes PRE migrate checks ✅",);
+        Ok(vec![])
+    }
+
+    fn on_runtime_upgrade() -> Weight {
+        if PalletVersion::<T>::get() == types::StorageVersion::V12 {
+            migrate_to_version_13::<T>()
+        } else {
+            info!(" >>> Unused Smart Contract pallet V13 migration");
+            Weight::zero()
+        }
+    }
+
+    #[cfg(feature = "try-runtime")]
+    fn post_upgrade(_: Vec<u8>) -> Result<(), sp_runtime::TryRuntimeError> {
+        debug!("current pallet version: {:?}", PalletVersion::<T>::get());
+        ensure!(
+            PalletVersion::<T>::get() >= types::StorageVersion::V13,
+            DispatchError::Other("Unexpected pallet version")
+        );
+
+        check_service_contracts_in_billing_loop_v13::<T>()
+    }
+}
+
+pub fn migrate_to_version_13<T: Config>() -> frame_support::weights::Weight {
+    debug!(
+        " >>> Starting contract pallet migration, pallet version: {:?}",
+        PalletVersion::<T>::get()
+    );
+
+    let mut r = 1;
+    let mut w = 0;
+
+    // Base fee was only billed by the service, for at most one reference period at once
+    // so never bill more than that for the time elapsed before the upgrade
+    let now = Pallet::<T>::get_current_timestamp_in_secs();
+    let oldest_bill = now.saturating_sub(T::BillingReferencePeriod::get());
+
+    for (service_contract_id, mut service_contract) in ServiceContracts::<T>::iter() {
+        r += 1;
+        if matches!(
+            service_contract.state,
+            types::ServiceContractState::ApprovedByBoth
+        ) {
+            debug!(
+                "Inserting service contract {:?} in billing loop",
+                service_contract_id
+            );
+            ServiceContractLastVariableBill::<T>::insert(
+                service_contract_id,
+                service_contract.last_bill,
+            );
+            service_contract.last_bill = service_contract.last_bill.max(oldest_bill);
+            ServiceContracts::<T>::insert(service_contract_id, service_contract);
+            Pallet::<T>::insert_service_contract_in_billing_loop(service_contract_id);
+            r += 1;
+            w += 3;
+        }
+    }
+
+    // Set the new storage version
+    PalletVersion::<T>::put(types::StorageVersion::V13);
+    w += 1;
+
+    T::DbWeight::get().reads_writes(r, w)
+}
+
+#[cfg(feature = "try-runtime")]
+pub fn check_service_contracts_in_billing_loop_v13<T: Config>(
+) -> Result<(), sp_runtime::TryRuntimeError> {
+    debug!(
+        "🔎  Smart Contract pallet {:?} checking ServiceContractsToBillAt storage map START",
+        PalletVersion::<T>::get()
+    );
+
+    // Check each approved service contract is in billing loop
+    for (service_contract_id, service_contract) in ServiceContracts::<T>::iter() {
+        if matches!(
+            service_contract.state,
+            types::ServiceContractState::ApprovedByBoth
+        ) {
+            let index = Pallet::<T>::get_billing_loop_index_from_contract_id(service_contract_id);
+            ensure!(
+                ServiceContractsToBillAt::<T>::get(index).contains(&service_contract_id),
+                DispatchError::Other("Service contract not in billing loop")
+            );
+        }
+    }
+
+    debug!(
+        "🏁  Smart Contract pallet {:?} checking ServiceContractsToBillAt storage map END",
+        PalletVersion::<T>::get()
+    );
+
+    debug!(
+        "👥  Smart Contract pallet to {:?} passes POST migrate checks ✅",
+        PalletVersion::<T>::get()
+    );
+
+    Ok(())
+}
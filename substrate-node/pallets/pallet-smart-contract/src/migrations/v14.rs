@@ -0,0 +1,126 @@
+use crate::*;
+use frame_support::{traits::OnRuntimeUpgrade, weights::Weight};
+use log::{debug, info};
+use sp_core::Get;
+use sp_std::marker::PhantomData;
+
+#[cfg(feature = "try-runtime")]
+use frame_support::{dispatch::DispatchError, ensure};
+#[cfg(feature = "try-runtime")]
+use parity_scale_codec::{Decode, Encode};
+#[cfg(feature = "try-runtime")]
+use sp_std::vec::Vec;
+
+pub struct ExtendSolutionProviders<T: Config>(PhantomData<T>);
+impl<T: Config> OnRuntimeUpgrade for ExtendSolutionProviders<T> {
+    #[cfg(feature = "try-runtime")]
+    fn pre_upgrade() -> Result<Vec<u8>, sp_runtime::TryRuntimeError> {
+        debug!("current pallet version: {:?}", PalletVersion::<T>::get());
+        ensure!(
+            PalletVersion::<T>::get() >= types::StorageVersion::V13,
+            DispatchError::Other("Unexpected pallet version")
+        );
+
+        let solution_providers_count: u64 = SolutionProviders::<T>::iter_keys().count() as u64;
+
+        debug!("👥  Smart Contract pallet to V14 passes PRE migrate checks ✅",);
+        Ok(solution_providers_count.encode())
+    }
+
+    fn on_runtime_upgrade() -> Weight {
+        if PalletVersion::<T>::get() == types::StorageVersion::V13 {
+            migrate_to_version_14::<T>()
+        } else {
+            info!(" >>> Unused Smart Contract pallet V14 migration");
+            Weight::zero()
+        }
+    }
+
+    #[cfg(feature = "try-runtime")]
+    fn post_upgrade(
+        pre_solution_providers_count: Vec<u8>,
+    ) -> Result<(), sp_runtime::TryRuntimeError> {
+        debug!("current pallet version: {:?}", PalletVersion::<T>::get());
+        ensure!(
+            PalletVersion::<T>::get() >= types::StorageVersion::V14,
+            DispatchError::Other("Unexpected pallet version")
+        );
+
+        check_solution_providers_v14::<T>(pre_solution_providers_count)
+    }
+}
+
+pub fn migrate_to_version_14<T: Config>() -> frame_support::weights::Weight {
+    debug!(
+        " >>> Starting contract pallet migration, pallet version: {:?}",
+        PalletVersion::<T>::get()
+    );
+
+    let mut r = 0;
+    let mut w = 0;
+
+    // migrate solution providers
+    SolutionProviders::<T>::translate::<super::types::v13::SolutionProvider<T::AccountId>, _>(
+        |k, sp| {
+            r += 1;
+            w += 1;
+            debug!("Migrating solution provider {:?}", k);
+            Some(types::SolutionProvider::<T::AccountId> {
+                solution_provider_id: sp.solution_provider_id,
+                providers: sp.providers,
+                description: sp.description,
+                link: sp.link,
+                approved: sp.approved,
+                // Default to not revoked and no expiry
+                revoked: false,
+                expires_at: None,
+            })
+        },
+    );
+
+    // Set the new storage version
+    PalletVersion::<T>::put(types::StorageVersion::V14);
+    w += 1;
+
+    T::DbWeight::get().reads_writes(r, w)
+}
+
+#[cfg(feature = "try-runtime")]
+pub fn check_solution_providers_v14<T: Config>(
+    pre_solution_providers_count: Vec<u8>,
+) -> Result<(), sp_runtime::TryRuntimeError> {
+    debug!(
+        "🔎  Smart Contract pallet {:?} checking SolutionProviders storage map START",
+        PalletVersion::<T>::get()
+    );
+
+    let pre_solution_providers_count: u64 =
+        Decode::decode(&mut pre_solution_providers_count.as_slice())
+            .expect("the state parameter should be something that was generated by pre_upgrade");
+
+    // Check each solution provider was migrated
+    let solution_providers_count: u64 = SolutionProviders::<T>::iter().count() as u64;
+    ensure!(
+        solution_providers_count == pre_solution_providers_count,
+        DispatchError::Other("Number of solution providers migrated does not match")
+    );
+
+    for (_, solution_provider) in SolutionProviders::<T>::iter() {
+        ensure!(
+            !solution_provider.revoked && solution_provider.expires_at.is_none(),
+            DispatchError::Other("Unexpected solution provider state")
+        );
+    }
+
+    debug!(
+        "🏁  Smart Contract pallet {:?} checking SolutionProviders storage map END",
+        PalletVersion::<T>::get()
+    );
+
+    debug!(
+        "👥  Smart Contract pallet to {:?} passes POST migrate checks ✅",
+        PalletVersion::<T>::get()
+    );
+
+    Ok(())
+}
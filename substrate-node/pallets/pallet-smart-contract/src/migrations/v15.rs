@@ -0,0 +1,161 @@
+use crate::*;
+use frame_support::{traits::OnRuntimeUpgrade, weights::Weight};
+use log::{debug, info};
+use sp_core::Get;
+use sp_std::marker::PhantomData;
+
+#[cfg(feature = "try-runtime")]
+use frame_support::{dispatch::DispatchError, ensure};
+#[cfg(feature = "try-runtime")]
+use parity_scale_codec::{Decode, Encode};
+#[cfg(feature = "try-runtime")]
+use sp_std::{collections::btree_map::BTreeMap, vec::Vec};
+#[cfg(feature = "try-runtime")]
+use tfchain_support::resources::Resources;
+
+pub struct ReserveReportedResources<T: Config>(PhantomData<T>);
+impl<T: Config> OnRuntimeUpgrade for ReserveReportedResources<T> {
+    #[cfg(feature = "try-runtime")]
+    fn pre_upgrade() -> Result<Vec<u8>, sp_runtime::TryRuntimeError> {
+        debug!("current pallet version: {:?}", PalletVersion::<T>::get());
+        ensure!(
+            PalletVersion::<T>::get() >= types::StorageVersion::V14,
+            DispatchError::Other("Unexpected pallet version")
+        );
+
+        // Contracts with reported resources that should end up with a reservation
+        let reported_contracts_count: u64 = NodeContractResources::<T>::iter()
+            .filter(|(contract_id, resources)| {
+                !resources.used.is_empty() && get_node_id::<T>(*contract_id).is_some()
+            })
+            .count() as u64;
+
+        debug!("👥  Smart Contract pallet to V15 passes PRE migrate checks ✅",);
+        Ok(reported_contracts_count.encode())
+    }
+
+    fn on_runtime_upgrade() -> Weight {
+        if PalletVersion::<T>::get() == types::StorageVersion::V14 {
+            migrate_to_version_15::<T>()
+        } else {
+            info!(" >>> Unused Smart Contract pallet V15 migration");
+            Weight::zero()
+        }
+    }
+
+    #[cfg(feature = "try-runtime")]
+    fn post_upgrade(
+        pre_reported_contracts_count: Vec<u8>,
+    ) -> Result<(), sp_runtime::TryRuntimeError> {
+        debug!("current pallet version: {:?}", PalletVersion::<T>::get());
+        ensure!(
+            PalletVersion::<T>::get() >= types::StorageVersion::V15,
+            DispatchError::Other("Unexpected pallet version")
+        );
+
+        check_reserved_resources_v15::<T>(pre_reported_contracts_count)
+    }
+}
+
+pub fn migrate_to_version_15<T: Config>() -> frame_support::weights::Weight {
+    debug!(
+        " >>> Starting contract pallet migration, pallet version: {:?}",
+        PalletVersion::<T>::get()
+    );
+
+    let mut r = 0;
+    let mut w = 0;
+
+    // Reserve the resources reported for the node contracts created before
+    // node capacity was accounted for, on top of what they declared if anything
+    for (contract_id, resources) in NodeContractResources::<T>::iter() {
+        r += 2;
+        if resources.used.is_empty() {
+            continue;
+        }
+        if let Some(node_id) = get_node_id::<T>(contract_id) {
+            debug!("Reserving resources of contract {:?}", contract_id);
+            Pallet::<T>::reserve_reported_resources(node_id, contract_id, &resources.used);
+            r += 2;
+            w += 2;
+        }
+    }
+
+    // Set the new storage version
+    PalletVersion::<T>::put(types::StorageVersion::V15);
+    w += 1;
+
+    T::DbWeight::get().reads_writes(r, w)
+}
+
+fn get_node_id<T: Config>(contract_id: u64) -> Option<u32> {
+    match Contracts::<T>::get(contract_id)?.contract_type {
+        types::ContractData::NodeContract(node_contract) => Some(node_contract.node_id),
+        _ => None,
+    }
+}
+
+#[cfg(feature = "try-runtime")]
+pub fn check_reserved_resources_v15<T: Config>(
+    pre_reported_contracts_count: Vec<u8>,
+) -> Result<(), sp_runtime::TryRuntimeError> {
+    debug!(
+        "🔎  Smart Contract pallet {:?} checking NodeReservedResources storage map START",
+        PalletVersion::<T>::get()
+    );
+
+    let pre_reported_contracts_count: u64 =
+        Decode::decode(&mut pre_reported_contracts_count.as_slice())
+            .expect("the state parameter should be something that was generated by pre_upgrade");
+
+    // Check each contract with reported resources reserves at least what it uses
+    let mut reported_contracts_count = 0;
+    for (contract_id, resources) in NodeContractResources::<T>::iter() {
+        if resources.used.is_empty() || get_node_id::<T>(contract_id).is_none() {
+            continue;
+        }
+        reported_contracts_count += 1;
+
+        let reserved = ContractReservedResources::<T>::get(contract_id).unwrap_or_default();
+        ensure!(
+            resources.used.clone().saturating_sub(&reserved).is_empty(),
+            DispatchError::Other("Reported resources of contract not reserved")
+        );
+    }
+    ensure!(
+        reported_contracts_count == pre_reported_contracts_count,
+        DispatchError::Other("Number of contracts with reported resources does not match")
+    );
+
+    // Check the reservation of each node is the sum of the ones of its contracts
+    let mut node_reserved: BTreeMap<u32, Resources> = BTreeMap::new();
+    for (contract_id, reserved) in ContractReservedResources::<T>::iter() {
+        let node_id = get_node_id::<T>(contract_id).ok_or(DispatchError::Other(
+            "Resources reserved by unknown node contract",
+        ))?;
+        let total = node_reserved.entry(node_id).or_default();
+        *total = total.clone().saturating_add(&reserved);
+    }
+    for (node_id, reserved) in NodeReservedResources::<T>::iter() {
+        ensure!(
+            node_reserved.remove(&node_id).unwrap_or_default() == reserved,
+            DispatchError::Other("Reserved resources of node do not match its contracts")
+        );
+    }
+    ensure!(
+        node_reserved.values().all(|reserved| reserved.is_empty()),
+        DispatchError::Other("Reserved resources of node missing")
+    );
+
+    debug!(
+        "🏁  Smart Contract pallet {:?} checking NodeReservedResources storage map END",
+        PalletVersion::<T>::get()
+    );
+
+    debug!(
+        "👥  Smart Contract pallet to {:?} passes POST migrate checks ✅",
+        PalletVersion::<T>::get()
+    );
+
+    Ok(())
+}
@@ -47,7 +47,7 @@ use std::{cell::RefCell, panic, thread};
 use tfchain_support::{
     constants::time::{MINUTES, SECS_PER_HOUR},
     traits::{ChangeNode, NodeActiveContracts, PublicIpModifier},
-    types::PublicIP,
+    types::{PublicIP, PublicIp6Prefix},
 };
 
 impl_opaque_keys! {
@@ -183,6 +183,12 @@ impl ChangeNode<Loc, Interface, Serial> for NodeChanged {
     fn node_deleted(node: &TfgridNode) {
         SmartContractModule::node_deleted(node);
     }
+    fn node_decommissioning(node: &TfgridNode, deadline: u64) {
+        SmartContractModule::node_decommissioning(node, deadline);
+    }
+    fn node_decommissioned(node: &TfgridNode, max_contracts: u32) -> Weight {
+        SmartContractModule::node_decommissioned(node, max_contracts)
+    }
     fn node_power_state_changed(node: &TfgridNode) {
         SmartContractModule::node_power_state_changed(node);
     }
@@ -193,6 +199,9 @@ impl PublicIpModifier for PublicIpModifierType {
     fn ip_removed(ip: &PublicIP) {
         SmartContractModule::ip_removed(ip);
     }
+    fn ip6_prefix_removed(farm_id: u32, prefix: &PublicIp6Prefix) {
+        SmartContractModule::ip6_prefix_removed(farm_id, prefix);
+    }
 }
 
 pub struct NodeActiveContractsType;
@@ -208,6 +217,7 @@ parameter_types! {
     pub const MaxInterfacesLength: u32 = 10;
     pub const MaxFarmPublicIps: u32 = 512;
     pub const TimestampHintDrift: u64 = 60;
+    pub const NodeDecommissionPeriod: u64 = 10;
 }
 
 pub(crate) type TestTermsAndConditions = TermsAndConditions<TestRuntime>;
@@ -244,6 +254,7 @@ impl pallet_tfgrid::Config for TestRuntime {
     type Location = TestLocation;
     type SerialNumber = TestSerialNumber;
     type TimestampHintDrift = TimestampHintDrift;
+    type NodeDecommissionPeriod = NodeDecommissionPeriod;
 }
 
 impl pallet_tft_price::Config for TestRuntime {
@@ -265,11 +276,17 @@ parameter_types! {
     pub const BillingFrequency: u64 = 10;
     pub const BillingReferencePeriod: u64 = SECS_PER_HOUR;
     pub const GracePeriod: u64 = 100;
+    pub const GracePeriodReminderFrequency: u64 = 20;
+    pub const MaxContractsRestoredFromGrace: u32 = 10;
+    pub const MaxNodeContractsInBatch: u32 = 5;
     pub const DistributionFrequency: u16 = 24;
     pub const MaxNameContractNameLength: u32 = 64;
     pub const MaxNodeContractPublicIPs: u32 = 512;
     pub const MaxDeploymentDataLength: u32 = 512;
     pub const SecondsPerHour: u64 = 3600;
+    pub const MaxContractBillingHistory: u32 = 3;
+    pub const MaxTwinBillingHistoryDays: u32 = 2;
+    pub const NodeWakeupTimeout: u64 = 10;
 }
 
 pub(crate) type TestNameContractName = NameContractName<TestRuntime>;
@@ -289,12 +306,18 @@ impl pallet_smart_contract::Config for TestRuntime {
     type BillingReferencePeriod = BillingReferencePeriod;
     type DistributionFrequency = DistributionFrequency;
     type GracePeriod = GracePeriod;
+    type GracePeriodReminderFrequency = GracePeriodReminderFrequency;
+    type MaxContractsRestoredFromGrace = MaxContractsRestoredFromGrace;
+    type MaxNodeContractsInBatch = MaxNodeContractsInBatch;
     type WeightInfo = weights::SubstrateWeight<TestRuntime>;
     type MaxNameContractNameLength = MaxNameContractNameLength;
     type NameContractName = TestNameContractName;
     type RestrictedOrigin = EnsureRootOrCouncilApproval;
     type MaxDeploymentDataLength = MaxDeploymentDataLength;
     type MaxNodeContractPublicIps = MaxNodeContractPublicIPs;
+    type MaxContractBillingHistory = MaxContractBillingHistory;
+    type MaxTwinBillingHistoryDays = MaxTwinBillingHistoryDays;
+    type NodeWakeupTimeout = NodeWakeupTimeout;
     type AuthorityId = pallet_smart_contract::crypto::AuthId;
     type Call = RuntimeCall;
     type PublicIpModifier = PublicIpModifierType;
@@ -571,6 +594,21 @@ impl PoolState {
         ));
     }
 
+    pub fn should_call_bill_service_contract(
+        &mut self,
+        service_contract_id: u64,
+        expected_result: ExtrinsicResult,
+        block_number: u64,
+    ) {
+        self.expected_calls.push((
+            crate::Call::bill_service_contract_for_block {
+                service_contract_id,
+            },
+            expected_result,
+            block_number,
+        ));
+    }
+
     pub fn execute_calls_and_check_results(&mut self, block_number: u64) {
         if self.calls_to_execute.len() == 0 {
             return;
@@ -586,6 +624,13 @@ impl PoolState {
                         contract_id,
                     )
                 }
+                // matches bill_service_contract_for_block
+                crate::Call::bill_service_contract_for_block {
+                    service_contract_id,
+                } => SmartContractModule::bill_service_contract_for_block(
+                    RuntimeOrigin::signed(bob()),
+                    service_contract_id,
+                ),
                 // did not match anything => unkown call => this means you should add
                 // a capture for that function here
                 _ => panic!("Unknown call!"),
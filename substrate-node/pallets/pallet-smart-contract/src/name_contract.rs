@@ -0,0 +1,232 @@
+use crate::{grid_contract::MIN_NAME_LENGTH, *};
+use frame_support::{
+    dispatch::{DispatchErrorWithPostInfo, DispatchResultWithPostInfo},
+    ensure,
+};
+use sp_std::vec::Vec;
+
+impl<T: Config> Pallet<T> {
+    pub fn _renew_name_contract(
+        account_id: T::AccountId,
+        contract_id: u64,
+        term: u64,
+    ) -> DispatchResultWithPostInfo {
+        let twin_id = pallet_tfgrid::TwinIdByAccountID::<T>::get(&account_id)
+            .ok_or(Error::<T>::TwinNotExists)?;
+        let contract = Contracts::<T>::get(contract_id).ok_or(Error::<T>::ContractNotExists)?;
+        ensure!(
+            contract.twin_id == twin_id,
+            Error::<T>::TwinNotAuthorizedToUpdateContract
+        );
+        ensure!(
+            matches!(contract.contract_type, types::ContractData::NameContract(_)),
+            Error::<T>::InvalidContractType
+        );
+
+        // Only fixed-term registrations can be renewed
+        let current_expires_at =
+            NameContractExpiry::<T>::get(contract_id).ok_or(Error::<T>::NameContractHasNoTerm)?;
+        Self::validate_name_contract_term(term)?;
+
+        // Renewing a registration held after it expired restores it from now on
+        let now = Self::get_current_timestamp_in_secs();
+        let expires_at = current_expires_at.max(now) + term;
+        ensure!(
+            expires_at - now <= types::MAX_NAME_CONTRACT_TERM,
+            Error::<T>::InvalidNameContractTerm
+        );
+
+        // The time the name was held is not billed, what is left
+        // to bill from before the registration expired still is
+        if now >= current_expires_at {
+            ContractLock::<T>::mutate(contract_id, |contract_lock| {
+                let unbilled = current_expires_at.saturating_sub(contract_lock.lock_updated);
+                contract_lock.lock_updated = now.saturating_sub(unbilled);
+            });
+        }
+
+        NameContractExpiry::<T>::insert(contract_id, expires_at);
+
+        Self::deposit_event(Event::NameContractRenewed {
+            contract_id,
+            expires_at,
+        });
+
+        Ok(().into())
+    }
+
+    pub fn _set_name_contract_hold_period(hold_period: u64) -> DispatchResultWithPostInfo {
+        NameContractHoldPeriod::<T>::put(hold_period);
+
+        Self::deposit_event(Event::NameContractHoldPeriodSet { hold_period });
+
+        Ok(().into())
+    }
+
+    pub fn _add_name_restriction(
+        pattern: Vec<u8>,
+        matching: types::NameMatching,
+        restriction: types::NameRestriction,
+    ) -> DispatchResultWithPostInfo {
+        let pattern =
+            NameContractNameOf::<T>::try_from(pattern).map_err(DispatchErrorWithPostInfo::from)?;
+        ensure!(
+            !NameRestrictions::<T>::contains_key(matching, &pattern),
+            Error::<T>::NameRestrictionExists
+        );
+
+        NameRestrictions::<T>::insert(matching, &pattern, restriction);
+
+        Self::deposit_event(Event::NameRestrictionAdded {
+            pattern,
+            matching,
+            restriction,
+        });
+
+        Ok(().into())
+    }
+
+    pub fn _remove_name_restriction(
+        pattern: Vec<u8>,
+        matching: types::NameMatching,
+    ) -> DispatchResultWithPostInfo {
+        let pattern =
+            NameContractNameOf::<T>::try_from(pattern).map_err(DispatchErrorWithPostInfo::from)?;
+        ensure!(
+            NameRestrictions::<T>::contains_key(matching, &pattern),
+            Error::<T>::NameRestrictionNotExists
+        );
+
+        NameRestrictions::<T>::remove(matching, &pattern);
+
+        Self::deposit_event(Event::NameRestrictionRemoved { pattern, matching });
+
+        Ok(().into())
+    }
+
+    pub fn _assign_reserved_name(
+        pattern: Vec<u8>,
+        matching: types::NameMatching,
+        twin_id: Option<u32>,
+    ) -> DispatchResultWithPostInfo {
+        let pattern =
+            NameContractNameOf::<T>::try_from(pattern).map_err(DispatchErrorWithPostInfo::from)?;
+        let restriction = NameRestrictions::<T>::get(matching, &pattern)
+            .ok_or(Error::<T>::NameRestrictionNotExists)?;
+        ensure!(
+            matches!(restriction, types::NameRestriction::Reserved { .. }),
+            Error::<T>::NameRestrictionNotReserved
+        );
+        if let Some(twin_id) = twin_id {
+            ensure!(
+                pallet_tfgrid::Twins::<T>::contains_key(twin_id),
+                Error::<T>::TwinNotExists
+            );
+        }
+
+        NameRestrictions::<T>::insert(
+            matching,
+            &pattern,
+            types::NameRestriction::Reserved { twin_id },
+        );
+
+        Self::deposit_event(Event::ReservedNameAssigned {
+            pattern,
+            matching,
+            twin_id,
+        });
+
+        Ok(().into())
+    }
+
+    // An exact restriction on the name applies first,
+    // otherwise the restriction on its longest matching prefix
+    pub fn get_name_restriction(name: &NameContractNameOf<T>) -> Option<types::NameRestriction> {
+        if let Some(restriction) = NameRestrictions::<T>::get(types::NameMatching::Exact, name) {
+            return Some(restriction);
+        }
+
+        let name: Vec<u8> = name.clone().into();
+        (MIN_NAME_LENGTH as usize..=name.len())
+            .rev()
+            .filter_map(|len| NameContractNameOf::<T>::try_from(name[..len].to_vec()).ok())
+            .find_map(|prefix| NameRestrictions::<T>::get(types::NameMatching::Prefix, prefix))
+    }
+
+    pub fn ensure_name_not_restricted(
+        name: &NameContractNameOf<T>,
+        twin_id: u32,
+    ) -> Result<(), Error<T>> {
+        match Self::get_name_restriction(name) {
+            Some(types::NameRestriction::Blocked) => Err(Error::<T>::NameBlocked),
+            Some(types::NameRestriction::Reserved {
+                twin_id: assigned_twin_id,
+            }) if assigned_twin_id != Some(twin_id) => Err(Error::<T>::NameReserved),
+            _ => Ok(()),
+        }
+    }
+
+    pub fn validate_name_contract_term(term: u64) -> Result<(), Error<T>> {
+        ensure!(
+            term > 0 && term <= types::MAX_NAME_CONTRACT_TERM,
+            Error::<T>::InvalidNameContractTerm
+        );
+
+        Ok(())
+    }
+
+    // A fixed-term registration is expired once its term is over,
+    // the name is then held for its owner during the hold period
+    pub fn is_name_contract_expired(contract_id: u64, now: u64) -> bool {
+        NameContractExpiry::<T>::get(contract_id).map_or(false, |expires_at| now >= expires_at)
+    }
+
+    pub fn deposit_name_contract_expired_event(contract: &types::Contract<T>, expires_at: u64) {
+        if let types::ContractData::NameContract(name_contract) = &contract.contract_type {
+            Self::deposit_event(Event::NameContractExpired {
+                contract_id: contract.contract_id,
+                name: name_contract.name.clone(),
+                released_at: expires_at.saturating_add(NameContractHoldPeriod::<T>::get()),
+            });
+        }
+    }
+
+    // Once the hold period is over as well the name contract gets removed
+    pub fn is_name_contract_released(contract_id: u64, now: u64) -> bool {
+        NameContractExpiry::<T>::get(contract_id).map_or(false, |expires_at| {
+            now >= expires_at.saturating_add(NameContractHoldPeriod::<T>::get())
+        })
+    }
+
+    pub fn get_name_availability(name: Vec<u8>) -> types::NameAvailability {
+        let valid_name = match NameContractNameOf::<T>::try_from(name) {
+            Ok(valid_name) => valid_name,
+            Err(_) => return types::NameAvailability::Invalid,
+        };
+
+        let contract_id = match ContractIDByNameRegistration::<T>::try_get(&valid_name) {
+            Ok(contract_id) => contract_id,
+            Err(_) => {
+                return match Self::get_name_restriction(&valid_name) {
+                    Some(types::NameRestriction::Reserved { twin_id }) => {
+                        types::NameAvailability::Reserved { twin_id }
+                    }
+                    Some(types::NameRestriction::Blocked) => types::NameAvailability::Blocked,
+                    None => types::NameAvailability::Available,
+                }
+            }
+        };
+
+        let now = Self::get_current_timestamp_in_secs();
+        match NameContractExpiry::<T>::get(contract_id) {
+            Some(expires_at) if now >= expires_at => types::NameAvailability::Held {
+                contract_id,
+                released_at: expires_at.saturating_add(NameContractHoldPeriod::<T>::get()),
+            },
+            expires_at => types::NameAvailability::Registered {
+                contract_id,
+                expires_at,
+            },
+        }
+    }
+}
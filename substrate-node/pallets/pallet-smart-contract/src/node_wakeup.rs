@@ -0,0 +1,101 @@
+use crate::*;
+use frame_support::{dispatch::DispatchResultWithPostInfo, ensure, weights::Weight};
+use frame_system::pallet_prelude::BlockNumberFor;
+use sp_core::Get;
+use sp_runtime::SaturatedConversion;
+use tfchain_support::types::Power;
+
+impl<T: Config> Pallet<T> {
+    pub fn _request_node_wakeup(
+        account_id: T::AccountId,
+        node_id: u32,
+    ) -> DispatchResultWithPostInfo {
+        let twin_id = pallet_tfgrid::TwinIdByAccountID::<T>::get(&account_id)
+            .ok_or(Error::<T>::TwinNotExists)?;
+        let node = pallet_tfgrid::Nodes::<T>::get(node_id).ok_or(Error::<T>::NodeNotExists)?;
+
+        // Only a twin renting the node or with a node contract on it can wake it up
+        ensure!(
+            Self::twin_has_contract_on_node(twin_id, node_id),
+            Error::<T>::TwinNotAuthorizedToWakeUpNode
+        );
+
+        let node_power = pallet_tfgrid::NodePower::<T>::get(node_id);
+        ensure!(node_power.is_standby_phase(), Error::<T>::NodeNotInStandby);
+        ensure!(
+            !NodeWakeupRequests::<T>::contains_key(node_id),
+            Error::<T>::NodeWakeupAlreadyRequested
+        );
+
+        pallet_tfgrid::Pallet::<T>::set_node_power_target(&node, Power::Up);
+
+        // A node that is still up only has to keep running
+        if node_power.is_standby() {
+            let current_block = <frame_system::Pallet<T>>::block_number().saturated_into::<u64>();
+            let timeout_at = current_block + T::NodeWakeupTimeout::get();
+            NodeWakeupRequests::<T>::insert(
+                node_id,
+                types::NodeWakeupRequest {
+                    twin_id,
+                    timeout_at,
+                },
+            );
+            NodeWakeupTimeouts::<T>::insert(timeout_at, node_id, ());
+
+            Self::deposit_event(Event::NodeWakeupRequested {
+                node_id,
+                twin_id,
+                timeout_at,
+            });
+        }
+
+        Ok(().into())
+    }
+
+    fn twin_has_contract_on_node(twin_id: u32, node_id: u32) -> bool {
+        let has_contract = |contract_id| {
+            Contracts::<T>::get(contract_id).map_or(false, |contract| contract.twin_id == twin_id)
+        };
+
+        ActiveRentContractForNode::<T>::get(node_id).map_or(false, has_contract)
+            || ActiveNodeContracts::<T>::get(node_id)
+                .into_iter()
+                .any(has_contract)
+    }
+
+    // A pending wake-up request is completed once the node is back up
+    pub fn node_wakeup_completed(node_id: u32) {
+        if let Some(request) = NodeWakeupRequests::<T>::take(node_id) {
+            Self::deposit_event(Event::NodeWakeupSucceeded {
+                node_id,
+                twin_id: request.twin_id,
+            });
+        }
+    }
+
+    pub fn time_out_node_wakeup_requests(block_number: BlockNumberFor<T>) -> Weight {
+        let current_block = block_number.saturated_into::<u64>();
+        let mut weight = T::DbWeight::get().reads(1);
+
+        // Requests made in a single block all time out in the same block,
+        // so there are never more of them than fit in a block
+        for (node_id, _) in NodeWakeupTimeouts::<T>::drain_prefix(current_block) {
+            weight.saturating_accrue(T::DbWeight::get().reads_writes(2, 1));
+            // Node may have come back up or been requested again since
+            match NodeWakeupRequests::<T>::get(node_id) {
+                Some(request) if request.timeout_at == current_block => {
+                    NodeWakeupRequests::<T>::remove(node_id);
+                    weight.saturating_accrue(T::DbWeight::get().writes(1));
+
+                    Self::deposit_event(Event::NodeWakeupTimedOut {
+                        node_id,
+                        twin_id: request.twin_id,
+                    });
+                }
+                _ => (),
+            }
+        }
+
+        weight
+    }
+}
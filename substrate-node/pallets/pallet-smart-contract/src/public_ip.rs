@@ -0,0 +1,180 @@
+use crate::*;
+use frame_support::{
+    dispatch::{DispatchErrorWithPostInfo, DispatchResultWithPostInfo},
+    ensure, BoundedVec,
+};
+use sp_std::{collections::btree_map::BTreeMap, vec::Vec};
+use tfchain_support::{resources::Resources, types::PublicIP};
+
+impl<T: Config> Pallet<T> {
+    pub fn _create_node_contract_with_public_ips(
+        account_id: T::AccountId,
+        node_id: u32,
+        deployment_hash: types::HexHash,
+        deployment_data: DeploymentDataInput<T>,
+        public_ips_request: types::PublicIpsRequest<T>,
+        resources: Option<Resources>,
+        solution_provider_id: Option<u64>,
+    ) -> DispatchResultWithPostInfo {
+        let twin_id = pallet_tfgrid::TwinIdByAccountID::<T>::get(&account_id)
+            .ok_or(Error::<T>::TwinNotExists)?;
+        let node = pallet_tfgrid::Nodes::<T>::get(node_id).ok_or(Error::<T>::NodeNotExists)?;
+
+        let public_ips_list = Self::select_public_ips(node.farm_id, &public_ips_request)?;
+
+        Self::create_node_contract_for_twin(
+            twin_id,
+            node_id,
+            deployment_hash,
+            deployment_data,
+            public_ips_list.len() as u32,
+            public_ips_list,
+            0,
+            resources,
+            solution_provider_id,
+        )?;
+
+        Ok(().into())
+    }
+
+    pub fn _create_node_contract_with_ip6_subnets(
+        account_id: T::AccountId,
+        node_id: u32,
+        deployment_hash: types::HexHash,
+        deployment_data: DeploymentDataInput<T>,
+        public_ips: u32,
+        ip6_subnets: u32,
+        resources: Option<Resources>,
+        solution_provider_id: Option<u64>,
+    ) -> DispatchResultWithPostInfo {
+        let twin_id = pallet_tfgrid::TwinIdByAccountID::<T>::get(&account_id)
+            .ok_or(Error::<T>::TwinNotExists)?;
+
+        Self::create_node_contract_for_twin(
+            twin_id,
+            node_id,
+            deployment_hash,
+            deployment_data,
+            public_ips,
+            BoundedVec::default(),
+            ip6_subnets,
+            resources,
+            solution_provider_id,
+        )?;
+
+        Ok(().into())
+    }
+
+    pub fn _set_public_ip_price_multiplier(
+        account_id: T::AccountId,
+        farm_id: u32,
+        ip: pallet_tfgrid::Ip4Input,
+        multiplier: u32,
+    ) -> DispatchResultWithPostInfo {
+        // Make sure only the farmer that owns this farm can set the multiplier
+        let twin_id = pallet_tfgrid::TwinIdByAccountID::<T>::get(&account_id)
+            .ok_or(Error::<T>::TwinNotExists)?;
+        let farm = pallet_tfgrid::Farms::<T>::get(farm_id).ok_or(Error::<T>::FarmNotExists)?;
+        ensure!(
+            twin_id == farm.twin_id,
+            Error::<T>::UnauthorizedToSetPublicIpPriceMultiplier
+        );
+        ensure!(
+            multiplier > 0 && multiplier <= types::MAX_PUBLIC_IP_PRICE_MULTIPLIER,
+            Error::<T>::InvalidPublicIpPriceMultiplier
+        );
+
+        // Make sure the price of an ip does not change while it is used by a contract
+        let public_ip = farm
+            .public_ips
+            .iter()
+            .find(|public_ip| public_ip.ip == ip)
+            .ok_or(Error::<T>::PublicIpNotExists)?;
+        ensure!(public_ip.contract_id == 0, Error::<T>::PublicIpIsReserved);
+
+        if multiplier == types::DEFAULT_PUBLIC_IP_PRICE_MULTIPLIER {
+            PublicIpPriceMultiplier::<T>::remove(farm_id, &ip);
+        } else {
+            PublicIpPriceMultiplier::<T>::insert(farm_id, &ip, multiplier);
+        }
+
+        Self::deposit_event(Event::PublicIpPriceMultiplierSet {
+            farm_id,
+            ip,
+            multiplier,
+        });
+
+        Ok(().into())
+    }
+
+    // Selects the free public ips of the farm matching the request
+    pub fn select_public_ips(
+        farm_id: u32,
+        public_ips_request: &types::PublicIpsRequest<T>,
+    ) -> Result<BoundedVec<PublicIP, MaxNodeContractPublicIPs<T>>, DispatchErrorWithPostInfo> {
+        let farm = pallet_tfgrid::Farms::<T>::get(farm_id).ok_or(Error::<T>::FarmNotExists)?;
+
+        let ips = match public_ips_request {
+            types::PublicIpsRequest::Ips(requested_ips) => {
+                ensure!(
+                    !requested_ips.is_empty(),
+                    Error::<T>::InvalidPublicIpsRequest
+                );
+
+                let mut ips = Vec::with_capacity(requested_ips.len());
+                for requested_ip in requested_ips {
+                    ensure!(
+                        !ips.iter().any(|ip: &PublicIP| ip.ip == *requested_ip),
+                        Error::<T>::InvalidPublicIpsRequest
+                    );
+                    let public_ip = farm
+                        .public_ips
+                        .iter()
+                        .find(|public_ip| public_ip.ip == *requested_ip)
+                        .ok_or(Error::<T>::PublicIpNotExists)?;
+                    ensure!(public_ip.contract_id == 0, Error::<T>::PublicIpNotAvailable);
+                    ips.push(public_ip.clone());
+                }
+                ips
+            }
+            types::PublicIpsRequest::Block(size) => {
+                let size = *size;
+                ensure!(
+                    size.is_power_of_two() && size <= T::MaxNodeContractPublicIps::get(),
+                    Error::<T>::InvalidPublicIpsRequest
+                );
+
+                // Free ips of the farm by address
+                let free_ips: BTreeMap<u32, PublicIP> = farm
+                    .public_ips
+                    .iter()
+                    .filter(|public_ip| public_ip.contract_id == 0)
+                    .filter_map(|public_ip| {
+                        public_ip
+                            .address()
+                            .map(|address| (address, public_ip.clone()))
+                    })
+                    .collect();
+
+                // Take the first block with all its ips free
+                free_ips
+                    .keys()
+                    .filter(|address| *address % size == 0)
+                    .map(|start| {
+                        (0..size)
+                            .map_while(|i| {
+                                start
+                                    .checked_add(i)
+                                    .and_then(|address| free_ips.get(&address).cloned())
+                            })
+                            .collect::<Vec<PublicIP>>()
+                    })
+                    .find(|block| block.len() == size as usize)
+                    .ok_or(Error::<T>::PublicIpsBlockNotAvailable)?
+            }
+        };
+
+        BoundedVec::try_from(ips)
+            .map_err(|_| DispatchErrorWithPostInfo::from(Error::<T>::InvalidPublicIpsRequest))
+    }
+}
@@ -2,10 +2,11 @@ use crate::*;
 use frame_support::{
     dispatch::{DispatchErrorWithPostInfo, DispatchResultWithPostInfo},
     ensure,
-    traits::{Currency, ExistenceRequirement},
+    traits::{BalanceStatus, Currency, ExistenceRequirement, ReservableCurrency},
     transactional, BoundedVec,
 };
 use sp_core::Get;
+use sp_runtime::{traits::Zero, SaturatedConversion};
 use sp_std::{vec, vec::Vec};
 use substrate_fixed::types::U64F64;
 
@@ -192,6 +193,10 @@ impl<T: Config> Pallet<T> {
             // Initialize billing time
             let now = Self::get_current_timestamp_in_secs();
             service_contract.last_bill = now;
+            ServiceContractLastVariableBill::<T>::insert(service_contract_id, now);
+
+            // Base fee is billed along with other contracts
+            Self::insert_service_contract_in_billing_loop(service_contract_id);
         }
 
         // Update service contract in map after modification
@@ -251,7 +256,15 @@ impl<T: Config> Pallet<T> {
         // Remove contract from service contract map
         // Can be done at any state of contract
         // so no need to handle state validation
+        // Bills in escrow are kept until they are released or their dispute is resolved
         ServiceContracts::<T>::remove(service_contract_id);
+        ServiceContractDisputeWindow::<T>::remove(service_contract_id);
+        ServiceContractLastVariableBill::<T>::remove(service_contract_id);
+        ServiceContractPendingVariableBill::<T>::remove(service_contract_id);
+        ServiceContractGracePeriodStart::<T>::remove(service_contract_id);
+        ServiceContractAmendments::<T>::remove(service_contract_id);
+        ServiceContractPlan::<T>::remove(service_contract_id);
+        Self::remove_service_contract_from_billing_loop(service_contract_id);
 
         // Trigger event for service contract cancelation
         Self::deposit_event(Event::ServiceContractCanceled {
@@ -267,6 +280,152 @@ impl<T: Config> Pallet<T> {
         Ok(().into())
     }
 
+    pub fn _service_contract_propose_amendment(
+        account_id: T::AccountId,
+        service_contract_id: u64,
+        base_fee: u64,
+        variable_fee: u64,
+        metadata: Vec<u8>,
+    ) -> DispatchResultWithPostInfo {
+        let twin_id = pallet_tfgrid::TwinIdByAccountID::<T>::get(&account_id)
+            .ok_or(Error::<T>::TwinNotExists)?;
+
+        let service_contract = ServiceContracts::<T>::get(service_contract_id)
+            .ok_or(Error::<T>::ServiceContractNotExists)?;
+
+        // Only service or consumer can propose new terms
+        ensure!(
+            twin_id == service_contract.service_twin_id
+                || twin_id == service_contract.consumer_twin_id,
+            Error::<T>::TwinNotAuthorized,
+        );
+
+        // Before approval terms are set with the regular setters
+        ensure!(
+            matches!(
+                service_contract.state,
+                types::ServiceContractState::ApprovedByBoth
+            ),
+            Error::<T>::ServiceContractAmendmentNotAllowed,
+        );
+
+        let metadata = BoundedVec::try_from(metadata)
+            .map_err(|_| Error::<T>::ServiceContractMetadataTooLong)?;
+
+        // Base fee and metadata are mandatory as for a new contract
+        ensure!(
+            base_fee != 0 && !metadata.is_empty(),
+            Error::<T>::InvalidServiceContractAmendment
+        );
+
+        // A new proposal replaces the pending one, if any
+        let amendment = types::ServiceContractAmendment {
+            proposed_by: twin_id,
+            base_fee,
+            variable_fee,
+            metadata,
+        };
+        ServiceContractAmendments::<T>::insert(service_contract_id, &amendment);
+
+        Self::deposit_event(Event::ServiceContractAmendmentProposed {
+            service_contract_id,
+            amendment,
+        });
+
+        Ok(().into())
+    }
+
+    pub fn _service_contract_accept_amendment(
+        account_id: T::AccountId,
+        service_contract_id: u64,
+    ) -> DispatchResultWithPostInfo {
+        let twin_id = pallet_tfgrid::TwinIdByAccountID::<T>::get(&account_id)
+            .ok_or(Error::<T>::TwinNotExists)?;
+
+        let service_contract = ServiceContracts::<T>::get(service_contract_id)
+            .ok_or(Error::<T>::ServiceContractNotExists)?;
+
+        let amendment = ServiceContractAmendments::<T>::get(service_contract_id)
+            .ok_or(Error::<T>::ServiceContractAmendmentNotProposed)?;
+
+        // Only the party that did not propose the new terms can accept them
+        let other_party = if amendment.proposed_by == service_contract.service_twin_id {
+            service_contract.consumer_twin_id
+        } else {
+            service_contract.service_twin_id
+        };
+        ensure!(twin_id == other_party, Error::<T>::TwinNotAuthorized);
+
+        // Variable amount not billed yet is bounded by the old variable fee,
+        // the service can still bill it along with the next variable bill
+        let now = Self::get_current_timestamp_in_secs();
+        let mut pending =
+            ServiceContractPendingVariableBill::<T>::get(service_contract_id).unwrap_or_default();
+        let window = Self::get_variable_bill_window(service_contract_id, &service_contract, now)
+            .min(T::BillingReferencePeriod::get().saturating_sub(pending.window));
+        let max_variable_amount =
+            Self::get_max_variable_amount(window, service_contract.variable_fee);
+        pending.window = pending.window.saturating_add(window);
+        pending.max_variable_amount = pending
+            .max_variable_amount
+            .saturating_add(max_variable_amount);
+
+        // Bill base fee with old terms up to now
+        Self::bill_service_contract(service_contract_id)?;
+        ensure!(
+            !ServiceContractGracePeriodStart::<T>::contains_key(service_contract_id),
+            Error::<T>::CannotAmendServiceContractInGracePeriod
+        );
+
+        ServiceContractPendingVariableBill::<T>::insert(service_contract_id, pending);
+        ServiceContractLastVariableBill::<T>::insert(service_contract_id, now);
+
+        let mut service_contract = ServiceContracts::<T>::get(service_contract_id)
+            .ok_or(Error::<T>::ServiceContractNotExists)?;
+        service_contract.last_bill = now;
+        service_contract.base_fee = amendment.base_fee;
+        service_contract.variable_fee = amendment.variable_fee;
+        service_contract.metadata = amendment.metadata;
+
+        ServiceContracts::<T>::insert(service_contract_id, service_contract.clone());
+        ServiceContractAmendments::<T>::remove(service_contract_id);
+
+        Self::deposit_event(Event::ServiceContractAmended(service_contract));
+
+        Ok(().into())
+    }
+
+    pub fn _service_contract_reject_amendment(
+        account_id: T::AccountId,
+        service_contract_id: u64,
+    ) -> DispatchResultWithPostInfo {
+        let twin_id = pallet_tfgrid::TwinIdByAccountID::<T>::get(&account_id)
+            .ok_or(Error::<T>::TwinNotExists)?;
+
+        let service_contract = ServiceContracts::<T>::get(service_contract_id)
+            .ok_or(Error::<T>::ServiceContractNotExists)?;
+
+        // Proposer can withdraw its proposal and the other party can reject it
+        ensure!(
+            twin_id == service_contract.service_twin_id
+                || twin_id == service_contract.consumer_twin_id,
+            Error::<T>::TwinNotAuthorized,
+        );
+        ensure!(
+            ServiceContractAmendments::<T>::contains_key(service_contract_id),
+            Error::<T>::ServiceContractAmendmentNotProposed
+        );
+
+        ServiceContractAmendments::<T>::remove(service_contract_id);
+
+        Self::deposit_event(Event::ServiceContractAmendmentRejected {
+            service_contract_id,
+            twin_id,
+        });
+
+        Ok(().into())
+    }
+
     #[transactional]
     pub fn _service_contract_bill(
         account_id: T::AccountId,
@@ -277,7 +436,7 @@ impl<T: Config> Pallet<T> {
         let twin_id = pallet_tfgrid::TwinIdByAccountID::<T>::get(&account_id)
             .ok_or(Error::<T>::TwinNotExists)?;
 
-        let mut service_contract = ServiceContracts::<T>::get(service_contract_id)
+        let service_contract = ServiceContracts::<T>::get(service_contract_id)
             .ok_or(Error::<T>::ServiceContractNotExists)?;
 
         // Only service can bill consumer for service contract
@@ -295,23 +454,30 @@ impl<T: Config> Pallet<T> {
             Error::<T>::ServiceContractBillingNotApprovedByBoth,
         );
 
-        // Get elapsed time (in seconds) to bill for service
+        // Get elapsed time (in seconds) since variable amount was last billed
+        // Base fee is billed in the billing loop so only variable amount is billed here
         let now = Self::get_current_timestamp_in_secs();
-        let elapsed_seconds_since_last_bill = now - service_contract.last_bill;
+        let elapsed_seconds_since_last_bill =
+            Self::get_variable_bill_window(service_contract_id, &service_contract, now);
+
+        // What was left to bill before the last amendment is billed with the old terms
+        let pending =
+            ServiceContractPendingVariableBill::<T>::take(service_contract_id).unwrap_or_default();
 
         // Billing time (window) is max 1h by design
         // So extra time will not be billed
         // It is the service responsability to bill on right frequency
-        let window = elapsed_seconds_since_last_bill.min(T::BillingReferencePeriod::get());
+        let window = elapsed_seconds_since_last_bill
+            .min(T::BillingReferencePeriod::get().saturating_sub(pending.window));
 
         // Billing variable amount is bounded by contract variable fee
+        let max_variable_amount =
+            Self::get_max_variable_amount(window, service_contract.variable_fee);
         ensure!(
             variable_amount
-                <= ((U64F64::from_num(window)
-                    / U64F64::from_num(T::BillingReferencePeriod::get()))
-                    * U64F64::from_num(service_contract.variable_fee))
-                .round()
-                .to_num::<u64>(),
+                <= pending
+                    .max_variable_amount
+                    .saturating_add(max_variable_amount),
             Error::<T>::ServiceContractBillingVariableAmountTooHigh,
         );
 
@@ -319,9 +485,10 @@ impl<T: Config> Pallet<T> {
             .map_err(|_| Error::<T>::ServiceContractBillMetadataTooLong)?;
 
         // Create service contract bill
+        // Window is left empty since base fee is not part of it
         let service_contract_bill = types::ServiceContractBill {
             variable_amount,
-            window,
+            window: 0,
             metadata: bill_metadata,
         };
 
@@ -329,11 +496,114 @@ impl<T: Config> Pallet<T> {
         let amount =
             Self::_service_contract_pay_bill(service_contract_id, service_contract_bill.clone())?;
 
-        // Update contract in list after modification
+        ServiceContractLastVariableBill::<T>::insert(service_contract_id, now);
+
+        // Trigger event for service contract billing
+        Self::deposit_event(Event::ServiceContractBilled {
+            service_contract,
+            bill: service_contract_bill,
+            amount,
+        });
+
+        Ok(().into())
+    }
+
+    // Time (in seconds) elapsed since the variable amount was last billed
+    fn get_variable_bill_window(
+        service_contract_id: u64,
+        service_contract: &types::ServiceContract,
+        now: u64,
+    ) -> u64 {
+        let last_variable_bill = ServiceContractLastVariableBill::<T>::get(service_contract_id)
+            .unwrap_or(service_contract.last_bill);
+        now.saturating_sub(last_variable_bill)
+    }
+
+    // Variable amount that can be billed over a window given a variable fee
+    fn get_max_variable_amount(window: u64, variable_fee: u64) -> u64 {
+        ((U64F64::from_num(window) / U64F64::from_num(T::BillingReferencePeriod::get()))
+            * U64F64::from_num(variable_fee))
+        .round()
+        .to_num::<u64>()
+    }
+
+    // Bills the base fee of a service contract for the time elapsed since last billing
+    // If the consumer can not pay, the contract goes in grace period
+    // and is canceled when grace period is over
+    pub fn bill_service_contract(service_contract_id: u64) -> DispatchResultWithPostInfo {
+        let mut service_contract = ServiceContracts::<T>::get(service_contract_id)
+            .ok_or(Error::<T>::ServiceContractNotExists)?;
+
+        ensure!(
+            matches!(
+                service_contract.state,
+                types::ServiceContractState::ApprovedByBoth
+            ),
+            Error::<T>::ServiceContractBillingNotApprovedByBoth,
+        );
+
+        let now = Self::get_current_timestamp_in_secs();
+        let service_contract_bill = types::ServiceContractBill {
+            variable_amount: 0,
+            window: now.saturating_sub(service_contract.last_bill),
+            metadata: BoundedVec::default(),
+        };
+
+        let amount =
+            service_contract.calculate_bill_cost_tft::<T>(service_contract_bill.clone())?;
+        if amount.is_zero() {
+            log::debug!("amount to be billed is 0, nothing to do");
+            return Ok(().into());
+        }
+
+        let consumer_twin = pallet_tfgrid::Twins::<T>::get(service_contract.consumer_twin_id)
+            .ok_or(Error::<T>::TwinNotExists)?;
+
+        // Billing time is not updated while in grace period
+        // so that what is due gets billed once the consumer can pay again
+        if Self::get_usable_balance(&consumer_twin.account_id) < amount {
+            let current_block = <frame_system::Pallet<T>>::block_number().saturated_into::<u64>();
+            match ServiceContractGracePeriodStart::<T>::get(service_contract_id) {
+                Some(grace_start) => {
+                    if current_block.saturating_sub(grace_start)
+                        >= Self::get_contract_kind_grace_period(types::ContractKind::Service)
+                    {
+                        let service_twin =
+                            pallet_tfgrid::Twins::<T>::get(service_contract.service_twin_id)
+                                .ok_or(Error::<T>::TwinNotExists)?;
+                        Self::_service_contract_cancel(
+                            service_twin.account_id,
+                            service_contract_id,
+                            types::Cause::OutOfFunds,
+                        )?;
+                    }
+                }
+                None => {
+                    ServiceContractGracePeriodStart::<T>::insert(
+                        service_contract_id,
+                        current_block,
+                    );
+                    Self::deposit_event(Event::ServiceContractGracePeriodStarted {
+                        service_contract_id,
+                        block_number: current_block,
+                    });
+                }
+            }
+            return Ok(().into());
+        }
+
+        let amount =
+            Self::_service_contract_pay_bill(service_contract_id, service_contract_bill.clone())?;
+
+        if ServiceContractGracePeriodStart::<T>::take(service_contract_id).is_some() {
+            Self::deposit_event(Event::ServiceContractGracePeriodEnded {
+                service_contract_id,
+            });
+        }
+
         service_contract.last_bill = now;
         ServiceContracts::<T>::insert(service_contract_id, service_contract.clone());
 
-        // Trigger event for service contract billing
         Self::deposit_event(Event::ServiceContractBilled {
             service_contract,
             bill: service_contract_bill,
@@ -362,32 +632,313 @@ impl<T: Config> Pallet<T> {
 
         let usable_balance = Self::get_usable_balance(&consumer_twin.account_id);
 
-        // If consumer is out of funds then contract is canceled
-        // by service and removed from service contract map
-        if usable_balance < amount {
-            Self::_service_contract_cancel(
-                service_twin.account_id,
-                service_contract_id,
-                types::Cause::OutOfFunds,
-            )?;
+        // The bill is rejected if the consumer is out of funds, running out
+        // of funds for the base fee is handled by the grace period of the contract
+        ensure!(
+            usable_balance >= amount,
+            Error::<T>::ServiceContractNotEnoughFundsToPayBill
+        );
+
+        match ServiceContractDisputeWindow::<T>::get(service_contract_id) {
+            // In escrow mode the amount due is held on consumer account
+            // until the dispute window is over
+            Some(dispute_window) if !amount.is_zero() => {
+                <T as Config>::Currency::reserve(&consumer_twin.account_id, amount)?;
+
+                let bill_id = ServiceContractBillID::<T>::get() + 1;
+                let dispute_deadline = Self::get_current_timestamp_in_secs() + dispute_window;
+                ServiceContractEscrowedBills::<T>::insert(
+                    service_contract_id,
+                    bill_id,
+                    types::EscrowedServiceContractBill {
+                        service_twin_id,
+                        consumer_twin_id: consumer_twin.id,
+                        bill,
+                        amount,
+                        dispute_deadline,
+                        disputed: false,
+                        refund_proposed_by_service: None,
+                        refund_proposed_by_consumer: None,
+                    },
+                );
+                ServiceContractBillID::<T>::put(bill_id);
+
+                Self::deposit_event(Event::ServiceContractBillEscrowed {
+                    service_contract_id,
+                    bill_id,
+                    amount,
+                    dispute_deadline,
+                });
+            }
+            // Transfer amount due from consumer account to service account
+            _ => {
+                <T as Config>::Currency::transfer(
+                    &consumer_twin.account_id,
+                    &service_twin.account_id,
+                    amount,
+                    ExistenceRequirement::KeepAlive,
+                )?;
+            }
+        }
+
+        log::debug!(
+            "bill successfully payed by consumer for service contract with id {:?}",
+            service_contract_id,
+        );
+
+        Ok(amount)
+    }
+
+    pub fn _service_contract_set_dispute_window(
+        account_id: T::AccountId,
+        service_contract_id: u64,
+        dispute_window: Option<u64>,
+    ) -> DispatchResultWithPostInfo {
+        let twin_id = pallet_tfgrid::TwinIdByAccountID::<T>::get(&account_id)
+            .ok_or(Error::<T>::TwinNotExists)?;
+
+        let service_contract = ServiceContracts::<T>::get(service_contract_id)
+            .ok_or(Error::<T>::ServiceContractNotExists)?;
+
+        // Only service or consumer can set dispute window
+        ensure!(
+            twin_id == service_contract.service_twin_id
+                || twin_id == service_contract.consumer_twin_id,
+            Error::<T>::TwinNotAuthorized,
+        );
+
+        // Only allow to modify dispute window if contract still not approved by both parties
+        ensure!(
+            !matches!(
+                service_contract.state,
+                types::ServiceContractState::ApprovedByBoth
+            ),
+            Error::<T>::ServiceContractModificationNotAllowed,
+        );
+
+        if let Some(window) = dispute_window {
+            ensure!(
+                window > 0 && window <= types::MAX_DISPUTE_WINDOW,
+                Error::<T>::InvalidDisputeWindow
+            );
+        }
+
+        ServiceContractDisputeWindow::<T>::set(service_contract_id, dispute_window);
+
+        Self::deposit_event(Event::ServiceContractDisputeWindowSet {
+            service_contract_id,
+            dispute_window,
+        });
+
+        Ok(().into())
+    }
+
+    pub fn _service_contract_dispute_bill(
+        account_id: T::AccountId,
+        service_contract_id: u64,
+        bill_id: u64,
+    ) -> DispatchResultWithPostInfo {
+        let twin_id = pallet_tfgrid::TwinIdByAccountID::<T>::get(&account_id)
+            .ok_or(Error::<T>::TwinNotExists)?;
+
+        let mut escrowed_bill =
+            ServiceContractEscrowedBills::<T>::get(service_contract_id, bill_id)
+                .ok_or(Error::<T>::ServiceContractBillNotInEscrow)?;
+
+        // Only consumer can dispute a bill
+        ensure!(
+            twin_id == escrowed_bill.consumer_twin_id,
+            Error::<T>::TwinNotAuthorized,
+        );
+        ensure!(
+            !escrowed_bill.disputed,
+            Error::<T>::ServiceContractBillDisputed
+        );
+        ensure!(
+            Self::get_current_timestamp_in_secs() <= escrowed_bill.dispute_deadline,
+            Error::<T>::ServiceContractDisputeWindowClosed
+        );
+
+        escrowed_bill.disputed = true;
+        ServiceContractEscrowedBills::<T>::insert(service_contract_id, bill_id, escrowed_bill);
+
+        Self::deposit_event(Event::ServiceContractBillDisputed {
+            service_contract_id,
+            bill_id,
+        });
+
+        Ok(().into())
+    }
+
+    pub fn _service_contract_resolve_dispute(
+        account_id: T::AccountId,
+        service_contract_id: u64,
+        bill_id: u64,
+        refund: BalanceOf<T>,
+    ) -> DispatchResultWithPostInfo {
+        let twin_id = pallet_tfgrid::TwinIdByAccountID::<T>::get(&account_id)
+            .ok_or(Error::<T>::TwinNotExists)?;
+
+        let mut escrowed_bill =
+            ServiceContractEscrowedBills::<T>::get(service_contract_id, bill_id)
+                .ok_or(Error::<T>::ServiceContractBillNotInEscrow)?;
+
+        ensure!(
+            escrowed_bill.disputed,
+            Error::<T>::ServiceContractBillNotDisputed
+        );
+        ensure!(
+            refund <= escrowed_bill.amount,
+            Error::<T>::InvalidServiceContractBillRefund
+        );
+
+        // Only service or consumer can propose a refund
+        if twin_id == escrowed_bill.service_twin_id {
+            escrowed_bill.refund_proposed_by_service = Some(refund);
+        } else if twin_id == escrowed_bill.consumer_twin_id {
+            escrowed_bill.refund_proposed_by_consumer = Some(refund);
+        } else {
             return Err(DispatchErrorWithPostInfo::from(
-                Error::<T>::ServiceContractNotEnoughFundsToPayBill,
+                Error::<T>::TwinNotAuthorized,
             ));
         }
 
-        // Transfer amount due from consumer account to service account
-        <T as Config>::Currency::transfer(
+        Self::deposit_event(Event::ServiceContractDisputeRefundProposed {
+            service_contract_id,
+            bill_id,
+            twin_id,
+            refund,
+        });
+
+        // Dispute is settled once both parties agree on the same refund
+        if escrowed_bill.refund_proposed_by_service.is_some()
+            && escrowed_bill.refund_proposed_by_service == escrowed_bill.refund_proposed_by_consumer
+        {
+            Self::settle_escrowed_bill(service_contract_id, bill_id, &escrowed_bill, refund)?;
+        } else {
+            ServiceContractEscrowedBills::<T>::insert(service_contract_id, bill_id, escrowed_bill);
+        }
+
+        Ok(().into())
+    }
+
+    pub fn _service_contract_force_resolve_dispute(
+        service_contract_id: u64,
+        bill_id: u64,
+        refund: BalanceOf<T>,
+    ) -> DispatchResultWithPostInfo {
+        let escrowed_bill = ServiceContractEscrowedBills::<T>::get(service_contract_id, bill_id)
+            .ok_or(Error::<T>::ServiceContractBillNotInEscrow)?;
+
+        ensure!(
+            escrowed_bill.disputed,
+            Error::<T>::ServiceContractBillNotDisputed
+        );
+        ensure!(
+            refund <= escrowed_bill.amount,
+            Error::<T>::InvalidServiceContractBillRefund
+        );
+
+        Self::settle_escrowed_bill(service_contract_id, bill_id, &escrowed_bill, refund)?;
+
+        Ok(().into())
+    }
+
+    pub fn _service_contract_release_bill(
+        account_id: T::AccountId,
+        service_contract_id: u64,
+        bill_id: u64,
+    ) -> DispatchResultWithPostInfo {
+        let twin_id = pallet_tfgrid::TwinIdByAccountID::<T>::get(&account_id)
+            .ok_or(Error::<T>::TwinNotExists)?;
+
+        let escrowed_bill = ServiceContractEscrowedBills::<T>::get(service_contract_id, bill_id)
+            .ok_or(Error::<T>::ServiceContractBillNotInEscrow)?;
+
+        ensure!(
+            !escrowed_bill.disputed,
+            Error::<T>::ServiceContractBillDisputed
+        );
+
+        // Consumer can release a bill at any time
+        // while service has to wait for the end of the dispute window
+        if twin_id == escrowed_bill.service_twin_id {
+            ensure!(
+                Self::get_current_timestamp_in_secs() > escrowed_bill.dispute_deadline,
+                Error::<T>::ServiceContractDisputeWindowNotClosed
+            );
+        } else {
+            ensure!(
+                twin_id == escrowed_bill.consumer_twin_id,
+                Error::<T>::TwinNotAuthorized
+            );
+        }
+
+        Self::pay_escrowed_amount(&escrowed_bill, escrowed_bill.amount)?;
+        ServiceContractEscrowedBills::<T>::remove(service_contract_id, bill_id);
+
+        Self::deposit_event(Event::ServiceContractBillReleased {
+            service_contract_id,
+            bill_id,
+            amount: escrowed_bill.amount,
+        });
+
+        Ok(().into())
+    }
+
+    // Refund part of a disputed bill to the consumer and pay the rest to the service
+    fn settle_escrowed_bill(
+        service_contract_id: u64,
+        bill_id: u64,
+        escrowed_bill: &types::EscrowedServiceContractBill<BalanceOf<T>>,
+        refund: BalanceOf<T>,
+    ) -> DispatchResultWithPostInfo {
+        let consumer_twin = pallet_tfgrid::Twins::<T>::get(escrowed_bill.consumer_twin_id)
+            .ok_or(Error::<T>::TwinNotExists)?;
+
+        let not_refunded = <T as Config>::Currency::unreserve(&consumer_twin.account_id, refund);
+        ensure!(
+            not_refunded.is_zero(),
+            Error::<T>::ServiceContractEscrowedAmountNotReserved
+        );
+        let paid = escrowed_bill.amount.saturating_sub(refund);
+        Self::pay_escrowed_amount(escrowed_bill, paid)?;
+
+        ServiceContractEscrowedBills::<T>::remove(service_contract_id, bill_id);
+
+        Self::deposit_event(Event::ServiceContractDisputeResolved {
+            service_contract_id,
+            bill_id,
+            refund,
+            paid,
+        });
+
+        Ok(().into())
+    }
+
+    // Move an amount held in escrow from consumer account to service account
+    fn pay_escrowed_amount(
+        escrowed_bill: &types::EscrowedServiceContractBill<BalanceOf<T>>,
+        amount: BalanceOf<T>,
+    ) -> DispatchResultWithPostInfo {
+        let service_twin = pallet_tfgrid::Twins::<T>::get(escrowed_bill.service_twin_id)
+            .ok_or(Error::<T>::TwinNotExists)?;
+        let consumer_twin = pallet_tfgrid::Twins::<T>::get(escrowed_bill.consumer_twin_id)
+            .ok_or(Error::<T>::TwinNotExists)?;
+
+        // Part of the amount that was no longer held on consumer account,
+        // the bill stays in escrow since the extrinsic is reverted
+        let unpaid = <T as Config>::Currency::repatriate_reserved(
             &consumer_twin.account_id,
             &service_twin.account_id,
             amount,
-            ExistenceRequirement::KeepAlive,
+            BalanceStatus::Free,
         )?;
-
-        log::debug!(
-            "bill successfully payed by consumer for service contract with id {:?}",
-            service_contract_id,
+        ensure!(
+            unpaid.is_zero(),
+            Error::<T>::ServiceContractEscrowedAmountNotReserved
         );
 
-        Ok(amount)
+        Ok(().into())
     }
 }
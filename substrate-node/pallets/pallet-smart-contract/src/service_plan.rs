@@ -0,0 +1,156 @@
+use crate::*;
+use frame_support::{dispatch::DispatchResultWithPostInfo, ensure, traits::ConstU32, BoundedVec};
+use sp_std::vec::Vec;
+
+impl<T: Config> Pallet<T> {
+    pub fn _service_plan_create(
+        account_id: T::AccountId,
+        base_fee: u64,
+        variable_fee: u64,
+        metadata: Vec<u8>,
+    ) -> DispatchResultWithPostInfo {
+        let twin_id = pallet_tfgrid::TwinIdByAccountID::<T>::get(&account_id)
+            .ok_or(Error::<T>::TwinNotExists)?;
+
+        let metadata = Self::validate_service_plan_terms(base_fee, metadata)?;
+
+        let plan_id = ServicePlanID::<T>::get() + 1;
+        let plan = types::ServicePlan {
+            plan_id,
+            service_twin_id: twin_id,
+            base_fee,
+            variable_fee,
+            metadata,
+            retired: false,
+        };
+
+        ServicePlans::<T>::insert(plan_id, &plan);
+        ServicePlanID::<T>::put(plan_id);
+
+        Self::deposit_event(Event::ServicePlanCreated(plan));
+
+        Ok(().into())
+    }
+
+    pub fn _service_plan_update(
+        account_id: T::AccountId,
+        plan_id: u64,
+        base_fee: u64,
+        variable_fee: u64,
+        metadata: Vec<u8>,
+    ) -> DispatchResultWithPostInfo {
+        let mut plan = Self::get_service_plan_of_owner(&account_id, plan_id)?;
+        ensure!(!plan.retired, Error::<T>::ServicePlanIsRetired);
+
+        // New terms only apply to new subscriptions, existing ones
+        // keep the terms their consumer agreed on until amended
+        plan.metadata = Self::validate_service_plan_terms(base_fee, metadata)?;
+        plan.base_fee = base_fee;
+        plan.variable_fee = variable_fee;
+
+        ServicePlans::<T>::insert(plan_id, &plan);
+
+        Self::deposit_event(Event::ServicePlanUpdated(plan));
+
+        Ok(().into())
+    }
+
+    pub fn _service_plan_retire(
+        account_id: T::AccountId,
+        plan_id: u64,
+    ) -> DispatchResultWithPostInfo {
+        let mut plan = Self::get_service_plan_of_owner(&account_id, plan_id)?;
+        ensure!(!plan.retired, Error::<T>::ServicePlanIsRetired);
+
+        // Existing subscriptions keep running until they are canceled
+        plan.retired = true;
+        ServicePlans::<T>::insert(plan_id, &plan);
+
+        Self::deposit_event(Event::ServicePlanRetired { plan_id });
+
+        Ok(().into())
+    }
+
+    pub fn _service_plan_subscribe(
+        account_id: T::AccountId,
+        plan_id: u64,
+    ) -> DispatchResultWithPostInfo {
+        let consumer_twin_id = pallet_tfgrid::TwinIdByAccountID::<T>::get(&account_id)
+            .ok_or(Error::<T>::TwinNotExists)?;
+
+        let plan = ServicePlans::<T>::get(plan_id).ok_or(Error::<T>::ServicePlanNotExists)?;
+        ensure!(!plan.retired, Error::<T>::ServicePlanIsRetired);
+
+        // Service twin and consumer twin can not be the same
+        ensure!(
+            plan.service_twin_id != consumer_twin_id,
+            Error::<T>::ServiceContractCreationNotAllowed,
+        );
+
+        // Service approved the terms by publishing the plan
+        // and consumer approves them by subscribing
+        let service_contract_id = ServiceContractID::<T>::get() + 1;
+        let now = Self::get_current_timestamp_in_secs();
+        let service_contract = types::ServiceContract {
+            service_contract_id,
+            service_twin_id: plan.service_twin_id,
+            consumer_twin_id,
+            base_fee: plan.base_fee,
+            variable_fee: plan.variable_fee,
+            metadata: plan.metadata,
+            accepted_by_service: true,
+            accepted_by_consumer: true,
+            last_bill: now,
+            state: types::ServiceContractState::ApprovedByBoth,
+        };
+
+        ServiceContracts::<T>::insert(service_contract_id, &service_contract);
+        ServiceContractID::<T>::put(service_contract_id);
+        ServiceContractPlan::<T>::insert(service_contract_id, plan_id);
+        ServiceContractLastVariableBill::<T>::insert(service_contract_id, now);
+        Self::insert_service_contract_in_billing_loop(service_contract_id);
+
+        Self::deposit_event(Event::ServiceContractCreated(service_contract));
+        Self::deposit_event(Event::ServicePlanSubscribed {
+            plan_id,
+            service_contract_id,
+            consumer_twin_id,
+        });
+
+        Ok(().into())
+    }
+
+    fn get_service_plan_of_owner(
+        account_id: &T::AccountId,
+        plan_id: u64,
+    ) -> Result<types::ServicePlan, Error<T>> {
+        let twin_id = pallet_tfgrid::TwinIdByAccountID::<T>::get(account_id)
+            .ok_or(Error::<T>::TwinNotExists)?;
+
+        let plan = ServicePlans::<T>::get(plan_id).ok_or(Error::<T>::ServicePlanNotExists)?;
+
+        // Only the service that published the plan can manage it
+        ensure!(
+            twin_id == plan.service_twin_id,
+            Error::<T>::TwinNotAuthorized
+        );
+
+        Ok(plan)
+    }
+
+    // Base fee and metadata are mandatory as for a service contract
+    fn validate_service_plan_terms(
+        base_fee: u64,
+        metadata: Vec<u8>,
+    ) -> Result<BoundedVec<u8, ConstU32<{ types::MAX_METADATA_LENGTH }>>, Error<T>> {
+        let metadata = BoundedVec::try_from(metadata)
+            .map_err(|_| Error::<T>::ServiceContractMetadataTooLong)?;
+
+        ensure!(
+            base_fee != 0 && !metadata.is_empty(),
+            Error::<T>::InvalidServicePlan
+        );
+
+        Ok(metadata)
+    }
+}
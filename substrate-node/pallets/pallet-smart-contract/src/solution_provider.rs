@@ -2,6 +2,11 @@ use crate::*;
 use frame_support::{
     dispatch::{DispatchErrorWithPostInfo, DispatchResultWithPostInfo},
     ensure,
+    traits::{Currency, ExistenceRequirement},
+};
+use sp_runtime::{
+    traits::{AccountIdConversion, Saturating, Zero},
+    DispatchResult,
 };
 use sp_std::vec::Vec;
 
@@ -23,6 +28,8 @@ impl<T: Config> Pallet<T> {
             description,
             link,
             approved: false,
+            revoked: false,
+            expires_at: None,
         };
 
         SolutionProviderID::<T>::put(id);
@@ -43,6 +50,10 @@ impl<T: Config> Pallet<T> {
         );
 
         if let Some(mut solution_provider) = SolutionProviders::<T>::get(solution_provider_id) {
+            ensure!(
+                !solution_provider.revoked,
+                Error::<T>::SolutionProviderRevoked
+            );
             solution_provider.approved = approve;
             SolutionProviders::<T>::insert(solution_provider_id, &solution_provider);
 
@@ -55,18 +66,101 @@ impl<T: Config> Pallet<T> {
         Ok(().into())
     }
 
-    pub fn _attach_solution_provider_id(
+    pub fn _update_solution_provider(
         account_id: T::AccountId,
-        contract_id: u64,
         solution_provider_id: u64,
+        description: Vec<u8>,
+        link: Vec<u8>,
+        providers: Vec<types::Provider<T::AccountId>>,
+    ) -> DispatchResultWithPostInfo {
+        let mut solution_provider = SolutionProviders::<T>::get(solution_provider_id)
+            .ok_or(Error::<T>::NoSuchSolutionProvider)?;
+        ensure!(
+            !solution_provider.revoked,
+            Error::<T>::SolutionProviderRevoked
+        );
+
+        // Only one of the current providers can update the solution provider
+        ensure!(
+            solution_provider
+                .providers
+                .iter()
+                .any(|provider| provider.who == account_id),
+            Error::<T>::UnauthorizedToUpdateSolutionProvider
+        );
+
+        let total_take: u8 = providers.iter().map(|provider| provider.take).sum();
+        ensure!(total_take <= 50, Error::<T>::InvalidProviderConfiguration);
+
+        // Changing who gets paid or how much has to be approved again by the council
+        if providers != solution_provider.providers {
+            solution_provider.approved = false;
+        }
+
+        solution_provider.providers = providers;
+        solution_provider.description = description;
+        solution_provider.link = link;
+        SolutionProviders::<T>::insert(solution_provider_id, &solution_provider);
+
+        Self::deposit_event(Event::SolutionProviderUpdated(solution_provider));
+
+        Ok(().into())
+    }
+
+    pub fn _revoke_solution_provider(solution_provider_id: u64) -> DispatchResultWithPostInfo {
+        let mut solution_provider = SolutionProviders::<T>::get(solution_provider_id)
+            .ok_or(Error::<T>::NoSuchSolutionProvider)?;
+        ensure!(
+            !solution_provider.revoked,
+            Error::<T>::SolutionProviderRevoked
+        );
+
+        // Contracts keep their solution provider id but it no longer gets its take
+        solution_provider.revoked = true;
+        solution_provider.approved = false;
+        SolutionProviders::<T>::insert(solution_provider_id, &solution_provider);
+
+        Self::deposit_event(Event::SolutionProviderRevoked(solution_provider_id));
+
+        Ok(().into())
+    }
+
+    pub fn _set_solution_provider_expiry(
+        solution_provider_id: u64,
+        expires_at: Option<u64>,
     ) -> DispatchResultWithPostInfo {
-        let solution_provider = SolutionProviders::<T>::get(solution_provider_id)
+        let mut solution_provider = SolutionProviders::<T>::get(solution_provider_id)
             .ok_or(Error::<T>::NoSuchSolutionProvider)?;
         ensure!(
-            solution_provider.approved,
-            Error::<T>::SolutionProviderNotApproved
+            !solution_provider.revoked,
+            Error::<T>::SolutionProviderRevoked
         );
 
+        if let Some(expires_at) = expires_at {
+            ensure!(
+                expires_at > Self::get_current_timestamp_in_secs(),
+                Error::<T>::InvalidSolutionProviderExpiry
+            );
+        }
+
+        solution_provider.expires_at = expires_at;
+        SolutionProviders::<T>::insert(solution_provider_id, &solution_provider);
+
+        Self::deposit_event(Event::SolutionProviderExpirySet {
+            solution_provider_id,
+            expires_at,
+        });
+
+        Ok(().into())
+    }
+
+    pub fn _attach_solution_provider_id(
+        account_id: T::AccountId,
+        contract_id: u64,
+        solution_provider_id: u64,
+    ) -> DispatchResultWithPostInfo {
+        Self::validate_solution_provider(Some(solution_provider_id))?;
+
         let mut contract = Contracts::<T>::get(contract_id).ok_or(Error::<T>::ContractNotExists)?;
 
         let twin_id = pallet_tfgrid::TwinIdByAccountID::<T>::get(&account_id)
@@ -77,13 +171,20 @@ impl<T: Config> Pallet<T> {
             Error::<T>::UnauthorizedToChangeSolutionProviderId
         );
 
+        // A revoked or expired solution provider can be replaced
+        let now = Self::get_current_timestamp_in_secs();
+        let replaceable = |current_id: u64| {
+            SolutionProviders::<T>::get(current_id)
+                .map_or(true, |current| current.revoked || current.is_expired(now))
+        };
+
         match contract.solution_provider_id {
-            Some(_) => {
+            Some(current_id) if !replaceable(current_id) => {
                 return Err(DispatchErrorWithPostInfo::from(
                     Error::<T>::UnauthorizedToChangeSolutionProviderId,
                 ))
             }
-            None => {
+            _ => {
                 contract.solution_provider_id = Some(solution_provider_id);
                 Contracts::<T>::insert(contract_id, &contract);
                 Self::deposit_event(Event::ContractUpdated(contract));
@@ -103,6 +204,14 @@ impl<T: Config> Pallet<T> {
             );
 
             if let Some(solution_provider) = SolutionProviders::<T>::get(provider_id) {
+                ensure!(
+                    !solution_provider.revoked,
+                    Error::<T>::SolutionProviderRevoked
+                );
+                ensure!(
+                    !solution_provider.is_expired(Self::get_current_timestamp_in_secs()),
+                    Error::<T>::SolutionProviderExpired
+                );
                 ensure!(
                     solution_provider.approved,
                     Error::<T>::SolutionProviderNotApproved
@@ -112,4 +221,118 @@ impl<T: Config> Pallet<T> {
         }
         Ok(().into())
     }
+
+    pub fn _set_solution_provider_rewards_accrual(
+        account_id: T::AccountId,
+        accrue: bool,
+    ) -> DispatchResultWithPostInfo {
+        if accrue {
+            SolutionProviderRewardsAccrual::<T>::insert(&account_id, true);
+        } else {
+            // Shares accrued so far can still be claimed
+            SolutionProviderRewardsAccrual::<T>::remove(&account_id);
+        }
+
+        Self::deposit_event(Event::SolutionProviderRewardsAccrualSet { account_id, accrue });
+
+        Ok(().into())
+    }
+
+    pub fn _claim_solution_provider_rewards(
+        account_id: T::AccountId,
+    ) -> DispatchResultWithPostInfo {
+        let amount = UnclaimedSolutionProviderRewards::<T>::get(&account_id);
+        ensure!(
+            !amount.is_zero(),
+            Error::<T>::NoSolutionProviderRewardsToClaim
+        );
+
+        <T as Config>::Currency::transfer(
+            &Self::solution_provider_rewards_account(),
+            &account_id,
+            amount,
+            ExistenceRequirement::AllowDeath,
+        )?;
+        UnclaimedSolutionProviderRewards::<T>::remove(&account_id);
+
+        Self::deposit_event(Event::SolutionProviderRewardsClaimed { account_id, amount });
+
+        Ok(().into())
+    }
+
+    // Pays the share of a provider for a bill of a contract and keeps track of the earnings.
+    // Shares of accounts accruing their rewards are kept on the rewards account until claimed.
+    pub fn pay_solution_provider_share(
+        solution_provider_id: u64,
+        contract_id: u64,
+        source: &T::AccountId,
+        provider: &T::AccountId,
+        share: BalanceOf<T>,
+    ) -> DispatchResult {
+        if share.is_zero() {
+            return Ok(());
+        }
+
+        // A share below the existential deposit can not be accrued while the rewards
+        // account does not exist, it is transferred to the provider instead
+        let accrued = SolutionProviderRewardsAccrual::<T>::get(provider)
+            && <T as Config>::Currency::transfer(
+                source,
+                &Self::solution_provider_rewards_account(),
+                share,
+                ExistenceRequirement::KeepAlive,
+            )
+            .is_ok();
+
+        if accrued {
+            UnclaimedSolutionProviderRewards::<T>::mutate(provider, |unclaimed| {
+                unclaimed.saturating_accrue(share)
+            });
+        } else {
+            <T as Config>::Currency::transfer(
+                source,
+                provider,
+                share,
+                ExistenceRequirement::KeepAlive,
+            )?;
+        }
+
+        SolutionProviderAccountEarnings::<T>::mutate(solution_provider_id, provider, |earned| {
+            earned.saturating_accrue(share)
+        });
+        SolutionProviderContractEarnings::<T>::mutate(
+            solution_provider_id,
+            contract_id,
+            |earned| earned.saturating_accrue(share),
+        );
+
+        Ok(())
+    }
+
+    pub fn solution_provider_rewards_account() -> T::AccountId {
+        SOLUTION_PROVIDER_REWARDS_ID.into_account_truncating()
+    }
+
+    pub fn get_solution_provider_earnings(
+        solution_provider_id: u64,
+    ) -> types::SolutionProviderEarnings<T::AccountId, BalanceOf<T>> {
+        let providers = SolutionProviderAccountEarnings::<T>::iter_prefix(solution_provider_id)
+            .map(|(who, earned)| types::ProviderEarnings { who, earned })
+            .collect();
+
+        let mut contracts: Vec<types::ContractEarnings<BalanceOf<T>>> =
+            SolutionProviderContractEarnings::<T>::iter_prefix(solution_provider_id)
+                .map(|(contract_id, earned)| types::ContractEarnings {
+                    contract_id,
+                    earned,
+                })
+                .collect();
+        contracts.sort_by_key(|contract| contract.contract_id);
+
+        types::SolutionProviderEarnings {
+            solution_provider_id,
+            providers,
+            contracts,
+        }
+    }
 }
@@ -0,0 +1,172 @@
+use crate::*;
+use frame_support::{dispatch::DispatchResultWithPostInfo, ensure};
+use sp_runtime::traits::{Saturating, Zero};
+
+impl<T: Config> Pallet<T> {
+    pub fn _set_contract_spending_cap(
+        account_id: T::AccountId,
+        contract_id: u64,
+        amount: BalanceOf<T>,
+        period: u64,
+        action: types::SpendingCapAction,
+    ) -> DispatchResultWithPostInfo {
+        ensure!(period > 0, Error::<T>::InvalidSpendingCapPeriod);
+
+        let twin_id = pallet_tfgrid::TwinIdByAccountID::<T>::get(&account_id)
+            .ok_or(Error::<T>::TwinNotExists)?;
+        let contract = Contracts::<T>::get(contract_id).ok_or(Error::<T>::ContractNotExists)?;
+        ensure!(
+            contract.twin_id == twin_id,
+            Error::<T>::TwinNotAuthorizedToUpdateContract
+        );
+
+        ContractSpendingCap::<T>::insert(
+            contract_id,
+            Self::new_spending_cap(
+                ContractSpendingCap::<T>::get(contract_id),
+                amount,
+                period,
+                action,
+            ),
+        );
+
+        Self::deposit_event(Event::ContractSpendingCapSet {
+            contract_id,
+            amount,
+            period,
+            action,
+        });
+
+        Ok(().into())
+    }
+
+    pub fn _clear_contract_spending_cap(
+        account_id: T::AccountId,
+        contract_id: u64,
+    ) -> DispatchResultWithPostInfo {
+        let twin_id = pallet_tfgrid::TwinIdByAccountID::<T>::get(&account_id)
+            .ok_or(Error::<T>::TwinNotExists)?;
+        let contract = Contracts::<T>::get(contract_id).ok_or(Error::<T>::ContractNotExists)?;
+        ensure!(
+            contract.twin_id == twin_id,
+            Error::<T>::TwinNotAuthorizedToUpdateContract
+        );
+        ensure!(
+            ContractSpendingCap::<T>::contains_key(contract_id),
+            Error::<T>::SpendingCapNotExists
+        );
+
+        ContractSpendingCap::<T>::remove(contract_id);
+
+        Self::deposit_event(Event::ContractSpendingCapCleared { contract_id });
+
+        Ok(().into())
+    }
+
+    pub fn _set_twin_spending_cap(
+        account_id: T::AccountId,
+        amount: BalanceOf<T>,
+        period: u64,
+        action: types::SpendingCapAction,
+    ) -> DispatchResultWithPostInfo {
+        ensure!(period > 0, Error::<T>::InvalidSpendingCapPeriod);
+
+        let twin_id = pallet_tfgrid::TwinIdByAccountID::<T>::get(&account_id)
+            .ok_or(Error::<T>::TwinNotExists)?;
+
+        TwinSpendingCap::<T>::insert(
+            twin_id,
+            Self::new_spending_cap(TwinSpendingCap::<T>::get(twin_id), amount, period, action),
+        );
+
+        Self::deposit_event(Event::TwinSpendingCapSet {
+            twin_id,
+            amount,
+            period,
+            action,
+        });
+
+        Ok(().into())
+    }
+
+    pub fn _clear_twin_spending_cap(account_id: T::AccountId) -> DispatchResultWithPostInfo {
+        let twin_id = pallet_tfgrid::TwinIdByAccountID::<T>::get(&account_id)
+            .ok_or(Error::<T>::TwinNotExists)?;
+        ensure!(
+            TwinSpendingCap::<T>::contains_key(twin_id),
+            Error::<T>::SpendingCapNotExists
+        );
+
+        TwinSpendingCap::<T>::remove(twin_id);
+
+        Self::deposit_event(Event::TwinSpendingCapCleared { twin_id });
+
+        Ok(().into())
+    }
+
+    // Updating a cap keeps track of what was already spent in the current period
+    // so a cap can not be reset by setting it again
+    fn new_spending_cap(
+        current: Option<types::SpendingCap<BalanceOf<T>>>,
+        amount: BalanceOf<T>,
+        period: u64,
+        action: types::SpendingCapAction,
+    ) -> types::SpendingCap<BalanceOf<T>> {
+        let now = Self::get_current_timestamp_in_secs();
+        match current {
+            Some(mut cap) => {
+                cap.amount = amount;
+                cap.period = period;
+                cap.action = action;
+                cap.refresh(now);
+                cap
+            }
+            None => types::SpendingCap {
+                amount,
+                period,
+                period_start: now,
+                spent: BalanceOf::<T>::zero(),
+                action,
+            },
+        }
+    }
+
+    // Returns the scope and action of the first spending cap that
+    // would be exceeded if the amount due was billed on the contract
+    pub fn get_exceeded_spending_cap(
+        contract: &types::Contract<T>,
+        amount_due: BalanceOf<T>,
+    ) -> Option<(types::SpendingCapScope, types::SpendingCapAction)> {
+        let now = Self::get_current_timestamp_in_secs();
+
+        if let Some(mut cap) = ContractSpendingCap::<T>::get(contract.contract_id) {
+            cap.refresh(now);
+            if !cap.allows(amount_due) {
+                return Some((types::SpendingCapScope::Contract, cap.action));
+            }
+        }
+
+        if let Some(mut cap) = TwinSpendingCap::<T>::get(contract.twin_id) {
+            cap.refresh(now);
+            if !cap.allows(amount_due) {
+                return Some((types::SpendingCapScope::Twin, cap.action));
+            }
+        }
+
+        None
+    }
+
+    // Adds the amount billed to the spending caps of the contract and its twin
+    pub fn consume_spending_caps(contract: &types::Contract<T>, amount_billed: BalanceOf<T>) {
+        let now = Self::get_current_timestamp_in_secs();
+        let consume = |maybe_cap: &mut Option<types::SpendingCap<BalanceOf<T>>>| {
+            if let Some(cap) = maybe_cap {
+                cap.refresh(now);
+                cap.spent = cap.spent.saturating_add(amount_billed);
+            }
+        };
+
+        ContractSpendingCap::<T>::mutate_exists(contract.contract_id, consume);
+        TwinSpendingCap::<T>::mutate_exists(contract.twin_id, consume);
+    }
+}
@@ -5,7 +5,7 @@ use crate::{
 use frame_support::{
     assert_noop, assert_ok, bounded_vec,
     dispatch::Pays,
-    traits::{LockableCurrency, WithdrawReasons},
+    traits::{Hooks, LockableCurrency, ReservableCurrency, WithdrawReasons},
     BoundedVec,
 };
 use frame_system::{EventRecord, Phase, RawOrigin};
@@ -19,7 +19,7 @@ use sp_runtime::{assert_eq_error_rate, traits::SaturatedConversion, Perbill, Per
 use sp_std::convert::{TryFrom, TryInto};
 use substrate_fixed::types::U64F64;
 use tfchain_support::{
-    constants::time::{SECS_PER_BLOCK, SECS_PER_HOUR},
+    constants::time::{SECS_PER_BLOCK, SECS_PER_DAY, SECS_PER_HOUR},
     resources::Resources,
     types::{FarmCertification, NodeCertification, PublicIP, IP4},
 };
@@ -101,6 +101,46 @@ fn test_create_node_contract_and_switch_node_to_standby_fails() {
     });
 }
 
+#[test]
+fn test_power_schedule_skips_node_with_active_contracts_works() {
+    new_test_ext().execute_with(|| {
+        run_to_block(1, None);
+        prepare_farm_and_node();
+        let node_id = 1;
+
+        assert_ok!(SmartContractModule::create_node_contract(
+            RuntimeOrigin::signed(bob()),
+            node_id,
+            generate_deployment_hash(),
+            get_deployment_data(),
+            0,
+            None
+        ));
+
+        // Window holding the current time (13:00 UTC)
+        assert_ok!(TfgridModule::set_node_power_schedule(
+            RuntimeOrigin::signed(alice()),
+            node_id,
+            bounded_vec![pallet_tfgrid_types::PowerWindow {
+                down_from: 780,
+                down_until: 840,
+            }],
+        ));
+
+        assert_eq!(
+            TfgridModule::node_power_state(node_id).target,
+            tfchain_support::types::Power::Up
+        );
+        assert_eq!(
+            System::events().last().unwrap().event,
+            MockEvent::TfgridModule(pallet_tfgrid::Event::NodePowerScheduleSkipped {
+                farm_id: 1,
+                node_id,
+            })
+        );
+    });
+}
+
 #[test]
 fn test_create_node_contract_with_public_ips_works() {
     new_test_ext().execute_with(|| {
@@ -147,188 +187,305 @@ fn test_create_node_contract_with_public_ips_works() {
 }
 
 #[test]
-fn test_create_node_contract_with_undefined_node_fails() {
+fn test_create_node_contract_with_specific_public_ip_works() {
     new_test_ext().execute_with(|| {
         run_to_block(1, None);
         prepare_farm_and_node();
         let node_id = 1;
 
-        assert_noop!(
-            SmartContractModule::create_node_contract(
-                RuntimeOrigin::signed(alice()),
-                node_id + 1,
-                generate_deployment_hash(),
-                get_deployment_data(),
-                0,
-                None
-            ),
-            Error::<TestRuntime>::NodeNotExists
-        );
+        assert_ok!(SmartContractModule::create_node_contract_with_public_ips(
+            RuntimeOrigin::signed(bob()),
+            node_id,
+            generate_deployment_hash(),
+            get_deployment_data(),
+            types::PublicIpsRequest::Ips(bounded_vec![get_public_ip_ip_input(
+                b"185.206.122.34/24"
+            )]),
+            None,
+            None
+        ));
+        let contract_id = 1;
+
+        let farm = TfgridModule::farms(1).unwrap();
+        assert_eq!(farm.public_ips[0].contract_id, 0);
+        assert_eq!(farm.public_ips[1].contract_id, contract_id);
+
+        let contract = SmartContractModule::contracts(contract_id).unwrap();
+        match contract.contract_type {
+            types::ContractData::NodeContract(c) => {
+                assert_eq!(c.public_ips, 1);
+                assert_eq!(c.public_ips_list.to_vec(), vec![farm.public_ips[1].clone()]);
+            }
+            _ => panic!("expected a node contract"),
+        }
     });
 }
 
 #[test]
-fn test_create_node_contract_with_same_hash_and_node_fails() {
+fn test_create_node_contract_with_unavailable_public_ip_fails() {
     new_test_ext().execute_with(|| {
         run_to_block(1, None);
         prepare_farm_and_node();
         let node_id = 1;
 
-        let h = generate_deployment_hash();
         assert_ok!(SmartContractModule::create_node_contract(
             RuntimeOrigin::signed(alice()),
             node_id,
-            h,
+            generate_deployment_hash(),
             get_deployment_data(),
-            0,
+            1,
             None
         ));
 
         assert_noop!(
-            SmartContractModule::create_node_contract(
-                RuntimeOrigin::signed(alice()),
+            SmartContractModule::create_node_contract_with_public_ips(
+                RuntimeOrigin::signed(bob()),
                 node_id,
-                h,
+                generate_deployment_hash(),
                 get_deployment_data(),
-                0,
+                types::PublicIpsRequest::Ips(bounded_vec![get_public_ip_ip_input(
+                    b"185.206.122.33/24"
+                )]),
+                None,
                 None
             ),
-            Error::<TestRuntime>::ContractIsNotUnique
+            Error::<TestRuntime>::PublicIpNotAvailable
+        );
+
+        assert_noop!(
+            SmartContractModule::create_node_contract_with_public_ips(
+                RuntimeOrigin::signed(bob()),
+                node_id,
+                generate_deployment_hash(),
+                get_deployment_data(),
+                types::PublicIpsRequest::Ips(bounded_vec![get_public_ip_ip_input(
+                    b"185.206.122.40/24"
+                )]),
+                None,
+                None
+            ),
+            Error::<TestRuntime>::PublicIpNotExists
         );
     });
 }
 
 #[test]
-fn test_create_node_contract_which_was_canceled_before_works() {
+fn test_create_node_contract_with_public_ips_block_works() {
     new_test_ext().execute_with(|| {
         run_to_block(1, None);
         prepare_farm_and_node();
         let node_id = 1;
 
-        let h = generate_deployment_hash();
-        assert_ok!(SmartContractModule::create_node_contract(
-            RuntimeOrigin::signed(alice()),
-            node_id,
-            h,
-            get_deployment_data(),
-            0,
-            None
-        ));
-        let contract_id = SmartContractModule::node_contract_by_hash(node_id, h);
-        assert_eq!(contract_id, 1);
+        // Block size must be a power of 2
+        assert_noop!(
+            SmartContractModule::create_node_contract_with_public_ips(
+                RuntimeOrigin::signed(bob()),
+                node_id,
+                generate_deployment_hash(),
+                get_deployment_data(),
+                types::PublicIpsRequest::Block(3),
+                None,
+                None
+            ),
+            Error::<TestRuntime>::InvalidPublicIpsRequest
+        );
 
-        assert_ok!(SmartContractModule::cancel_contract(
-            RuntimeOrigin::signed(alice()),
-            contract_id
-        ));
+        // 185.206.122.33 and .34 are contiguous but not aligned to a block of 2
+        assert_noop!(
+            SmartContractModule::create_node_contract_with_public_ips(
+                RuntimeOrigin::signed(bob()),
+                node_id,
+                generate_deployment_hash(),
+                get_deployment_data(),
+                types::PublicIpsRequest::Block(2),
+                None,
+                None
+            ),
+            Error::<TestRuntime>::PublicIpsBlockNotAvailable
+        );
 
-        let h = generate_deployment_hash();
-        assert_ok!(SmartContractModule::create_node_contract(
+        assert_ok!(TfgridModule::add_farm_ip(
             RuntimeOrigin::signed(alice()),
+            1,
+            get_public_ip_ip_input(b"185.206.122.35/24"),
+            get_public_ip_gw_input(b"185.206.122.1")
+        ));
+        assert_ok!(SmartContractModule::create_node_contract_with_public_ips(
+            RuntimeOrigin::signed(bob()),
             node_id,
-            h,
+            generate_deployment_hash(),
             get_deployment_data(),
-            0,
+            types::PublicIpsRequest::Block(2),
+            None,
             None
         ));
-        let contract_id = SmartContractModule::node_contract_by_hash(node_id, h);
-        assert_eq!(contract_id, 2);
+        let contract_id = 1;
+
+        let farm = TfgridModule::farms(1).unwrap();
+        assert_eq!(farm.public_ips[0].contract_id, 0);
+        assert_eq!(farm.public_ips[1].contract_id, contract_id);
+        assert_eq!(farm.public_ips[2].contract_id, contract_id);
     });
 }
 
 #[test]
-fn test_update_node_contract_works() {
+fn test_set_public_ip_price_multiplier_works() {
     new_test_ext().execute_with(|| {
         run_to_block(1, None);
-        prepare_farm_node_and_node_contract();
-        let node_id = 1;
-        let contract_id = 1;
+        prepare_farm_and_node();
+        let farm_id = 1;
+        let ip = get_public_ip_ip_input(b"185.206.122.33/24");
 
-        let new_hash = generate_deployment_hash();
-        let deployment_data = get_deployment_data();
-        assert_ok!(SmartContractModule::update_node_contract(
+        assert_ok!(SmartContractModule::set_public_ip_price_multiplier(
             RuntimeOrigin::signed(alice()),
-            contract_id,
-            new_hash,
-            get_deployment_data()
+            farm_id,
+            ip.clone(),
+            200
         ));
+        assert_eq!(
+            SmartContractModule::public_ip_price_multiplier(farm_id, &ip),
+            200
+        );
 
-        let node_contract = types::NodeContract {
-            node_id,
-            deployment_hash: new_hash,
-            deployment_data,
-            public_ips: 0,
-            public_ips_list: bounded_vec![],
-        };
-        let contract_type = types::ContractData::NodeContract(node_contract);
-
-        let expected_contract_value = types::Contract {
-            contract_id,
-            state: types::ContractState::Created,
-            twin_id: 1,
-            version: crate::CONTRACT_VERSION,
-            contract_type,
-            solution_provider_id: None,
-        };
-
-        let node_contract = SmartContractModule::contracts(contract_id).unwrap();
-        assert_eq!(node_contract, expected_contract_value);
-
-        let contracts = SmartContractModule::active_node_contracts(1);
-        assert_eq!(contracts.len(), 1);
-
-        assert_eq!(contracts[0], contract_id);
-
-        let node_contract_id_by_hash =
-            SmartContractModule::node_contract_by_hash(node_id, new_hash);
-        assert_eq!(node_contract_id_by_hash, contract_id);
+        // Back to the default price
+        assert_ok!(SmartContractModule::set_public_ip_price_multiplier(
+            RuntimeOrigin::signed(alice()),
+            farm_id,
+            ip.clone(),
+            types::DEFAULT_PUBLIC_IP_PRICE_MULTIPLIER
+        ));
+        assert_eq!(
+            SmartContractModule::public_ip_price_multiplier(farm_id, &ip),
+            types::DEFAULT_PUBLIC_IP_PRICE_MULTIPLIER
+        );
     });
 }
 
 #[test]
-fn test_update_node_contract_not_exists_fails() {
+fn test_set_public_ip_price_multiplier_fails() {
     new_test_ext().execute_with(|| {
         run_to_block(1, None);
         prepare_farm_and_node();
-        let node_id = 1;
+        let farm_id = 1;
+        let ip = get_public_ip_ip_input(b"185.206.122.33/24");
 
         assert_noop!(
-            SmartContractModule::update_node_contract(
+            SmartContractModule::set_public_ip_price_multiplier(
+                RuntimeOrigin::signed(bob()),
+                farm_id,
+                ip.clone(),
+                200
+            ),
+            Error::<TestRuntime>::UnauthorizedToSetPublicIpPriceMultiplier
+        );
+        assert_noop!(
+            SmartContractModule::set_public_ip_price_multiplier(
                 RuntimeOrigin::signed(alice()),
-                node_id,
-                generate_deployment_hash(),
-                get_deployment_data()
+                farm_id,
+                ip.clone(),
+                types::MAX_PUBLIC_IP_PRICE_MULTIPLIER + 1
             ),
-            Error::<TestRuntime>::ContractNotExists
+            Error::<TestRuntime>::InvalidPublicIpPriceMultiplier
+        );
+
+        assert_ok!(SmartContractModule::create_node_contract(
+            RuntimeOrigin::signed(bob()),
+            1,
+            generate_deployment_hash(),
+            get_deployment_data(),
+            1,
+            None
+        ));
+        assert_noop!(
+            SmartContractModule::set_public_ip_price_multiplier(
+                RuntimeOrigin::signed(alice()),
+                farm_id,
+                ip,
+                200
+            ),
+            Error::<TestRuntime>::PublicIpIsReserved
         );
     });
 }
 
 #[test]
-fn test_update_node_contract_wrong_twins_fails() {
+fn test_create_node_contract_with_ip6_subnets_works() {
     new_test_ext().execute_with(|| {
         run_to_block(1, None);
-        prepare_farm_node_and_node_contract();
+        prepare_farm_and_node();
+        let farm_id = 1;
+        let node_id = 1;
+        add_farm_ip6_prefix(b"2a10:b600:1::/63");
+
+        assert_ok!(SmartContractModule::create_node_contract_with_ip6_subnets(
+            RuntimeOrigin::signed(alice()),
+            node_id,
+            generate_deployment_hash(),
+            get_deployment_data(),
+            0,
+            2,
+            None,
+            None
+        ));
         let contract_id = 1;
 
+        let subnets = SmartContractModule::contract_ip6_subnets(contract_id);
+        assert_eq!(subnets.len(), 2);
+        assert_eq!(subnets[0].prefix, get_ip6_input(b"2a10:b600:1::/63"));
+        assert_eq!(subnets[0].subnet, 0x2a10b60000010000);
+        assert_eq!(subnets[1].subnet, 0x2a10b60000010001);
+        assert_eq!(
+            SmartContractModule::delegated_ip6_subnets(farm_id, 0x2a10b60000010000),
+            Some(contract_id)
+        );
+        assert_eq!(TfgridModule::farm_ip6_prefixes(farm_id)[0].delegated, 2);
+
+        // All subnets of the prefix are delegated
         assert_noop!(
-            SmartContractModule::update_node_contract(
-                RuntimeOrigin::signed(bob()),
-                contract_id,
+            SmartContractModule::create_node_contract_with_ip6_subnets(
+                RuntimeOrigin::signed(alice()),
+                node_id,
                 generate_deployment_hash(),
-                get_deployment_data()
+                get_deployment_data(),
+                0,
+                1,
+                None,
+                None
             ),
-            Error::<TestRuntime>::TwinNotAuthorizedToUpdateContract
+            Error::<TestRuntime>::FarmHasNotEnoughIp6SubnetsFree
+        );
+
+        // Farmer can not remove a prefix that is in use
+        assert_noop!(
+            TfgridModule::remove_farm_ip6_prefix(
+                RuntimeOrigin::signed(alice()),
+                farm_id,
+                get_ip6_input(b"2a10:b600:1::/63")
+            ),
+            pallet_tfgrid::Error::<TestRuntime>::Ip6PrefixInUse
         );
     });
 }
 
 #[test]
-fn test_cancel_contract_by_node_works() {
+fn test_cancel_node_contract_frees_ip6_subnets_works() {
     new_test_ext().execute_with(|| {
         run_to_block(1, None);
-        prepare_farm_node_and_node_contract();
+        prepare_farm_and_node();
+        let farm_id = 1;
         let node_id = 1;
+        add_farm_ip6_prefix(b"2a10:b600:1::/64");
+
+        assert_ok!(SmartContractModule::create_node_contract_with_ip6_subnets(
+            RuntimeOrigin::signed(alice()),
+            node_id,
+            generate_deployment_hash(),
+            get_deployment_data(),
+            0,
+            1,
+            None,
+            None
+        ));
         let contract_id = 1;
 
         assert_ok!(SmartContractModule::cancel_contract(
@@ -336,862 +493,1233 @@ fn test_cancel_contract_by_node_works() {
             contract_id
         ));
 
-        assert_eq!(SmartContractModule::contracts(contract_id), None);
-        assert_eq!(SmartContractModule::active_node_contracts(node_id).len(), 0);
-    });
-}
-
-#[test]
-fn test_cancel_contract_collective_by_dao_approval_works() {
-    new_test_ext().execute_with(|| {
-        run_to_block(1, None);
-        prepare_farm_node_and_node_contract();
-        let node_id = 1;
-        let contract_id = 1;
+        assert!(SmartContractModule::contract_ip6_subnets(contract_id).is_empty());
+        assert_eq!(
+            SmartContractModule::delegated_ip6_subnets(farm_id, 0x2a10b60000010000),
+            None
+        );
+        assert_eq!(TfgridModule::farm_ip6_prefixes(farm_id)[0].delegated, 0);
 
-        assert_ok!(SmartContractModule::cancel_contract_collective(
-            RawOrigin::Root.into(),
-            contract_id
+        // Subnet can be delegated again
+        assert_ok!(SmartContractModule::create_node_contract_with_ip6_subnets(
+            RuntimeOrigin::signed(alice()),
+            node_id,
+            generate_deployment_hash(),
+            get_deployment_data(),
+            0,
+            1,
+            None,
+            None
         ));
-
-        assert_eq!(SmartContractModule::contracts(contract_id), None);
-        assert_eq!(SmartContractModule::active_node_contracts(node_id).len(), 0);
+        assert_eq!(
+            SmartContractModule::delegated_ip6_subnets(farm_id, 0x2a10b60000010000),
+            Some(2)
+        );
     });
 }
 
 #[test]
-fn test_cancel_contract_collective_by_council_approval_works() {
+fn test_force_remove_farm_ip6_prefix_frees_ip6_subnets_works() {
     new_test_ext().execute_with(|| {
         run_to_block(1, None);
-        prepare_farm_node_and_node_contract();
+        prepare_farm_and_node();
+        let farm_id = 1;
         let node_id = 1;
+        add_farm_ip6_prefix(b"2a10:b600:1::/48");
+
+        assert_ok!(SmartContractModule::create_node_contract_with_ip6_subnets(
+            RuntimeOrigin::signed(alice()),
+            node_id,
+            generate_deployment_hash(),
+            get_deployment_data(),
+            0,
+            2,
+            None,
+            None
+        ));
         let contract_id = 1;
 
-        assert_ok!(SmartContractModule::cancel_contract_collective(
-            pallet_collective::RawOrigin::Members(3, 5).into(),
-            contract_id
+        assert_ok!(TfgridModule::force_remove_farm_ip6_prefix(
+            RawOrigin::Root.into(),
+            farm_id,
+            get_ip6_input(b"2a10:b600:1::/48")
         ));
 
-        assert_eq!(SmartContractModule::contracts(contract_id), None);
-        assert_eq!(SmartContractModule::active_node_contracts(node_id).len(), 0);
+        assert!(TfgridModule::farm_ip6_prefixes(farm_id).is_empty());
+        assert!(SmartContractModule::contract_ip6_subnets(contract_id).is_empty());
+        assert_eq!(
+            SmartContractModule::delegated_ip6_subnets(farm_id, 0x2a10b60000010000),
+            None
+        );
+
+        // Contract keeps running without its subnets
+        assert!(SmartContractModule::contracts(contract_id).is_some());
     });
 }
 
 #[test]
-fn test_create_multiple_node_contracts_works() {
+fn test_node_contract_billing_honours_public_ip_price_multiplier() {
     new_test_ext().execute_with(|| {
         run_to_block(1, None);
         prepare_farm_and_node();
-        let node_id = 1;
+        let farm_id = 1;
+        let ip = get_public_ip_ip_input(b"185.206.122.33/24");
 
-        assert_ok!(SmartContractModule::create_node_contract(
+        assert_ok!(SmartContractModule::set_public_ip_price_multiplier(
             RuntimeOrigin::signed(alice()),
-            node_id,
+            farm_id,
+            ip.clone(),
+            250
+        ));
+        assert_ok!(SmartContractModule::create_node_contract_with_public_ips(
+            RuntimeOrigin::signed(bob()),
+            1,
             generate_deployment_hash(),
             get_deployment_data(),
-            0,
+            types::PublicIpsRequest::Ips(bounded_vec![ip]),
+            None,
             None
         ));
         let contract_id = 1;
 
+        // A single ip priced 2.5 times the ip price of the pricing policy
+        let contract = SmartContractModule::contracts(contract_id).unwrap();
+        let pricing_policy = TfgridModule::pricing_policies(1).unwrap();
+        let cost_units_usd = cost::calculate_resources_cost_units_usd::<TestRuntime>(
+            Resources::empty(),
+            U64F64::from_num(5) / U64F64::from_num(2),
+            SECS_PER_HOUR,
+            &pricing_policy,
+            true,
+        );
+        assert_eq!(
+            contract
+                .calculate_contract_cost_units_usd(&pricing_policy, SECS_PER_HOUR)
+                .unwrap(),
+            cost_units_usd
+        );
+    });
+}
+
+#[test]
+fn test_create_node_contract_with_undefined_node_fails() {
+    new_test_ext().execute_with(|| {
+        run_to_block(1, None);
+        prepare_farm_and_node();
+        let node_id = 1;
+
+        assert_noop!(
+            SmartContractModule::create_node_contract(
+                RuntimeOrigin::signed(alice()),
+                node_id + 1,
+                generate_deployment_hash(),
+                get_deployment_data(),
+                0,
+                None
+            ),
+            Error::<TestRuntime>::NodeNotExists
+        );
+    });
+}
+
+#[test]
+fn test_create_node_contract_with_same_hash_and_node_fails() {
+    new_test_ext().execute_with(|| {
+        run_to_block(1, None);
+        prepare_farm_and_node();
+        let node_id = 1;
+
+        let h = generate_deployment_hash();
         assert_ok!(SmartContractModule::create_node_contract(
             RuntimeOrigin::signed(alice()),
             node_id,
-            generate_deployment_hash(),
+            h,
             get_deployment_data(),
             0,
             None
         ));
 
+        assert_noop!(
+            SmartContractModule::create_node_contract(
+                RuntimeOrigin::signed(alice()),
+                node_id,
+                h,
+                get_deployment_data(),
+                0,
+                None
+            ),
+            Error::<TestRuntime>::ContractIsNotUnique
+        );
+    });
+}
+
+#[test]
+fn test_create_node_contract_which_was_canceled_before_works() {
+    new_test_ext().execute_with(|| {
+        run_to_block(1, None);
+        prepare_farm_and_node();
+        let node_id = 1;
+
+        let h = generate_deployment_hash();
         assert_ok!(SmartContractModule::create_node_contract(
             RuntimeOrigin::signed(alice()),
             node_id,
-            generate_deployment_hash(),
+            h,
             get_deployment_data(),
             0,
             None
         ));
+        let contract_id = SmartContractModule::node_contract_by_hash(node_id, h);
+        assert_eq!(contract_id, 1);
 
-        let node_contracts = SmartContractModule::active_node_contracts(node_id);
-        assert_eq!(node_contracts.len(), 3);
-
-        // now cancel 1 and check if the storage maps are updated correctly
         assert_ok!(SmartContractModule::cancel_contract(
             RuntimeOrigin::signed(alice()),
             contract_id
         ));
 
-        let node_contracts = SmartContractModule::active_node_contracts(node_id);
-        assert_eq!(node_contracts.len(), 2);
+        let h = generate_deployment_hash();
+        assert_ok!(SmartContractModule::create_node_contract(
+            RuntimeOrigin::signed(alice()),
+            node_id,
+            h,
+            get_deployment_data(),
+            0,
+            None
+        ));
+        let contract_id = SmartContractModule::node_contract_by_hash(node_id, h);
+        assert_eq!(contract_id, 2);
     });
 }
 
 #[test]
-fn test_cancel_node_contract_frees_public_ips_works() {
+fn test_update_node_contract_works() {
     new_test_ext().execute_with(|| {
         run_to_block(1, None);
-        prepare_farm_and_node();
-        let farm_id = 1;
+        prepare_farm_node_and_node_contract();
         let node_id = 1;
+        let contract_id = 1;
 
-        assert_ok!(SmartContractModule::create_node_contract(
+        let new_hash = generate_deployment_hash();
+        let deployment_data = get_deployment_data();
+        assert_ok!(SmartContractModule::update_node_contract(
             RuntimeOrigin::signed(alice()),
-            node_id,
-            generate_deployment_hash(),
-            get_deployment_data(),
-            2,
-            None
+            contract_id,
+            new_hash,
+            get_deployment_data()
         ));
-        let contract_id = 1;
 
-        let farm = TfgridModule::farms(farm_id).unwrap();
-        assert_eq!(farm.public_ips[0].contract_id, contract_id);
-        assert_eq!(farm.public_ips[1].contract_id, contract_id);
+        let node_contract = types::NodeContract {
+            node_id,
+            deployment_hash: new_hash,
+            deployment_data,
+            public_ips: 0,
+            public_ips_list: bounded_vec![],
+        };
+        let contract_type = types::ContractData::NodeContract(node_contract);
 
-        assert_ok!(SmartContractModule::cancel_contract(
-            RuntimeOrigin::signed(alice()),
-            contract_id
-        ));
+        let expected_contract_value = types::Contract {
+            contract_id,
+            state: types::ContractState::Created,
+            twin_id: 1,
+            version: crate::CONTRACT_VERSION,
+            contract_type,
+            solution_provider_id: None,
+        };
 
-        let farm = TfgridModule::farms(farm_id).unwrap();
-        assert_eq!(farm.public_ips[0].contract_id, 0);
-        assert_eq!(farm.public_ips[1].contract_id, 0);
+        let node_contract = SmartContractModule::contracts(contract_id).unwrap();
+        assert_eq!(node_contract, expected_contract_value);
+
+        let contracts = SmartContractModule::active_node_contracts(1);
+        assert_eq!(contracts.len(), 1);
+
+        assert_eq!(contracts[0], contract_id);
+
+        let node_contract_id_by_hash =
+            SmartContractModule::node_contract_by_hash(node_id, new_hash);
+        assert_eq!(node_contract_id_by_hash, contract_id);
     });
 }
 
 #[test]
-fn test_cancel_node_contract_not_exists_fails() {
+fn test_update_node_contract_not_exists_fails() {
     new_test_ext().execute_with(|| {
+        run_to_block(1, None);
         prepare_farm_and_node();
+        let node_id = 1;
 
-        let contract_id = 1;
         assert_noop!(
-            SmartContractModule::cancel_contract(RuntimeOrigin::signed(alice()), contract_id),
+            SmartContractModule::update_node_contract(
+                RuntimeOrigin::signed(alice()),
+                node_id,
+                generate_deployment_hash(),
+                get_deployment_data()
+            ),
             Error::<TestRuntime>::ContractNotExists
         );
     });
 }
 
 #[test]
-fn test_cancel_node_contract_wrong_twins_fails() {
+fn test_update_node_contract_wrong_twins_fails() {
     new_test_ext().execute_with(|| {
         run_to_block(1, None);
         prepare_farm_node_and_node_contract();
         let contract_id = 1;
 
         assert_noop!(
-            SmartContractModule::cancel_contract(RuntimeOrigin::signed(bob()), contract_id),
-            Error::<TestRuntime>::TwinNotAuthorizedToCancelContract
+            SmartContractModule::update_node_contract(
+                RuntimeOrigin::signed(bob()),
+                contract_id,
+                generate_deployment_hash(),
+                get_deployment_data()
+            ),
+            Error::<TestRuntime>::TwinNotAuthorizedToUpdateContract
         );
     });
 }
 
 #[test]
-fn test_cancel_node_contract_and_remove_from_billing_loop_works() {
+fn test_cancel_contract_by_node_works() {
     new_test_ext().execute_with(|| {
         run_to_block(1, None);
         prepare_farm_node_and_node_contract();
+        let node_id = 1;
         let contract_id = 1;
 
-        run_to_block(6, None);
-
-        // Ensure contract_id is stored at right billing loop index
-        let index = SmartContractModule::get_billing_loop_index_from_contract_id(contract_id);
-        assert_eq!(
-            SmartContractModule::contract_to_bill_at_block(index),
-            vec![contract_id]
-        );
-
-        // Canceling contract will remove it from contract storage, try
-        // to bill remaining amount due and also remove it from billing
         assert_ok!(SmartContractModule::cancel_contract(
             RuntimeOrigin::signed(alice()),
             contract_id
         ));
 
-        // Ensure contract has been removed from billing loop
-        assert_eq!(
-            SmartContractModule::contract_to_bill_at_block(index),
-            Vec::<u64>::new()
-        );
+        assert_eq!(SmartContractModule::contracts(contract_id), None);
+        assert_eq!(SmartContractModule::active_node_contracts(node_id).len(), 0);
     });
 }
 
 #[test]
-fn test_remove_from_billing_loop_wrong_index_fails() {
+fn test_cancel_contract_collective_by_dao_approval_works() {
     new_test_ext().execute_with(|| {
         run_to_block(1, None);
         prepare_farm_node_and_node_contract();
+        let node_id = 1;
         let contract_id = 1;
 
-        // Ensure contract_id is stored at right billing loop index
-        let index = SmartContractModule::get_billing_loop_index_from_contract_id(contract_id);
-        assert_eq!(
-            SmartContractModule::contract_to_bill_at_block(index),
-            vec![contract_id]
-        );
-
-        assert_noop!(
-            SmartContractModule::remove_contract_from_billing_loop(2),
-            Error::<TestRuntime>::ContractWrongBillingLoopIndex
-        );
+        assert_ok!(SmartContractModule::cancel_contract_collective(
+            RawOrigin::Root.into(),
+            contract_id
+        ));
 
-        assert_noop!(
-            SmartContractModule::remove_contract_from_billing_loop(11),
-            Error::<TestRuntime>::ContractWrongBillingLoopIndex
-        );
+        assert_eq!(SmartContractModule::contracts(contract_id), None);
+        assert_eq!(SmartContractModule::active_node_contracts(node_id).len(), 0);
     });
 }
 
-//  NAME CONTRACT TESTS //
-// -------------------- //
-
 #[test]
-fn test_create_name_contract_works() {
+fn test_cancel_contract_collective_by_council_approval_works() {
     new_test_ext().execute_with(|| {
         run_to_block(1, None);
-        prepare_farm_and_node();
+        prepare_farm_node_and_node_contract();
+        let node_id = 1;
+        let contract_id = 1;
 
-        assert_ok!(SmartContractModule::create_name_contract(
-            RuntimeOrigin::signed(bob()),
-            b"foobar".to_vec()
+        assert_ok!(SmartContractModule::cancel_contract_collective(
+            pallet_collective::RawOrigin::Members(3, 5).into(),
+            contract_id
         ));
+
+        assert_eq!(SmartContractModule::contracts(contract_id), None);
+        assert_eq!(SmartContractModule::active_node_contracts(node_id).len(), 0);
     });
 }
 
 #[test]
-fn test_cancel_name_contract_works() {
+fn test_create_multiple_node_contracts_works() {
     new_test_ext().execute_with(|| {
         run_to_block(1, None);
         prepare_farm_and_node();
+        let node_id = 1;
 
-        assert_ok!(SmartContractModule::create_name_contract(
+        assert_ok!(SmartContractModule::create_node_contract(
             RuntimeOrigin::signed(alice()),
-            b"some_name".to_vec()
+            node_id,
+            generate_deployment_hash(),
+            get_deployment_data(),
+            0,
+            None
         ));
         let contract_id = 1;
 
+        assert_ok!(SmartContractModule::create_node_contract(
+            RuntimeOrigin::signed(alice()),
+            node_id,
+            generate_deployment_hash(),
+            get_deployment_data(),
+            0,
+            None
+        ));
+
+        assert_ok!(SmartContractModule::create_node_contract(
+            RuntimeOrigin::signed(alice()),
+            node_id,
+            generate_deployment_hash(),
+            get_deployment_data(),
+            0,
+            None
+        ));
+
+        let node_contracts = SmartContractModule::active_node_contracts(node_id);
+        assert_eq!(node_contracts.len(), 3);
+
+        // now cancel 1 and check if the storage maps are updated correctly
         assert_ok!(SmartContractModule::cancel_contract(
             RuntimeOrigin::signed(alice()),
             contract_id
         ));
 
-        let name_contract = SmartContractModule::contracts(contract_id);
-        assert_eq!(name_contract, None);
-
-        let contract_id = SmartContractModule::contract_id_by_name_registration(
-            get_name_contract_name(&b"some_name".to_vec()),
-        );
-        assert_eq!(contract_id, 0);
+        let node_contracts = SmartContractModule::active_node_contracts(node_id);
+        assert_eq!(node_contracts.len(), 2);
     });
 }
 
 #[test]
-fn test_create_name_contract_double_with_same_name_fails() {
+fn test_cancel_node_contract_frees_public_ips_works() {
     new_test_ext().execute_with(|| {
         run_to_block(1, None);
         prepare_farm_and_node();
+        let farm_id = 1;
+        let node_id = 1;
 
-        assert_ok!(SmartContractModule::create_name_contract(
-            RuntimeOrigin::signed(bob()),
-            b"foobar".to_vec()
+        assert_ok!(SmartContractModule::create_node_contract(
+            RuntimeOrigin::signed(alice()),
+            node_id,
+            generate_deployment_hash(),
+            get_deployment_data(),
+            2,
+            None
+        ));
+        let contract_id = 1;
+
+        let farm = TfgridModule::farms(farm_id).unwrap();
+        assert_eq!(farm.public_ips[0].contract_id, contract_id);
+        assert_eq!(farm.public_ips[1].contract_id, contract_id);
+
+        assert_ok!(SmartContractModule::cancel_contract(
+            RuntimeOrigin::signed(alice()),
+            contract_id
         ));
+
+        let farm = TfgridModule::farms(farm_id).unwrap();
+        assert_eq!(farm.public_ips[0].contract_id, 0);
+        assert_eq!(farm.public_ips[1].contract_id, 0);
+    });
+}
+
+#[test]
+fn test_cancel_node_contract_not_exists_fails() {
+    new_test_ext().execute_with(|| {
+        prepare_farm_and_node();
+
+        let contract_id = 1;
         assert_noop!(
-            SmartContractModule::create_name_contract(
-                RuntimeOrigin::signed(alice()),
-                b"foobar".to_vec()
-            ),
-            Error::<TestRuntime>::NameExists
+            SmartContractModule::cancel_contract(RuntimeOrigin::signed(alice()), contract_id),
+            Error::<TestRuntime>::ContractNotExists
         );
     });
 }
 
 #[test]
-fn test_recreate_name_contract_after_cancel_works() {
+fn test_cancel_node_contract_wrong_twins_fails() {
     new_test_ext().execute_with(|| {
         run_to_block(1, None);
-        prepare_farm_and_node();
+        prepare_farm_node_and_node_contract();
+        let contract_id = 1;
 
-        assert_ok!(SmartContractModule::create_name_contract(
-            RuntimeOrigin::signed(bob()),
-            b"foobar".to_vec()
-        ));
+        assert_noop!(
+            SmartContractModule::cancel_contract(RuntimeOrigin::signed(bob()), contract_id),
+            Error::<TestRuntime>::TwinNotAuthorizedToCancelContract
+        );
+    });
+}
+
+#[test]
+fn test_cancel_node_contract_and_remove_from_billing_loop_works() {
+    new_test_ext().execute_with(|| {
+        run_to_block(1, None);
+        prepare_farm_node_and_node_contract();
         let contract_id = 1;
 
+        run_to_block(6, None);
+
+        // Ensure contract_id is stored at right billing loop index
+        let index = SmartContractModule::get_billing_loop_index_from_contract_id(contract_id);
+        assert_eq!(
+            SmartContractModule::contract_to_bill_at_block(index),
+            vec![contract_id]
+        );
+
+        // Canceling contract will remove it from contract storage, try
+        // to bill remaining amount due and also remove it from billing
         assert_ok!(SmartContractModule::cancel_contract(
-            RuntimeOrigin::signed(bob()),
+            RuntimeOrigin::signed(alice()),
             contract_id
         ));
 
-        assert_ok!(SmartContractModule::create_name_contract(
-            RuntimeOrigin::signed(bob()),
-            b"foobar".to_vec()
-        ));
+        // Ensure contract has been removed from billing loop
+        assert_eq!(
+            SmartContractModule::contract_to_bill_at_block(index),
+            Vec::<u64>::new()
+        );
     });
 }
 
 #[test]
-fn test_create_name_contract_with_invalid_dns_name_fails() {
+fn test_remove_from_billing_loop_wrong_index_fails() {
     new_test_ext().execute_with(|| {
         run_to_block(1, None);
-        prepare_farm_and_node();
-
-        assert_noop!(
-            SmartContractModule::create_name_contract(
-                RuntimeOrigin::signed(alice()),
-                b"foo.bar".to_vec()
-            ),
-            Error::<TestRuntime>::NameNotValid
-        );
+        prepare_farm_node_and_node_contract();
+        let contract_id = 1;
 
-        assert_noop!(
-            SmartContractModule::create_name_contract(
-                RuntimeOrigin::signed(alice()),
-                b"foo!".to_vec()
-            ),
-            Error::<TestRuntime>::NameNotValid
+        // Ensure contract_id is stored at right billing loop index
+        let index = SmartContractModule::get_billing_loop_index_from_contract_id(contract_id);
+        assert_eq!(
+            SmartContractModule::contract_to_bill_at_block(index),
+            vec![contract_id]
         );
 
         assert_noop!(
-            SmartContractModule::create_name_contract(
-                RuntimeOrigin::signed(alice()),
-                b"foo;'".to_vec()
-            ),
-            Error::<TestRuntime>::NameNotValid
+            SmartContractModule::remove_contract_from_billing_loop(2),
+            Error::<TestRuntime>::ContractWrongBillingLoopIndex
         );
 
         assert_noop!(
-            SmartContractModule::create_name_contract(
-                RuntimeOrigin::signed(alice()),
-                b"foo123.%".to_vec()
-            ),
-            Error::<TestRuntime>::NameNotValid
+            SmartContractModule::remove_contract_from_billing_loop(11),
+            Error::<TestRuntime>::ContractWrongBillingLoopIndex
         );
     });
 }
 
-//  RENT CONTRACT TESTS //
-// -------------------- //
-
 #[test]
-fn test_create_rent_contract_works() {
+fn test_create_node_contracts_batch_works() {
     new_test_ext().execute_with(|| {
         run_to_block(1, None);
-        prepare_dedicated_farm_and_node();
-
+        prepare_farm_and_node();
         let node_id = 1;
-        assert_ok!(SmartContractModule::create_rent_contract(
-            RuntimeOrigin::signed(bob()),
-            node_id,
+
+        assert_ok!(SmartContractModule::create_node_contracts_batch(
+            RuntimeOrigin::signed(alice()),
+            bounded_vec![
+                get_node_contract_input(node_id, 1),
+                get_node_contract_input(node_id, 1),
+                get_node_contract_input(node_id, 0),
+            ],
             None
         ));
 
-        let contract = SmartContractModule::contracts(1).unwrap();
-        let rent_contract = types::RentContract { node_id };
         assert_eq!(
-            contract.contract_type,
-            types::ContractData::RentContract(rent_contract)
+            SmartContractModule::active_node_contracts(node_id),
+            vec![1, 2, 3]
+        );
+
+        let farm = TfgridModule::farms(1).unwrap();
+        assert_eq!(farm.public_ips[0].contract_id, 1);
+        assert_eq!(farm.public_ips[1].contract_id, 2);
+
+        let our_events = System::events();
+        assert_eq!(
+            our_events.last().unwrap(),
+            &record(MockEvent::SmartContractModule(SmartContractEvent::<
+                TestRuntime,
+            >::NodeContractsBatchCreated {
+                twin_id: 1,
+                contract_ids: vec![1, 2, 3],
+            }))
         );
     });
 }
 
 #[test]
-fn test_create_rent_contract_on_standby_node_works() {
+fn test_create_node_contracts_batch_not_enough_public_ips_fails() {
     new_test_ext().execute_with(|| {
         run_to_block(1, None);
-        prepare_dedicated_farm_and_node();
+        prepare_farm_and_node();
         let node_id = 1;
 
-        assert_ok!(TfgridModule::change_power_state(
-            RuntimeOrigin::signed(alice()),
-            tfchain_support::types::Power::Down
-        ));
+        // Farm only has 2 public ips, nothing should be created
+        assert_noop!(
+            SmartContractModule::create_node_contracts_batch(
+                RuntimeOrigin::signed(alice()),
+                bounded_vec![
+                    get_node_contract_input(node_id, 2),
+                    get_node_contract_input(node_id, 1),
+                ],
+                None
+            ),
+            Error::<TestRuntime>::FarmHasNotEnoughPublicIPs
+        );
 
-        assert_ok!(SmartContractModule::create_rent_contract(
-            RuntimeOrigin::signed(bob()),
+        assert_ok!(SmartContractModule::create_node_contract(
+            RuntimeOrigin::signed(alice()),
             node_id,
+            generate_deployment_hash(),
+            get_deployment_data(),
+            1,
             None
         ));
+
+        assert_noop!(
+            SmartContractModule::create_node_contracts_batch(
+                RuntimeOrigin::signed(alice()),
+                bounded_vec![
+                    get_node_contract_input(node_id, 1),
+                    get_node_contract_input(node_id, 1),
+                ],
+                None
+            ),
+            Error::<TestRuntime>::FarmHasNotEnoughPublicIPsFree
+        );
     });
 }
 
 #[test]
-fn test_create_rent_contract_and_switch_node_to_standby_fails() {
+fn test_create_node_contracts_batch_with_same_hash_and_node_fails() {
     new_test_ext().execute_with(|| {
         run_to_block(1, None);
-        prepare_dedicated_farm_and_node();
+        prepare_farm_and_node();
         let node_id = 1;
 
-        assert_ok!(SmartContractModule::create_rent_contract(
-            RuntimeOrigin::signed(bob()),
-            node_id,
-            None
-        ));
-
+        let input = get_node_contract_input(node_id, 0);
         assert_noop!(
-            TfgridModule::change_power_target(
+            SmartContractModule::create_node_contracts_batch(
                 RuntimeOrigin::signed(alice()),
-                node_id,
-                tfchain_support::types::Power::Down
+                bounded_vec![get_node_contract_input(node_id, 1), input.clone(), input],
+                None
             ),
-            pallet_tfgrid::Error::<TestRuntime>::NodeHasActiveContracts
+            Error::<TestRuntime>::ContractIsNotUnique
         );
     });
 }
 
 #[test]
-fn test_create_rent_contract_on_standby_node_and_wake_it_up_works() {
+fn test_create_node_contracts_batch_with_undefined_node_fails() {
     new_test_ext().execute_with(|| {
         run_to_block(1, None);
-        prepare_dedicated_farm_and_node();
+        prepare_farm_and_node();
         let node_id = 1;
 
-        assert_ok!(TfgridModule::change_power_target(
-            RuntimeOrigin::signed(alice()),
-            node_id,
-            tfchain_support::types::Power::Down,
-        ));
-
-        assert_ok!(SmartContractModule::create_rent_contract(
-            RuntimeOrigin::signed(bob()),
-            node_id,
-            None
-        ));
+        assert_noop!(
+            SmartContractModule::create_node_contracts_batch(
+                RuntimeOrigin::signed(alice()),
+                bounded_vec![
+                    get_node_contract_input(node_id, 1),
+                    get_node_contract_input(node_id + 1, 0),
+                ],
+                None
+            ),
+            Error::<TestRuntime>::NodeNotExists
+        );
+    });
+}
 
-        assert_ok!(TfgridModule::change_power_target(
-            RuntimeOrigin::signed(alice()),
-            node_id,
-            tfchain_support::types::Power::Up,
-        ));
+#[test]
+fn test_create_node_contracts_batch_size_fails() {
+    new_test_ext().execute_with(|| {
+        run_to_block(1, None);
+        prepare_farm_and_node();
+        let node_id = 1;
+
+        assert_noop!(
+            SmartContractModule::create_node_contracts_batch(
+                RuntimeOrigin::signed(alice()),
+                bounded_vec![],
+                None
+            ),
+            Error::<TestRuntime>::EmptyContractsBatch
+        );
+
+        // A batch above the maximum can not be decoded
+        let max_contracts = MaxNodeContractsInBatch::get();
+        let contracts: Vec<types::NodeContractInput<TestRuntime>> = (0..=max_contracts)
+            .map(|_| get_node_contract_input(node_id, 0))
+            .collect();
+        assert!(
+            BoundedVec::<types::NodeContractInput<TestRuntime>, MaxNodeContractsInBatch>::try_from(
+                contracts
+            )
+            .is_err()
+        );
     });
 }
 
 #[test]
-fn test_cancel_rent_contract_works() {
+fn test_create_node_contract_with_resources_works() {
     new_test_ext().execute_with(|| {
         run_to_block(1, None);
-        prepare_dedicated_farm_and_node();
+        prepare_farm_and_node();
         let node_id = 1;
 
-        assert_ok!(SmartContractModule::create_rent_contract(
-            RuntimeOrigin::signed(bob()),
+        let resources = get_resources(4, 8);
+        assert_ok!(SmartContractModule::create_node_contract_with_resources(
+            RuntimeOrigin::signed(alice()),
             node_id,
+            generate_deployment_hash(),
+            get_deployment_data(),
+            0,
+            resources.clone(),
             None
         ));
         let contract_id = 1;
 
-        let contract = SmartContractModule::contracts(1).unwrap();
-        let rent_contract = types::RentContract { node_id };
         assert_eq!(
-            contract.contract_type,
-            types::ContractData::RentContract(rent_contract)
+            SmartContractModule::contract_reserved_resources(contract_id),
+            Some(resources.clone())
+        );
+        assert_eq!(
+            SmartContractModule::node_reserved_resources(node_id),
+            resources
         );
 
+        // Resources are released when the contract is canceled
         assert_ok!(SmartContractModule::cancel_contract(
-            RuntimeOrigin::signed(bob()),
+            RuntimeOrigin::signed(alice()),
             contract_id
         ));
-
-        let contract = SmartContractModule::contracts(contract_id);
-        assert_eq!(contract, None);
-    });
-}
-
-#[test]
-fn test_create_rent_contract_on_node_in_use_fails() {
-    new_test_ext().execute_with(|| {
-        run_to_block(1, None);
-        prepare_farm_node_and_node_contract();
-        let node_id = 1;
-
-        assert_noop!(
-            SmartContractModule::create_rent_contract(RuntimeOrigin::signed(bob()), node_id, None),
-            Error::<TestRuntime>::NodeNotAvailableToDeploy
+        assert_eq!(
+            SmartContractModule::contract_reserved_resources(contract_id),
+            None
         );
-    })
+        assert_eq!(
+            SmartContractModule::node_reserved_resources(node_id),
+            Resources::empty()
+        );
+    });
 }
 
 #[test]
-fn test_create_rent_contract_non_dedicated_empty_node_works() {
+fn test_create_node_contract_with_resources_overcommit_fails() {
     new_test_ext().execute_with(|| {
         run_to_block(1, None);
         prepare_farm_and_node();
         let node_id = 1;
 
-        assert_ok!(SmartContractModule::create_rent_contract(
-            RuntimeOrigin::signed(bob()),
+        // Node has 8 cru and 16 GB of mru
+        assert_ok!(SmartContractModule::create_node_contract_with_resources(
+            RuntimeOrigin::signed(alice()),
             node_id,
+            generate_deployment_hash(),
+            get_deployment_data(),
+            0,
+            get_resources(6, 8),
             None
         ));
-    })
-}
-
-#[test]
-fn test_create_node_contract_on_dedicated_node_without_rent_contract_fails() {
-    new_test_ext().execute_with(|| {
-        run_to_block(1, None);
-        prepare_dedicated_farm_and_node();
-        let node_id = 1;
 
         assert_noop!(
-            SmartContractModule::create_node_contract(
-                RuntimeOrigin::signed(bob()),
+            SmartContractModule::create_node_contract_with_resources(
+                RuntimeOrigin::signed(alice()),
                 node_id,
                 generate_deployment_hash(),
                 get_deployment_data(),
-                1,
+                0,
+                get_resources(4, 8),
                 None
             ),
-            Error::<TestRuntime>::NodeNotAvailableToDeploy
+            Error::<TestRuntime>::NodeHasNotEnoughCapacity
         );
-    })
-}
-
-#[test]
-fn test_create_node_contract_when_having_a_rentcontract_works() {
-    new_test_ext().execute_with(|| {
-        run_to_block(1, None);
-        prepare_dedicated_farm_and_node();
-        let node_id = 1;
-
-        assert_ok!(SmartContractModule::create_rent_contract(
-            RuntimeOrigin::signed(bob()),
-            node_id,
-            None
-        ));
 
+        // Contracts without declared resources only need the node not to be full
         assert_ok!(SmartContractModule::create_node_contract(
-            RuntimeOrigin::signed(bob()),
+            RuntimeOrigin::signed(alice()),
             node_id,
             generate_deployment_hash(),
             get_deployment_data(),
-            1,
+            0,
             None
         ));
-    })
+    });
 }
 
 #[test]
-fn test_create_node_contract_on_node_rented_by_other_fails() {
+fn test_node_contract_reserves_reported_resources_works() {
     new_test_ext().execute_with(|| {
         run_to_block(1, None);
         prepare_farm_and_node();
         let node_id = 1;
 
-        // create rent contract with bob
-        assert_ok!(SmartContractModule::create_rent_contract(
-            RuntimeOrigin::signed(bob()),
+        assert_ok!(SmartContractModule::create_node_contract(
+            RuntimeOrigin::signed(alice()),
             node_id,
+            generate_deployment_hash(),
+            get_deployment_data(),
+            0,
             None
         ));
+        let contract_id = 1;
+        assert_eq!(
+            SmartContractModule::contract_reserved_resources(contract_id),
+            None
+        );
 
-        // try to create node contract with Alice
-        // Alice not the owner of the rent contract so she is unauthorized to deploy a node contract
+        // Node has 8 cru and 16 GB of mru, all of it is reported as used
+        assert_ok!(SmartContractModule::report_contract_resources(
+            RuntimeOrigin::signed(alice()),
+            vec![types::ContractResources {
+                contract_id,
+                used: get_resources(8, 16),
+            }]
+        ));
+        assert_eq!(
+            SmartContractModule::contract_reserved_resources(contract_id),
+            Some(get_resources(8, 16))
+        );
+        assert_eq!(
+            SmartContractModule::node_reserved_resources(node_id),
+            get_resources(8, 16)
+        );
+
+        assert_noop!(
+            SmartContractModule::create_node_contract_with_resources(
+                RuntimeOrigin::signed(alice()),
+                node_id,
+                generate_deployment_hash(),
+                get_deployment_data(),
+                0,
+                get_resources(1, 1),
+                None
+            ),
+            Error::<TestRuntime>::NodeHasNotEnoughCapacity
+        );
+
+        // Reservation does not shrink with a lower report
+        assert_ok!(SmartContractModule::report_contract_resources(
+            RuntimeOrigin::signed(alice()),
+            vec![types::ContractResources {
+                contract_id,
+                used: get_resources(4, 8),
+            }]
+        ));
+        assert_eq!(
+            SmartContractModule::node_reserved_resources(node_id),
+            get_resources(8, 16)
+        );
+
+        // A node used above its capacity takes no more contracts
+        assert_ok!(SmartContractModule::report_contract_resources(
+            RuntimeOrigin::signed(alice()),
+            vec![types::ContractResources {
+                contract_id,
+                used: get_resources(10, 16),
+            }]
+        ));
         assert_noop!(
             SmartContractModule::create_node_contract(
                 RuntimeOrigin::signed(alice()),
                 node_id,
                 generate_deployment_hash(),
                 get_deployment_data(),
-                1,
+                0,
                 None
             ),
-            Error::<TestRuntime>::NodeNotAvailableToDeploy
+            Error::<TestRuntime>::NodeHasNotEnoughCapacity
         );
-    })
+    });
 }
 
 #[test]
-fn test_create_node_contract_on_dedicated_node_rented_by_other_fails() {
+fn test_create_node_contract_with_resources_farm_overcommit_ratio_works() {
     new_test_ext().execute_with(|| {
         run_to_block(1, None);
-        prepare_dedicated_farm_and_node();
+        prepare_farm_and_node();
         let node_id = 1;
+        let farm_id = 1;
 
-        // create rent contract with bob
-        assert_ok!(SmartContractModule::create_rent_contract(
-            RuntimeOrigin::signed(bob()),
-            node_id,
-            None
+        assert_ok!(TfgridModule::set_farm_overcommit_ratio(
+            RuntimeOrigin::signed(alice()),
+            farm_id,
+            150
         ));
 
-        // try to create node contract with Alice
-        // Alice not the owner of the rent contract so she is unauthorized to deploy a node contract
+        // 150% of 8 cru can be reserved
+        for _ in 0..2 {
+            assert_ok!(SmartContractModule::create_node_contract_with_resources(
+                RuntimeOrigin::signed(alice()),
+                node_id,
+                generate_deployment_hash(),
+                get_deployment_data(),
+                0,
+                get_resources(6, 8),
+                None
+            ));
+        }
+        assert_eq!(
+            SmartContractModule::node_reserved_resources(node_id),
+            get_resources(12, 16)
+        );
+
         assert_noop!(
-            SmartContractModule::create_node_contract(
+            SmartContractModule::create_node_contract_with_resources(
                 RuntimeOrigin::signed(alice()),
                 node_id,
                 generate_deployment_hash(),
                 get_deployment_data(),
-                1,
+                0,
+                get_resources(1, 1),
                 None
             ),
-            Error::<TestRuntime>::NodeNotAvailableToDeploy
+            Error::<TestRuntime>::NodeHasNotEnoughCapacity
         );
-    })
+    });
 }
 
 #[test]
-fn test_cancel_rent_contract_with_active_node_contracts_fails() {
+fn test_create_node_contracts_batch_overcommit_fails() {
     new_test_ext().execute_with(|| {
         run_to_block(1, None);
-        prepare_dedicated_farm_and_node();
+        prepare_farm_and_node();
         let node_id = 1;
 
-        TFTPriceModule::set_prices(RuntimeOrigin::signed(alice()), 50, 101).unwrap();
-
-        assert_ok!(SmartContractModule::create_rent_contract(
-            RuntimeOrigin::signed(bob()),
-            node_id,
-            None
-        ));
-        let contract_id = 1;
-
-        assert_ok!(SmartContractModule::create_node_contract(
-            RuntimeOrigin::signed(bob()),
-            node_id,
-            generate_deployment_hash(),
-            get_deployment_data(),
-            1,
-            None
-        ));
+        let mut first = get_node_contract_input(node_id, 0);
+        first.resources = Some(get_resources(4, 8));
+        let mut second = get_node_contract_input(node_id, 0);
+        second.resources = Some(get_resources(5, 8));
 
         assert_noop!(
-            SmartContractModule::cancel_contract(RuntimeOrigin::signed(bob()), contract_id),
-            Error::<TestRuntime>::NodeHasActiveContracts
+            SmartContractModule::create_node_contracts_batch(
+                RuntimeOrigin::signed(alice()),
+                bounded_vec![first, second],
+                None
+            ),
+            Error::<TestRuntime>::NodeHasNotEnoughCapacity
         );
     });
 }
 
-//  CONTRACT BILLING TESTS //
-// ----------------------- //
+//  NAME CONTRACT TESTS //
+// -------------------- //
 
 #[test]
-fn test_node_contract_billing_details() {
-    let (mut ext, mut pool_state) = new_test_ext_with_pool_state(0);
-    ext.execute_with(|| {
+fn test_create_name_contract_works() {
+    new_test_ext().execute_with(|| {
         run_to_block(1, None);
         prepare_farm_and_node();
-        let node_id = 1;
 
-        TFTPriceModule::set_prices(RuntimeOrigin::signed(alice()), 50, 101).unwrap();
+        assert_ok!(SmartContractModule::create_name_contract(
+            RuntimeOrigin::signed(bob()),
+            b"foobar".to_vec()
+        ));
+    });
+}
 
-        let twin = TfgridModule::twins(2).unwrap();
-        let initial_twin_balance = Balances::free_balance(&twin.account_id);
+#[test]
+fn test_cancel_name_contract_works() {
+    new_test_ext().execute_with(|| {
+        run_to_block(1, None);
+        prepare_farm_and_node();
 
-        assert_ok!(SmartContractModule::create_node_contract(
-            RuntimeOrigin::signed(bob()),
-            node_id,
-            generate_deployment_hash(),
-            get_deployment_data(),
-            1,
-            None
+        assert_ok!(SmartContractModule::create_name_contract(
+            RuntimeOrigin::signed(alice()),
+            b"some_name".to_vec()
         ));
         let contract_id = 1;
 
-        push_contract_resources_used(contract_id);
-        push_nru_report_for_contract(contract_id, 10);
+        assert_ok!(SmartContractModule::cancel_contract(
+            RuntimeOrigin::signed(alice()),
+            contract_id
+        ));
 
-        // Ensure contract_id is stored at right billing loop index
-        let index = SmartContractModule::get_billing_loop_index_from_contract_id(contract_id);
-        assert_eq!(
-            SmartContractModule::contract_to_bill_at_block(index),
-            vec![contract_id]
+        let name_contract = SmartContractModule::contracts(contract_id);
+        assert_eq!(name_contract, None);
+
+        let contract_id = SmartContractModule::contract_id_by_name_registration(
+            get_name_contract_name(&b"some_name".to_vec()),
         );
+        assert_eq!(contract_id, 0);
+    });
+}
 
-        let initial_total_issuance = Balances::total_issuance();
-        // advance 25 cycles
-        for i in 0..25 {
-            let block_number = 11 + i * 10;
-            pool_state.write().should_call_bill_contract(
-                contract_id,
-                Ok(Pays::Yes.into()),
-                block_number,
-            );
-            run_to_block(block_number, Some(&mut pool_state));
-        }
+#[test]
+fn test_create_name_contract_double_with_same_name_fails() {
+    new_test_ext().execute_with(|| {
+        run_to_block(1, None);
+        prepare_farm_and_node();
 
-        let free_balance = Balances::free_balance(&twin.account_id);
-        let total_amount_billed = initial_twin_balance - free_balance;
-        info!("locked balance {:?}", total_amount_billed);
+        assert_ok!(SmartContractModule::create_name_contract(
+            RuntimeOrigin::signed(bob()),
+            b"foobar".to_vec()
+        ));
+        assert_noop!(
+            SmartContractModule::create_name_contract(
+                RuntimeOrigin::signed(alice()),
+                b"foobar".to_vec()
+            ),
+            Error::<TestRuntime>::NameExists
+        );
+    });
+}
 
-        info!("total locked balance {:?}", total_amount_billed);
+#[test]
+fn test_recreate_name_contract_after_cancel_works() {
+    new_test_ext().execute_with(|| {
+        run_to_block(1, None);
+        prepare_farm_and_node();
 
-        let staking_pool_account_balance = Balances::free_balance(&get_staking_pool_account());
-        info!(
-            "staking pool account balance, {:?}",
-            staking_pool_account_balance
-        );
+        assert_ok!(SmartContractModule::create_name_contract(
+            RuntimeOrigin::signed(bob()),
+            b"foobar".to_vec()
+        ));
+        let contract_id = 1;
 
-        // 5% is sent to the staking pool account
-        assert_eq!(
-            staking_pool_account_balance,
-            Perbill::from_percent(5) * total_amount_billed
-        );
+        assert_ok!(SmartContractModule::cancel_contract(
+            RuntimeOrigin::signed(bob()),
+            contract_id
+        ));
 
-        // 10% is sent to the foundation account
-        let pricing_policy = TfgridModule::pricing_policies(1).unwrap();
-        let foundation_account_balance = Balances::free_balance(&pricing_policy.foundation_account);
-        assert_eq!(
-            foundation_account_balance,
-            Perbill::from_percent(10) * total_amount_billed
+        assert_ok!(SmartContractModule::create_name_contract(
+            RuntimeOrigin::signed(bob()),
+            b"foobar".to_vec()
+        ));
+    });
+}
+
+#[test]
+fn test_create_name_contract_with_invalid_dns_name_fails() {
+    new_test_ext().execute_with(|| {
+        run_to_block(1, None);
+        prepare_farm_and_node();
+
+        assert_noop!(
+            SmartContractModule::create_name_contract(
+                RuntimeOrigin::signed(alice()),
+                b"foo.bar".to_vec()
+            ),
+            Error::<TestRuntime>::NameNotValid
         );
 
-        // 50% is sent to the sales account
-        let sales_account_balance = Balances::free_balance(&pricing_policy.certified_sales_account);
-        assert_eq!(
-            sales_account_balance,
-            Perbill::from_percent(50) * total_amount_billed
+        assert_noop!(
+            SmartContractModule::create_name_contract(
+                RuntimeOrigin::signed(alice()),
+                b"foo!".to_vec()
+            ),
+            Error::<TestRuntime>::NameNotValid
         );
 
-        let total_issuance = Balances::total_issuance();
-        // total issueance is now previous total - amount burned from contract billed (35%)
-        let burned_amount = Perbill::from_percent(35) * total_amount_billed;
-        assert_eq_error_rate!(
-            total_issuance,
-            initial_total_issuance - burned_amount as u64,
-            1
+        assert_noop!(
+            SmartContractModule::create_name_contract(
+                RuntimeOrigin::signed(alice()),
+                b"foo;'".to_vec()
+            ),
+            Error::<TestRuntime>::NameNotValid
         );
 
-        // amount unbilled should have been reset after a transfer between contract owner and farmer
-        let contract_billing_info =
-            SmartContractModule::contract_billing_information_by_id(contract_id);
-        assert_eq!(contract_billing_info.amount_unbilled, 0);
+        assert_noop!(
+            SmartContractModule::create_name_contract(
+                RuntimeOrigin::signed(alice()),
+                b"foo123.%".to_vec()
+            ),
+            Error::<TestRuntime>::NameNotValid
+        );
     });
 }
 
+//  RENT CONTRACT TESTS //
+// -------------------- //
+
 #[test]
-fn test_node_contract_billing_details_with_solution_provider() {
-    let (mut ext, mut pool_state) = new_test_ext_with_pool_state(0);
-    ext.execute_with(|| {
+fn test_create_rent_contract_works() {
+    new_test_ext().execute_with(|| {
         run_to_block(1, None);
-        prepare_farm_and_node();
+        prepare_dedicated_farm_and_node();
+
         let node_id = 1;
+        assert_ok!(SmartContractModule::create_rent_contract(
+            RuntimeOrigin::signed(bob()),
+            node_id,
+            None
+        ));
 
-        prepare_solution_provider(dave());
+        let contract = SmartContractModule::contracts(1).unwrap();
+        let rent_contract = types::RentContract { node_id };
+        assert_eq!(
+            contract.contract_type,
+            types::ContractData::RentContract(rent_contract)
+        );
+    });
+}
 
-        TFTPriceModule::set_prices(RuntimeOrigin::signed(alice()), 50, 101).unwrap();
+#[test]
+fn test_create_rent_contract_on_standby_node_works() {
+    new_test_ext().execute_with(|| {
+        run_to_block(1, None);
+        prepare_dedicated_farm_and_node();
+        let node_id = 1;
 
-        let twin = TfgridModule::twins(2).unwrap();
-        let initial_twin_balance = Balances::free_balance(&twin.account_id);
-        let initial_total_issuance = Balances::total_issuance();
+        assert_ok!(TfgridModule::change_power_state(
+            RuntimeOrigin::signed(alice()),
+            tfchain_support::types::Power::Down
+        ));
 
-        assert_ok!(SmartContractModule::create_node_contract(
+        assert_ok!(SmartContractModule::create_rent_contract(
             RuntimeOrigin::signed(bob()),
             node_id,
-            generate_deployment_hash(),
-            get_deployment_data(),
-            1,
-            Some(1)
+            None
         ));
-        let contract_id = 1;
+    });
+}
 
-        push_contract_resources_used(contract_id);
-        push_nru_report_for_contract(contract_id, 10);
+#[test]
+fn test_create_rent_contract_and_switch_node_to_standby_fails() {
+    new_test_ext().execute_with(|| {
+        run_to_block(1, None);
+        prepare_dedicated_farm_and_node();
+        let node_id = 1;
 
-        // Ensure contract_id is stored at right billing loop index
-        let index = SmartContractModule::get_billing_loop_index_from_contract_id(contract_id);
-        assert_eq!(
-            SmartContractModule::contract_to_bill_at_block(index),
-            vec![contract_id]
+        assert_ok!(SmartContractModule::create_rent_contract(
+            RuntimeOrigin::signed(bob()),
+            node_id,
+            None
+        ));
+
+        assert_noop!(
+            TfgridModule::change_power_target(
+                RuntimeOrigin::signed(alice()),
+                node_id,
+                tfchain_support::types::Power::Down
+            ),
+            pallet_tfgrid::Error::<TestRuntime>::NodeHasActiveContracts
         );
+    });
+}
 
-        // advance 25 cycles
-        for i in 0..25 {
-            let block_number = 11 + i * 10;
-            pool_state.write().should_call_bill_contract(
-                contract_id,
-                Ok(Pays::Yes.into()),
-                block_number,
-            );
-            run_to_block(block_number, Some(&mut pool_state));
-        }
+#[test]
+fn test_create_rent_contract_on_standby_node_and_wake_it_up_works() {
+    new_test_ext().execute_with(|| {
+        run_to_block(1, None);
+        prepare_dedicated_farm_and_node();
+        let node_id = 1;
 
-        let free_balance = Balances::free_balance(&twin.account_id);
-        let total_amount_billed = initial_twin_balance - free_balance;
+        assert_ok!(TfgridModule::change_power_target(
+            RuntimeOrigin::signed(alice()),
+            node_id,
+            tfchain_support::types::Power::Down,
+        ));
 
-        validate_distribution_rewards(initial_total_issuance, total_amount_billed, true);
+        assert_ok!(SmartContractModule::create_rent_contract(
+            RuntimeOrigin::signed(bob()),
+            node_id,
+            None
+        ));
 
-        // amount unbilled should have been reset after a transfer between contract owner and farmer
-        let contract_billing_info =
-            SmartContractModule::contract_billing_information_by_id(contract_id);
-        assert_eq!(contract_billing_info.amount_unbilled, 0);
+        assert_ok!(TfgridModule::change_power_target(
+            RuntimeOrigin::signed(alice()),
+            node_id,
+            tfchain_support::types::Power::Up,
+        ));
     });
 }
 
 #[test]
-fn test_multiple_contracts_billing_loop_works() {
-    let (mut ext, mut pool_state) = new_test_ext_with_pool_state(0);
-    ext.execute_with(|| {
+fn test_decommission_node_with_contract_works() {
+    new_test_ext().execute_with(|| {
+        run_to_block(1, None);
         prepare_farm_and_node();
         let node_id = 1;
-        run_to_block(1, None);
-
-        TFTPriceModule::set_prices(RuntimeOrigin::signed(alice()), 50, 101).unwrap();
 
         assert_ok!(SmartContractModule::create_node_contract(
             RuntimeOrigin::signed(bob()),
             node_id,
             generate_deployment_hash(),
             get_deployment_data(),
-            1,
+            0,
             None
         ));
-        let node_contract_id = 1;
-
-        run_to_block(2, None);
+        let contract_id = 1;
 
-        assert_ok!(SmartContractModule::create_name_contract(
-            RuntimeOrigin::signed(bob()),
-            "some_name".as_bytes().to_vec(),
+        assert_ok!(TfgridModule::decommission_node(
+            RuntimeOrigin::signed(alice()),
+            node_id
         ));
-        let name_contract_id = 2;
+        assert_eq!(TfgridModule::decommissioning_nodes(node_id), Some(12));
 
-        // Ensure node_contract_id is stored at right billing loop index
-        let index = SmartContractModule::get_billing_loop_index_from_contract_id(node_contract_id);
+        let our_events = System::events();
         assert_eq!(
-            SmartContractModule::contract_to_bill_at_block(index),
-            vec![node_contract_id]
+            our_events.contains(&record(MockEvent::SmartContractModule(
+                SmartContractEvent::<TestRuntime>::ContractNodeDecommissioning {
+                    contract_id,
+                    node_id,
+                    deadline: 12,
+                }
+            ))),
+            true
         );
 
-        // Ensure name_contract_id is stored at right billing loop index
-        let index = SmartContractModule::get_billing_loop_index_from_contract_id(name_contract_id);
-        assert_eq!(
-            SmartContractModule::contract_to_bill_at_block(index),
-            vec![name_contract_id]
+        // No new contracts on a node being decommissioned
+        assert_noop!(
+            SmartContractModule::create_node_contract(
+                RuntimeOrigin::signed(charlie()),
+                node_id,
+                generate_deployment_hash(),
+                get_deployment_data(),
+                0,
+                None
+            ),
+            Error::<TestRuntime>::NodeIsDecommissioning
         );
 
-        // 2 contracts => 2 billings
-        pool_state
-            .write()
-            .should_call_bill_contract(node_contract_id, Ok(Pays::Yes.into()), 11);
-        run_to_block(11, Some(&mut pool_state));
-        pool_state
-            .write()
-            .should_call_bill_contract(name_contract_id, Ok(Pays::Yes.into()), 12);
-        run_to_block(12, Some(&mut pool_state));
+        // Node is deleted once its last contract is gone
+        assert_ok!(SmartContractModule::cancel_contract(
+            RuntimeOrigin::signed(bob()),
+            contract_id
+        ));
+        assert!(TfgridModule::nodes(node_id).is_none());
+        assert_eq!(TfgridModule::decommissioning_nodes(node_id), None);
+    });
+}
 
-        // Test that the expected events were emitted
-        let our_events = System::events();
+#[test]
+fn test_delete_decommissioning_node_works() {
+    new_test_ext().execute_with(|| {
+        run_to_block(1, None);
+        prepare_farm_and_node();
+        let node_id = 1;
 
-        // 1: Contract Created (node contract)
-        // 2: Contract created (name contract)
-        // 3: Contract Billed (node contract)
-        // 4: Contract Billed (name contract)
-        assert_eq!(our_events.len(), 8);
-    })
+        assert_ok!(SmartContractModule::create_node_contract(
+            RuntimeOrigin::signed(bob()),
+            node_id,
+            generate_deployment_hash(),
+            get_deployment_data(),
+            0,
+            None
+        ));
+        assert_ok!(TfgridModule::decommission_node(
+            RuntimeOrigin::signed(alice()),
+            node_id
+        ));
+
+        assert_ok!(TfgridModule::delete_node_farm(
+            RuntimeOrigin::signed(alice()),
+            node_id
+        ));
+        assert!(TfgridModule::nodes(node_id).is_none());
+        assert_eq!(TfgridModule::decommissioning_nodes(node_id), None);
+
+        // Node is only deleted once
+        let node_deleted_events = System::events()
+            .into_iter()
+            .filter(|e| {
+                e == &record(MockEvent::TfgridModule(
+                    pallet_tfgrid::Event::<TestRuntime>::NodeDeleted(node_id),
+                ))
+            })
+            .count();
+        assert_eq!(node_deleted_events, 1);
+    });
 }
 
 #[test]
-fn test_node_contract_billing_cycles() {
-    let (mut ext, mut pool_state) = new_test_ext_with_pool_state(0);
-    ext.execute_with(|| {
+fn test_decommission_node_cancels_contracts_at_deadline_works() {
+    new_test_ext().execute_with(|| {
         run_to_block(1, None);
         prepare_farm_and_node();
         let node_id = 1;
 
-        TFTPriceModule::set_prices(RuntimeOrigin::signed(alice()), 50, 101).unwrap();
-
         assert_ok!(SmartContractModule::create_node_contract(
             RuntimeOrigin::signed(bob()),
             node_id,
@@ -1201,471 +1729,459 @@ fn test_node_contract_billing_cycles() {
             None
         ));
         let contract_id = 1;
-        let twin_id = 2;
 
-        push_contract_resources_used(contract_id);
+        assert_ok!(TfgridModule::decommission_node(
+            RuntimeOrigin::signed(alice()),
+            node_id
+        ));
 
-        let (amount_due_1, discount_received) = calculate_tft_cost(contract_id, twin_id, 10);
-        pool_state
-            .write()
-            .should_call_bill_contract(contract_id, Ok(Pays::Yes.into()), 11);
-        run_to_block(11, Some(&mut pool_state));
-        check_report_cost(1, amount_due_1, 11, discount_received);
+        run_to_block(11, None);
+        TfgridModule::on_initialize(11);
+        assert!(TfgridModule::nodes(node_id).is_some());
 
-        let twin = TfgridModule::twins(twin_id).unwrap();
-        let usable_balance = Balances::usable_balance(&twin.account_id);
-        let free_balance = Balances::free_balance(&twin.account_id);
+        run_to_block(12, None);
+        TfgridModule::on_initialize(12);
+        assert!(TfgridModule::nodes(node_id).is_none());
+        assert_eq!(SmartContractModule::contracts(contract_id), None);
+        assert_eq!(SmartContractModule::active_node_contracts(node_id).len(), 0);
 
-        let locked_balance = free_balance - usable_balance;
+        let our_events = System::events();
         assert_eq!(
-            locked_balance.saturated_into::<u128>(),
-            amount_due_1 as u128
+            our_events.contains(&record(MockEvent::SmartContractModule(
+                SmartContractEvent::<TestRuntime>::NodeContractCanceled {
+                    contract_id,
+                    node_id,
+                    twin_id: 2,
+                }
+            ))),
+            true
         );
+    });
+}
 
-        let (amount_due_2, discount_received) = calculate_tft_cost(contract_id, twin_id, 10);
-        pool_state
-            .write()
-            .should_call_bill_contract(contract_id, Ok(Pays::Yes.into()), 21);
-        run_to_block(21, Some(&mut pool_state));
-        check_report_cost(1, amount_due_2, 21, discount_received);
+#[test]
+fn test_decommission_node_cancels_contracts_over_blocks_works() {
+    new_test_ext().execute_with(|| {
+        run_to_block(1, None);
+        prepare_farm_and_node();
+        let node_id = 1;
 
-        let (amount_due_3, discount_received) = calculate_tft_cost(contract_id, twin_id, 10);
-        pool_state
-            .write()
-            .should_call_bill_contract(contract_id, Ok(Pays::Yes.into()), 31);
-        run_to_block(31, Some(&mut pool_state));
-        check_report_cost(1, amount_due_3, 31, discount_received);
+        let max_contracts = pallet_tfgrid_types::MAX_NODE_DECOMMISSION_CONTRACTS_PER_BLOCK;
+        for _ in 0..max_contracts + 1 {
+            assert_ok!(SmartContractModule::create_node_contract(
+                RuntimeOrigin::signed(bob()),
+                node_id,
+                generate_deployment_hash(),
+                get_deployment_data(),
+                0,
+                None
+            ));
+        }
 
-        let twin = TfgridModule::twins(twin_id).unwrap();
-        let usable_balance = Balances::usable_balance(&twin.account_id);
-        let free_balance = Balances::free_balance(&twin.account_id);
+        assert_ok!(TfgridModule::decommission_node(
+            RuntimeOrigin::signed(alice()),
+            node_id
+        ));
 
-        let locked_balance = free_balance - usable_balance;
+        // Only part of the contracts are canceled at the deadline
+        run_to_block(12, None);
+        TfgridModule::on_initialize(12);
+        assert!(TfgridModule::nodes(node_id).is_some());
+        assert_eq!(SmartContractModule::active_node_contracts(node_id).len(), 1);
+        assert_eq!(TfgridModule::decommissioning_nodes(node_id), Some(12));
+        assert_eq!(TfgridModule::node_decommissions_at(13), vec![node_id]);
+
+        // The rest are canceled in the next block and the node is deleted
+        run_to_block(13, None);
+        TfgridModule::on_initialize(13);
+        assert!(TfgridModule::nodes(node_id).is_none());
+        assert_eq!(SmartContractModule::active_node_contracts(node_id).len(), 0);
+        assert_eq!(TfgridModule::decommissioning_nodes(node_id), None);
         assert_eq!(
-            locked_balance.saturated_into::<u128>(),
-            amount_due_1 as u128 + amount_due_2 as u128 + amount_due_3 as u128
+            SmartContractModule::contracts(max_contracts as u64 + 1),
+            None
         );
     });
 }
 
 #[test]
-fn test_node_multiple_contract_billing_cycles() {
-    let (mut ext, mut pool_state) = new_test_ext_with_pool_state(0);
-    ext.execute_with(|| {
+fn test_create_node_contract_during_node_maintenance_fails() {
+    new_test_ext().execute_with(|| {
         run_to_block(1, None);
         prepare_farm_and_node();
         let node_id = 1;
 
-        TFTPriceModule::set_prices(RuntimeOrigin::signed(alice()), 50, 101).unwrap();
-
-        assert_ok!(SmartContractModule::create_node_contract(
-            RuntimeOrigin::signed(bob()),
+        let now = 1628082000 + SECS_PER_BLOCK;
+        assert_ok!(TfgridModule::schedule_node_maintenance(
+            RuntimeOrigin::signed(alice()),
             node_id,
-            generate_deployment_hash(),
-            get_deployment_data(),
-            0,
-            None
+            now,
+            now + SECS_PER_HOUR
         ));
-        let first_node_contract_id = 1;
-        push_contract_resources_used(first_node_contract_id);
 
-        run_to_block(2, None);
+        assert_noop!(
+            SmartContractModule::create_node_contract(
+                RuntimeOrigin::signed(bob()),
+                node_id,
+                generate_deployment_hash(),
+                get_deployment_data(),
+                0,
+                None
+            ),
+            Error::<TestRuntime>::NodeInMaintenance
+        );
+        assert_noop!(
+            SmartContractModule::create_rent_contract(RuntimeOrigin::signed(bob()), node_id, None),
+            Error::<TestRuntime>::NodeInMaintenance
+        );
+    });
+}
 
-        assert_ok!(SmartContractModule::create_node_contract(
+#[test]
+fn test_request_node_wakeup_works() {
+    new_test_ext().execute_with(|| {
+        run_to_block(1, None);
+        prepare_dedicated_farm_and_node();
+        let node_id = 1;
+
+        assert_ok!(SmartContractModule::create_rent_contract(
             RuntimeOrigin::signed(bob()),
             node_id,
-            generate_deployment_hash(),
-            get_deployment_data(),
-            0,
             None
         ));
-        let second_node_contract_id = 2;
-        let twin_id = 2;
-        push_contract_resources_used(second_node_contract_id);
-
-        pool_state.write().should_call_bill_contract(
-            first_node_contract_id,
-            Ok(Pays::Yes.into()),
-            11,
-        );
-
-        let (amount_due_contract_1, discount_received) =
-            calculate_tft_cost(first_node_contract_id, twin_id, 10);
-        run_to_block(11, Some(&mut pool_state));
-        check_report_cost(
-            first_node_contract_id,
-            amount_due_contract_1,
-            11,
-            discount_received,
-        );
+        assert_ok!(TfgridModule::change_power_state(
+            RuntimeOrigin::signed(alice()),
+            tfchain_support::types::Power::Down
+        ));
 
-        pool_state.write().should_call_bill_contract(
-            second_node_contract_id,
-            Ok(Pays::Yes.into()),
-            12,
+        assert_ok!(SmartContractModule::request_node_wakeup(
+            RuntimeOrigin::signed(bob()),
+            node_id
+        ));
+        assert_eq!(
+            TfgridModule::node_power_state(node_id).target,
+            tfchain_support::types::Power::Up
         );
-
-        let (amount_due_contract_2, discount_received) =
-            calculate_tft_cost(second_node_contract_id, twin_id, 10);
-        run_to_block(12, Some(&mut pool_state));
-        check_report_cost(
-            second_node_contract_id,
-            amount_due_contract_2,
-            12,
-            discount_received,
+        assert_eq!(
+            SmartContractModule::node_wakeup_requests(node_id),
+            Some(types::NodeWakeupRequest {
+                twin_id: 2,
+                timeout_at: 12,
+            })
         );
 
-        let twin = TfgridModule::twins(twin_id).unwrap();
-        let usable_balance = Balances::usable_balance(&twin.account_id);
-        let free_balance = Balances::free_balance(&twin.account_id);
+        // Node comes back up
+        assert_ok!(TfgridModule::change_power_state(
+            RuntimeOrigin::signed(alice()),
+            tfchain_support::types::Power::Up
+        ));
+        assert_eq!(SmartContractModule::node_wakeup_requests(node_id), None);
 
-        let locked_balance = free_balance - usable_balance;
+        let our_events = System::events();
         assert_eq!(
-            locked_balance.saturated_into::<u128>(),
-            amount_due_contract_1 as u128 + amount_due_contract_2 as u128
+            our_events.contains(&record(MockEvent::SmartContractModule(
+                SmartContractEvent::<TestRuntime>::NodeWakeupSucceeded {
+                    node_id,
+                    twin_id: 2,
+                }
+            ))),
+            true
         );
     });
 }
 
 #[test]
-fn test_node_contract_billing_cycles_delete_node_cancels_contract() {
-    let (mut ext, mut pool_state) = new_test_ext_with_pool_state(0);
-    ext.execute_with(|| {
+fn test_request_node_wakeup_times_out_works() {
+    new_test_ext().execute_with(|| {
         run_to_block(1, None);
-        prepare_farm_and_node();
+        prepare_dedicated_farm_and_node();
         let node_id = 1;
 
-        TFTPriceModule::set_prices(RuntimeOrigin::signed(alice()), 50, 101).unwrap();
-
-        assert_ok!(SmartContractModule::create_node_contract(
+        assert_ok!(SmartContractModule::create_rent_contract(
             RuntimeOrigin::signed(bob()),
             node_id,
-            generate_deployment_hash(),
-            get_deployment_data(),
-            1,
             None
         ));
-        let contract_id = 1;
-        let twin_id = 2;
-
-        for i in 0..5 {
-            let block_number = 11 + i * 10;
-            pool_state.write().should_call_bill_contract(
-                contract_id,
-                Ok(Pays::Yes.into()),
-                block_number,
-            );
-        }
-        push_contract_resources_used(contract_id);
-
-        let (amount_due_as_u128, discount_received) = calculate_tft_cost(contract_id, twin_id, 10);
-        run_to_block(11, Some(&mut pool_state));
-        check_report_cost(1, amount_due_as_u128, 11, discount_received);
-
-        let (amount_due_as_u128, discount_received) = calculate_tft_cost(contract_id, twin_id, 10);
-        run_to_block(21, Some(&mut pool_state));
-        check_report_cost(1, amount_due_as_u128, 21, discount_received);
-
-        let (amount_due_as_u128, discount_received) = calculate_tft_cost(contract_id, twin_id, 10);
-        run_to_block(31, Some(&mut pool_state));
-        check_report_cost(1, amount_due_as_u128, 31, discount_received);
-
-        let (amount_due_as_u128, discount_received) = calculate_tft_cost(contract_id, twin_id, 10);
-        run_to_block(41, Some(&mut pool_state));
-        check_report_cost(1, amount_due_as_u128, 41, discount_received);
-
-        let (amount_due_as_u128, discount_received) = calculate_tft_cost(contract_id, twin_id, 10);
-        run_to_block(51, Some(&mut pool_state));
-        check_report_cost(1, amount_due_as_u128, 51, discount_received);
-
-        let (amount_due_as_u128, discount_received) = calculate_tft_cost(contract_id, twin_id, 4);
-        run_to_block(55, None);
+        assert_ok!(TfgridModule::change_power_state(
+            RuntimeOrigin::signed(alice()),
+            tfchain_support::types::Power::Down
+        ));
+        assert_ok!(SmartContractModule::request_node_wakeup(
+            RuntimeOrigin::signed(bob()),
+            node_id
+        ));
 
-        // Delete node
-        TfgridModule::delete_node_farm(RuntimeOrigin::signed(alice()), 1).unwrap();
+        assert_eq!(crate::NodeWakeupTimeouts::<TestRuntime>::iter().count(), 1);
 
-        // After deleting a node, the contract gets billed before it's canceled
-        check_report_cost(1, amount_due_as_u128, 55, discount_received);
+        run_to_block(12, None);
+        assert_eq!(SmartContractModule::node_wakeup_requests(node_id), None);
+        assert_eq!(crate::NodeWakeupTimeouts::<TestRuntime>::iter().count(), 0);
 
         let our_events = System::events();
-
-        for e in our_events.clone().iter() {
-            info!("{:?}", e);
-        }
-
-        let public_ip = PublicIP {
-            ip: get_public_ip_ip_input(b"185.206.122.33/24"),
-            gateway: get_public_ip_gw_input(b"185.206.122.1"),
-            contract_id: 0,
-        };
-
-        let mut ips: BoundedVec<PublicIP, crate::MaxNodeContractPublicIPs<TestRuntime>> =
-            vec![].try_into().unwrap();
-        ips.try_push(public_ip).unwrap();
-
-        assert_eq!(
-            our_events.contains(&record(MockEvent::SmartContractModule(
-                SmartContractEvent::<TestRuntime>::IPsFreed {
-                    contract_id,
-                    public_ips: ips
-                }
-            ))),
-            true
-        );
         assert_eq!(
             our_events.contains(&record(MockEvent::SmartContractModule(
-                SmartContractEvent::<TestRuntime>::NodeContractCanceled {
-                    contract_id,
-                    node_id: 1,
-                    twin_id: 2
+                SmartContractEvent::<TestRuntime>::NodeWakeupTimedOut {
+                    node_id,
+                    twin_id: 2,
                 }
             ))),
             true
         );
+
+        // Twin can ask again
+        assert_ok!(SmartContractModule::request_node_wakeup(
+            RuntimeOrigin::signed(bob()),
+            node_id
+        ));
     });
 }
 
 #[test]
-fn test_node_contract_only_public_ip_billing_cycles() {
-    let (mut ext, mut pool_state) = new_test_ext_with_pool_state(0);
-    ext.execute_with(|| {
+fn test_request_node_wakeup_without_contract_on_node_fails() {
+    new_test_ext().execute_with(|| {
         run_to_block(1, None);
-        prepare_farm_and_node();
+        prepare_dedicated_farm_and_node();
         let node_id = 1;
 
-        TFTPriceModule::set_prices(RuntimeOrigin::signed(alice()), 50, 101).unwrap();
-
-        assert_ok!(SmartContractModule::create_node_contract(
+        assert_ok!(SmartContractModule::create_rent_contract(
             RuntimeOrigin::signed(bob()),
             node_id,
-            generate_deployment_hash(),
-            get_deployment_data(),
-            1,
             None
         ));
-        let contract_id = 1;
-        let twin_id = 2;
-
-        for i in 0..5 {
-            let block_number = 11 + i * 10;
-            pool_state.write().should_call_bill_contract(
-                contract_id,
-                Ok(Pays::Yes.into()),
-                block_number,
-            );
-        }
-
-        let (amount_due_as_u128, discount_received) = calculate_tft_cost(contract_id, twin_id, 10);
-        assert_ne!(amount_due_as_u128, 0);
-        run_to_block(11, Some(&mut pool_state));
-        check_report_cost(1, amount_due_as_u128, 11, discount_received);
+        assert_ok!(TfgridModule::change_power_state(
+            RuntimeOrigin::signed(alice()),
+            tfchain_support::types::Power::Down
+        ));
 
-        let (amount_due_as_u128, discount_received) = calculate_tft_cost(contract_id, twin_id, 10);
-        run_to_block(21, Some(&mut pool_state));
-        check_report_cost(1, amount_due_as_u128, 21, discount_received);
+        assert_noop!(
+            SmartContractModule::request_node_wakeup(RuntimeOrigin::signed(charlie()), node_id),
+            Error::<TestRuntime>::TwinNotAuthorizedToWakeUpNode
+        );
+    });
+}
 
-        let (amount_due_as_u128, discount_received) = calculate_tft_cost(contract_id, twin_id, 10);
-        run_to_block(31, Some(&mut pool_state));
-        check_report_cost(1, amount_due_as_u128, 31, discount_received);
+#[test]
+fn test_request_node_wakeup_node_not_in_standby_fails() {
+    new_test_ext().execute_with(|| {
+        run_to_block(1, None);
+        prepare_dedicated_farm_and_node();
+        let node_id = 1;
 
-        let (amount_due_as_u128, discount_received) = calculate_tft_cost(contract_id, twin_id, 10);
-        run_to_block(41, Some(&mut pool_state));
-        check_report_cost(1, amount_due_as_u128, 41, discount_received);
+        assert_ok!(SmartContractModule::create_rent_contract(
+            RuntimeOrigin::signed(bob()),
+            node_id,
+            None
+        ));
 
-        let (amount_due_as_u128, discount_received) = calculate_tft_cost(contract_id, twin_id, 10);
-        run_to_block(51, Some(&mut pool_state));
-        check_report_cost(1, amount_due_as_u128, 51, discount_received);
+        assert_noop!(
+            SmartContractModule::request_node_wakeup(RuntimeOrigin::signed(bob()), node_id),
+            Error::<TestRuntime>::NodeNotInStandby
+        );
     });
 }
 
 #[test]
-fn test_node_contract_billing_cycles_cancel_contract_during_cycle_works() {
-    let (mut ext, mut pool_state) = new_test_ext_with_pool_state(0);
-    ext.execute_with(|| {
+fn test_cancel_rent_contract_works() {
+    new_test_ext().execute_with(|| {
         run_to_block(1, None);
-        prepare_farm_and_node();
+        prepare_dedicated_farm_and_node();
         let node_id = 1;
 
-        TFTPriceModule::set_prices(RuntimeOrigin::signed(alice()), 50, 101).unwrap();
-
-        assert_ok!(SmartContractModule::create_node_contract(
+        assert_ok!(SmartContractModule::create_rent_contract(
             RuntimeOrigin::signed(bob()),
             node_id,
-            generate_deployment_hash(),
-            get_deployment_data(),
-            0,
             None
         ));
         let contract_id = 1;
-        let twin_id = 2;
-
-        // 2 cycles for billing
-        for i in 0..2 {
-            let block_number = 11 + i * 10;
-            pool_state.write().should_call_bill_contract(
-                contract_id,
-                Ok(Pays::Yes.into()),
-                block_number,
-            );
-        }
-        push_contract_resources_used(contract_id);
 
-        let (amount_due_as_u128, discount_received) = calculate_tft_cost(contract_id, twin_id, 10);
-        run_to_block(11, Some(&mut pool_state));
-        check_report_cost(1, amount_due_as_u128, 11, discount_received);
-
-        let (amount_due_as_u128, discount_received) = calculate_tft_cost(contract_id, twin_id, 10);
-        run_to_block(21, Some(&mut pool_state));
-        check_report_cost(1, amount_due_as_u128, 21, discount_received);
+        let contract = SmartContractModule::contracts(1).unwrap();
+        let rent_contract = types::RentContract { node_id };
+        assert_eq!(
+            contract.contract_type,
+            types::ContractData::RentContract(rent_contract)
+        );
 
-        run_to_block(28, Some(&mut pool_state));
-        let (amount_due_as_u128, discount_received) = calculate_tft_cost(contract_id, twin_id, 7);
         assert_ok!(SmartContractModule::cancel_contract(
             RuntimeOrigin::signed(bob()),
-            1
+            contract_id
         ));
 
-        run_to_block(29, Some(&mut pool_state));
-        check_report_cost(1, amount_due_as_u128, 28, discount_received);
-
-        let contract = SmartContractModule::contracts(1);
+        let contract = SmartContractModule::contracts(contract_id);
         assert_eq!(contract, None);
-
-        let billing_info = SmartContractModule::contract_billing_information_by_id(contract_id);
-        assert_eq!(billing_info.amount_unbilled, 0);
     });
 }
 
 #[test]
-fn test_node_contract_billing_cycles_cancel_contract_during_cycle_without_balance_works() {
-    let (mut ext, mut pool_state) = new_test_ext_with_pool_state(0);
-    ext.execute_with(|| {
+fn test_create_rent_contract_on_node_in_use_fails() {
+    new_test_ext().execute_with(|| {
+        run_to_block(1, None);
+        prepare_farm_node_and_node_contract();
+        let node_id = 1;
+
+        assert_noop!(
+            SmartContractModule::create_rent_contract(RuntimeOrigin::signed(bob()), node_id, None),
+            Error::<TestRuntime>::NodeNotAvailableToDeploy
+        );
+    })
+}
+
+#[test]
+fn test_create_rent_contract_non_dedicated_empty_node_works() {
+    new_test_ext().execute_with(|| {
         run_to_block(1, None);
         prepare_farm_and_node();
         let node_id = 1;
 
-        TFTPriceModule::set_prices(RuntimeOrigin::signed(alice()), 50, 101).unwrap();
+        assert_ok!(SmartContractModule::create_rent_contract(
+            RuntimeOrigin::signed(bob()),
+            node_id,
+            None
+        ));
+    })
+}
 
-        let twin = TfgridModule::twins(2).unwrap();
-        let initial_twin_balance = Balances::free_balance(&twin.account_id);
-        info!("initial twin balance: {:?}", initial_twin_balance);
-        let initial_total_issuance = Balances::total_issuance();
+#[test]
+fn test_create_node_contract_on_dedicated_node_without_rent_contract_fails() {
+    new_test_ext().execute_with(|| {
+        run_to_block(1, None);
+        prepare_dedicated_farm_and_node();
+        let node_id = 1;
+
+        assert_noop!(
+            SmartContractModule::create_node_contract(
+                RuntimeOrigin::signed(bob()),
+                node_id,
+                generate_deployment_hash(),
+                get_deployment_data(),
+                1,
+                None
+            ),
+            Error::<TestRuntime>::NodeNotAvailableToDeploy
+        );
+    })
+}
+
+#[test]
+fn test_create_node_contract_when_having_a_rentcontract_works() {
+    new_test_ext().execute_with(|| {
+        run_to_block(1, None);
+        prepare_dedicated_farm_and_node();
+        let node_id = 1;
+
+        assert_ok!(SmartContractModule::create_rent_contract(
+            RuntimeOrigin::signed(bob()),
+            node_id,
+            None
+        ));
 
         assert_ok!(SmartContractModule::create_node_contract(
             RuntimeOrigin::signed(bob()),
             node_id,
             generate_deployment_hash(),
             get_deployment_data(),
-            0,
+            1,
             None
         ));
-        let contract_id = 1;
-        let twin_id = 2;
-        push_contract_resources_used(contract_id);
+    })
+}
 
-        let (amount_due_1, discount_received) = calculate_tft_cost(contract_id, twin_id, 10);
-        pool_state
-            .write()
-            .should_call_bill_contract(contract_id, Ok(Pays::Yes.into()), 11);
-        run_to_block(11, Some(&mut pool_state));
-        check_report_cost(1, amount_due_1, 11, discount_received);
-
-        let (amount_due_2, discount_received) = calculate_tft_cost(contract_id, twin_id, 10);
-        pool_state
-            .write()
-            .should_call_bill_contract(contract_id, Ok(Pays::Yes.into()), 21);
-        run_to_block(21, Some(&mut pool_state));
-        check_report_cost(1, amount_due_2, 21, discount_received);
-
-        // Run halfway ish next cycle and cancel
-        run_to_block(25, Some(&mut pool_state));
-
-        let usable_balance = Balances::usable_balance(&twin.account_id);
-        let total_amount_billed = initial_twin_balance - usable_balance;
+#[test]
+fn test_create_node_contract_on_node_rented_by_other_fails() {
+    new_test_ext().execute_with(|| {
+        run_to_block(1, None);
+        prepare_farm_and_node();
+        let node_id = 1;
 
-        let extrinsic_fee = 10000;
-        Balances::transfer(
+        // create rent contract with bob
+        assert_ok!(SmartContractModule::create_rent_contract(
             RuntimeOrigin::signed(bob()),
-            alice(),
-            initial_twin_balance - total_amount_billed - extrinsic_fee,
-        )
-        .unwrap();
+            node_id,
+            None
+        ));
 
-        let usable_balance_before_canceling = Balances::usable_balance(&twin.account_id);
-        assert_ne!(usable_balance_before_canceling, 0);
+        // try to create node contract with Alice
+        // Alice not the owner of the rent contract so she is unauthorized to deploy a node contract
+        assert_noop!(
+            SmartContractModule::create_node_contract(
+                RuntimeOrigin::signed(alice()),
+                node_id,
+                generate_deployment_hash(),
+                get_deployment_data(),
+                1,
+                None
+            ),
+            Error::<TestRuntime>::NodeNotAvailableToDeploy
+        );
+    })
+}
 
-        assert_ok!(SmartContractModule::cancel_contract(
+#[test]
+fn test_create_node_contract_on_dedicated_node_rented_by_other_fails() {
+    new_test_ext().execute_with(|| {
+        run_to_block(1, None);
+        prepare_dedicated_farm_and_node();
+        let node_id = 1;
+
+        // create rent contract with bob
+        assert_ok!(SmartContractModule::create_rent_contract(
             RuntimeOrigin::signed(bob()),
-            contract_id
+            node_id,
+            None
         ));
 
-        // After canceling contract, and not being able to pay for the remainder of the cycle
-        // where the cancel was excecuted, the remaining balance should still be the same
-        let usable_balance_after_canceling = Balances::usable_balance(&twin.account_id);
-        assert_eq!(
-            usable_balance_after_canceling,
-            usable_balance_before_canceling
+        // try to create node contract with Alice
+        // Alice not the owner of the rent contract so she is unauthorized to deploy a node contract
+        assert_noop!(
+            SmartContractModule::create_node_contract(
+                RuntimeOrigin::signed(alice()),
+                node_id,
+                generate_deployment_hash(),
+                get_deployment_data(),
+                1,
+                None
+            ),
+            Error::<TestRuntime>::NodeNotAvailableToDeploy
         );
-
-        validate_distribution_rewards(initial_total_issuance, total_amount_billed, false);
-    });
+    })
 }
 
 #[test]
-fn test_node_contract_out_of_funds_should_move_state_to_graceperiod_works() {
-    let (mut ext, mut pool_state) = new_test_ext_with_pool_state(0);
-    ext.execute_with(|| {
+fn test_cancel_rent_contract_with_active_node_contracts_fails() {
+    new_test_ext().execute_with(|| {
         run_to_block(1, None);
-        prepare_farm_and_node();
+        prepare_dedicated_farm_and_node();
         let node_id = 1;
 
         TFTPriceModule::set_prices(RuntimeOrigin::signed(alice()), 50, 101).unwrap();
 
+        assert_ok!(SmartContractModule::create_rent_contract(
+            RuntimeOrigin::signed(bob()),
+            node_id,
+            None
+        ));
+        let contract_id = 1;
+
         assert_ok!(SmartContractModule::create_node_contract(
-            RuntimeOrigin::signed(charlie()),
+            RuntimeOrigin::signed(bob()),
             node_id,
             generate_deployment_hash(),
             get_deployment_data(),
-            0,
+            1,
             None
         ));
-        let contract_id = 1;
-
-        push_contract_resources_used(contract_id);
-
-        // cycle 1
-        pool_state
-            .write()
-            .should_call_bill_contract(contract_id, Ok(Pays::Yes.into()), 11);
-        run_to_block(11, Some(&mut pool_state));
-
-        // cycle 2
-        // user does not have enough funds to pay for 2 cycles
-        pool_state
-            .write()
-            .should_call_bill_contract(contract_id, Ok(Pays::Yes.into()), 21);
-        run_to_block(21, Some(&mut pool_state));
-
-        let c1 = SmartContractModule::contracts(1).unwrap();
-        assert_eq!(c1.state, types::ContractState::GracePeriod(21));
 
-        let our_events = System::events();
-        assert_eq!(
-            our_events.contains(&record(MockEvent::SmartContractModule(
-                SmartContractEvent::<TestRuntime>::ContractGracePeriodStarted {
-                    contract_id,
-                    node_id,
-                    twin_id: 3,
-                    block_number: 21
-                }
-            ))),
-            true
+        assert_noop!(
+            SmartContractModule::cancel_contract(RuntimeOrigin::signed(bob()), contract_id),
+            Error::<TestRuntime>::NodeHasActiveContracts
         );
     });
 }
 
+//  CONTRACT BILLING TESTS //
+// ----------------------- //
+
 #[test]
-fn test_restore_node_contract_in_grace_works() {
+fn test_node_contract_billing_details() {
     let (mut ext, mut pool_state) = new_test_ext_with_pool_state(0);
     ext.execute_with(|| {
         run_to_block(1, None);
@@ -1674,660 +2190,678 @@ fn test_restore_node_contract_in_grace_works() {
 
         TFTPriceModule::set_prices(RuntimeOrigin::signed(alice()), 50, 101).unwrap();
 
+        let twin = TfgridModule::twins(2).unwrap();
+        let initial_twin_balance = Balances::free_balance(&twin.account_id);
+
         assert_ok!(SmartContractModule::create_node_contract(
-            RuntimeOrigin::signed(charlie()),
+            RuntimeOrigin::signed(bob()),
             node_id,
             generate_deployment_hash(),
             get_deployment_data(),
-            0,
+            1,
             None
         ));
         let contract_id = 1;
 
-        for i in 0..6 {
+        push_contract_resources_used(contract_id);
+        push_nru_report_for_contract(contract_id, 10);
+
+        // Ensure contract_id is stored at right billing loop index
+        let index = SmartContractModule::get_billing_loop_index_from_contract_id(contract_id);
+        assert_eq!(
+            SmartContractModule::contract_to_bill_at_block(index),
+            vec![contract_id]
+        );
+
+        let initial_total_issuance = Balances::total_issuance();
+        // advance 25 cycles
+        for i in 0..25 {
             let block_number = 11 + i * 10;
             pool_state.write().should_call_bill_contract(
                 contract_id,
                 Ok(Pays::Yes.into()),
                 block_number,
             );
+            run_to_block(block_number, Some(&mut pool_state));
         }
-        push_contract_resources_used(contract_id);
 
-        // cycle 1
-        run_to_block(11, Some(&mut pool_state));
+        let free_balance = Balances::free_balance(&twin.account_id);
+        let total_amount_billed = initial_twin_balance - free_balance;
+        info!("locked balance {:?}", total_amount_billed);
 
-        // cycle 2
-        // user does not have enough funds to pay for 2 cycles
-        run_to_block(21, Some(&mut pool_state));
+        info!("total locked balance {:?}", total_amount_billed);
 
-        let c1 = SmartContractModule::contracts(1).unwrap();
-        assert_eq!(c1.state, types::ContractState::GracePeriod(21));
+        let staking_pool_account_balance = Balances::free_balance(&get_staking_pool_account());
+        info!(
+            "staking pool account balance, {:?}",
+            staking_pool_account_balance
+        );
 
-        let our_events = System::events();
+        // 5% is sent to the staking pool account
         assert_eq!(
-            our_events.contains(&record(MockEvent::SmartContractModule(
-                SmartContractEvent::<TestRuntime>::ContractGracePeriodStarted {
-                    contract_id,
-                    node_id,
-                    twin_id: 3,
-                    block_number: 21
-                }
-            ))),
-            true
+            staking_pool_account_balance,
+            Perbill::from_percent(5) * total_amount_billed
         );
 
-        run_to_block(31, Some(&mut pool_state));
-        run_to_block(41, Some(&mut pool_state));
-        // Transfer some balance to the owner of the contract to trigger the grace period to stop
-        Balances::transfer(RuntimeOrigin::signed(bob()), charlie(), 100000000).unwrap();
-        run_to_block(52, Some(&mut pool_state));
-        run_to_block(62, Some(&mut pool_state));
+        // 10% is sent to the foundation account
+        let pricing_policy = TfgridModule::pricing_policies(1).unwrap();
+        let foundation_account_balance = Balances::free_balance(&pricing_policy.foundation_account);
+        assert_eq!(
+            foundation_account_balance,
+            Perbill::from_percent(10) * total_amount_billed
+        );
 
-        let c1 = SmartContractModule::contracts(contract_id).unwrap();
-        assert_eq!(c1.state, types::ContractState::Created);
+        // 50% is sent to the sales account
+        let sales_account_balance = Balances::free_balance(&pricing_policy.certified_sales_account);
+        assert_eq!(
+            sales_account_balance,
+            Perbill::from_percent(50) * total_amount_billed
+        );
+
+        let total_issuance = Balances::total_issuance();
+        // total issueance is now previous total - amount burned from contract billed (35%)
+        let burned_amount = Perbill::from_percent(35) * total_amount_billed;
+        assert_eq_error_rate!(
+            total_issuance,
+            initial_total_issuance - burned_amount as u64,
+            1
+        );
+
+        // amount unbilled should have been reset after a transfer between contract owner and farmer
+        let contract_billing_info =
+            SmartContractModule::contract_billing_information_by_id(contract_id);
+        assert_eq!(contract_billing_info.amount_unbilled, 0);
     });
 }
 
 #[test]
-fn test_node_contract_grace_period_cancels_contract_when_grace_period_ends_works() {
+fn test_node_contract_billing_details_with_solution_provider() {
     let (mut ext, mut pool_state) = new_test_ext_with_pool_state(0);
     ext.execute_with(|| {
         run_to_block(1, None);
         prepare_farm_and_node();
         let node_id = 1;
 
+        prepare_solution_provider(dave());
+
         TFTPriceModule::set_prices(RuntimeOrigin::signed(alice()), 50, 101).unwrap();
 
-        let twin = TfgridModule::twins(3).unwrap();
-        let initial_total_issuance = Balances::total_issuance();
+        let twin = TfgridModule::twins(2).unwrap();
         let initial_twin_balance = Balances::free_balance(&twin.account_id);
+        let initial_total_issuance = Balances::total_issuance();
 
         assert_ok!(SmartContractModule::create_node_contract(
-            RuntimeOrigin::signed(charlie()),
+            RuntimeOrigin::signed(bob()),
             node_id,
             generate_deployment_hash(),
             get_deployment_data(),
-            0,
-            None
+            1,
+            Some(1)
         ));
         let contract_id = 1;
 
         push_contract_resources_used(contract_id);
+        push_nru_report_for_contract(contract_id, 10);
 
-        // cycle 1
-        pool_state
-            .write()
-            .should_call_bill_contract(contract_id, Ok(Pays::Yes.into()), 11);
-        run_to_block(11, Some(&mut pool_state));
-
-        // cycle 2
-        // user does not have enough funds to pay for 2 cycles
-        pool_state
-            .write()
-            .should_call_bill_contract(contract_id, Ok(Pays::Yes.into()), 21);
-        run_to_block(21, Some(&mut pool_state));
-
-        let c1 = SmartContractModule::contracts(1).unwrap();
-        assert_eq!(c1.state, types::ContractState::GracePeriod(21));
-
-        let our_events = System::events();
+        // Ensure contract_id is stored at right billing loop index
+        let index = SmartContractModule::get_billing_loop_index_from_contract_id(contract_id);
         assert_eq!(
-            our_events.contains(&record(MockEvent::SmartContractModule(
-                SmartContractEvent::<TestRuntime>::ContractGracePeriodStarted {
-                    contract_id,
-                    node_id,
-                    twin_id: 3,
-                    block_number: 21
-                }
-            ))),
-            true
+            SmartContractModule::contract_to_bill_at_block(index),
+            vec![contract_id]
         );
 
-        // grace period stops after 100 blocknumbers, so after 121
-        for i in 1..11 {
-            let block_number = 21 + i * 10;
+        // advance 25 cycles
+        for i in 0..25 {
+            let block_number = 11 + i * 10;
             pool_state.write().should_call_bill_contract(
                 contract_id,
                 Ok(Pays::Yes.into()),
                 block_number,
             );
+            run_to_block(block_number, Some(&mut pool_state));
         }
 
-        for i in 1..11 {
-            run_to_block(21 + i * 10, Some(&mut pool_state));
-        }
-
-        // The user's total free balance should be distributed
         let free_balance = Balances::free_balance(&twin.account_id);
         let total_amount_billed = initial_twin_balance - free_balance;
 
-        validate_distribution_rewards(initial_total_issuance, total_amount_billed, false);
+        validate_distribution_rewards(initial_total_issuance, total_amount_billed, true);
 
-        let c1 = SmartContractModule::contracts(contract_id);
-        assert_eq!(c1, None);
+        // amount unbilled should have been reset after a transfer between contract owner and farmer
+        let contract_billing_info =
+            SmartContractModule::contract_billing_information_by_id(contract_id);
+        assert_eq!(contract_billing_info.amount_unbilled, 0);
     });
 }
 
 #[test]
-fn test_name_contract_billing() {
+fn test_multiple_contracts_billing_loop_works() {
     let (mut ext, mut pool_state) = new_test_ext_with_pool_state(0);
     ext.execute_with(|| {
         prepare_farm_and_node();
+        let node_id = 1;
         run_to_block(1, None);
 
         TFTPriceModule::set_prices(RuntimeOrigin::signed(alice()), 50, 101).unwrap();
 
+        assert_ok!(SmartContractModule::create_node_contract(
+            RuntimeOrigin::signed(bob()),
+            node_id,
+            generate_deployment_hash(),
+            get_deployment_data(),
+            1,
+            None
+        ));
+        let node_contract_id = 1;
+
+        run_to_block(2, None);
+
         assert_ok!(SmartContractModule::create_name_contract(
             RuntimeOrigin::signed(bob()),
-            b"foobar".to_vec()
+            "some_name".as_bytes().to_vec(),
         ));
-        let contract_id = 1;
+        let name_contract_id = 2;
 
-        // Ensure contract_id is stored at right billing loop index
-        let index = SmartContractModule::get_billing_loop_index_from_contract_id(contract_id);
+        // Ensure node_contract_id is stored at right billing loop index
+        let index = SmartContractModule::get_billing_loop_index_from_contract_id(node_contract_id);
         assert_eq!(
             SmartContractModule::contract_to_bill_at_block(index),
-            vec![contract_id]
+            vec![node_contract_id]
         );
 
-        // let mature 11 blocks
-        // because we bill every 10 blocks
+        // Ensure name_contract_id is stored at right billing loop index
+        let index = SmartContractModule::get_billing_loop_index_from_contract_id(name_contract_id);
+        assert_eq!(
+            SmartContractModule::contract_to_bill_at_block(index),
+            vec![name_contract_id]
+        );
+
+        // 2 contracts => 2 billings
         pool_state
             .write()
-            .should_call_bill_contract(contract_id, Ok(Pays::Yes.into()), 11);
+            .should_call_bill_contract(node_contract_id, Ok(Pays::Yes.into()), 11);
         run_to_block(11, Some(&mut pool_state));
+        pool_state
+            .write()
+            .should_call_bill_contract(name_contract_id, Ok(Pays::Yes.into()), 12);
+        run_to_block(12, Some(&mut pool_state));
 
-        // get the contract cost for 1 billing cycle
-        let contract = SmartContractModule::contracts(contract_id).unwrap();
-        let twin_id = 2;
-        let twin = TfgridModule::twins(twin_id).unwrap();
-        let balance = Balances::free_balance(&twin.account_id);
-        let second_elapsed = BillingFrequency::get() * SECS_PER_BLOCK;
-        let (contract_cost, discount_level) = contract
-            .calculate_contract_cost_tft(balance, second_elapsed)
-            .unwrap();
-
-        // the contractbill event should look like:
-        let contract_bill_event = types::ContractBill {
-            contract_id,
-            timestamp: 1628082066,
-            discount_level,
-            amount_billed: contract_cost as u128,
-        };
-
+        // Test that the expected events were emitted
         let our_events = System::events();
-        info!("events: {:?}", our_events.clone());
-        assert_eq!(
-            our_events[4],
-            record(MockEvent::SmartContractModule(SmartContractEvent::<
-                TestRuntime,
-            >::ContractBilled(
-                contract_bill_event
-            )))
-        );
-    });
+
+        // 1: Contract Created (node contract)
+        // 2: Contract created (name contract)
+        // 3: Contract Billed (node contract)
+        // 4: Contract Billed (name contract)
+        assert_eq!(our_events.len(), 8);
+    })
 }
 
 #[test]
-fn test_rent_contract_billing() {
+fn test_node_contract_billing_cycles() {
     let (mut ext, mut pool_state) = new_test_ext_with_pool_state(0);
     ext.execute_with(|| {
         run_to_block(1, None);
-        prepare_dedicated_farm_and_node();
+        prepare_farm_and_node();
         let node_id = 1;
 
-        // switch node to standby at block 1
-        assert_ok!(TfgridModule::change_power_state(
-            RuntimeOrigin::signed(alice()),
-            tfchain_support::types::Power::Down
-        ));
-
         TFTPriceModule::set_prices(RuntimeOrigin::signed(alice()), 50, 101).unwrap();
 
-        assert_ok!(SmartContractModule::create_rent_contract(
+        assert_ok!(SmartContractModule::create_node_contract(
             RuntimeOrigin::signed(bob()),
             node_id,
+            generate_deployment_hash(),
+            get_deployment_data(),
+            0,
             None
         ));
         let contract_id = 1;
+        let twin_id = 2;
 
-        let contract = SmartContractModule::contracts(contract_id).unwrap();
-        let rent_contract = types::RentContract { node_id };
-        assert_eq!(
-            contract.contract_type,
-            types::ContractData::RentContract(rent_contract)
-        );
+        push_contract_resources_used(contract_id);
 
-        // go to end of cycle 1 [1-11] and expect a call to bill_contract()
+        let (amount_due_1, discount_received) = calculate_tft_cost(contract_id, twin_id, 10);
         pool_state
             .write()
             .should_call_bill_contract(contract_id, Ok(Pays::Yes.into()), 11);
         run_to_block(11, Some(&mut pool_state));
+        check_report_cost(1, amount_due_1, 11, discount_received);
 
-        // wake up node at block 15, in the middle of cycle 2 [11-21]
-        run_to_block(15, Some(&mut pool_state));
-        assert_ok!(TfgridModule::change_power_state(
-            RuntimeOrigin::signed(alice()),
-            tfchain_support::types::Power::Up
-        ));
+        let twin = TfgridModule::twins(twin_id).unwrap();
+        let usable_balance = Balances::usable_balance(&twin.account_id);
+        let free_balance = Balances::free_balance(&twin.account_id);
 
-        // go to end of cycle 2 [11-21] and expect a call to bill_contract()
+        let locked_balance = free_balance - usable_balance;
+        assert_eq!(
+            locked_balance.saturated_into::<u128>(),
+            amount_due_1 as u128
+        );
+
+        let (amount_due_2, discount_received) = calculate_tft_cost(contract_id, twin_id, 10);
         pool_state
             .write()
             .should_call_bill_contract(contract_id, Ok(Pays::Yes.into()), 21);
         run_to_block(21, Some(&mut pool_state));
+        check_report_cost(1, amount_due_2, 21, discount_received);
 
-        // should bill partial cycle 2 [15-21], 6 blocks
-        let (amount_due_as_u128, discount_received) = calculate_tft_cost(contract_id, 2, 6);
-        assert_ne!(amount_due_as_u128, 0);
-        check_report_cost(contract_id, amount_due_as_u128, 21, discount_received);
-
-        // switch node to standby at block 25, in the middle of cycle 3 [21-31]
-        run_to_block(25, Some(&mut pool_state));
-        assert_ok!(TfgridModule::change_power_state(
-            RuntimeOrigin::signed(alice()),
-            tfchain_support::types::Power::Down
-        ));
-
-        // go to end of cycle 3 [21-31] and expect a call to bill_contract()
+        let (amount_due_3, discount_received) = calculate_tft_cost(contract_id, twin_id, 10);
         pool_state
             .write()
             .should_call_bill_contract(contract_id, Ok(Pays::Yes.into()), 31);
         run_to_block(31, Some(&mut pool_state));
+        check_report_cost(1, amount_due_3, 31, discount_received);
 
-        // go to end of cycle 4 [31-41] and expect a call to bill_contract()
-        pool_state
-            .write()
-            .should_call_bill_contract(contract_id, Ok(Pays::Yes.into()), 41);
-        run_to_block(41, Some(&mut pool_state));
-
-        // wake up node at block 45, in the middle of cycle 5 [41-51]
-        run_to_block(45, Some(&mut pool_state));
-        assert_ok!(TfgridModule::change_power_state(
-            RuntimeOrigin::signed(alice()),
-            tfchain_support::types::Power::Up
-        ));
-
-        // go to end of cycle 5 [41-51] and expect a call to bill_contract()
-        pool_state
-            .write()
-            .should_call_bill_contract(contract_id, Ok(Pays::Yes.into()), 51);
-        run_to_block(51, Some(&mut pool_state));
+        let twin = TfgridModule::twins(twin_id).unwrap();
+        let usable_balance = Balances::usable_balance(&twin.account_id);
+        let free_balance = Balances::free_balance(&twin.account_id);
 
-        // should bill partial cycle 5 [45-51], 6 blocks
-        let (amount_due_as_u128, discount_received) = calculate_tft_cost(contract_id, 2, 6);
-        assert_ne!(amount_due_as_u128, 0);
-        check_report_cost(contract_id, amount_due_as_u128, 51, discount_received);
+        let locked_balance = free_balance - usable_balance;
+        assert_eq!(
+            locked_balance.saturated_into::<u128>(),
+            amount_due_1 as u128 + amount_due_2 as u128 + amount_due_3 as u128
+        );
     });
 }
 
 #[test]
-fn test_rent_contract_billing_cancel_should_bill_reserved_balance() {
+fn test_node_multiple_contract_billing_cycles() {
     let (mut ext, mut pool_state) = new_test_ext_with_pool_state(0);
     ext.execute_with(|| {
         run_to_block(1, None);
-        prepare_dedicated_farm_and_node();
+        prepare_farm_and_node();
         let node_id = 1;
 
         TFTPriceModule::set_prices(RuntimeOrigin::signed(alice()), 50, 101).unwrap();
 
-        assert_ok!(SmartContractModule::create_rent_contract(
+        assert_ok!(SmartContractModule::create_node_contract(
             RuntimeOrigin::signed(bob()),
             node_id,
+            generate_deployment_hash(),
+            get_deployment_data(),
+            0,
             None
         ));
-        let contract_id = 1;
+        let first_node_contract_id = 1;
+        push_contract_resources_used(first_node_contract_id);
 
-        let contract = SmartContractModule::contracts(contract_id).unwrap();
-        let rent_contract = types::RentContract { node_id };
-        assert_eq!(
-            contract.contract_type,
-            types::ContractData::RentContract(rent_contract)
+        run_to_block(2, None);
+
+        assert_ok!(SmartContractModule::create_node_contract(
+            RuntimeOrigin::signed(bob()),
+            node_id,
+            generate_deployment_hash(),
+            get_deployment_data(),
+            0,
+            None
+        ));
+        let second_node_contract_id = 2;
+        let twin_id = 2;
+        push_contract_resources_used(second_node_contract_id);
+
+        pool_state.write().should_call_bill_contract(
+            first_node_contract_id,
+            Ok(Pays::Yes.into()),
+            11,
         );
 
-        pool_state
-            .write()
-            .should_call_bill_contract(contract_id, Ok(Pays::Yes.into()), 11);
+        let (amount_due_contract_1, discount_received) =
+            calculate_tft_cost(first_node_contract_id, twin_id, 10);
         run_to_block(11, Some(&mut pool_state));
-
-        let (amount_due_as_u128, discount_received) = calculate_tft_cost(contract_id, 2, 10);
-        assert_ne!(amount_due_as_u128, 0);
         check_report_cost(
-            contract_id,
-            amount_due_as_u128,
+            first_node_contract_id,
+            amount_due_contract_1,
             11,
-            discount_received.clone(),
+            discount_received,
         );
 
-        let twin = TfgridModule::twins(2).unwrap();
-        let usable_balance = Balances::usable_balance(&twin.account_id);
-        let free_balance = Balances::free_balance(&twin.account_id);
-        assert_ne!(usable_balance, free_balance);
-
-        run_to_block(13, Some(&mut pool_state));
-        // cancel contract
-        // it will bill before removing the contract and it should bill all
-        // reserved balance
-        let (amount_due_as_u128, discount_received) = calculate_tft_cost(contract_id, 2, 2);
-        assert_ok!(SmartContractModule::cancel_contract(
-            RuntimeOrigin::signed(bob()),
-            contract_id
-        ));
-
-        let twin = TfgridModule::twins(2).unwrap();
-        let usable_balance = Balances::usable_balance(&twin.account_id);
-        assert_ne!(usable_balance, 0);
-        Balances::transfer(RuntimeOrigin::signed(bob()), alice(), usable_balance).unwrap();
+        pool_state.write().should_call_bill_contract(
+            second_node_contract_id,
+            Ok(Pays::Yes.into()),
+            12,
+        );
 
-        // Last amount due is the same as the first one
-        assert_ne!(amount_due_as_u128, 0);
-        check_report_cost(contract_id, amount_due_as_u128, 13, discount_received);
+        let (amount_due_contract_2, discount_received) =
+            calculate_tft_cost(second_node_contract_id, twin_id, 10);
+        run_to_block(12, Some(&mut pool_state));
+        check_report_cost(
+            second_node_contract_id,
+            amount_due_contract_2,
+            12,
+            discount_received,
+        );
 
+        let twin = TfgridModule::twins(twin_id).unwrap();
         let usable_balance = Balances::usable_balance(&twin.account_id);
         let free_balance = Balances::free_balance(&twin.account_id);
-        assert_eq!(usable_balance, free_balance);
+
+        let locked_balance = free_balance - usable_balance;
+        assert_eq!(
+            locked_balance.saturated_into::<u128>(),
+            amount_due_contract_1 as u128 + amount_due_contract_2 as u128
+        );
     });
 }
 
 #[test]
-fn test_rent_contract_canceled_mid_cycle_should_bill_for_remainder() {
+fn test_node_contract_billing_cycles_delete_node_cancels_contract() {
     let (mut ext, mut pool_state) = new_test_ext_with_pool_state(0);
     ext.execute_with(|| {
         run_to_block(1, None);
-        prepare_dedicated_farm_and_node();
+        prepare_farm_and_node();
         let node_id = 1;
 
         TFTPriceModule::set_prices(RuntimeOrigin::signed(alice()), 50, 101).unwrap();
 
-        assert_ok!(SmartContractModule::create_rent_contract(
+        assert_ok!(SmartContractModule::create_node_contract(
             RuntimeOrigin::signed(bob()),
             node_id,
+            generate_deployment_hash(),
+            get_deployment_data(),
+            1,
             None
         ));
         let contract_id = 1;
+        let twin_id = 2;
 
-        let contract = SmartContractModule::contracts(contract_id).unwrap();
-        let rent_contract = types::RentContract { node_id };
-        assert_eq!(
-            contract.contract_type,
-            types::ContractData::RentContract(rent_contract)
-        );
+        for i in 0..5 {
+            let block_number = 11 + i * 10;
+            pool_state.write().should_call_bill_contract(
+                contract_id,
+                Ok(Pays::Yes.into()),
+                block_number,
+            );
+        }
+        push_contract_resources_used(contract_id);
 
-        let twin = TfgridModule::twins(2).unwrap();
-        let usable_balance = Balances::usable_balance(&twin.account_id);
-        let free_balance = Balances::free_balance(&twin.account_id);
+        let (amount_due_as_u128, discount_received) = calculate_tft_cost(contract_id, twin_id, 10);
+        run_to_block(11, Some(&mut pool_state));
+        check_report_cost(1, amount_due_as_u128, 11, discount_received);
 
-        let locked_balance = free_balance - usable_balance;
-        info!("locked balance: {:?}", locked_balance);
+        let (amount_due_as_u128, discount_received) = calculate_tft_cost(contract_id, twin_id, 10);
+        run_to_block(21, Some(&mut pool_state));
+        check_report_cost(1, amount_due_as_u128, 21, discount_received);
 
-        run_to_block(8, Some(&mut pool_state));
-        // Calculate the cost for 7 blocks of runtime (created a block 1, canceled at block 8)
-        let (amount_due_as_u128, discount_received) = calculate_tft_cost(contract_id, 2, 7);
-        // cancel rent contract at block 8
-        assert_ok!(SmartContractModule::cancel_contract(
-            RuntimeOrigin::signed(bob()),
-            contract_id
-        ));
-        assert_ne!(amount_due_as_u128, 0);
-        check_report_cost(
-            contract_id,
-            amount_due_as_u128,
-            8,
-            discount_received.clone(),
-        );
+        let (amount_due_as_u128, discount_received) = calculate_tft_cost(contract_id, twin_id, 10);
+        run_to_block(31, Some(&mut pool_state));
+        check_report_cost(1, amount_due_as_u128, 31, discount_received);
 
-        // Twin should have no more locked balance
-        let twin = TfgridModule::twins(2).unwrap();
-        let usable_balance = Balances::usable_balance(&twin.account_id);
-        let free_balance = Balances::free_balance(&twin.account_id);
-        assert_eq!(usable_balance, free_balance);
+        let (amount_due_as_u128, discount_received) = calculate_tft_cost(contract_id, twin_id, 10);
+        run_to_block(41, Some(&mut pool_state));
+        check_report_cost(1, amount_due_as_u128, 41, discount_received);
+
+        let (amount_due_as_u128, discount_received) = calculate_tft_cost(contract_id, twin_id, 10);
+        run_to_block(51, Some(&mut pool_state));
+        check_report_cost(1, amount_due_as_u128, 51, discount_received);
+
+        let (amount_due_as_u128, discount_received) = calculate_tft_cost(contract_id, twin_id, 4);
+        run_to_block(55, None);
+
+        // Delete node
+        TfgridModule::delete_node_farm(RuntimeOrigin::signed(alice()), 1).unwrap();
+
+        // After deleting a node, the contract gets billed before it's canceled
+        check_report_cost(1, amount_due_as_u128, 55, discount_received);
+
+        let our_events = System::events();
+
+        for e in our_events.clone().iter() {
+            info!("{:?}", e);
+        }
+
+        let public_ip = PublicIP {
+            ip: get_public_ip_ip_input(b"185.206.122.33/24"),
+            gateway: get_public_ip_gw_input(b"185.206.122.1"),
+            contract_id: 0,
+        };
+
+        let mut ips: BoundedVec<PublicIP, crate::MaxNodeContractPublicIPs<TestRuntime>> =
+            vec![].try_into().unwrap();
+        ips.try_push(public_ip).unwrap();
+
+        assert_eq!(
+            our_events.contains(&record(MockEvent::SmartContractModule(
+                SmartContractEvent::<TestRuntime>::IPsFreed {
+                    contract_id,
+                    public_ips: ips
+                }
+            ))),
+            true
+        );
+        assert_eq!(
+            our_events.contains(&record(MockEvent::SmartContractModule(
+                SmartContractEvent::<TestRuntime>::NodeContractCanceled {
+                    contract_id,
+                    node_id: 1,
+                    twin_id: 2
+                }
+            ))),
+            true
+        );
     });
 }
 
 #[test]
-fn test_create_rent_contract_and_node_contract_excludes_node_contract_from_billing_works() {
+fn test_node_contract_only_public_ip_billing_cycles() {
     let (mut ext, mut pool_state) = new_test_ext_with_pool_state(0);
     ext.execute_with(|| {
-        prepare_dedicated_farm_and_node();
-        let node_id = 1;
         run_to_block(1, None);
+        prepare_farm_and_node();
+        let node_id = 1;
 
         TFTPriceModule::set_prices(RuntimeOrigin::signed(alice()), 50, 101).unwrap();
 
-        assert_ok!(SmartContractModule::create_rent_contract(
-            RuntimeOrigin::signed(bob()),
-            node_id,
-            None
-        ));
-        let rent_contract_id = 1;
-
-        run_to_block(2, None);
-
         assert_ok!(SmartContractModule::create_node_contract(
             RuntimeOrigin::signed(bob()),
             node_id,
             generate_deployment_hash(),
             get_deployment_data(),
-            0,
+            1,
             None
         ));
-        let node_contract_id = 2;
-        push_contract_resources_used(node_contract_id);
+        let contract_id = 1;
+        let twin_id = 2;
 
-        pool_state
-            .write()
-            .should_call_bill_contract(rent_contract_id, Ok(Pays::Yes.into()), 11);
-        run_to_block(11, Some(&mut pool_state));
-        pool_state
-            .write()
-            .should_call_bill_contract(node_contract_id, Ok(Pays::Yes.into()), 12);
-        run_to_block(12, Some(&mut pool_state));
+        for i in 0..5 {
+            let block_number = 11 + i * 10;
+            pool_state.write().should_call_bill_contract(
+                contract_id,
+                Ok(Pays::Yes.into()),
+                block_number,
+            );
+        }
 
-        let (amount_due_as_u128, discount_received) = calculate_tft_cost(rent_contract_id, 2, 10);
+        let (amount_due_as_u128, discount_received) = calculate_tft_cost(contract_id, twin_id, 10);
         assert_ne!(amount_due_as_u128, 0);
-        check_report_cost(rent_contract_id, amount_due_as_u128, 11, discount_received);
+        run_to_block(11, Some(&mut pool_state));
+        check_report_cost(1, amount_due_as_u128, 11, discount_received);
 
-        let our_events = System::events();
-        // Event 1: Price Stored
-        // Event 2: Avg price stored
-        // Event 3: Rent contract created
-        // Event 4: Node Contract created
-        // Event 5: Updated resources contract
-        // Event 6: Balances locked
-        // Event 7: Contract Billed
-        // => no Node Contract billed event
-        assert_eq!(our_events.len(), 7);
+        let (amount_due_as_u128, discount_received) = calculate_tft_cost(contract_id, twin_id, 10);
+        run_to_block(21, Some(&mut pool_state));
+        check_report_cost(1, amount_due_as_u128, 21, discount_received);
+
+        let (amount_due_as_u128, discount_received) = calculate_tft_cost(contract_id, twin_id, 10);
+        run_to_block(31, Some(&mut pool_state));
+        check_report_cost(1, amount_due_as_u128, 31, discount_received);
+
+        let (amount_due_as_u128, discount_received) = calculate_tft_cost(contract_id, twin_id, 10);
+        run_to_block(41, Some(&mut pool_state));
+        check_report_cost(1, amount_due_as_u128, 41, discount_received);
+
+        let (amount_due_as_u128, discount_received) = calculate_tft_cost(contract_id, twin_id, 10);
+        run_to_block(51, Some(&mut pool_state));
+        check_report_cost(1, amount_due_as_u128, 51, discount_received);
     });
 }
 
 #[test]
-fn test_rent_contract_canceled_due_to_out_of_funds_should_cancel_node_contracts_works() {
+fn test_node_contract_billing_cycles_cancel_contract_during_cycle_works() {
     let (mut ext, mut pool_state) = new_test_ext_with_pool_state(0);
     ext.execute_with(|| {
-        prepare_dedicated_farm_and_node();
-        let node_id = 1;
         run_to_block(1, None);
+        prepare_farm_and_node();
+        let node_id = 1;
 
         TFTPriceModule::set_prices(RuntimeOrigin::signed(alice()), 50, 101).unwrap();
 
-        assert_ok!(SmartContractModule::create_rent_contract(
-            RuntimeOrigin::signed(charlie()),
-            node_id,
-            None
-        ));
-        let rent_contract_id = 1;
-
-        run_to_block(2, None);
-
         assert_ok!(SmartContractModule::create_node_contract(
-            RuntimeOrigin::signed(charlie()),
+            RuntimeOrigin::signed(bob()),
             node_id,
             generate_deployment_hash(),
             get_deployment_data(),
             0,
             None
         ));
-        let node_contract_id = 2;
-        push_contract_resources_used(node_contract_id);
+        let contract_id = 1;
+        let twin_id = 2;
 
-        // run 10 cycles
-        for i in 0..10 {
+        // 2 cycles for billing
+        for i in 0..2 {
             let block_number = 11 + i * 10;
             pool_state.write().should_call_bill_contract(
-                rent_contract_id,
+                contract_id,
                 Ok(Pays::Yes.into()),
                 block_number,
             );
-            run_to_block(block_number, Some(&mut pool_state));
-            pool_state.write().should_call_bill_contract(
-                node_contract_id,
-                Ok(Pays::Yes.into()),
-                block_number + 1,
-            );
-            run_to_block(block_number + 1, Some(&mut pool_state));
         }
+        push_contract_resources_used(contract_id);
 
-        // contracts should cancel at 11th due to lack of funds
-        let end_grace_block_number = 11 + 10 * 10;
-        pool_state.write().should_call_bill_contract(
-            rent_contract_id,
-            Ok(Pays::Yes.into()),
-            end_grace_block_number,
-        );
-        run_to_block(end_grace_block_number, Some(&mut pool_state));
+        let (amount_due_as_u128, discount_received) = calculate_tft_cost(contract_id, twin_id, 10);
+        run_to_block(11, Some(&mut pool_state));
+        check_report_cost(1, amount_due_as_u128, 11, discount_received);
 
-        let our_events = System::events();
-        assert_eq!(our_events.len(), 21);
+        let (amount_due_as_u128, discount_received) = calculate_tft_cost(contract_id, twin_id, 10);
+        run_to_block(21, Some(&mut pool_state));
+        check_report_cost(1, amount_due_as_u128, 21, discount_received);
 
-        for e in our_events.clone() {
-            log::info!("event: {:?}", e);
-        }
+        run_to_block(28, Some(&mut pool_state));
+        let (amount_due_as_u128, discount_received) = calculate_tft_cost(contract_id, twin_id, 7);
+        assert_ok!(SmartContractModule::cancel_contract(
+            RuntimeOrigin::signed(bob()),
+            1
+        ));
 
-        assert_eq!(
-            our_events[5],
-            record(MockEvent::SmartContractModule(SmartContractEvent::<
-                TestRuntime,
-            >::ContractGracePeriodStarted {
-                contract_id: rent_contract_id,
-                node_id,
-                twin_id: 3,
-                block_number: 11
-            }))
-        );
-        assert_eq!(
-            our_events[6],
-            record(MockEvent::SmartContractModule(SmartContractEvent::<
-                TestRuntime,
-            >::ContractGracePeriodStarted {
-                contract_id: node_contract_id,
-                node_id,
-                twin_id: 3,
-                block_number: 11
-            }))
-        );
+        run_to_block(29, Some(&mut pool_state));
+        check_report_cost(1, amount_due_as_u128, 28, discount_received);
 
-        assert_eq!(
-            our_events[19],
-            record(MockEvent::SmartContractModule(SmartContractEvent::<
-                TestRuntime,
-            >::NodeContractCanceled {
-                contract_id: node_contract_id,
-                node_id,
-                twin_id: 3
-            }))
-        );
-        assert_eq!(
-            our_events[20],
-            record(MockEvent::SmartContractModule(SmartContractEvent::<
-                TestRuntime,
-            >::RentContractCanceled {
-                contract_id: rent_contract_id
-            }))
-        );
+        let contract = SmartContractModule::contracts(1);
+        assert_eq!(contract, None);
+
+        let billing_info = SmartContractModule::contract_billing_information_by_id(contract_id);
+        assert_eq!(billing_info.amount_unbilled, 0);
     });
 }
 
 #[test]
-fn test_create_rent_contract_and_node_contract_with_ip_billing_works() {
+fn test_node_contract_billing_cycles_cancel_contract_during_cycle_without_balance_works() {
     let (mut ext, mut pool_state) = new_test_ext_with_pool_state(0);
     ext.execute_with(|| {
-        prepare_dedicated_farm_and_node();
-        run_to_block(1, Some(&mut pool_state));
-        TFTPriceModule::set_prices(RuntimeOrigin::signed(alice()), 50, 101).unwrap();
-
+        run_to_block(1, None);
+        prepare_farm_and_node();
         let node_id = 1;
-        assert_ok!(SmartContractModule::create_rent_contract(
-            RuntimeOrigin::signed(bob()),
-            node_id,
-            None
-        ));
-        let rent_contract_id = 1;
 
-        run_to_block(2, None);
+        TFTPriceModule::set_prices(RuntimeOrigin::signed(alice()), 50, 101).unwrap();
+
+        let twin = TfgridModule::twins(2).unwrap();
+        let initial_twin_balance = Balances::free_balance(&twin.account_id);
+        info!("initial twin balance: {:?}", initial_twin_balance);
+        let initial_total_issuance = Balances::total_issuance();
 
         assert_ok!(SmartContractModule::create_node_contract(
             RuntimeOrigin::signed(bob()),
             node_id,
             generate_deployment_hash(),
             get_deployment_data(),
-            1,
+            0,
             None
         ));
-        let node_contract_id = 2;
+        let contract_id = 1;
+        let twin_id = 2;
+        push_contract_resources_used(contract_id);
 
-        // 2 contracts => we expect 2 calls to bill_contract
+        let (amount_due_1, discount_received) = calculate_tft_cost(contract_id, twin_id, 10);
         pool_state
             .write()
-            .should_call_bill_contract(rent_contract_id, Ok(Pays::Yes.into()), 11);
+            .should_call_bill_contract(contract_id, Ok(Pays::Yes.into()), 11);
         run_to_block(11, Some(&mut pool_state));
+        check_report_cost(1, amount_due_1, 11, discount_received);
 
-        // check contract 1 costs (Rent Contract)
-        let (amount_due_as_u128, discount_received) = calculate_tft_cost(rent_contract_id, 2, 10);
-        assert_ne!(amount_due_as_u128, 0);
-        check_report_cost(rent_contract_id, amount_due_as_u128, 11, discount_received);
-
+        let (amount_due_2, discount_received) = calculate_tft_cost(contract_id, twin_id, 10);
         pool_state
             .write()
-            .should_call_bill_contract(node_contract_id, Ok(Pays::Yes.into()), 12);
-        run_to_block(12, Some(&mut pool_state));
+            .should_call_bill_contract(contract_id, Ok(Pays::Yes.into()), 21);
+        run_to_block(21, Some(&mut pool_state));
+        check_report_cost(1, amount_due_2, 21, discount_received);
 
-        // check contract 2 costs (Node Contract)
-        let (amount_due_as_u128, discount_received) = calculate_tft_cost(node_contract_id, 2, 10);
-        assert_ne!(amount_due_as_u128, 0);
-        check_report_cost(node_contract_id, amount_due_as_u128, 12, discount_received);
+        // Run halfway ish next cycle and cancel
+        run_to_block(25, Some(&mut pool_state));
 
-        let our_events = System::events();
-        assert_eq!(our_events.len(), 8);
+        let usable_balance = Balances::usable_balance(&twin.account_id);
+        let total_amount_billed = initial_twin_balance - usable_balance;
+
+        let extrinsic_fee = 10000;
+        Balances::transfer(
+            RuntimeOrigin::signed(bob()),
+            alice(),
+            initial_twin_balance - total_amount_billed - extrinsic_fee,
+        )
+        .unwrap();
+
+        let usable_balance_before_canceling = Balances::usable_balance(&twin.account_id);
+        assert_ne!(usable_balance_before_canceling, 0);
+
+        assert_ok!(SmartContractModule::cancel_contract(
+            RuntimeOrigin::signed(bob()),
+            contract_id
+        ));
+
+        // After canceling contract, and not being able to pay for the remainder of the cycle
+        // where the cancel was excecuted, the remaining balance should still be the same
+        let usable_balance_after_canceling = Balances::usable_balance(&twin.account_id);
+        assert_eq!(
+            usable_balance_after_canceling,
+            usable_balance_before_canceling
+        );
+
+        validate_distribution_rewards(initial_total_issuance, total_amount_billed, false);
     });
 }
 
 #[test]
-fn test_rent_contract_out_of_funds_should_move_state_to_graceperiod_works() {
+fn test_node_contract_out_of_funds_should_move_state_to_graceperiod_works() {
     let (mut ext, mut pool_state) = new_test_ext_with_pool_state(0);
     ext.execute_with(|| {
         run_to_block(1, None);
-        prepare_dedicated_farm_and_node();
+        prepare_farm_and_node();
         let node_id = 1;
 
         TFTPriceModule::set_prices(RuntimeOrigin::signed(alice()), 50, 101).unwrap();
 
-        assert_ok!(SmartContractModule::create_rent_contract(
+        assert_ok!(SmartContractModule::create_node_contract(
             RuntimeOrigin::signed(charlie()),
             node_id,
+            generate_deployment_hash(),
+            get_deployment_data(),
+            0,
             None
         ));
         let contract_id = 1;
 
+        push_contract_resources_used(contract_id);
+
         // cycle 1
-        // user does not have enough funds to pay for 1 cycle
         pool_state
             .write()
             .should_call_bill_contract(contract_id, Ok(Pays::Yes.into()), 11);
         run_to_block(11, Some(&mut pool_state));
 
+        // cycle 2
+        // user does not have enough funds to pay for 2 cycles
+        pool_state
+            .write()
+            .should_call_bill_contract(contract_id, Ok(Pays::Yes.into()), 21);
+        run_to_block(21, Some(&mut pool_state));
+
         let c1 = SmartContractModule::contracts(1).unwrap();
-        assert_eq!(c1.state, types::ContractState::GracePeriod(11));
+        assert_eq!(c1.state, types::ContractState::GracePeriod(21));
 
         let our_events = System::events();
         assert_eq!(
@@ -2336,7 +2870,7 @@ fn test_rent_contract_out_of_funds_should_move_state_to_graceperiod_works() {
                     contract_id,
                     node_id,
                     twin_id: 3,
-                    block_number: 11
+                    block_number: 21
                 }
             ))),
             true
@@ -2345,67 +2879,64 @@ fn test_rent_contract_out_of_funds_should_move_state_to_graceperiod_works() {
 }
 
 #[test]
-fn test_restore_rent_contract_in_grace_works() {
+fn test_restore_node_contract_in_grace_works() {
     let (mut ext, mut pool_state) = new_test_ext_with_pool_state(0);
     ext.execute_with(|| {
-        prepare_dedicated_farm_and_node();
-        let node_id = 1;
         run_to_block(1, None);
+        prepare_farm_and_node();
+        let node_id = 1;
 
         TFTPriceModule::set_prices(RuntimeOrigin::signed(alice()), 50, 101).unwrap();
 
-        assert_ok!(SmartContractModule::create_rent_contract(
+        assert_ok!(SmartContractModule::create_node_contract(
             RuntimeOrigin::signed(charlie()),
             node_id,
+            generate_deployment_hash(),
+            get_deployment_data(),
+            0,
             None
         ));
         let contract_id = 1;
 
+        for i in 0..6 {
+            let block_number = 11 + i * 10;
+            pool_state.write().should_call_bill_contract(
+                contract_id,
+                Ok(Pays::Yes.into()),
+                block_number,
+            );
+        }
+        push_contract_resources_used(contract_id);
+
         // cycle 1
-        pool_state
-            .write()
-            .should_call_bill_contract(contract_id, Ok(Pays::Yes.into()), 11);
         run_to_block(11, Some(&mut pool_state));
 
-        let c1 = SmartContractModule::contracts(contract_id).unwrap();
-        assert_eq!(c1.state, types::ContractState::GracePeriod(11));
+        // cycle 2
+        // user does not have enough funds to pay for 2 cycles
+        run_to_block(21, Some(&mut pool_state));
+
+        let c1 = SmartContractModule::contracts(1).unwrap();
+        assert_eq!(c1.state, types::ContractState::GracePeriod(21));
 
         let our_events = System::events();
-        info!("events: {:?}", our_events.clone());
         assert_eq!(
-            our_events[3],
-            record(MockEvent::SmartContractModule(SmartContractEvent::<
-                TestRuntime,
-            >::ContractGracePeriodStarted {
-                contract_id,
-                node_id,
-                twin_id: 3,
-                block_number: 11
-            }))
+            our_events.contains(&record(MockEvent::SmartContractModule(
+                SmartContractEvent::<TestRuntime>::ContractGracePeriodStarted {
+                    contract_id,
+                    node_id,
+                    twin_id: 3,
+                    block_number: 21
+                }
+            ))),
+            true
         );
 
-        pool_state
-            .write()
-            .should_call_bill_contract(contract_id, Ok(Pays::Yes.into()), 21);
-        run_to_block(21, Some(&mut pool_state));
-
-        pool_state
-            .write()
-            .should_call_bill_contract(contract_id, Ok(Pays::Yes.into()), 31);
         run_to_block(31, Some(&mut pool_state));
-
+        run_to_block(41, Some(&mut pool_state));
         // Transfer some balance to the owner of the contract to trigger the grace period to stop
         Balances::transfer(RuntimeOrigin::signed(bob()), charlie(), 100000000).unwrap();
-
-        pool_state
-            .write()
-            .should_call_bill_contract(contract_id, Ok(Pays::Yes.into()), 41);
-        run_to_block(41, Some(&mut pool_state));
-
-        pool_state
-            .write()
-            .should_call_bill_contract(contract_id, Ok(Pays::Yes.into()), 51);
-        run_to_block(51, Some(&mut pool_state));
+        run_to_block(52, Some(&mut pool_state));
+        run_to_block(62, Some(&mut pool_state));
 
         let c1 = SmartContractModule::contracts(contract_id).unwrap();
         assert_eq!(c1.state, types::ContractState::Created);
@@ -2413,23 +2944,18 @@ fn test_restore_rent_contract_in_grace_works() {
 }
 
 #[test]
-fn test_restore_rent_contract_and_node_contracts_in_grace_works() {
+fn test_node_contract_grace_period_cancels_contract_when_grace_period_ends_works() {
     let (mut ext, mut pool_state) = new_test_ext_with_pool_state(0);
     ext.execute_with(|| {
-        prepare_dedicated_farm_and_node();
-        let node_id = 1;
         run_to_block(1, None);
+        prepare_farm_and_node();
+        let node_id = 1;
 
         TFTPriceModule::set_prices(RuntimeOrigin::signed(alice()), 50, 101).unwrap();
 
-        assert_ok!(SmartContractModule::create_rent_contract(
-            RuntimeOrigin::signed(charlie()),
-            node_id,
-            None
-        ));
-        let rent_contract_id = 1;
-
-        run_to_block(2, None);
+        let twin = TfgridModule::twins(3).unwrap();
+        let initial_total_issuance = Balances::total_issuance();
+        let initial_twin_balance = Balances::free_balance(&twin.account_id);
 
         assert_ok!(SmartContractModule::create_node_contract(
             RuntimeOrigin::signed(charlie()),
@@ -2439,633 +2965,4843 @@ fn test_restore_rent_contract_and_node_contracts_in_grace_works() {
             0,
             None
         ));
-        let node_contract_id = 2;
-        push_contract_resources_used(node_contract_id);
+        let contract_id = 1;
+
+        push_contract_resources_used(contract_id);
 
         // cycle 1
         pool_state
             .write()
-            .should_call_bill_contract(rent_contract_id, Ok(Pays::Yes.into()), 11);
+            .should_call_bill_contract(contract_id, Ok(Pays::Yes.into()), 11);
         run_to_block(11, Some(&mut pool_state));
+
+        // cycle 2
+        // user does not have enough funds to pay for 2 cycles
         pool_state
             .write()
-            .should_call_bill_contract(node_contract_id, Ok(Pays::Yes.into()), 12);
-        run_to_block(12, Some(&mut pool_state));
+            .should_call_bill_contract(contract_id, Ok(Pays::Yes.into()), 21);
+        run_to_block(21, Some(&mut pool_state));
 
-        let c1 = SmartContractModule::contracts(rent_contract_id).unwrap();
-        assert_eq!(c1.state, types::ContractState::GracePeriod(11));
+        let c1 = SmartContractModule::contracts(1).unwrap();
+        assert_eq!(c1.state, types::ContractState::GracePeriod(21));
 
         let our_events = System::events();
         assert_eq!(
-            our_events[5],
-            record(MockEvent::SmartContractModule(SmartContractEvent::<
-                TestRuntime,
-            >::ContractGracePeriodStarted {
-                contract_id: rent_contract_id,
-                node_id,
-                twin_id: 3,
-                block_number: 11
-            }))
-        );
-        assert_eq!(
-            our_events[6],
-            record(MockEvent::SmartContractModule(SmartContractEvent::<
-                TestRuntime,
-            >::ContractGracePeriodStarted {
-                contract_id: node_contract_id,
-                node_id,
-                twin_id: 3,
-                block_number: 11
-            }))
+            our_events.contains(&record(MockEvent::SmartContractModule(
+                SmartContractEvent::<TestRuntime>::ContractGracePeriodStarted {
+                    contract_id,
+                    node_id,
+                    twin_id: 3,
+                    block_number: 21
+                }
+            ))),
+            true
         );
 
-        pool_state
-            .write()
-            .should_call_bill_contract(rent_contract_id, Ok(Pays::Yes.into()), 21);
-        run_to_block(21, Some(&mut pool_state));
-        pool_state
-            .write()
-            .should_call_bill_contract(node_contract_id, Ok(Pays::Yes.into()), 22);
-        run_to_block(22, Some(&mut pool_state));
+        // grace period stops after 100 blocknumbers, so after 121
+        for i in 1..11 {
+            let block_number = 21 + i * 10;
+            pool_state.write().should_call_bill_contract(
+                contract_id,
+                Ok(Pays::Yes.into()),
+                block_number,
+            );
+        }
 
-        pool_state
-            .write()
-            .should_call_bill_contract(rent_contract_id, Ok(Pays::Yes.into()), 31);
-        run_to_block(31, Some(&mut pool_state));
-        pool_state
-            .write()
-            .should_call_bill_contract(node_contract_id, Ok(Pays::Yes.into()), 32);
-        run_to_block(32, Some(&mut pool_state));
+        for i in 1..11 {
+            run_to_block(21 + i * 10, Some(&mut pool_state));
+        }
 
-        // Transfer some balance to the owner of the contract to trigger the grace period to stop
-        Balances::transfer(RuntimeOrigin::signed(bob()), charlie(), 100000000).unwrap();
+        // The user's total free balance should be distributed
+        let free_balance = Balances::free_balance(&twin.account_id);
+        let total_amount_billed = initial_twin_balance - free_balance;
 
-        pool_state
-            .write()
-            .should_call_bill_contract(rent_contract_id, Ok(Pays::Yes.into()), 41);
-        run_to_block(41, Some(&mut pool_state));
-        pool_state
-            .write()
-            .should_call_bill_contract(node_contract_id, Ok(Pays::Yes.into()), 42);
-        run_to_block(42, Some(&mut pool_state));
+        validate_distribution_rewards(initial_total_issuance, total_amount_billed, false);
 
-        pool_state
-            .write()
-            .should_call_bill_contract(rent_contract_id, Ok(Pays::Yes.into()), 51);
-        run_to_block(51, Some(&mut pool_state));
-        pool_state
-            .write()
-            .should_call_bill_contract(node_contract_id, Ok(Pays::Yes.into()), 52);
-        run_to_block(52, Some(&mut pool_state));
+        let c1 = SmartContractModule::contracts(contract_id);
+        assert_eq!(c1, None);
+    });
+}
 
-        let c1 = SmartContractModule::contracts(rent_contract_id).unwrap();
-        assert_eq!(c1.state, types::ContractState::Created);
+#[test]
+fn test_node_contract_grace_period_reminder_works() {
+    let (mut ext, mut pool_state) = new_test_ext_with_pool_state(0);
+    ext.execute_with(|| {
+        run_to_block(1, None);
+        prepare_farm_and_node();
+        let node_id = 1;
 
-        let our_events = System::events();
+        TFTPriceModule::set_prices(RuntimeOrigin::signed(alice()), 50, 101).unwrap();
+
+        assert_ok!(SmartContractModule::create_node_contract(
+            RuntimeOrigin::signed(charlie()),
+            node_id,
+            generate_deployment_hash(),
+            get_deployment_data(),
+            0,
+            None
+        ));
+        let contract_id = 1;
+        let twin_id = 3;
+
+        push_contract_resources_used(contract_id);
+
+        // user does not have enough funds to pay for 2 cycles
+        for i in 1..7 {
+            pool_state.write().should_call_bill_contract(
+                contract_id,
+                Ok(Pays::Yes.into()),
+                1 + i * 10,
+            );
+        }
+        for i in 1..3 {
+            run_to_block(1 + i * 10, Some(&mut pool_state));
+        }
 
+        let c1 = SmartContractModule::contracts(contract_id).unwrap();
+        assert_eq!(c1.state, types::ContractState::GracePeriod(21));
         assert_eq!(
-            our_events[8],
-            record(MockEvent::SmartContractModule(SmartContractEvent::<
-                TestRuntime,
-            >::ContractGracePeriodEnded {
-                contract_id: rent_contract_id,
-                node_id,
-                twin_id: 3,
-            }))
+            SmartContractModule::get_contracts_in_grace(twin_id),
+            vec![types::ContractInGrace {
+                contract_id,
+                grace_period_start: 21,
+                remaining_blocks: 100,
+            }]
         );
+
+        // reminders are sent every 20 blocks, at 41 and 61
+        for i in 3..7 {
+            run_to_block(1 + i * 10, Some(&mut pool_state));
+        }
+
+        let reminders: Vec<(u64, u64)> = System::events()
+            .into_iter()
+            .filter_map(|record| match record.event {
+                MockEvent::SmartContractModule(
+                    SmartContractEvent::<TestRuntime>::ContractGracePeriodReminder {
+                        contract_id: id,
+                        twin_id: twin,
+                        remaining_blocks,
+                        amount_needed,
+                        ..
+                    },
+                ) if id == contract_id && twin == twin_id => {
+                    assert!(amount_needed > 0);
+                    Some((remaining_blocks, amount_needed))
+                }
+                _ => None,
+            })
+            .collect();
         assert_eq!(
-            our_events[9],
-            record(MockEvent::SmartContractModule(SmartContractEvent::<
-                TestRuntime,
-            >::ContractGracePeriodEnded {
-                contract_id: node_contract_id,
-                node_id,
-                twin_id: 3,
-            }))
+            reminders.iter().map(|(r, _)| *r).collect::<Vec<u64>>(),
+            vec![80, 60]
+        );
+        assert_eq!(
+            SmartContractModule::last_grace_period_reminder(contract_id),
+            Some(61)
+        );
+        assert_eq!(
+            SmartContractModule::get_contracts_in_grace(twin_id)[0].remaining_blocks,
+            60
         );
     });
 }
 
 #[test]
-fn test_rent_contract_grace_period_cancels_contract_when_grace_period_ends_works() {
+fn test_contracts_in_grace_cleared_when_grace_period_ends_works() {
     let (mut ext, mut pool_state) = new_test_ext_with_pool_state(0);
     ext.execute_with(|| {
         run_to_block(1, None);
-        prepare_dedicated_farm_and_node();
+        prepare_farm_and_node();
         let node_id = 1;
 
         TFTPriceModule::set_prices(RuntimeOrigin::signed(alice()), 50, 101).unwrap();
 
-        assert_ok!(SmartContractModule::create_rent_contract(
+        assert_ok!(SmartContractModule::create_node_contract(
             RuntimeOrigin::signed(charlie()),
             node_id,
+            generate_deployment_hash(),
+            get_deployment_data(),
+            0,
             None
         ));
         let contract_id = 1;
+        let twin_id = 3;
 
-        // cycle 1
-        pool_state
-            .write()
-            .should_call_bill_contract(contract_id, Ok(Pays::Yes.into()), 11);
-        run_to_block(11, Some(&mut pool_state));
-
-        let c1 = SmartContractModule::contracts(contract_id).unwrap();
-        assert_eq!(c1.state, types::ContractState::GracePeriod(11));
-
-        let our_events = System::events();
-        assert_eq!(
-            our_events.contains(&record(MockEvent::SmartContractModule(
-                SmartContractEvent::<TestRuntime>::ContractGracePeriodStarted {
-                    contract_id,
-                    node_id,
-                    twin_id: 3,
-                    block_number: 11
-                }
-            ))),
-            true
-        );
+        push_contract_resources_used(contract_id);
 
-        // run 9 more cycles
-        for i in 0..10 {
-            let block_number = 21 + i * 10;
+        for i in 1..6 {
             pool_state.write().should_call_bill_contract(
                 contract_id,
                 Ok(Pays::Yes.into()),
-                block_number,
+                1 + i * 10,
             );
-            run_to_block(block_number, Some(&mut pool_state));
         }
+        for i in 1..5 {
+            run_to_block(1 + i * 10, Some(&mut pool_state));
+        }
+        assert!(crate::TwinContractsInGrace::<TestRuntime>::contains_key(
+            twin_id,
+            contract_id
+        ));
+        assert!(SmartContractModule::last_grace_period_reminder(contract_id).is_some());
 
-        // after 10 cycles grace period has finished
-        // so contract is removed and no more billing!
-        let c1 = SmartContractModule::contracts(contract_id);
-        assert_eq!(c1, None);
+        // Transfer some balance to the owner of the contract to trigger the grace period to stop
+        Balances::transfer(RuntimeOrigin::signed(bob()), charlie(), 100000000).unwrap();
+        run_to_block(51, Some(&mut pool_state));
 
-        // Ensure contract_id is not in billing loop anymore
-        let index = SmartContractModule::get_billing_loop_index_from_contract_id(contract_id);
+        let c1 = SmartContractModule::contracts(contract_id).unwrap();
+        assert_eq!(c1.state, types::ContractState::Created);
+        assert!(
+            crate::TwinContractsInGrace::<TestRuntime>::iter_key_prefix(twin_id)
+                .next()
+                .is_none()
+        );
+        assert!(SmartContractModule::get_contracts_in_grace(twin_id).is_empty());
         assert_eq!(
-            SmartContractModule::contract_to_bill_at_block(index),
-            Vec::<u64>::new()
+            SmartContractModule::last_grace_period_reminder(contract_id),
+            None
         );
     });
 }
 
 #[test]
-fn test_rent_contract_and_node_contract_canceled_when_node_is_deleted_works() {
+fn test_restore_contracts_from_grace_works() {
     let (mut ext, mut pool_state) = new_test_ext_with_pool_state(0);
     ext.execute_with(|| {
         run_to_block(1, None);
-        prepare_dedicated_farm_and_node();
+        prepare_farm_and_node();
         let node_id = 1;
 
         TFTPriceModule::set_prices(RuntimeOrigin::signed(alice()), 50, 101).unwrap();
 
-        assert_ok!(SmartContractModule::create_rent_contract(
-            RuntimeOrigin::signed(bob()),
-            node_id,
-            None
-        ));
-        let rent_contract_id = 1;
-
-        run_to_block(2, None);
-
         assert_ok!(SmartContractModule::create_node_contract(
-            RuntimeOrigin::signed(bob()),
+            RuntimeOrigin::signed(charlie()),
             node_id,
             generate_deployment_hash(),
             get_deployment_data(),
             0,
             None
         ));
-        let node_contract_id = 2;
-        push_contract_resources_used(node_contract_id);
+        let contract_id = 1;
+        let twin_id = 3;
 
-        // 2 contracts => 2 calls to bill_contract
-        pool_state
-            .write()
-            .should_call_bill_contract(rent_contract_id, Ok(Pays::Yes.into()), 11);
-        run_to_block(11, Some(&mut pool_state));
-        pool_state
-            .write()
-            .should_call_bill_contract(node_contract_id, Ok(Pays::Yes.into()), 12);
-        run_to_block(12, Some(&mut pool_state));
+        push_contract_resources_used(contract_id);
 
-        run_to_block(16, Some(&mut pool_state));
+        // user does not have enough funds to pay for 2 cycles
+        for i in 1..3 {
+            pool_state.write().should_call_bill_contract(
+                contract_id,
+                Ok(Pays::Yes.into()),
+                1 + i * 10,
+            );
+            run_to_block(1 + i * 10, Some(&mut pool_state));
+        }
+        let c1 = SmartContractModule::contracts(contract_id).unwrap();
+        assert_eq!(c1.state, types::ContractState::GracePeriod(21));
 
-        // Delete node
-        TfgridModule::delete_node_farm(RuntimeOrigin::signed(alice()), node_id).unwrap();
+        // Nothing happens while the twin can not pay
+        run_to_block(23, Some(&mut pool_state));
+        assert_ok!(SmartContractModule::restore_contracts_from_grace(
+            RuntimeOrigin::signed(bob()),
+            twin_id
+        ));
+        let c1 = SmartContractModule::contracts(contract_id).unwrap();
+        assert_eq!(c1.state, types::ContractState::GracePeriod(21));
 
-        let our_events = System::events();
+        // Top up and restore without waiting for next billing cycle
+        Balances::transfer(RuntimeOrigin::signed(bob()), charlie(), 100000000).unwrap();
+        run_to_block(25, Some(&mut pool_state));
+        assert_ok!(SmartContractModule::restore_contracts_from_grace(
+            RuntimeOrigin::signed(charlie()),
+            twin_id
+        ));
 
-        let ip = b"1.1.1.0".to_vec();
-        let mut ips = Vec::new();
-        ips.push(ip);
+        let c1 = SmartContractModule::contracts(contract_id).unwrap();
+        assert_eq!(c1.state, types::ContractState::Created);
+        assert!(
+            crate::TwinContractsInGrace::<TestRuntime>::iter_key_prefix(twin_id)
+                .next()
+                .is_none()
+        );
 
+        let our_events = System::events();
         assert_eq!(
             our_events.contains(&record(MockEvent::SmartContractModule(
-                SmartContractEvent::<TestRuntime>::NodeContractCanceled {
-                    contract_id: node_contract_id,
+                SmartContractEvent::<TestRuntime>::ContractGracePeriodEnded {
+                    contract_id,
                     node_id,
-                    twin_id: 2
+                    twin_id,
                 }
             ))),
             true
         );
+
+        // Amount accumulated during grace period is paid
+        let contract_lock = SmartContractModule::contract_number_of_cylces_billed(contract_id);
+        assert_eq!(contract_lock.amount_locked, 0);
+        assert_eq!(contract_lock.cycles, 0);
+        let twin = TfgridModule::twins(twin_id).unwrap();
         assert_eq!(
-            our_events.contains(&record(MockEvent::SmartContractModule(
-                SmartContractEvent::<TestRuntime>::RentContractCanceled {
-                    contract_id: rent_contract_id
+            Balances::free_balance(&twin.account_id),
+            Balances::usable_balance(&twin.account_id)
+        );
+    });
+}
+
+#[test]
+fn test_restore_contracts_from_grace_billed_in_same_block_works() {
+    let (mut ext, mut pool_state) = new_test_ext_with_pool_state(0);
+    ext.execute_with(|| {
+        run_to_block(1, None);
+        prepare_farm_and_node();
+        let node_id = 1;
+
+        TFTPriceModule::set_prices(RuntimeOrigin::signed(alice()), 50, 101).unwrap();
+
+        assert_ok!(SmartContractModule::create_node_contract(
+            RuntimeOrigin::signed(charlie()),
+            node_id,
+            generate_deployment_hash(),
+            get_deployment_data(),
+            0,
+            None
+        ));
+        let contract_id = 1;
+        let twin_id = 3;
+
+        push_contract_resources_used(contract_id);
+
+        for i in 1..3 {
+            pool_state.write().should_call_bill_contract(
+                contract_id,
+                Ok(Pays::Yes.into()),
+                1 + i * 10,
+            );
+            run_to_block(1 + i * 10, Some(&mut pool_state));
+        }
+        let c1 = SmartContractModule::contracts(contract_id).unwrap();
+        assert_eq!(c1.state, types::ContractState::GracePeriod(21));
+        let contract_lock = SmartContractModule::contract_number_of_cylces_billed(contract_id);
+        assert!(contract_lock.amount_locked > 0);
+
+        // Top up and restore in the block the contract was billed in, there is nothing left to bill
+        Balances::transfer(RuntimeOrigin::signed(bob()), charlie(), 100000000).unwrap();
+        assert_ok!(SmartContractModule::restore_contracts_from_grace(
+            RuntimeOrigin::signed(charlie()),
+            twin_id
+        ));
+
+        let c1 = SmartContractModule::contracts(contract_id).unwrap();
+        assert_eq!(c1.state, types::ContractState::Created);
+        assert!(!crate::TwinContractsInGrace::<TestRuntime>::contains_key(
+            twin_id,
+            contract_id
+        ));
+
+        let contract_lock = SmartContractModule::contract_number_of_cylces_billed(contract_id);
+        assert_eq!(contract_lock.amount_locked, 0);
+    });
+}
+
+#[test]
+fn test_restore_contracts_from_grace_skips_failing_contract_works() {
+    let (mut ext, mut pool_state) = new_test_ext_with_pool_state(0);
+    ext.execute_with(|| {
+        run_to_block(1, None);
+        prepare_farm_and_node();
+        let node_id = 1;
+
+        TFTPriceModule::set_prices(RuntimeOrigin::signed(alice()), 50, 101).unwrap();
+
+        assert_ok!(SmartContractModule::create_node_contract(
+            RuntimeOrigin::signed(charlie()),
+            node_id,
+            generate_deployment_hash(),
+            get_deployment_data(),
+            0,
+            None
+        ));
+        let contract_id = 1;
+        let twin_id = 3;
+
+        push_contract_resources_used(contract_id);
+
+        for i in 1..3 {
+            pool_state.write().should_call_bill_contract(
+                contract_id,
+                Ok(Pays::Yes.into()),
+                1 + i * 10,
+            );
+            run_to_block(1 + i * 10, Some(&mut pool_state));
+        }
+        let c1 = SmartContractModule::contracts(contract_id).unwrap();
+        assert_eq!(c1.state, types::ContractState::GracePeriod(21));
+
+        // Contract in grace that fails to be billed because its twin does not exist
+        let failing_contract_id = 2;
+        let mut failing_contract = c1.clone();
+        failing_contract.contract_id = failing_contract_id;
+        failing_contract.twin_id = 99;
+        crate::Contracts::<TestRuntime>::insert(failing_contract_id, failing_contract);
+        crate::TwinContractsInGrace::<TestRuntime>::insert(twin_id, failing_contract_id, ());
+
+        Balances::transfer(RuntimeOrigin::signed(bob()), charlie(), 100000000).unwrap();
+        run_to_block(25, Some(&mut pool_state));
+        assert_ok!(SmartContractModule::restore_contracts_from_grace(
+            RuntimeOrigin::signed(charlie()),
+            twin_id
+        ));
+
+        let c1 = SmartContractModule::contracts(contract_id).unwrap();
+        assert_eq!(c1.state, types::ContractState::Created);
+        assert_eq!(
+            crate::TwinContractsInGrace::<TestRuntime>::iter_key_prefix(twin_id)
+                .collect::<Vec<u64>>(),
+            vec![failing_contract_id]
+        );
+    });
+}
+
+#[test]
+fn test_restore_contracts_from_grace_no_contracts_in_grace_fails() {
+    new_test_ext().execute_with(|| {
+        run_to_block(1, None);
+        prepare_farm_node_and_node_contract();
+
+        assert_noop!(
+            SmartContractModule::restore_contracts_from_grace(RuntimeOrigin::signed(alice()), 1),
+            Error::<TestRuntime>::NoContractsInGrace
+        );
+    });
+}
+
+#[test]
+fn test_node_contract_grace_period_follows_contract_kind_grace_period_works() {
+    let (mut ext, mut pool_state) = new_test_ext_with_pool_state(0);
+    ext.execute_with(|| {
+        run_to_block(1, None);
+        prepare_farm_and_node();
+        let node_id = 1;
+
+        TFTPriceModule::set_prices(RuntimeOrigin::signed(alice()), 50, 101).unwrap();
+
+        assert_ok!(SmartContractModule::set_contract_kind_grace_period(
+            RawOrigin::Root.into(),
+            types::ContractKind::Node,
+            Some(30)
+        ));
+
+        assert_ok!(SmartContractModule::create_node_contract(
+            RuntimeOrigin::signed(charlie()),
+            node_id,
+            generate_deployment_hash(),
+            get_deployment_data(),
+            0,
+            None
+        ));
+        let contract_id = 1;
+
+        push_contract_resources_used(contract_id);
+
+        // user does not have enough funds to pay for 2 cycles
+        for i in 1..6 {
+            pool_state.write().should_call_bill_contract(
+                contract_id,
+                Ok(Pays::Yes.into()),
+                1 + i * 10,
+            );
+        }
+
+        for i in 1..3 {
+            run_to_block(1 + i * 10, Some(&mut pool_state));
+        }
+        let c1 = SmartContractModule::contracts(contract_id).unwrap();
+        assert_eq!(c1.state, types::ContractState::GracePeriod(21));
+
+        // grace period stops after 30 blocknumbers, so after 51
+        for i in 3..5 {
+            run_to_block(1 + i * 10, Some(&mut pool_state));
+        }
+        assert!(SmartContractModule::contracts(contract_id).is_some());
+
+        run_to_block(51, Some(&mut pool_state));
+        assert_eq!(SmartContractModule::contracts(contract_id), None);
+    });
+}
+
+#[test]
+fn test_solution_provider_grace_period_takes_precedence_works() {
+    new_test_ext().execute_with(|| {
+        run_to_block(1, None);
+        prepare_farm_and_node();
+        let node_id = 1;
+
+        prepare_solution_provider(alice());
+        let provider_id = 1;
+
+        assert_ok!(SmartContractModule::create_node_contract(
+            RuntimeOrigin::signed(alice()),
+            node_id,
+            generate_deployment_hash(),
+            get_deployment_data(),
+            0,
+            Some(provider_id)
+        ));
+        let contract = SmartContractModule::contracts(1).unwrap();
+
+        // Runtime default
+        assert_eq!(SmartContractModule::get_grace_period(&contract), 100);
+
+        assert_ok!(SmartContractModule::set_contract_kind_grace_period(
+            RawOrigin::Root.into(),
+            types::ContractKind::Node,
+            Some(200)
+        ));
+        assert_eq!(SmartContractModule::get_grace_period(&contract), 200);
+
+        assert_ok!(SmartContractModule::set_solution_provider_grace_period(
+            RawOrigin::Root.into(),
+            provider_id,
+            Some(500)
+        ));
+        assert_eq!(SmartContractModule::get_grace_period(&contract), 500);
+
+        assert_ok!(SmartContractModule::set_solution_provider_grace_period(
+            RawOrigin::Root.into(),
+            provider_id,
+            None
+        ));
+        assert_eq!(SmartContractModule::get_grace_period(&contract), 200);
+    });
+}
+
+#[test]
+fn test_set_contract_kind_grace_period_fails() {
+    new_test_ext().execute_with(|| {
+        run_to_block(1, None);
+
+        assert_noop!(
+            SmartContractModule::set_contract_kind_grace_period(
+                RuntimeOrigin::signed(alice()),
+                types::ContractKind::Name,
+                Some(10)
+            ),
+            sp_runtime::DispatchError::BadOrigin
+        );
+
+        assert_noop!(
+            SmartContractModule::set_contract_kind_grace_period(
+                RawOrigin::Root.into(),
+                types::ContractKind::Name,
+                Some(0)
+            ),
+            Error::<TestRuntime>::InvalidGracePeriod
+        );
+
+        assert_noop!(
+            SmartContractModule::set_solution_provider_grace_period(
+                RawOrigin::Root.into(),
+                1,
+                Some(10)
+            ),
+            Error::<TestRuntime>::NoSuchSolutionProvider
+        );
+    });
+}
+
+#[test]
+fn test_create_name_contract_with_term_works() {
+    new_test_ext().execute_with(|| {
+        run_to_block(1, None);
+        prepare_farm_and_node();
+
+        let term = 365 * 24 * 60 * 60;
+        assert_ok!(SmartContractModule::create_name_contract_with_term(
+            RuntimeOrigin::signed(bob()),
+            b"foobar".to_vec(),
+            term
+        ));
+        let contract_id = 1;
+
+        assert_eq!(
+            SmartContractModule::name_contract_expiry(contract_id),
+            Some(get_timestamp_in_seconds_for_block(1) + term)
+        );
+    });
+}
+
+#[test]
+fn test_create_name_contract_with_invalid_term_fails() {
+    new_test_ext().execute_with(|| {
+        run_to_block(1, None);
+        prepare_farm_and_node();
+
+        assert_noop!(
+            SmartContractModule::create_name_contract_with_term(
+                RuntimeOrigin::signed(bob()),
+                b"foobar".to_vec(),
+                0
+            ),
+            Error::<TestRuntime>::InvalidNameContractTerm
+        );
+
+        assert_noop!(
+            SmartContractModule::create_name_contract_with_term(
+                RuntimeOrigin::signed(bob()),
+                b"foobar".to_vec(),
+                types::MAX_NAME_CONTRACT_TERM + 1
+            ),
+            Error::<TestRuntime>::InvalidNameContractTerm
+        );
+    });
+}
+
+#[test]
+fn test_renew_name_contract_works() {
+    new_test_ext().execute_with(|| {
+        run_to_block(1, None);
+        prepare_farm_and_node();
+
+        let term = 60;
+        assert_ok!(SmartContractModule::create_name_contract_with_term(
+            RuntimeOrigin::signed(bob()),
+            b"foobar".to_vec(),
+            term
+        ));
+        let contract_id = 1;
+        let expires_at = SmartContractModule::name_contract_expiry(contract_id).unwrap();
+
+        assert_ok!(SmartContractModule::renew_name_contract(
+            RuntimeOrigin::signed(bob()),
+            contract_id,
+            term
+        ));
+        assert_eq!(
+            SmartContractModule::name_contract_expiry(contract_id),
+            Some(expires_at + term)
+        );
+
+        let our_events = System::events();
+        assert_eq!(
+            our_events.last().unwrap(),
+            &record(MockEvent::SmartContractModule(SmartContractEvent::<
+                TestRuntime,
+            >::NameContractRenewed {
+                contract_id,
+                expires_at: expires_at + term,
+            }))
+        );
+    });
+}
+
+#[test]
+fn test_renew_name_contract_by_other_twin_fails() {
+    new_test_ext().execute_with(|| {
+        run_to_block(1, None);
+        prepare_farm_and_node();
+
+        assert_ok!(SmartContractModule::create_name_contract_with_term(
+            RuntimeOrigin::signed(bob()),
+            b"foobar".to_vec(),
+            60
+        ));
+        let contract_id = 1;
+
+        assert_noop!(
+            SmartContractModule::renew_name_contract(
+                RuntimeOrigin::signed(alice()),
+                contract_id,
+                60
+            ),
+            Error::<TestRuntime>::TwinNotAuthorizedToUpdateContract
+        );
+    });
+}
+
+#[test]
+fn test_renew_name_contract_without_term_fails() {
+    new_test_ext().execute_with(|| {
+        run_to_block(1, None);
+        prepare_farm_and_node();
+
+        assert_ok!(SmartContractModule::create_name_contract(
+            RuntimeOrigin::signed(bob()),
+            b"foobar".to_vec()
+        ));
+        let contract_id = 1;
+
+        assert_noop!(
+            SmartContractModule::renew_name_contract(RuntimeOrigin::signed(bob()), contract_id, 60),
+            Error::<TestRuntime>::NameContractHasNoTerm
+        );
+    });
+}
+
+#[test]
+fn test_name_availability_works() {
+    new_test_ext().execute_with(|| {
+        run_to_block(1, None);
+        prepare_farm_and_node();
+
+        assert_eq!(
+            SmartContractModule::get_name_availability(b"foobar".to_vec()),
+            types::NameAvailability::Available
+        );
+        assert_eq!(
+            SmartContractModule::get_name_availability(b"foo.bar".to_vec()),
+            types::NameAvailability::Invalid
+        );
+
+        assert_ok!(SmartContractModule::create_name_contract_with_term(
+            RuntimeOrigin::signed(bob()),
+            b"foobar".to_vec(),
+            60
+        ));
+        let contract_id = 1;
+        let expires_at = SmartContractModule::name_contract_expiry(contract_id).unwrap();
+
+        assert_eq!(
+            SmartContractModule::get_name_availability(b"foobar".to_vec()),
+            types::NameAvailability::Registered {
+                contract_id,
+                expires_at: Some(expires_at),
+            }
+        );
+
+        // Term is over after 10 blocks
+        run_to_block(11, None);
+        assert_eq!(
+            SmartContractModule::get_name_availability(b"foobar".to_vec()),
+            types::NameAvailability::Held {
+                contract_id,
+                released_at: expires_at + types::DEFAULT_NAME_CONTRACT_HOLD_PERIOD,
+            }
+        );
+    });
+}
+
+#[test]
+fn test_transfer_expired_name_contract_fails() {
+    new_test_ext().execute_with(|| {
+        run_to_block(1, None);
+        prepare_farm_and_node();
+
+        assert_ok!(SmartContractModule::create_name_contract_with_term(
+            RuntimeOrigin::signed(bob()),
+            b"foobar".to_vec(),
+            60
+        ));
+        let contract_id = 1;
+
+        assert_ok!(SmartContractModule::offer_contract_transfer(
+            RuntimeOrigin::signed(bob()),
+            contract_id,
+            1
+        ));
+
+        run_to_block(11, None);
+        assert_noop!(
+            SmartContractModule::accept_contract_transfer(
+                RuntimeOrigin::signed(alice()),
+                contract_id
+            ),
+            Error::<TestRuntime>::NameContractExpired
+        );
+    });
+}
+
+#[test]
+fn test_expired_name_contract_is_not_billed_while_held_works() {
+    let (mut ext, mut pool_state) = new_test_ext_with_pool_state(0);
+    ext.execute_with(|| {
+        run_to_block(1, None);
+        prepare_farm_and_node();
+        TFTPriceModule::set_prices(RuntimeOrigin::signed(alice()), 50, 101).unwrap();
+
+        assert_ok!(SmartContractModule::create_name_contract_with_term(
+            RuntimeOrigin::signed(bob()),
+            b"foobar".to_vec(),
+            60
+        ));
+        let contract_id = 1;
+        let expires_at = get_timestamp_in_seconds_for_block(11);
+
+        // Billed up to its expiry, then held
+        for block_number in [11, 21] {
+            pool_state.write().should_call_bill_contract(
+                contract_id,
+                Ok(Pays::Yes.into()),
+                block_number,
+            );
+        }
+        run_to_block(21, Some(&mut pool_state));
+
+        let contract = SmartContractModule::contracts(contract_id).unwrap();
+        assert_eq!(contract.state, types::ContractState::Created);
+        assert_eq!(
+            SmartContractModule::contract_billing_history_count(contract_id),
+            1
+        );
+        assert_eq!(
+            SmartContractModule::contract_number_of_cylces_billed(contract_id).lock_updated,
+            expires_at
+        );
+
+        let held_events: Vec<_> = System::events()
+            .into_iter()
+            .filter(|record| {
+                matches!(
+                    record.event,
+                    MockEvent::SmartContractModule(
+                        SmartContractEvent::<TestRuntime>::NameContractExpired { .. }
+                    )
+                )
+            })
+            .collect();
+        assert_eq!(held_events.len(), 1);
+        assert_eq!(
+            held_events[0],
+            record(MockEvent::SmartContractModule(SmartContractEvent::<
+                TestRuntime,
+            >::NameContractExpired {
+                contract_id,
+                name: get_name_contract_name(b"foobar"),
+                released_at: expires_at + types::DEFAULT_NAME_CONTRACT_HOLD_PERIOD,
+            }))
+        );
+
+        // The time the name was held is not billed after renewal
+        run_to_block(25, Some(&mut pool_state));
+        assert_ok!(SmartContractModule::renew_name_contract(
+            RuntimeOrigin::signed(bob()),
+            contract_id,
+            600
+        ));
+        assert_eq!(
+            SmartContractModule::contract_number_of_cylces_billed(contract_id).lock_updated,
+            get_timestamp_in_seconds_for_block(25)
+        );
+    });
+}
+
+#[test]
+fn test_expired_name_contract_is_removed_after_hold_period() {
+    let (mut ext, mut pool_state) = new_test_ext_with_pool_state(0);
+    ext.execute_with(|| {
+        run_to_block(1, None);
+        prepare_farm_and_node();
+        TFTPriceModule::set_prices(RuntimeOrigin::signed(alice()), 50, 101).unwrap();
+
+        assert_ok!(SmartContractModule::set_name_contract_hold_period(
+            RawOrigin::Root.into(),
+            0
+        ));
+        assert_ok!(SmartContractModule::create_name_contract_with_term(
+            RuntimeOrigin::signed(bob()),
+            b"foobar".to_vec(),
+            60
+        ));
+        let contract_id = 1;
+
+        pool_state
+            .write()
+            .should_call_bill_contract(contract_id, Ok(Pays::Yes.into()), 11);
+        run_to_block(11, Some(&mut pool_state));
+
+        assert_eq!(SmartContractModule::contracts(contract_id), None);
+        assert_eq!(SmartContractModule::name_contract_expiry(contract_id), None);
+        assert_eq!(
+            SmartContractModule::get_name_availability(b"foobar".to_vec()),
+            types::NameAvailability::Available
+        );
+    });
+}
+
+#[test]
+fn test_create_name_contract_with_blocked_name_fails() {
+    new_test_ext().execute_with(|| {
+        run_to_block(1, None);
+        prepare_farm_and_node();
+
+        assert_ok!(SmartContractModule::add_name_restriction(
+            RawOrigin::Root.into(),
+            b"admin".to_vec(),
+            types::NameMatching::Exact,
+            types::NameRestriction::Blocked
+        ));
+        assert_ok!(SmartContractModule::add_name_restriction(
+            RawOrigin::Root.into(),
+            b"grid".to_vec(),
+            types::NameMatching::Prefix,
+            types::NameRestriction::Blocked
+        ));
+
+        assert_noop!(
+            SmartContractModule::create_name_contract(
+                RuntimeOrigin::signed(bob()),
+                b"admin".to_vec()
+            ),
+            Error::<TestRuntime>::NameBlocked
+        );
+        assert_noop!(
+            SmartContractModule::create_name_contract(
+                RuntimeOrigin::signed(bob()),
+                b"gridnode".to_vec()
+            ),
+            Error::<TestRuntime>::NameBlocked
+        );
+
+        // Exact restrictions only apply to the name itself
+        assert_ok!(SmartContractModule::create_name_contract(
+            RuntimeOrigin::signed(bob()),
+            b"admins".to_vec()
+        ));
+    });
+}
+
+#[test]
+fn test_create_name_contract_with_reserved_name_works_for_assigned_twin() {
+    new_test_ext().execute_with(|| {
+        run_to_block(1, None);
+        prepare_farm_and_node();
+
+        assert_ok!(SmartContractModule::add_name_restriction(
+            RawOrigin::Root.into(),
+            b"tfgrid".to_vec(),
+            types::NameMatching::Exact,
+            types::NameRestriction::Reserved { twin_id: None }
+        ));
+        assert_noop!(
+            SmartContractModule::create_name_contract(
+                RuntimeOrigin::signed(bob()),
+                b"tfgrid".to_vec()
+            ),
+            Error::<TestRuntime>::NameReserved
+        );
+
+        let twin_id = 2;
+        assert_ok!(SmartContractModule::assign_reserved_name(
+            RawOrigin::Root.into(),
+            b"tfgrid".to_vec(),
+            types::NameMatching::Exact,
+            Some(twin_id)
+        ));
+        assert_noop!(
+            SmartContractModule::create_name_contract(
+                RuntimeOrigin::signed(alice()),
+                b"tfgrid".to_vec()
+            ),
+            Error::<TestRuntime>::NameReserved
+        );
+        assert_ok!(SmartContractModule::create_name_contract(
+            RuntimeOrigin::signed(bob()),
+            b"tfgrid".to_vec()
+        ));
+    });
+}
+
+#[test]
+fn test_longest_prefix_name_restriction_applies() {
+    new_test_ext().execute_with(|| {
+        run_to_block(1, None);
+        prepare_farm_and_node();
+
+        assert_ok!(SmartContractModule::add_name_restriction(
+            RawOrigin::Root.into(),
+            b"grid".to_vec(),
+            types::NameMatching::Prefix,
+            types::NameRestriction::Blocked
+        ));
+        assert_ok!(SmartContractModule::add_name_restriction(
+            RawOrigin::Root.into(),
+            b"grid-team".to_vec(),
+            types::NameMatching::Prefix,
+            types::NameRestriction::Reserved { twin_id: Some(2) }
+        ));
+
+        assert_eq!(
+            SmartContractModule::get_name_availability(b"grid-team-a".to_vec()),
+            types::NameAvailability::Reserved { twin_id: Some(2) }
+        );
+        assert_eq!(
+            SmartContractModule::get_name_availability(b"grid-a".to_vec()),
+            types::NameAvailability::Blocked
+        );
+
+        assert_ok!(SmartContractModule::create_name_contract(
+            RuntimeOrigin::signed(bob()),
+            b"grid-team-a".to_vec()
+        ));
+        assert_noop!(
+            SmartContractModule::create_name_contract(
+                RuntimeOrigin::signed(bob()),
+                b"grid-a".to_vec()
+            ),
+            Error::<TestRuntime>::NameBlocked
+        );
+    });
+}
+
+#[test]
+fn test_manage_name_restrictions_works() {
+    new_test_ext().execute_with(|| {
+        run_to_block(1, None);
+        prepare_farm_and_node();
+
+        assert_ok!(SmartContractModule::add_name_restriction(
+            RawOrigin::Root.into(),
+            b"admin".to_vec(),
+            types::NameMatching::Exact,
+            types::NameRestriction::Blocked
+        ));
+        assert_noop!(
+            SmartContractModule::add_name_restriction(
+                RawOrigin::Root.into(),
+                b"admin".to_vec(),
+                types::NameMatching::Exact,
+                types::NameRestriction::Reserved { twin_id: None }
+            ),
+            Error::<TestRuntime>::NameRestrictionExists
+        );
+        assert_noop!(
+            SmartContractModule::assign_reserved_name(
+                RawOrigin::Root.into(),
+                b"admin".to_vec(),
+                types::NameMatching::Exact,
+                Some(1)
+            ),
+            Error::<TestRuntime>::NameRestrictionNotReserved
+        );
+
+        assert_ok!(SmartContractModule::remove_name_restriction(
+            RawOrigin::Root.into(),
+            b"admin".to_vec(),
+            types::NameMatching::Exact
+        ));
+        assert_eq!(
+            SmartContractModule::get_name_availability(b"admin".to_vec()),
+            types::NameAvailability::Available
+        );
+        assert_noop!(
+            SmartContractModule::remove_name_restriction(
+                RawOrigin::Root.into(),
+                b"admin".to_vec(),
+                types::NameMatching::Exact
+            ),
+            Error::<TestRuntime>::NameRestrictionNotExists
+        );
+    });
+}
+
+#[test]
+fn test_add_name_restriction_not_council_fails() {
+    new_test_ext().execute_with(|| {
+        run_to_block(1, None);
+        prepare_farm_and_node();
+
+        assert_noop!(
+            SmartContractModule::add_name_restriction(
+                RuntimeOrigin::signed(alice()),
+                b"admin".to_vec(),
+                types::NameMatching::Exact,
+                types::NameRestriction::Blocked
+            ),
+            sp_runtime::DispatchError::BadOrigin
+        );
+    });
+}
+
+#[test]
+fn test_name_contract_billing() {
+    let (mut ext, mut pool_state) = new_test_ext_with_pool_state(0);
+    ext.execute_with(|| {
+        prepare_farm_and_node();
+        run_to_block(1, None);
+
+        TFTPriceModule::set_prices(RuntimeOrigin::signed(alice()), 50, 101).unwrap();
+
+        assert_ok!(SmartContractModule::create_name_contract(
+            RuntimeOrigin::signed(bob()),
+            b"foobar".to_vec()
+        ));
+        let contract_id = 1;
+
+        // Ensure contract_id is stored at right billing loop index
+        let index = SmartContractModule::get_billing_loop_index_from_contract_id(contract_id);
+        assert_eq!(
+            SmartContractModule::contract_to_bill_at_block(index),
+            vec![contract_id]
+        );
+
+        // let mature 11 blocks
+        // because we bill every 10 blocks
+        pool_state
+            .write()
+            .should_call_bill_contract(contract_id, Ok(Pays::Yes.into()), 11);
+        run_to_block(11, Some(&mut pool_state));
+
+        // get the contract cost for 1 billing cycle
+        let contract = SmartContractModule::contracts(contract_id).unwrap();
+        let twin_id = 2;
+        let twin = TfgridModule::twins(twin_id).unwrap();
+        let balance = Balances::free_balance(&twin.account_id);
+        let second_elapsed = BillingFrequency::get() * SECS_PER_BLOCK;
+        let (contract_cost, discount_level) = contract
+            .calculate_contract_cost_tft(balance, second_elapsed)
+            .unwrap();
+
+        // the contractbill event should look like:
+        let contract_bill_event = types::ContractBill {
+            contract_id,
+            timestamp: 1628082066,
+            discount_level,
+            amount_billed: contract_cost as u128,
+        };
+
+        let our_events = System::events();
+        info!("events: {:?}", our_events.clone());
+        assert_eq!(
+            our_events[4],
+            record(MockEvent::SmartContractModule(SmartContractEvent::<
+                TestRuntime,
+            >::ContractBilled(
+                contract_bill_event
+            )))
+        );
+    });
+}
+
+#[test]
+fn test_rent_contract_billing() {
+    let (mut ext, mut pool_state) = new_test_ext_with_pool_state(0);
+    ext.execute_with(|| {
+        run_to_block(1, None);
+        prepare_dedicated_farm_and_node();
+        let node_id = 1;
+
+        // switch node to standby at block 1
+        assert_ok!(TfgridModule::change_power_state(
+            RuntimeOrigin::signed(alice()),
+            tfchain_support::types::Power::Down
+        ));
+
+        TFTPriceModule::set_prices(RuntimeOrigin::signed(alice()), 50, 101).unwrap();
+
+        assert_ok!(SmartContractModule::create_rent_contract(
+            RuntimeOrigin::signed(bob()),
+            node_id,
+            None
+        ));
+        let contract_id = 1;
+
+        let contract = SmartContractModule::contracts(contract_id).unwrap();
+        let rent_contract = types::RentContract { node_id };
+        assert_eq!(
+            contract.contract_type,
+            types::ContractData::RentContract(rent_contract)
+        );
+
+        // go to end of cycle 1 [1-11] and expect a call to bill_contract()
+        pool_state
+            .write()
+            .should_call_bill_contract(contract_id, Ok(Pays::Yes.into()), 11);
+        run_to_block(11, Some(&mut pool_state));
+
+        // wake up node at block 15, in the middle of cycle 2 [11-21]
+        run_to_block(15, Some(&mut pool_state));
+        assert_ok!(TfgridModule::change_power_state(
+            RuntimeOrigin::signed(alice()),
+            tfchain_support::types::Power::Up
+        ));
+
+        // go to end of cycle 2 [11-21] and expect a call to bill_contract()
+        pool_state
+            .write()
+            .should_call_bill_contract(contract_id, Ok(Pays::Yes.into()), 21);
+        run_to_block(21, Some(&mut pool_state));
+
+        // should bill partial cycle 2 [15-21], 6 blocks
+        let (amount_due_as_u128, discount_received) = calculate_tft_cost(contract_id, 2, 6);
+        assert_ne!(amount_due_as_u128, 0);
+        check_report_cost(contract_id, amount_due_as_u128, 21, discount_received);
+
+        // switch node to standby at block 25, in the middle of cycle 3 [21-31]
+        run_to_block(25, Some(&mut pool_state));
+        assert_ok!(TfgridModule::change_power_state(
+            RuntimeOrigin::signed(alice()),
+            tfchain_support::types::Power::Down
+        ));
+
+        // go to end of cycle 3 [21-31] and expect a call to bill_contract()
+        pool_state
+            .write()
+            .should_call_bill_contract(contract_id, Ok(Pays::Yes.into()), 31);
+        run_to_block(31, Some(&mut pool_state));
+
+        // go to end of cycle 4 [31-41] and expect a call to bill_contract()
+        pool_state
+            .write()
+            .should_call_bill_contract(contract_id, Ok(Pays::Yes.into()), 41);
+        run_to_block(41, Some(&mut pool_state));
+
+        // wake up node at block 45, in the middle of cycle 5 [41-51]
+        run_to_block(45, Some(&mut pool_state));
+        assert_ok!(TfgridModule::change_power_state(
+            RuntimeOrigin::signed(alice()),
+            tfchain_support::types::Power::Up
+        ));
+
+        // go to end of cycle 5 [41-51] and expect a call to bill_contract()
+        pool_state
+            .write()
+            .should_call_bill_contract(contract_id, Ok(Pays::Yes.into()), 51);
+        run_to_block(51, Some(&mut pool_state));
+
+        // should bill partial cycle 5 [45-51], 6 blocks
+        let (amount_due_as_u128, discount_received) = calculate_tft_cost(contract_id, 2, 6);
+        assert_ne!(amount_due_as_u128, 0);
+        check_report_cost(contract_id, amount_due_as_u128, 51, discount_received);
+    });
+}
+
+#[test]
+fn test_rent_contract_billing_paused_during_node_maintenance_works() {
+    let (mut ext, mut pool_state) = new_test_ext_with_pool_state(0);
+    ext.execute_with(|| {
+        run_to_block(1, None);
+        prepare_dedicated_farm_and_node();
+        let node_id = 1;
+
+        TFTPriceModule::set_prices(RuntimeOrigin::signed(alice()), 50, 101).unwrap();
+
+        assert_ok!(SmartContractModule::create_rent_contract(
+            RuntimeOrigin::signed(bob()),
+            node_id,
+            None
+        ));
+        let contract_id = 1;
+
+        // go to end of cycle 1 [1-11] and expect a call to bill_contract()
+        pool_state
+            .write()
+            .should_call_bill_contract(contract_id, Ok(Pays::Yes.into()), 11);
+        run_to_block(11, Some(&mut pool_state));
+
+        // node is in maintenance from block 13 to block 19, in the middle of cycle 2 [11-21]
+        let start = 1628082000 + 13 * SECS_PER_BLOCK;
+        let end = 1628082000 + 19 * SECS_PER_BLOCK;
+        assert_ok!(TfgridModule::schedule_node_maintenance(
+            RuntimeOrigin::signed(alice()),
+            node_id,
+            start,
+            end
+        ));
+
+        // go to end of cycle 2 [11-21] and expect a call to bill_contract()
+        pool_state
+            .write()
+            .should_call_bill_contract(contract_id, Ok(Pays::Yes.into()), 21);
+        run_to_block(21, Some(&mut pool_state));
+
+        // should bill cycle 2 [11-21] without the maintenance window, 4 blocks
+        let (amount_due_as_u128, discount_received) = calculate_tft_cost(contract_id, 2, 4);
+        assert_ne!(amount_due_as_u128, 0);
+        check_report_cost(contract_id, amount_due_as_u128, 21, discount_received);
+    });
+}
+
+#[test]
+fn test_rent_contract_billing_paused_during_node_maintenance_windows_works() {
+    let (mut ext, mut pool_state) = new_test_ext_with_pool_state(0);
+    ext.execute_with(|| {
+        run_to_block(1, None);
+        prepare_dedicated_farm_and_node();
+        let node_id = 1;
+
+        TFTPriceModule::set_prices(RuntimeOrigin::signed(alice()), 50, 101).unwrap();
+
+        assert_ok!(SmartContractModule::create_rent_contract(
+            RuntimeOrigin::signed(bob()),
+            node_id,
+            None
+        ));
+        let contract_id = 1;
+
+        // go to end of cycle 1 [1-11] and expect a call to bill_contract()
+        pool_state
+            .write()
+            .should_call_bill_contract(contract_id, Ok(Pays::Yes.into()), 11);
+        run_to_block(11, Some(&mut pool_state));
+
+        // node is in maintenance from block 13 to block 15 and from block 17 to block 19,
+        // the second window is scheduled once the first one is over
+        let start = 1628082000 + 13 * SECS_PER_BLOCK;
+        let end = 1628082000 + 15 * SECS_PER_BLOCK;
+        assert_ok!(TfgridModule::schedule_node_maintenance(
+            RuntimeOrigin::signed(alice()),
+            node_id,
+            start,
+            end
+        ));
+        run_to_block(16, Some(&mut pool_state));
+        let start = 1628082000 + 17 * SECS_PER_BLOCK;
+        let end = 1628082000 + 19 * SECS_PER_BLOCK;
+        assert_ok!(TfgridModule::schedule_node_maintenance(
+            RuntimeOrigin::signed(alice()),
+            node_id,
+            start,
+            end
+        ));
+
+        // go to end of cycle 2 [11-21] and expect a call to bill_contract()
+        pool_state
+            .write()
+            .should_call_bill_contract(contract_id, Ok(Pays::Yes.into()), 21);
+        run_to_block(21, Some(&mut pool_state));
+
+        // should bill cycle 2 [11-21] without both maintenance windows, 6 blocks
+        let (amount_due_as_u128, discount_received) = calculate_tft_cost(contract_id, 2, 6);
+        assert_ne!(amount_due_as_u128, 0);
+        check_report_cost(contract_id, amount_due_as_u128, 21, discount_received);
+    });
+}
+
+#[test]
+fn test_node_maintenance_extends_grace_period_works() {
+    let (mut ext, mut pool_state) = new_test_ext_with_pool_state(0);
+    ext.execute_with(|| {
+        run_to_block(1, None);
+        prepare_farm_and_node();
+        let node_id = 1;
+
+        TFTPriceModule::set_prices(RuntimeOrigin::signed(alice()), 50, 101).unwrap();
+
+        assert_ok!(SmartContractModule::set_contract_kind_grace_period(
+            RawOrigin::Root.into(),
+            types::ContractKind::Node,
+            Some(30)
+        ));
+
+        assert_ok!(SmartContractModule::create_node_contract(
+            RuntimeOrigin::signed(charlie()),
+            node_id,
+            generate_deployment_hash(),
+            get_deployment_data(),
+            0,
+            None
+        ));
+        let contract_id = 1;
+
+        push_contract_resources_used(contract_id);
+
+        // user does not have enough funds to pay for 2 cycles
+        for i in 1..7 {
+            pool_state.write().should_call_bill_contract(
+                contract_id,
+                Ok(Pays::Yes.into()),
+                1 + i * 10,
+            );
+        }
+        for i in 1..3 {
+            run_to_block(1 + i * 10, Some(&mut pool_state));
+        }
+        let c1 = SmartContractModule::contracts(contract_id).unwrap();
+        assert_eq!(c1.state, types::ContractState::GracePeriod(21));
+
+        // node is in maintenance from block 23 to block 29
+        let start = 1628082000 + 23 * SECS_PER_BLOCK;
+        let end = 1628082000 + 29 * SECS_PER_BLOCK;
+        assert_ok!(TfgridModule::schedule_node_maintenance(
+            RuntimeOrigin::signed(alice()),
+            node_id,
+            start,
+            end
+        ));
+
+        // grace period start is pushed forward by the 6 blocks of maintenance
+        run_to_block(31, Some(&mut pool_state));
+        let c1 = SmartContractModule::contracts(contract_id).unwrap();
+        assert_eq!(c1.state, types::ContractState::GracePeriod(27));
+
+        // grace period would have stopped at 51 without the maintenance
+        for i in 4..6 {
+            run_to_block(1 + i * 10, Some(&mut pool_state));
+        }
+        assert!(SmartContractModule::contracts(contract_id).is_some());
+
+        run_to_block(61, Some(&mut pool_state));
+        assert_eq!(SmartContractModule::contracts(contract_id), None);
+    });
+}
+
+#[test]
+fn test_node_maintenance_extends_grace_period_once_works() {
+    let (mut ext, mut pool_state) = new_test_ext_with_pool_state(0);
+    ext.execute_with(|| {
+        run_to_block(1, None);
+        prepare_farm_and_node();
+        let node_id = 1;
+
+        TFTPriceModule::set_prices(RuntimeOrigin::signed(alice()), 50, 101).unwrap();
+
+        assert_ok!(SmartContractModule::set_contract_kind_grace_period(
+            RawOrigin::Root.into(),
+            types::ContractKind::Node,
+            Some(30)
+        ));
+
+        assert_ok!(SmartContractModule::create_node_contract(
+            RuntimeOrigin::signed(charlie()),
+            node_id,
+            generate_deployment_hash(),
+            get_deployment_data(),
+            0,
+            None
+        ));
+        let contract_id = 1;
+
+        push_contract_resources_used(contract_id);
+
+        // user does not have enough funds to pay for 2 cycles
+        for i in 1..5 {
+            pool_state.write().should_call_bill_contract(
+                contract_id,
+                Ok(Pays::Yes.into()),
+                1 + i * 10,
+            );
+        }
+        for i in 1..3 {
+            run_to_block(1 + i * 10, Some(&mut pool_state));
+        }
+        let c1 = SmartContractModule::contracts(contract_id).unwrap();
+        assert_eq!(c1.state, types::ContractState::GracePeriod(21));
+
+        // node is in maintenance for the whole cycle 3 [21-31]
+        let start = 1628082000 + 21 * SECS_PER_BLOCK;
+        let end = 1628082000 + 31 * SECS_PER_BLOCK;
+        assert_ok!(TfgridModule::schedule_node_maintenance(
+            RuntimeOrigin::signed(alice()),
+            node_id,
+            start,
+            end
+        ));
+
+        // nothing is billed for cycle 3 so the contract lock is not moved
+        run_to_block(31, Some(&mut pool_state));
+        let c1 = SmartContractModule::contracts(contract_id).unwrap();
+        assert_eq!(c1.state, types::ContractState::GracePeriod(21));
+
+        // grace period start is pushed forward by the 10 blocks of maintenance only once
+        run_to_block(41, Some(&mut pool_state));
+        let c1 = SmartContractModule::contracts(contract_id).unwrap();
+        assert_eq!(c1.state, types::ContractState::GracePeriod(31));
+    });
+}
+
+#[test]
+fn test_rent_contract_billing_cancel_should_bill_reserved_balance() {
+    let (mut ext, mut pool_state) = new_test_ext_with_pool_state(0);
+    ext.execute_with(|| {
+        run_to_block(1, None);
+        prepare_dedicated_farm_and_node();
+        let node_id = 1;
+
+        TFTPriceModule::set_prices(RuntimeOrigin::signed(alice()), 50, 101).unwrap();
+
+        assert_ok!(SmartContractModule::create_rent_contract(
+            RuntimeOrigin::signed(bob()),
+            node_id,
+            None
+        ));
+        let contract_id = 1;
+
+        let contract = SmartContractModule::contracts(contract_id).unwrap();
+        let rent_contract = types::RentContract { node_id };
+        assert_eq!(
+            contract.contract_type,
+            types::ContractData::RentContract(rent_contract)
+        );
+
+        pool_state
+            .write()
+            .should_call_bill_contract(contract_id, Ok(Pays::Yes.into()), 11);
+        run_to_block(11, Some(&mut pool_state));
+
+        let (amount_due_as_u128, discount_received) = calculate_tft_cost(contract_id, 2, 10);
+        assert_ne!(amount_due_as_u128, 0);
+        check_report_cost(
+            contract_id,
+            amount_due_as_u128,
+            11,
+            discount_received.clone(),
+        );
+
+        let twin = TfgridModule::twins(2).unwrap();
+        let usable_balance = Balances::usable_balance(&twin.account_id);
+        let free_balance = Balances::free_balance(&twin.account_id);
+        assert_ne!(usable_balance, free_balance);
+
+        run_to_block(13, Some(&mut pool_state));
+        // cancel contract
+        // it will bill before removing the contract and it should bill all
+        // reserved balance
+        let (amount_due_as_u128, discount_received) = calculate_tft_cost(contract_id, 2, 2);
+        assert_ok!(SmartContractModule::cancel_contract(
+            RuntimeOrigin::signed(bob()),
+            contract_id
+        ));
+
+        let twin = TfgridModule::twins(2).unwrap();
+        let usable_balance = Balances::usable_balance(&twin.account_id);
+        assert_ne!(usable_balance, 0);
+        Balances::transfer(RuntimeOrigin::signed(bob()), alice(), usable_balance).unwrap();
+
+        // Last amount due is the same as the first one
+        assert_ne!(amount_due_as_u128, 0);
+        check_report_cost(contract_id, amount_due_as_u128, 13, discount_received);
+
+        let usable_balance = Balances::usable_balance(&twin.account_id);
+        let free_balance = Balances::free_balance(&twin.account_id);
+        assert_eq!(usable_balance, free_balance);
+    });
+}
+
+#[test]
+fn test_rent_contract_canceled_mid_cycle_should_bill_for_remainder() {
+    let (mut ext, mut pool_state) = new_test_ext_with_pool_state(0);
+    ext.execute_with(|| {
+        run_to_block(1, None);
+        prepare_dedicated_farm_and_node();
+        let node_id = 1;
+
+        TFTPriceModule::set_prices(RuntimeOrigin::signed(alice()), 50, 101).unwrap();
+
+        assert_ok!(SmartContractModule::create_rent_contract(
+            RuntimeOrigin::signed(bob()),
+            node_id,
+            None
+        ));
+        let contract_id = 1;
+
+        let contract = SmartContractModule::contracts(contract_id).unwrap();
+        let rent_contract = types::RentContract { node_id };
+        assert_eq!(
+            contract.contract_type,
+            types::ContractData::RentContract(rent_contract)
+        );
+
+        let twin = TfgridModule::twins(2).unwrap();
+        let usable_balance = Balances::usable_balance(&twin.account_id);
+        let free_balance = Balances::free_balance(&twin.account_id);
+
+        let locked_balance = free_balance - usable_balance;
+        info!("locked balance: {:?}", locked_balance);
+
+        run_to_block(8, Some(&mut pool_state));
+        // Calculate the cost for 7 blocks of runtime (created a block 1, canceled at block 8)
+        let (amount_due_as_u128, discount_received) = calculate_tft_cost(contract_id, 2, 7);
+        // cancel rent contract at block 8
+        assert_ok!(SmartContractModule::cancel_contract(
+            RuntimeOrigin::signed(bob()),
+            contract_id
+        ));
+        assert_ne!(amount_due_as_u128, 0);
+        check_report_cost(
+            contract_id,
+            amount_due_as_u128,
+            8,
+            discount_received.clone(),
+        );
+
+        // Twin should have no more locked balance
+        let twin = TfgridModule::twins(2).unwrap();
+        let usable_balance = Balances::usable_balance(&twin.account_id);
+        let free_balance = Balances::free_balance(&twin.account_id);
+        assert_eq!(usable_balance, free_balance);
+    });
+}
+
+#[test]
+fn test_create_rent_contract_and_node_contract_excludes_node_contract_from_billing_works() {
+    let (mut ext, mut pool_state) = new_test_ext_with_pool_state(0);
+    ext.execute_with(|| {
+        prepare_dedicated_farm_and_node();
+        let node_id = 1;
+        run_to_block(1, None);
+
+        TFTPriceModule::set_prices(RuntimeOrigin::signed(alice()), 50, 101).unwrap();
+
+        assert_ok!(SmartContractModule::create_rent_contract(
+            RuntimeOrigin::signed(bob()),
+            node_id,
+            None
+        ));
+        let rent_contract_id = 1;
+
+        run_to_block(2, None);
+
+        assert_ok!(SmartContractModule::create_node_contract(
+            RuntimeOrigin::signed(bob()),
+            node_id,
+            generate_deployment_hash(),
+            get_deployment_data(),
+            0,
+            None
+        ));
+        let node_contract_id = 2;
+        push_contract_resources_used(node_contract_id);
+
+        pool_state
+            .write()
+            .should_call_bill_contract(rent_contract_id, Ok(Pays::Yes.into()), 11);
+        run_to_block(11, Some(&mut pool_state));
+        pool_state
+            .write()
+            .should_call_bill_contract(node_contract_id, Ok(Pays::Yes.into()), 12);
+        run_to_block(12, Some(&mut pool_state));
+
+        let (amount_due_as_u128, discount_received) = calculate_tft_cost(rent_contract_id, 2, 10);
+        assert_ne!(amount_due_as_u128, 0);
+        check_report_cost(rent_contract_id, amount_due_as_u128, 11, discount_received);
+
+        let our_events = System::events();
+        // Event 1: Price Stored
+        // Event 2: Avg price stored
+        // Event 3: Rent contract created
+        // Event 4: Node Contract created
+        // Event 5: Updated resources contract
+        // Event 6: Balances locked
+        // Event 7: Contract Billed
+        // => no Node Contract billed event
+        assert_eq!(our_events.len(), 7);
+    });
+}
+
+#[test]
+fn test_rent_contract_canceled_due_to_out_of_funds_should_cancel_node_contracts_works() {
+    let (mut ext, mut pool_state) = new_test_ext_with_pool_state(0);
+    ext.execute_with(|| {
+        prepare_dedicated_farm_and_node();
+        let node_id = 1;
+        run_to_block(1, None);
+
+        TFTPriceModule::set_prices(RuntimeOrigin::signed(alice()), 50, 101).unwrap();
+
+        assert_ok!(SmartContractModule::create_rent_contract(
+            RuntimeOrigin::signed(charlie()),
+            node_id,
+            None
+        ));
+        let rent_contract_id = 1;
+
+        run_to_block(2, None);
+
+        assert_ok!(SmartContractModule::create_node_contract(
+            RuntimeOrigin::signed(charlie()),
+            node_id,
+            generate_deployment_hash(),
+            get_deployment_data(),
+            0,
+            None
+        ));
+        let node_contract_id = 2;
+        push_contract_resources_used(node_contract_id);
+
+        // run 10 cycles
+        for i in 0..10 {
+            let block_number = 11 + i * 10;
+            pool_state.write().should_call_bill_contract(
+                rent_contract_id,
+                Ok(Pays::Yes.into()),
+                block_number,
+            );
+            run_to_block(block_number, Some(&mut pool_state));
+            pool_state.write().should_call_bill_contract(
+                node_contract_id,
+                Ok(Pays::Yes.into()),
+                block_number + 1,
+            );
+            run_to_block(block_number + 1, Some(&mut pool_state));
+        }
+
+        // contracts should cancel at 11th due to lack of funds
+        let end_grace_block_number = 11 + 10 * 10;
+        pool_state.write().should_call_bill_contract(
+            rent_contract_id,
+            Ok(Pays::Yes.into()),
+            end_grace_block_number,
+        );
+        run_to_block(end_grace_block_number, Some(&mut pool_state));
+
+        let our_events = System::events();
+        assert_eq!(our_events.len(), 21);
+
+        for e in our_events.clone() {
+            log::info!("event: {:?}", e);
+        }
+
+        assert_eq!(
+            our_events[5],
+            record(MockEvent::SmartContractModule(SmartContractEvent::<
+                TestRuntime,
+            >::ContractGracePeriodStarted {
+                contract_id: rent_contract_id,
+                node_id,
+                twin_id: 3,
+                block_number: 11
+            }))
+        );
+        assert_eq!(
+            our_events[6],
+            record(MockEvent::SmartContractModule(SmartContractEvent::<
+                TestRuntime,
+            >::ContractGracePeriodStarted {
+                contract_id: node_contract_id,
+                node_id,
+                twin_id: 3,
+                block_number: 11
+            }))
+        );
+
+        assert_eq!(
+            our_events[19],
+            record(MockEvent::SmartContractModule(SmartContractEvent::<
+                TestRuntime,
+            >::NodeContractCanceled {
+                contract_id: node_contract_id,
+                node_id,
+                twin_id: 3
+            }))
+        );
+        assert_eq!(
+            our_events[20],
+            record(MockEvent::SmartContractModule(SmartContractEvent::<
+                TestRuntime,
+            >::RentContractCanceled {
+                contract_id: rent_contract_id
+            }))
+        );
+    });
+}
+
+#[test]
+fn test_create_rent_contract_and_node_contract_with_ip_billing_works() {
+    let (mut ext, mut pool_state) = new_test_ext_with_pool_state(0);
+    ext.execute_with(|| {
+        prepare_dedicated_farm_and_node();
+        run_to_block(1, Some(&mut pool_state));
+        TFTPriceModule::set_prices(RuntimeOrigin::signed(alice()), 50, 101).unwrap();
+
+        let node_id = 1;
+        assert_ok!(SmartContractModule::create_rent_contract(
+            RuntimeOrigin::signed(bob()),
+            node_id,
+            None
+        ));
+        let rent_contract_id = 1;
+
+        run_to_block(2, None);
+
+        assert_ok!(SmartContractModule::create_node_contract(
+            RuntimeOrigin::signed(bob()),
+            node_id,
+            generate_deployment_hash(),
+            get_deployment_data(),
+            1,
+            None
+        ));
+        let node_contract_id = 2;
+
+        // 2 contracts => we expect 2 calls to bill_contract
+        pool_state
+            .write()
+            .should_call_bill_contract(rent_contract_id, Ok(Pays::Yes.into()), 11);
+        run_to_block(11, Some(&mut pool_state));
+
+        // check contract 1 costs (Rent Contract)
+        let (amount_due_as_u128, discount_received) = calculate_tft_cost(rent_contract_id, 2, 10);
+        assert_ne!(amount_due_as_u128, 0);
+        check_report_cost(rent_contract_id, amount_due_as_u128, 11, discount_received);
+
+        pool_state
+            .write()
+            .should_call_bill_contract(node_contract_id, Ok(Pays::Yes.into()), 12);
+        run_to_block(12, Some(&mut pool_state));
+
+        // check contract 2 costs (Node Contract)
+        let (amount_due_as_u128, discount_received) = calculate_tft_cost(node_contract_id, 2, 10);
+        assert_ne!(amount_due_as_u128, 0);
+        check_report_cost(node_contract_id, amount_due_as_u128, 12, discount_received);
+
+        let our_events = System::events();
+        assert_eq!(our_events.len(), 8);
+    });
+}
+
+#[test]
+fn test_rent_contract_out_of_funds_should_move_state_to_graceperiod_works() {
+    let (mut ext, mut pool_state) = new_test_ext_with_pool_state(0);
+    ext.execute_with(|| {
+        run_to_block(1, None);
+        prepare_dedicated_farm_and_node();
+        let node_id = 1;
+
+        TFTPriceModule::set_prices(RuntimeOrigin::signed(alice()), 50, 101).unwrap();
+
+        assert_ok!(SmartContractModule::create_rent_contract(
+            RuntimeOrigin::signed(charlie()),
+            node_id,
+            None
+        ));
+        let contract_id = 1;
+
+        // cycle 1
+        // user does not have enough funds to pay for 1 cycle
+        pool_state
+            .write()
+            .should_call_bill_contract(contract_id, Ok(Pays::Yes.into()), 11);
+        run_to_block(11, Some(&mut pool_state));
+
+        let c1 = SmartContractModule::contracts(1).unwrap();
+        assert_eq!(c1.state, types::ContractState::GracePeriod(11));
+
+        let our_events = System::events();
+        assert_eq!(
+            our_events.contains(&record(MockEvent::SmartContractModule(
+                SmartContractEvent::<TestRuntime>::ContractGracePeriodStarted {
+                    contract_id,
+                    node_id,
+                    twin_id: 3,
+                    block_number: 11
+                }
+            ))),
+            true
+        );
+    });
+}
+
+#[test]
+fn test_restore_rent_contract_in_grace_works() {
+    let (mut ext, mut pool_state) = new_test_ext_with_pool_state(0);
+    ext.execute_with(|| {
+        prepare_dedicated_farm_and_node();
+        let node_id = 1;
+        run_to_block(1, None);
+
+        TFTPriceModule::set_prices(RuntimeOrigin::signed(alice()), 50, 101).unwrap();
+
+        assert_ok!(SmartContractModule::create_rent_contract(
+            RuntimeOrigin::signed(charlie()),
+            node_id,
+            None
+        ));
+        let contract_id = 1;
+
+        // cycle 1
+        pool_state
+            .write()
+            .should_call_bill_contract(contract_id, Ok(Pays::Yes.into()), 11);
+        run_to_block(11, Some(&mut pool_state));
+
+        let c1 = SmartContractModule::contracts(contract_id).unwrap();
+        assert_eq!(c1.state, types::ContractState::GracePeriod(11));
+
+        let our_events = System::events();
+        info!("events: {:?}", our_events.clone());
+        assert_eq!(
+            our_events[3],
+            record(MockEvent::SmartContractModule(SmartContractEvent::<
+                TestRuntime,
+            >::ContractGracePeriodStarted {
+                contract_id,
+                node_id,
+                twin_id: 3,
+                block_number: 11
+            }))
+        );
+
+        pool_state
+            .write()
+            .should_call_bill_contract(contract_id, Ok(Pays::Yes.into()), 21);
+        run_to_block(21, Some(&mut pool_state));
+
+        pool_state
+            .write()
+            .should_call_bill_contract(contract_id, Ok(Pays::Yes.into()), 31);
+        run_to_block(31, Some(&mut pool_state));
+
+        // Transfer some balance to the owner of the contract to trigger the grace period to stop
+        Balances::transfer(RuntimeOrigin::signed(bob()), charlie(), 100000000).unwrap();
+
+        pool_state
+            .write()
+            .should_call_bill_contract(contract_id, Ok(Pays::Yes.into()), 41);
+        run_to_block(41, Some(&mut pool_state));
+
+        pool_state
+            .write()
+            .should_call_bill_contract(contract_id, Ok(Pays::Yes.into()), 51);
+        run_to_block(51, Some(&mut pool_state));
+
+        let c1 = SmartContractModule::contracts(contract_id).unwrap();
+        assert_eq!(c1.state, types::ContractState::Created);
+    });
+}
+
+#[test]
+fn test_restore_rent_contract_and_node_contracts_in_grace_works() {
+    let (mut ext, mut pool_state) = new_test_ext_with_pool_state(0);
+    ext.execute_with(|| {
+        prepare_dedicated_farm_and_node();
+        let node_id = 1;
+        run_to_block(1, None);
+
+        TFTPriceModule::set_prices(RuntimeOrigin::signed(alice()), 50, 101).unwrap();
+
+        assert_ok!(SmartContractModule::create_rent_contract(
+            RuntimeOrigin::signed(charlie()),
+            node_id,
+            None
+        ));
+        let rent_contract_id = 1;
+
+        run_to_block(2, None);
+
+        assert_ok!(SmartContractModule::create_node_contract(
+            RuntimeOrigin::signed(charlie()),
+            node_id,
+            generate_deployment_hash(),
+            get_deployment_data(),
+            0,
+            None
+        ));
+        let node_contract_id = 2;
+        push_contract_resources_used(node_contract_id);
+
+        // cycle 1
+        pool_state
+            .write()
+            .should_call_bill_contract(rent_contract_id, Ok(Pays::Yes.into()), 11);
+        run_to_block(11, Some(&mut pool_state));
+        pool_state
+            .write()
+            .should_call_bill_contract(node_contract_id, Ok(Pays::Yes.into()), 12);
+        run_to_block(12, Some(&mut pool_state));
+
+        let c1 = SmartContractModule::contracts(rent_contract_id).unwrap();
+        assert_eq!(c1.state, types::ContractState::GracePeriod(11));
+
+        let our_events = System::events();
+        assert_eq!(
+            our_events[5],
+            record(MockEvent::SmartContractModule(SmartContractEvent::<
+                TestRuntime,
+            >::ContractGracePeriodStarted {
+                contract_id: rent_contract_id,
+                node_id,
+                twin_id: 3,
+                block_number: 11
+            }))
+        );
+        assert_eq!(
+            our_events[6],
+            record(MockEvent::SmartContractModule(SmartContractEvent::<
+                TestRuntime,
+            >::ContractGracePeriodStarted {
+                contract_id: node_contract_id,
+                node_id,
+                twin_id: 3,
+                block_number: 11
+            }))
+        );
+
+        pool_state
+            .write()
+            .should_call_bill_contract(rent_contract_id, Ok(Pays::Yes.into()), 21);
+        run_to_block(21, Some(&mut pool_state));
+        pool_state
+            .write()
+            .should_call_bill_contract(node_contract_id, Ok(Pays::Yes.into()), 22);
+        run_to_block(22, Some(&mut pool_state));
+
+        pool_state
+            .write()
+            .should_call_bill_contract(rent_contract_id, Ok(Pays::Yes.into()), 31);
+        run_to_block(31, Some(&mut pool_state));
+        pool_state
+            .write()
+            .should_call_bill_contract(node_contract_id, Ok(Pays::Yes.into()), 32);
+        run_to_block(32, Some(&mut pool_state));
+
+        // Transfer some balance to the owner of the contract to trigger the grace period to stop
+        Balances::transfer(RuntimeOrigin::signed(bob()), charlie(), 100000000).unwrap();
+
+        pool_state
+            .write()
+            .should_call_bill_contract(rent_contract_id, Ok(Pays::Yes.into()), 41);
+        run_to_block(41, Some(&mut pool_state));
+        pool_state
+            .write()
+            .should_call_bill_contract(node_contract_id, Ok(Pays::Yes.into()), 42);
+        run_to_block(42, Some(&mut pool_state));
+
+        pool_state
+            .write()
+            .should_call_bill_contract(rent_contract_id, Ok(Pays::Yes.into()), 51);
+        run_to_block(51, Some(&mut pool_state));
+        pool_state
+            .write()
+            .should_call_bill_contract(node_contract_id, Ok(Pays::Yes.into()), 52);
+        run_to_block(52, Some(&mut pool_state));
+
+        let c1 = SmartContractModule::contracts(rent_contract_id).unwrap();
+        assert_eq!(c1.state, types::ContractState::Created);
+
+        let our_events = System::events();
+
+        assert_eq!(
+            our_events[8],
+            record(MockEvent::SmartContractModule(SmartContractEvent::<
+                TestRuntime,
+            >::ContractGracePeriodEnded {
+                contract_id: rent_contract_id,
+                node_id,
+                twin_id: 3,
+            }))
+        );
+        assert_eq!(
+            our_events[9],
+            record(MockEvent::SmartContractModule(SmartContractEvent::<
+                TestRuntime,
+            >::ContractGracePeriodEnded {
+                contract_id: node_contract_id,
+                node_id,
+                twin_id: 3,
+            }))
+        );
+    });
+}
+
+#[test]
+fn test_rent_contract_grace_period_cancels_contract_when_grace_period_ends_works() {
+    let (mut ext, mut pool_state) = new_test_ext_with_pool_state(0);
+    ext.execute_with(|| {
+        run_to_block(1, None);
+        prepare_dedicated_farm_and_node();
+        let node_id = 1;
+
+        TFTPriceModule::set_prices(RuntimeOrigin::signed(alice()), 50, 101).unwrap();
+
+        assert_ok!(SmartContractModule::create_rent_contract(
+            RuntimeOrigin::signed(charlie()),
+            node_id,
+            None
+        ));
+        let contract_id = 1;
+
+        // cycle 1
+        pool_state
+            .write()
+            .should_call_bill_contract(contract_id, Ok(Pays::Yes.into()), 11);
+        run_to_block(11, Some(&mut pool_state));
+
+        let c1 = SmartContractModule::contracts(contract_id).unwrap();
+        assert_eq!(c1.state, types::ContractState::GracePeriod(11));
+
+        let our_events = System::events();
+        assert_eq!(
+            our_events.contains(&record(MockEvent::SmartContractModule(
+                SmartContractEvent::<TestRuntime>::ContractGracePeriodStarted {
+                    contract_id,
+                    node_id,
+                    twin_id: 3,
+                    block_number: 11
+                }
+            ))),
+            true
+        );
+
+        // run 9 more cycles
+        for i in 0..10 {
+            let block_number = 21 + i * 10;
+            pool_state.write().should_call_bill_contract(
+                contract_id,
+                Ok(Pays::Yes.into()),
+                block_number,
+            );
+            run_to_block(block_number, Some(&mut pool_state));
+        }
+
+        // after 10 cycles grace period has finished
+        // so contract is removed and no more billing!
+        let c1 = SmartContractModule::contracts(contract_id);
+        assert_eq!(c1, None);
+
+        // Ensure contract_id is not in billing loop anymore
+        let index = SmartContractModule::get_billing_loop_index_from_contract_id(contract_id);
+        assert_eq!(
+            SmartContractModule::contract_to_bill_at_block(index),
+            Vec::<u64>::new()
+        );
+    });
+}
+
+#[test]
+fn test_rent_contract_and_node_contract_canceled_when_node_is_deleted_works() {
+    let (mut ext, mut pool_state) = new_test_ext_with_pool_state(0);
+    ext.execute_with(|| {
+        run_to_block(1, None);
+        prepare_dedicated_farm_and_node();
+        let node_id = 1;
+
+        TFTPriceModule::set_prices(RuntimeOrigin::signed(alice()), 50, 101).unwrap();
+
+        assert_ok!(SmartContractModule::create_rent_contract(
+            RuntimeOrigin::signed(bob()),
+            node_id,
+            None
+        ));
+        let rent_contract_id = 1;
+
+        run_to_block(2, None);
+
+        assert_ok!(SmartContractModule::create_node_contract(
+            RuntimeOrigin::signed(bob()),
+            node_id,
+            generate_deployment_hash(),
+            get_deployment_data(),
+            0,
+            None
+        ));
+        let node_contract_id = 2;
+        push_contract_resources_used(node_contract_id);
+
+        // 2 contracts => 2 calls to bill_contract
+        pool_state
+            .write()
+            .should_call_bill_contract(rent_contract_id, Ok(Pays::Yes.into()), 11);
+        run_to_block(11, Some(&mut pool_state));
+        pool_state
+            .write()
+            .should_call_bill_contract(node_contract_id, Ok(Pays::Yes.into()), 12);
+        run_to_block(12, Some(&mut pool_state));
+
+        run_to_block(16, Some(&mut pool_state));
+
+        // Delete node
+        TfgridModule::delete_node_farm(RuntimeOrigin::signed(alice()), node_id).unwrap();
+
+        let our_events = System::events();
+
+        let ip = b"1.1.1.0".to_vec();
+        let mut ips = Vec::new();
+        ips.push(ip);
+
+        assert_eq!(
+            our_events.contains(&record(MockEvent::SmartContractModule(
+                SmartContractEvent::<TestRuntime>::NodeContractCanceled {
+                    contract_id: node_contract_id,
+                    node_id,
+                    twin_id: 2
+                }
+            ))),
+            true
+        );
+        assert_eq!(
+            our_events.contains(&record(MockEvent::SmartContractModule(
+                SmartContractEvent::<TestRuntime>::RentContractCanceled {
+                    contract_id: rent_contract_id
+                }
+            ))),
+            true
+        );
+    });
+}
+
+#[test]
+fn test_node_contract_billing_follows_farm_pricing_policy_works() {
+    let (mut ext, mut pool_state) = new_test_ext_with_pool_state(0);
+    ext.execute_with(|| {
+        run_to_block(1, None);
+        prepare_farm_and_node();
+        let node_id = 1;
+
+        TFTPriceModule::set_prices(RuntimeOrigin::signed(alice()), 50, 101).unwrap();
+
+        // Farm follows a pricing policy twice as expensive as the default one
+        prepare_farm_pricing_policy(1);
+
+        assert_ok!(SmartContractModule::create_node_contract(
+            RuntimeOrigin::signed(bob()),
+            node_id,
+            generate_deployment_hash(),
+            get_deployment_data(),
+            1,
+            None
+        ));
+        let contract_id = 1;
+        let twin_id = 2;
+
+        push_contract_resources_used(contract_id);
+
+        let contract = SmartContractModule::contracts(contract_id).unwrap();
+        let (pricing_policy, certification_type) =
+            contract.get_pricing_policy_and_certification().unwrap();
+        assert_eq!(pricing_policy.id, 2);
+        assert_eq!(certification_type, NodeCertification::Diy);
+
+        let default_pricing_policy = TfgridModule::pricing_policies(1).unwrap();
+        let default_cost = contract
+            .calculate_contract_cost_units_usd(&default_pricing_policy, SECS_PER_HOUR)
+            .unwrap();
+        let cost = contract
+            .calculate_contract_cost_units_usd(&pricing_policy, SECS_PER_HOUR)
+            .unwrap();
+        assert_eq_error_rate!(cost, 2 * default_cost, 1);
+
+        let twin = TfgridModule::twins(twin_id).unwrap();
+        let initial_twin_balance = Balances::free_balance(&twin.account_id);
+        let initial_foundation_account_balance =
+            Balances::free_balance(&pricing_policy.foundation_account);
+        let initial_sales_account_balance =
+            Balances::free_balance(&pricing_policy.certified_sales_account);
+        let initial_default_foundation_account_balance =
+            Balances::free_balance(&default_pricing_policy.foundation_account);
+        let initial_default_sales_account_balance =
+            Balances::free_balance(&default_pricing_policy.certified_sales_account);
+
+        let (amount_due, discount_received) = calculate_tft_cost(contract_id, twin_id, 10);
+        pool_state
+            .write()
+            .should_call_bill_contract(contract_id, Ok(Pays::Yes.into()), 11);
+        run_to_block(11, Some(&mut pool_state));
+        check_report_cost(contract_id, amount_due, 11, discount_received);
+
+        // advance enough cycles to distribute rewards
+        for i in 1..25 {
+            let block_number = 11 + i * 10;
+            pool_state.write().should_call_bill_contract(
+                contract_id,
+                Ok(Pays::Yes.into()),
+                block_number,
+            );
+            run_to_block(block_number, Some(&mut pool_state));
+        }
+
+        let free_balance = Balances::free_balance(&twin.account_id);
+        let total_amount_billed = initial_twin_balance - free_balance;
+
+        // 10% is sent to the foundation account of the farm pricing policy
+        let foundation_account_balance = Balances::free_balance(&pricing_policy.foundation_account);
+        assert_eq!(
+            foundation_account_balance - initial_foundation_account_balance,
+            Perbill::from_percent(10) * total_amount_billed
+        );
+
+        // 50% is sent to the sales account of the farm pricing policy
+        let sales_account_balance = Balances::free_balance(&pricing_policy.certified_sales_account);
+        assert_eq!(
+            sales_account_balance - initial_sales_account_balance,
+            Perbill::from_percent(50) * total_amount_billed
+        );
+
+        // Nothing is sent to the accounts of the default pricing policy
+        assert_eq!(
+            Balances::free_balance(&default_pricing_policy.foundation_account),
+            initial_default_foundation_account_balance
+        );
+        assert_eq!(
+            Balances::free_balance(&default_pricing_policy.certified_sales_account),
+            initial_default_sales_account_balance
+        );
+    });
+}
+
+#[test]
+fn test_node_contract_billing_on_certified_node_works() {
+    new_test_ext().execute_with(|| {
+        run_to_block(1, None);
+        prepare_farm_and_node();
+        let node_id = 1;
+
+        assert_ok!(SmartContractModule::create_node_contract(
+            RuntimeOrigin::signed(bob()),
+            node_id,
+            generate_deployment_hash(),
+            get_deployment_data(),
+            1,
+            None
+        ));
+        let contract_id = 1;
+        let twin_id = 2;
+
+        push_contract_resources_used(contract_id);
+
+        let (diy_amount_due, _) = calculate_tft_cost(contract_id, twin_id, 10);
+
+        assert_ok!(TfgridModule::set_node_certification(
+            RawOrigin::Root.into(),
+            node_id,
+            NodeCertification::Certified,
+        ));
+
+        let contract = SmartContractModule::contracts(contract_id).unwrap();
+        let (_, certification_type) = contract.get_pricing_policy_and_certification().unwrap();
+        assert_eq!(certification_type, NodeCertification::Certified);
+
+        // Certified capacity costs 25% more
+        let (certified_amount_due, _) = calculate_tft_cost(contract_id, twin_id, 10);
+        assert_eq_error_rate!(
+            certified_amount_due,
+            (U64F64::from_num(diy_amount_due) * U64F64::from_num(1.25))
+                .round()
+                .to_num::<u64>(),
+            1
+        );
+    });
+}
+
+#[test]
+fn test_name_contract_billing_follows_default_pricing_policy_works() {
+    new_test_ext().execute_with(|| {
+        run_to_block(1, None);
+        prepare_farm_and_node();
+        prepare_farm_pricing_policy(1);
+
+        assert_ok!(SmartContractModule::create_name_contract(
+            RuntimeOrigin::signed(bob()),
+            b"foobar".to_vec()
+        ));
+        let contract_id = 1;
+
+        let contract = SmartContractModule::contracts(contract_id).unwrap();
+        let (pricing_policy, certification_type) =
+            contract.get_pricing_policy_and_certification().unwrap();
+        assert_eq!(pricing_policy.id, pallet_tfgrid::DEFAULT_PRICING_POLICY_ID);
+        assert_eq!(certification_type, NodeCertification::Diy);
+    });
+}
+
+#[test]
+fn test_node_contract_billing_without_farm_pricing_policy_uses_default_works() {
+    new_test_ext().execute_with(|| {
+        run_to_block(1, None);
+        prepare_farm_and_node();
+        prepare_farm_pricing_policy(1);
+
+        assert_ok!(SmartContractModule::create_node_contract(
+            RuntimeOrigin::signed(bob()),
+            1,
+            generate_deployment_hash(),
+            get_deployment_data(),
+            0,
+            None
+        ));
+        let contract_id = 1;
+
+        // Pricing policy of the farm is gone
+        pallet_tfgrid::PricingPolicies::<TestRuntime>::remove(2);
+
+        let contract = SmartContractModule::contracts(contract_id).unwrap();
+        let (pricing_policy, certification_type) =
+            contract.get_pricing_policy_and_certification().unwrap();
+        assert_eq!(pricing_policy.id, pallet_tfgrid::DEFAULT_PRICING_POLICY_ID);
+        assert_eq!(certification_type, NodeCertification::Diy);
+    });
+}
+
+#[test]
+fn test_contract_billing_history_keeps_last_bills_works() {
+    let (mut ext, mut pool_state) = new_test_ext_with_pool_state(0);
+    ext.execute_with(|| {
+        run_to_block(1, None);
+        prepare_farm_and_node();
+        let node_id = 1;
+
+        TFTPriceModule::set_prices(RuntimeOrigin::signed(alice()), 50, 101).unwrap();
+
+        assert_ok!(SmartContractModule::create_node_contract(
+            RuntimeOrigin::signed(bob()),
+            node_id,
+            generate_deployment_hash(),
+            get_deployment_data(),
+            0,
+            None
+        ));
+        let contract_id = 1;
+        let twin_id = 2;
+
+        push_contract_resources_used(contract_id);
+
+        let mut total_billed = 0;
+        for i in 1..=4 {
+            let (amount_due, discount_received) = calculate_tft_cost(contract_id, twin_id, 10);
+            pool_state.write().should_call_bill_contract(
+                contract_id,
+                Ok(Pays::Yes.into()),
+                1 + i * 10,
+            );
+            run_to_block(1 + i * 10, Some(&mut pool_state));
+            check_report_cost(contract_id, amount_due, 1 + i * 10, discount_received);
+            total_billed += amount_due as u128;
+        }
+
+        // Only the last 3 bills are kept, oldest first
+        let history = SmartContractModule::contract_billing_history(contract_id);
+        assert_eq!(history.len(), 3);
+        assert_eq!(
+            SmartContractModule::contract_billing_history_count(contract_id),
+            4
+        );
+        assert!(history.windows(2).all(|w| w[0].timestamp < w[1].timestamp));
+        assert_eq!(
+            history.last().unwrap().timestamp,
+            get_timestamp_in_seconds_for_block(41)
+        );
+
+        // All bills happened on the same day
+        let twin_history = SmartContractModule::twin_billing_history(twin_id);
+        assert_eq!(twin_history.len(), 1);
+        assert_eq!(twin_history[0].amount_billed, total_billed);
+    });
+}
+
+#[test]
+fn test_twin_billing_history_prunes_old_days_works() {
+    new_test_ext().execute_with(|| {
+        let twin_id = 2;
+        let bill = |day: u64, amount_billed: u128| types::ContractBill {
+            contract_id: 1,
+            timestamp: day * SECS_PER_DAY + 100,
+            discount_level: types::DiscountLevel::None,
+            amount_billed,
+        };
+
+        SmartContractModule::record_contract_bill(twin_id, &bill(10, 5));
+        SmartContractModule::record_contract_bill(twin_id, &bill(10, 7));
+        SmartContractModule::record_contract_bill(twin_id, &bill(11, 3));
+        assert_eq!(
+            SmartContractModule::twin_billing_history(twin_id),
+            vec![
+                types::TwinDailyBill {
+                    day: 10,
+                    amount_billed: 12
+                },
+                types::TwinDailyBill {
+                    day: 11,
+                    amount_billed: 3
+                },
+            ]
+        );
+
+        // Empty bills are not aggregated
+        SmartContractModule::record_contract_bill(twin_id, &bill(12, 0));
+        assert_eq!(SmartContractModule::twin_billing_history(twin_id).len(), 2);
+
+        // Retention window is 2 days
+        SmartContractModule::record_contract_bill(twin_id, &bill(14, 1));
+        assert_eq!(
+            SmartContractModule::twin_billing_history(twin_id),
+            vec![types::TwinDailyBill {
+                day: 14,
+                amount_billed: 1
+            }]
+        );
+        assert_eq!(
+            SmartContractModule::twin_billing_days(twin_id).into_inner(),
+            vec![14]
+        );
+    });
+}
+
+#[test]
+fn test_contract_billing_history_removed_with_contract_works() {
+    let (mut ext, mut pool_state) = new_test_ext_with_pool_state(0);
+    ext.execute_with(|| {
+        run_to_block(1, None);
+        prepare_farm_and_node();
+        let node_id = 1;
+
+        TFTPriceModule::set_prices(RuntimeOrigin::signed(alice()), 50, 101).unwrap();
+
+        assert_ok!(SmartContractModule::create_node_contract(
+            RuntimeOrigin::signed(bob()),
+            node_id,
+            generate_deployment_hash(),
+            get_deployment_data(),
+            0,
+            None
+        ));
+        let contract_id = 1;
+        let twin_id = 2;
+
+        push_contract_resources_used(contract_id);
+
+        pool_state
+            .write()
+            .should_call_bill_contract(contract_id, Ok(Pays::Yes.into()), 11);
+        run_to_block(11, Some(&mut pool_state));
+        assert_eq!(
+            SmartContractModule::contract_billing_history(contract_id).len(),
+            1
+        );
+
+        assert_ok!(SmartContractModule::cancel_contract(
+            RuntimeOrigin::signed(bob()),
+            contract_id
+        ));
+
+        assert!(SmartContractModule::contract_billing_history(contract_id).is_empty());
+        assert_eq!(
+            SmartContractModule::contract_billing_history_count(contract_id),
+            0
+        );
+        // Twin aggregates outlive the contract
+        assert_eq!(SmartContractModule::twin_billing_history(twin_id).len(), 1);
+    });
+}
+
+//  SPENDING CAP TESTS //
+// ------------------- //
+
+#[test]
+fn test_set_contract_spending_cap_works() {
+    new_test_ext().execute_with(|| {
+        run_to_block(1, None);
+        prepare_farm_node_and_node_contract();
+        let contract_id = 1;
+
+        assert_ok!(SmartContractModule::set_contract_spending_cap(
+            RuntimeOrigin::signed(alice()),
+            contract_id,
+            1000,
+            SECS_PER_DAY,
+            types::SpendingCapAction::Grace
+        ));
+
+        let cap = SmartContractModule::contract_spending_cap(contract_id).unwrap();
+        assert_eq!(cap.amount, 1000);
+        assert_eq!(cap.period, SECS_PER_DAY);
+        assert_eq!(cap.spent, 0);
+        assert_eq!(cap.action, types::SpendingCapAction::Grace);
+
+        assert_ok!(SmartContractModule::clear_contract_spending_cap(
+            RuntimeOrigin::signed(alice()),
+            contract_id
+        ));
+        assert_eq!(
+            SmartContractModule::contract_spending_cap(contract_id),
+            None
+        );
+    });
+}
+
+#[test]
+fn test_set_contract_spending_cap_wrong_twin_fails() {
+    new_test_ext().execute_with(|| {
+        run_to_block(1, None);
+        prepare_farm_node_and_node_contract();
+        let contract_id = 1;
+
+        assert_noop!(
+            SmartContractModule::set_contract_spending_cap(
+                RuntimeOrigin::signed(bob()),
+                contract_id,
+                1000,
+                SECS_PER_DAY,
+                types::SpendingCapAction::Grace
+            ),
+            Error::<TestRuntime>::TwinNotAuthorizedToUpdateContract
+        );
+    });
+}
+
+#[test]
+fn test_set_twin_spending_cap_invalid_period_fails() {
+    new_test_ext().execute_with(|| {
+        run_to_block(1, None);
+        prepare_twins();
+
+        assert_noop!(
+            SmartContractModule::set_twin_spending_cap(
+                RuntimeOrigin::signed(bob()),
+                1000,
+                0,
+                types::SpendingCapAction::Grace
+            ),
+            Error::<TestRuntime>::InvalidSpendingCapPeriod
+        );
+    });
+}
+
+#[test]
+fn test_clear_twin_spending_cap_not_exists_fails() {
+    new_test_ext().execute_with(|| {
+        run_to_block(1, None);
+        prepare_twins();
+
+        assert_noop!(
+            SmartContractModule::clear_twin_spending_cap(RuntimeOrigin::signed(bob())),
+            Error::<TestRuntime>::SpendingCapNotExists
+        );
+    });
+}
+
+#[test]
+fn test_contract_spending_cap_reached_moves_contract_to_grace_works() {
+    let (mut ext, mut pool_state) = new_test_ext_with_pool_state(0);
+    ext.execute_with(|| {
+        run_to_block(1, None);
+        prepare_farm_and_node();
+        let node_id = 1;
+
+        TFTPriceModule::set_prices(RuntimeOrigin::signed(alice()), 50, 101).unwrap();
+
+        assert_ok!(SmartContractModule::create_node_contract(
+            RuntimeOrigin::signed(bob()),
+            node_id,
+            generate_deployment_hash(),
+            get_deployment_data(),
+            0,
+            None
+        ));
+        let contract_id = 1;
+        let twin_id = 2;
+
+        push_contract_resources_used(contract_id);
+
+        // Cap is lower than the first bill
+        let (amount_due, _) = calculate_tft_cost(contract_id, twin_id, 10);
+        assert_ok!(SmartContractModule::set_contract_spending_cap(
+            RuntimeOrigin::signed(bob()),
+            contract_id,
+            amount_due - 1,
+            SECS_PER_DAY,
+            types::SpendingCapAction::Grace
+        ));
+
+        pool_state
+            .write()
+            .should_call_bill_contract(contract_id, Ok(Pays::Yes.into()), 11);
+        run_to_block(11, Some(&mut pool_state));
+
+        let contract = SmartContractModule::contracts(contract_id).unwrap();
+        assert_eq!(contract.state, types::ContractState::GracePeriod(11));
+
+        let our_events = System::events();
+        assert_eq!(
+            our_events.contains(&record(MockEvent::SmartContractModule(
+                SmartContractEvent::<TestRuntime>::SpendingCapReached {
+                    contract_id,
+                    twin_id,
+                    scope: types::SpendingCapScope::Contract,
+                    action: types::SpendingCapAction::Grace,
+                }
+            ))),
+            true
+        );
+        assert!(SmartContractModule::contract_billing_history(contract_id).is_empty());
+        assert_eq!(
+            SmartContractModule::contract_billing_history_count(contract_id),
+            0
+        );
+
+        // Removing the cap ends the grace period on next billing
+        assert_ok!(SmartContractModule::clear_contract_spending_cap(
+            RuntimeOrigin::signed(bob()),
+            contract_id
+        ));
+
+        pool_state
+            .write()
+            .should_call_bill_contract(contract_id, Ok(Pays::Yes.into()), 21);
+        run_to_block(21, Some(&mut pool_state));
+
+        let contract = SmartContractModule::contracts(contract_id).unwrap();
+        assert_eq!(contract.state, types::ContractState::Created);
+    });
+}
+
+#[test]
+fn test_twin_spending_cap_reached_moves_contract_to_grace_works() {
+    let (mut ext, mut pool_state) = new_test_ext_with_pool_state(0);
+    ext.execute_with(|| {
+        run_to_block(1, None);
+        prepare_farm_and_node();
+        let node_id = 1;
+
+        TFTPriceModule::set_prices(RuntimeOrigin::signed(alice()), 50, 101).unwrap();
+
+        assert_ok!(SmartContractModule::create_node_contract(
+            RuntimeOrigin::signed(bob()),
+            node_id,
+            generate_deployment_hash(),
+            get_deployment_data(),
+            0,
+            None
+        ));
+        let contract_id = 1;
+        let twin_id = 2;
+
+        push_contract_resources_used(contract_id);
+
+        // Cap allows a single bill
+        let (amount_due, _) = calculate_tft_cost(contract_id, twin_id, 10);
+        assert_ok!(SmartContractModule::set_twin_spending_cap(
+            RuntimeOrigin::signed(bob()),
+            amount_due + amount_due / 2,
+            SECS_PER_DAY,
+            types::SpendingCapAction::Grace
+        ));
+
+        pool_state
+            .write()
+            .should_call_bill_contract(contract_id, Ok(Pays::Yes.into()), 11);
+        run_to_block(11, Some(&mut pool_state));
+
+        let contract = SmartContractModule::contracts(contract_id).unwrap();
+        assert_eq!(contract.state, types::ContractState::Created);
+        assert_eq!(
+            SmartContractModule::twin_spending_cap(twin_id)
+                .unwrap()
+                .spent,
+            amount_due
+        );
+
+        let (second_amount_due, _) = calculate_tft_cost(contract_id, twin_id, 10);
+        pool_state
+            .write()
+            .should_call_bill_contract(contract_id, Ok(Pays::Yes.into()), 21);
+        run_to_block(21, Some(&mut pool_state));
+
+        let contract = SmartContractModule::contracts(contract_id).unwrap();
+        assert_eq!(contract.state, types::ContractState::GracePeriod(21));
+        // Amount due while in grace is settled later, it counts against the cap already
+        assert_eq!(
+            SmartContractModule::twin_spending_cap(twin_id)
+                .unwrap()
+                .spent,
+            amount_due + second_amount_due
+        );
+
+        let our_events = System::events();
+        assert_eq!(
+            our_events.contains(&record(MockEvent::SmartContractModule(
+                SmartContractEvent::<TestRuntime>::SpendingCapReached {
+                    contract_id,
+                    twin_id,
+                    scope: types::SpendingCapScope::Twin,
+                    action: types::SpendingCapAction::Grace,
+                }
+            ))),
+            true
+        );
+    });
+}
+
+#[test]
+fn test_contract_spending_cap_reached_pauses_contract_works() {
+    let (mut ext, mut pool_state) = new_test_ext_with_pool_state(0);
+    ext.execute_with(|| {
+        run_to_block(1, None);
+        prepare_farm_and_node();
+        let node_id = 1;
+
+        TFTPriceModule::set_prices(RuntimeOrigin::signed(alice()), 50, 101).unwrap();
+
+        assert_ok!(SmartContractModule::create_node_contract(
+            RuntimeOrigin::signed(bob()),
+            node_id,
+            generate_deployment_hash(),
+            get_deployment_data(),
+            0,
+            None
+        ));
+        let contract_id = 1;
+        let twin_id = 2;
+
+        push_contract_resources_used(contract_id);
+
+        // Cap is lower than the first bill
+        let (amount_due, _) = calculate_tft_cost(contract_id, twin_id, 10);
+        assert_ok!(SmartContractModule::set_contract_spending_cap(
+            RuntimeOrigin::signed(bob()),
+            contract_id,
+            amount_due - 1,
+            SECS_PER_DAY,
+            types::SpendingCapAction::Pause
+        ));
+
+        // Paused contracts are kept past the end of the grace period (100 blocks)
+        for block_number in (11..=131).step_by(10) {
+            pool_state.write().should_call_bill_contract(
+                contract_id,
+                Ok(Pays::Yes.into()),
+                block_number,
+            );
+        }
+        run_to_block(131, Some(&mut pool_state));
+
+        // The grace period restarts on every billing while paused
+        let contract = SmartContractModule::contracts(contract_id).unwrap();
+        assert_eq!(contract.state, types::ContractState::GracePeriod(131));
+
+        // Nothing is billed nor counted against the cap while paused
+        let contract_lock = SmartContractModule::contract_number_of_cylces_billed(contract_id);
+        assert_eq!(contract_lock.amount_locked, 0);
+        assert_eq!(
+            SmartContractModule::contract_spending_cap(contract_id)
+                .unwrap()
+                .spent,
+            0
+        );
+
+        let our_events = System::events();
+        assert_eq!(
+            our_events.contains(&record(MockEvent::SmartContractModule(
+                SmartContractEvent::<TestRuntime>::SpendingCapReached {
+                    contract_id,
+                    twin_id,
+                    scope: types::SpendingCapScope::Contract,
+                    action: types::SpendingCapAction::Pause,
+                }
+            ))),
+            true
+        );
+
+        // Removing the cap resumes billing on next billing
+        assert_ok!(SmartContractModule::clear_contract_spending_cap(
+            RuntimeOrigin::signed(bob()),
+            contract_id
+        ));
+
+        // Only the time since the last billing is billed
+        let (amount_due, _) = calculate_tft_cost(contract_id, twin_id, 10);
+        pool_state
+            .write()
+            .should_call_bill_contract(contract_id, Ok(Pays::Yes.into()), 141);
+        run_to_block(141, Some(&mut pool_state));
+
+        let contract = SmartContractModule::contracts(contract_id).unwrap();
+        assert_eq!(contract.state, types::ContractState::Created);
+        let contract_lock = SmartContractModule::contract_number_of_cylces_billed(contract_id);
+        assert_eq!(contract_lock.amount_locked, amount_due);
+    });
+}
+
+//  CONTRACT TRANSFER TESTS //
+// ------------------------ //
+
+#[test]
+fn test_transfer_node_contract_works() {
+    new_test_ext().execute_with(|| {
+        run_to_block(1, None);
+        prepare_farm_node_and_node_contract();
+        let contract_id = 1;
+        let (from_twin_id, to_twin_id) = (1, 2);
+
+        push_contract_resources_used(contract_id);
+
+        assert_ok!(SmartContractModule::offer_contract_transfer(
+            RuntimeOrigin::signed(alice()),
+            contract_id,
+            to_twin_id
+        ));
+        assert_eq!(
+            SmartContractModule::contract_transfer_offer(contract_id),
+            Some(to_twin_id)
+        );
+
+        run_to_block(10, None);
+        let free_balance_before = Balances::free_balance(alice());
+        assert_ok!(SmartContractModule::accept_contract_transfer(
+            RuntimeOrigin::signed(bob()),
+            contract_id
+        ));
+
+        let contract = SmartContractModule::contracts(contract_id).unwrap();
+        assert_eq!(contract.twin_id, to_twin_id);
+        assert_eq!(contract.state, types::ContractState::Created);
+        assert_eq!(
+            SmartContractModule::contract_transfer_offer(contract_id),
+            None
+        );
+
+        // Previous owner paid what was due up to the transfer
+        assert!(Balances::free_balance(alice()) < free_balance_before);
+        assert_eq!(
+            Balances::free_balance(alice()),
+            Balances::usable_balance(alice())
+        );
+        let contract_lock = SmartContractModule::contract_number_of_cylces_billed(contract_id);
+        assert_eq!(contract_lock.amount_locked, 0);
+
+        let our_events = System::events();
+        assert_eq!(
+            our_events.last().unwrap(),
+            &record(MockEvent::SmartContractModule(SmartContractEvent::<
+                TestRuntime,
+            >::ContractTransferred {
+                contract_id,
+                from_twin_id,
+                to_twin_id,
+            }))
+        );
+    });
+}
+
+#[test]
+fn test_offer_contract_transfer_fails() {
+    new_test_ext().execute_with(|| {
+        run_to_block(1, None);
+        prepare_farm_node_and_node_contract();
+        let contract_id = 1;
+
+        assert_noop!(
+            SmartContractModule::offer_contract_transfer(
+                RuntimeOrigin::signed(bob()),
+                contract_id,
+                3
+            ),
+            Error::<TestRuntime>::TwinNotAuthorizedToUpdateContract
+        );
+
+        assert_noop!(
+            SmartContractModule::offer_contract_transfer(
+                RuntimeOrigin::signed(alice()),
+                contract_id,
+                1
+            ),
+            Error::<TestRuntime>::CannotTransferContractToSameTwin
+        );
+
+        assert_noop!(
+            SmartContractModule::offer_contract_transfer(
+                RuntimeOrigin::signed(alice()),
+                contract_id,
+                10
+            ),
+            Error::<TestRuntime>::TwinNotExists
+        );
+    });
+}
+
+#[test]
+fn test_accept_contract_transfer_not_offered_fails() {
+    new_test_ext().execute_with(|| {
+        run_to_block(1, None);
+        prepare_farm_node_and_node_contract();
+        let contract_id = 1;
+
+        assert_ok!(SmartContractModule::offer_contract_transfer(
+            RuntimeOrigin::signed(alice()),
+            contract_id,
+            2
+        ));
+
+        assert_noop!(
+            SmartContractModule::accept_contract_transfer(
+                RuntimeOrigin::signed(charlie()),
+                contract_id
+            ),
+            Error::<TestRuntime>::ContractTransferNotOffered
+        );
+
+        assert_ok!(SmartContractModule::cancel_contract_transfer(
+            RuntimeOrigin::signed(alice()),
+            contract_id
+        ));
+
+        assert_noop!(
+            SmartContractModule::accept_contract_transfer(
+                RuntimeOrigin::signed(bob()),
+                contract_id
+            ),
+            Error::<TestRuntime>::ContractTransferNotOffered
+        );
+    });
+}
+
+#[test]
+fn test_transfer_contracts_of_rented_node_fails() {
+    new_test_ext().execute_with(|| {
+        run_to_block(1, None);
+        prepare_dedicated_farm_and_node();
+        let node_id = 1;
+
+        assert_ok!(SmartContractModule::create_rent_contract(
+            RuntimeOrigin::signed(bob()),
+            node_id,
+            None
+        ));
+        let rent_contract_id = 1;
+
+        assert_ok!(SmartContractModule::create_node_contract(
+            RuntimeOrigin::signed(bob()),
+            node_id,
+            generate_deployment_hash(),
+            get_deployment_data(),
+            0,
+            None
+        ));
+        let node_contract_id = 2;
+
+        // Node contract can not move away from the twin renting the node
+        assert_ok!(SmartContractModule::offer_contract_transfer(
+            RuntimeOrigin::signed(bob()),
+            node_contract_id,
+            3
+        ));
+        assert_noop!(
+            SmartContractModule::accept_contract_transfer(
+                RuntimeOrigin::signed(charlie()),
+                node_contract_id
+            ),
+            Error::<TestRuntime>::NodeNotAvailableToDeploy
+        );
+
+        // Rent contract can not move while the node has node contracts
+        assert_ok!(SmartContractModule::offer_contract_transfer(
+            RuntimeOrigin::signed(bob()),
+            rent_contract_id,
+            3
+        ));
+        assert_noop!(
+            SmartContractModule::accept_contract_transfer(
+                RuntimeOrigin::signed(charlie()),
+                rent_contract_id
+            ),
+            Error::<TestRuntime>::NodeHasActiveContracts
+        );
+    });
+}
+
+//  SOLUTION PROVIDER TESTS //
+// ------------------------ //
+
+#[test]
+fn test_create_solution_provider_works() {
+    new_test_ext().execute_with(|| {
+        let provider1 = super::types::Provider {
+            take: 10,
+            who: alice(),
+        };
+        let provider2 = super::types::Provider {
+            take: 10,
+            who: bob(),
+        };
+        let providers = vec![provider1, provider2];
+
+        assert_ok!(SmartContractModule::create_solution_provider(
+            RuntimeOrigin::signed(alice()),
+            b"some_description".to_vec(),
+            b"some_link".to_vec(),
+            providers
+        ));
+        let provider_id = 1;
+
+        assert_ok!(SmartContractModule::approve_solution_provider(
+            RawOrigin::Root.into(),
+            provider_id,
+            true
+        ));
+    })
+}
+
+#[test]
+fn test_create_solution_provider_fails_if_take_to_high() {
+    new_test_ext().execute_with(|| {
+        let provider = super::types::Provider {
+            take: 51,
+            who: alice(),
+        };
+        let providers = vec![provider];
+
+        assert_noop!(
+            SmartContractModule::create_solution_provider(
+                RuntimeOrigin::signed(alice()),
+                b"some_description".to_vec(),
+                b"some_link".to_vec(),
+                providers
+            ),
+            Error::<TestRuntime>::InvalidProviderConfiguration
+        );
+    })
+}
+
+#[test]
+fn test_create_node_contract_with_solution_provider_works() {
+    new_test_ext().execute_with(|| {
+        run_to_block(1, None);
+        prepare_farm_and_node();
+        let node_id = 1;
+
+        prepare_solution_provider(alice());
+        let provider_id = 1;
+
+        assert_ok!(SmartContractModule::create_node_contract(
+            RuntimeOrigin::signed(alice()),
+            node_id,
+            generate_deployment_hash(),
+            get_deployment_data(),
+            0,
+            Some(provider_id)
+        ));
+    });
+}
+
+#[test]
+fn test_create_node_contract_with_solution_provider_fails_if_not_approved() {
+    new_test_ext().execute_with(|| {
+        prepare_farm_and_node();
+        let node_id = 1;
+
+        let provider = super::types::Provider {
+            take: 10,
+            who: alice(),
+        };
+        let providers = vec![provider];
+
+        assert_ok!(SmartContractModule::create_solution_provider(
+            RuntimeOrigin::signed(alice()),
+            b"some_description".to_vec(),
+            b"some_link".to_vec(),
+            providers
+        ));
+        let provider_id = 1;
+
+        assert_noop!(
+            SmartContractModule::create_node_contract(
+                RuntimeOrigin::signed(alice()),
+                node_id,
+                generate_deployment_hash(),
+                get_deployment_data(),
+                0,
+                Some(provider_id)
+            ),
+            Error::<TestRuntime>::SolutionProviderNotApproved
+        );
+    });
+}
+
+#[test]
+fn test_solution_provider_earnings_are_tracked() {
+    let (mut ext, mut pool_state) = new_test_ext_with_pool_state(0);
+    ext.execute_with(|| {
+        let contract_id = prepare_node_contract_with_solution_provider();
+        let provider_id = 1;
+        let initial_provider_balance = Balances::free_balance(dave());
+
+        // advance 25 cycles, rewards are distributed after 24 cycles
+        for i in 0..25 {
+            let block_number = 11 + i * 10;
+            pool_state.write().should_call_bill_contract(
+                contract_id,
+                Ok(Pays::Yes.into()),
+                block_number,
+            );
+            run_to_block(block_number, Some(&mut pool_state));
+        }
+
+        let earned = Balances::free_balance(dave()) - initial_provider_balance;
+        assert_ne!(earned, 0);
+        assert_eq!(
+            SmartContractModule::solution_provider_account_earnings(provider_id, dave()),
+            earned
+        );
+        assert_eq!(
+            SmartContractModule::solution_provider_contract_earnings(provider_id, contract_id),
+            earned
+        );
+        assert_eq!(
+            SmartContractModule::get_solution_provider_earnings(provider_id),
+            types::SolutionProviderEarnings {
+                solution_provider_id: provider_id,
+                providers: vec![types::ProviderEarnings {
+                    who: dave(),
+                    earned,
+                }],
+                contracts: vec![types::ContractEarnings {
+                    contract_id,
+                    earned,
+                }],
+            }
+        );
+        assert_eq!(
+            SmartContractModule::unclaimed_solution_provider_rewards(dave()),
+            0
+        );
+    });
+}
+
+#[test]
+fn test_solution_provider_rewards_accrual_and_claim_works() {
+    let (mut ext, mut pool_state) = new_test_ext_with_pool_state(0);
+    ext.execute_with(|| {
+        let contract_id = prepare_node_contract_with_solution_provider();
+        let provider_id = 1;
+        let rewards_account = SmartContractModule::solution_provider_rewards_account();
+        assert_ok!(Balances::force_set_balance(
+            RawOrigin::Root.into(),
+            rewards_account.clone(),
+            EXISTENTIAL_DEPOSIT
+        ));
+
+        assert_ok!(SmartContractModule::set_solution_provider_rewards_accrual(
+            RuntimeOrigin::signed(dave()),
+            true
+        ));
+        let initial_provider_balance = Balances::free_balance(dave());
+
+        // advance 25 cycles, rewards are distributed after 24 cycles
+        for i in 0..25 {
+            let block_number = 11 + i * 10;
+            pool_state.write().should_call_bill_contract(
+                contract_id,
+                Ok(Pays::Yes.into()),
+                block_number,
+            );
+            run_to_block(block_number, Some(&mut pool_state));
+        }
+
+        // Shares are kept on the rewards account until claimed
+        let unclaimed = SmartContractModule::unclaimed_solution_provider_rewards(dave());
+        assert_ne!(unclaimed, 0);
+        assert_eq!(
+            SmartContractModule::solution_provider_account_earnings(provider_id, dave()),
+            unclaimed
+        );
+        assert_eq!(Balances::free_balance(dave()), initial_provider_balance);
+        assert_eq!(
+            Balances::free_balance(&rewards_account),
+            EXISTENTIAL_DEPOSIT + unclaimed
+        );
+
+        assert_ok!(SmartContractModule::claim_solution_provider_rewards(
+            RuntimeOrigin::signed(dave())
+        ));
+
+        assert_eq!(
+            Balances::free_balance(dave()),
+            initial_provider_balance + unclaimed
+        );
+        assert_eq!(
+            SmartContractModule::unclaimed_solution_provider_rewards(dave()),
+            0
+        );
+        assert_eq!(
+            Balances::free_balance(&rewards_account),
+            EXISTENTIAL_DEPOSIT
+        );
+
+        let our_events = System::events();
+        assert_eq!(
+            our_events.last().unwrap(),
+            &record(MockEvent::SmartContractModule(
+                SmartContractEvent::<TestRuntime>::SolutionProviderRewardsClaimed {
+                    account_id: dave(),
+                    amount: unclaimed,
+                }
+            ))
+        );
+    });
+}
+
+#[test]
+fn test_claim_solution_provider_rewards_fails_if_nothing_accrued() {
+    new_test_ext().execute_with(|| {
+        assert_noop!(
+            SmartContractModule::claim_solution_provider_rewards(RuntimeOrigin::signed(dave())),
+            Error::<TestRuntime>::NoSolutionProviderRewardsToClaim
+        );
+    });
+}
+
+#[test]
+fn test_update_solution_provider_works() {
+    new_test_ext().execute_with(|| {
+        prepare_solution_provider(alice());
+        let provider_id = 1;
+
+        // Changing description and link does not require a new approval
+        assert_ok!(SmartContractModule::update_solution_provider(
+            RuntimeOrigin::signed(dave()),
+            provider_id,
+            b"other_description".to_vec(),
+            b"other_link".to_vec(),
+            vec![super::types::Provider {
+                take: 10,
+                who: dave(),
+            }]
+        ));
+        let solution_provider = SmartContractModule::solution_providers(provider_id).unwrap();
+        assert_eq!(solution_provider.description, b"other_description".to_vec());
+        assert_eq!(solution_provider.link, b"other_link".to_vec());
+        assert!(solution_provider.approved);
+
+        // Changing the takes does
+        let providers = vec![super::types::Provider {
+            take: 20,
+            who: dave(),
+        }];
+        assert_ok!(SmartContractModule::update_solution_provider(
+            RuntimeOrigin::signed(dave()),
+            provider_id,
+            b"other_description".to_vec(),
+            b"other_link".to_vec(),
+            providers.clone()
+        ));
+        let solution_provider = SmartContractModule::solution_providers(provider_id).unwrap();
+        assert_eq!(solution_provider.providers, providers);
+        assert!(!solution_provider.approved);
+
+        let our_events = System::events();
+        assert_eq!(
+            our_events.last().unwrap(),
+            &record(MockEvent::SmartContractModule(SmartContractEvent::<
+                TestRuntime,
+            >::SolutionProviderUpdated(
+                solution_provider
+            )))
+        );
+    });
+}
+
+#[test]
+fn test_update_solution_provider_fails_if_not_provider() {
+    new_test_ext().execute_with(|| {
+        prepare_solution_provider(alice());
+        let provider_id = 1;
+
+        assert_noop!(
+            SmartContractModule::update_solution_provider(
+                RuntimeOrigin::signed(alice()),
+                provider_id,
+                b"other_description".to_vec(),
+                b"other_link".to_vec(),
+                vec![super::types::Provider {
+                    take: 10,
+                    who: alice(),
+                }]
+            ),
+            Error::<TestRuntime>::UnauthorizedToUpdateSolutionProvider
+        );
+    });
+}
+
+#[test]
+fn test_revoke_solution_provider_works() {
+    let (mut ext, mut pool_state) = new_test_ext_with_pool_state(0);
+    ext.execute_with(|| {
+        let contract_id = prepare_node_contract_with_solution_provider();
+        let provider_id = 1;
+
+        assert_ok!(SmartContractModule::revoke_solution_provider(
+            RawOrigin::Root.into(),
+            provider_id
+        ));
+        let solution_provider = SmartContractModule::solution_providers(provider_id).unwrap();
+        assert!(solution_provider.revoked);
+        assert!(!solution_provider.approved);
+
+        // A revoked solution provider can not be approved again nor used
+        assert_noop!(
+            SmartContractModule::approve_solution_provider(
+                RawOrigin::Root.into(),
+                provider_id,
+                true
+            ),
+            Error::<TestRuntime>::SolutionProviderRevoked
+        );
+        assert_noop!(
+            SmartContractModule::create_node_contract(
+                RuntimeOrigin::signed(bob()),
+                1,
+                generate_deployment_hash(),
+                get_deployment_data(),
+                0,
+                Some(provider_id)
+            ),
+            Error::<TestRuntime>::SolutionProviderRevoked
+        );
+
+        let twin = TfgridModule::twins(2).unwrap();
+        let initial_twin_balance = Balances::free_balance(&twin.account_id);
+        let initial_total_issuance = Balances::total_issuance();
+        let initial_provider_balance = Balances::free_balance(dave());
+
+        // advance 25 cycles, rewards are distributed after 24 cycles
+        for i in 0..25 {
+            let block_number = 11 + i * 10;
+            pool_state.write().should_call_bill_contract(
+                contract_id,
+                Ok(Pays::Yes.into()),
+                block_number,
+            );
+            run_to_block(block_number, Some(&mut pool_state));
+        }
+
+        // Take of the revoked solution provider goes to the sales account
+        let total_amount_billed = initial_twin_balance - Balances::free_balance(&twin.account_id);
+        validate_distribution_rewards(initial_total_issuance, total_amount_billed, false);
+        assert_eq!(Balances::free_balance(dave()), initial_provider_balance);
+    });
+}
+
+#[test]
+fn test_solution_provider_expiry_works() {
+    new_test_ext().execute_with(|| {
+        run_to_block(1, None);
+        prepare_farm_and_node();
+        prepare_solution_provider(alice());
+        let provider_id = 1;
+
+        let now = SmartContractModule::get_current_timestamp_in_secs();
+        assert_noop!(
+            SmartContractModule::set_solution_provider_expiry(
+                RawOrigin::Root.into(),
+                provider_id,
+                Some(now)
+            ),
+            Error::<TestRuntime>::InvalidSolutionProviderExpiry
+        );
+
+        let expires_at = Some(now + 60);
+        assert_ok!(SmartContractModule::set_solution_provider_expiry(
+            RawOrigin::Root.into(),
+            provider_id,
+            expires_at
+        ));
+        assert_eq!(
+            SmartContractModule::solution_providers(provider_id)
+                .unwrap()
+                .expires_at,
+            expires_at
+        );
+
+        // 10 blocks later the solution provider expired
+        run_to_block(11, None);
+        assert_noop!(
+            SmartContractModule::create_node_contract(
+                RuntimeOrigin::signed(bob()),
+                1,
+                generate_deployment_hash(),
+                get_deployment_data(),
+                0,
+                Some(provider_id)
+            ),
+            Error::<TestRuntime>::SolutionProviderExpired
+        );
+    });
+}
+
+// SERVICE CONTRACT TESTS //
+// ---------------------- //
+
+#[test]
+fn test_service_contract_create_works() {
+    new_test_ext().execute_with(|| {
+        run_to_block(1, None);
+        create_service_consumer_contract();
+        let service_contract_id = 1;
+
+        let service_contract = get_service_contract();
+        assert_eq!(
+            service_contract,
+            SmartContractModule::service_contracts(service_contract_id).unwrap(),
+        );
+
+        let our_events = System::events();
+        assert_eq!(!our_events.is_empty(), true);
+        assert_eq!(
+            our_events.last().unwrap(),
+            &record(MockEvent::SmartContractModule(
+                SmartContractEvent::ServiceContractCreated(service_contract)
+            )),
+        );
+    });
+}
+
+#[test]
+fn test_service_contract_create_by_anyone_fails() {
+    new_test_ext().execute_with(|| {
+        create_twin(alice());
+        create_twin(bob());
+        create_twin(charlie());
+
+        assert_noop!(
+            SmartContractModule::service_contract_create(
+                RuntimeOrigin::signed(charlie()),
+                alice(),
+                bob(),
+            ),
+            Error::<TestRuntime>::TwinNotAuthorized
+        );
+    });
+}
+
+#[test]
+fn test_service_contract_create_same_account_fails() {
+    new_test_ext().execute_with(|| {
+        create_twin(alice());
+
+        assert_noop!(
+            SmartContractModule::service_contract_create(
+                RuntimeOrigin::signed(alice()),
+                alice(),
+                alice(),
+            ),
+            Error::<TestRuntime>::ServiceContractCreationNotAllowed
+        );
+    });
+}
+
+#[test]
+fn test_service_contract_set_metadata_works() {
+    new_test_ext().execute_with(|| {
+        run_to_block(1, None);
+        create_service_consumer_contract();
+        let service_contract_id = 1;
+
+        assert_ok!(SmartContractModule::service_contract_set_metadata(
+            RuntimeOrigin::signed(alice()),
+            service_contract_id,
+            b"some_metadata".to_vec(),
+        ));
+
+        let mut service_contract = get_service_contract();
+        service_contract.metadata = BoundedVec::try_from(b"some_metadata".to_vec()).unwrap();
+        assert_eq!(
+            service_contract,
+            SmartContractModule::service_contracts(1).unwrap(),
+        );
+
+        let our_events = System::events();
+        assert_eq!(!our_events.is_empty(), true);
+        assert_eq!(
+            our_events.last().unwrap(),
+            &record(MockEvent::SmartContractModule(
+                SmartContractEvent::ServiceContractMetadataSet(service_contract)
+            )),
+        );
+    });
+}
+
+#[test]
+fn test_service_contract_set_metadata_by_unauthorized_fails() {
+    new_test_ext().execute_with(|| {
+        create_service_consumer_contract();
+        let service_contract_id = 1;
+        create_twin(charlie());
+
+        assert_noop!(
+            SmartContractModule::service_contract_set_metadata(
+                RuntimeOrigin::signed(charlie()),
+                service_contract_id,
+                b"some_metadata".to_vec(),
+            ),
+            Error::<TestRuntime>::TwinNotAuthorized
+        );
+    });
+}
+
+#[test]
+fn test_service_contract_set_metadata_already_approved_fails() {
+    new_test_ext().execute_with(|| {
+        prepare_service_consumer_contract();
+        let service_contract_id = 1;
+        approve_service_consumer_contract(service_contract_id);
+
+        assert_noop!(
+            SmartContractModule::service_contract_set_metadata(
+                RuntimeOrigin::signed(alice()),
+                service_contract_id,
+                b"some_metadata".to_vec(),
+            ),
+            Error::<TestRuntime>::ServiceContractModificationNotAllowed
+        );
+    });
+}
+
+#[test]
+fn test_service_contract_set_metadata_too_long_fails() {
+    new_test_ext().execute_with(|| {
+        create_service_consumer_contract();
+        let service_contract_id = 1;
+
+        assert_noop!(
+            SmartContractModule::service_contract_set_metadata(
+                RuntimeOrigin::signed(alice()),
+                service_contract_id,
+                b"very_loooooooooooooooooooooooooooooooooooooooooooooooooong_metadata".to_vec(),
+            ),
+            Error::<TestRuntime>::ServiceContractMetadataTooLong
+        );
+    });
+}
+
+#[test]
+fn test_service_contract_set_fees_works() {
+    new_test_ext().execute_with(|| {
+        run_to_block(1, None);
+        create_service_consumer_contract();
+        let service_contract_id = 1;
+
+        assert_ok!(SmartContractModule::service_contract_set_fees(
+            RuntimeOrigin::signed(alice()),
+            1,
+            BASE_FEE,
+            VARIABLE_FEE,
+        ));
+
+        let mut service_contract = get_service_contract();
+        service_contract.base_fee = BASE_FEE;
+        service_contract.variable_fee = VARIABLE_FEE;
+        assert_eq!(
+            service_contract,
+            SmartContractModule::service_contracts(service_contract_id).unwrap(),
+        );
+
+        let our_events = System::events();
+        assert_eq!(!our_events.is_empty(), true);
+        assert_eq!(
+            our_events.last().unwrap(),
+            &record(MockEvent::SmartContractModule(
+                SmartContractEvent::ServiceContractFeesSet(service_contract)
+            )),
+        );
+    });
+}
+
+#[test]
+fn test_service_contract_set_fees_by_unauthorized_fails() {
+    new_test_ext().execute_with(|| {
+        create_service_consumer_contract();
+        let service_contract_id = 1;
+
+        assert_noop!(
+            SmartContractModule::service_contract_set_fees(
+                RuntimeOrigin::signed(bob()),
+                service_contract_id,
+                BASE_FEE,
+                VARIABLE_FEE,
+            ),
+            Error::<TestRuntime>::TwinNotAuthorized
+        );
+    });
+}
+
+#[test]
+fn test_service_contract_set_fees_already_approved_fails() {
+    new_test_ext().execute_with(|| {
+        prepare_service_consumer_contract();
+        let service_contract_id = 1;
+        approve_service_consumer_contract(service_contract_id);
+
+        assert_noop!(
+            SmartContractModule::service_contract_set_fees(
+                RuntimeOrigin::signed(alice()),
+                service_contract_id,
+                BASE_FEE,
+                VARIABLE_FEE,
+            ),
+            Error::<TestRuntime>::ServiceContractModificationNotAllowed
+        );
+    });
+}
+
+#[test]
+fn test_service_contract_approve_works() {
+    new_test_ext().execute_with(|| {
+        run_to_block(1, None);
+        prepare_service_consumer_contract();
+        let service_contract_id = 1;
+
+        let mut service_contract = get_service_contract();
+        service_contract.base_fee = BASE_FEE;
+        service_contract.variable_fee = VARIABLE_FEE;
+        service_contract.metadata = BoundedVec::try_from(b"some_metadata".to_vec()).unwrap();
+        service_contract.state = types::ServiceContractState::AgreementReady;
+        assert_eq!(
+            service_contract,
+            SmartContractModule::service_contracts(1).unwrap(),
+        );
+
+        // Service approves
+        assert_ok!(SmartContractModule::service_contract_approve(
+            RuntimeOrigin::signed(alice()),
+            service_contract_id,
+        ));
+
+        service_contract.accepted_by_service = true;
+        assert_eq!(
+            service_contract,
+            SmartContractModule::service_contracts(1).unwrap(),
+        );
+
+        let our_events = System::events();
+        assert_eq!(!our_events.is_empty(), true);
+        assert_eq!(
+            our_events.last().unwrap(),
+            &record(MockEvent::SmartContractModule(SmartContractEvent::<
+                TestRuntime,
+            >::ServiceContractApproved(
+                service_contract.clone()
+            ))),
+        );
+
+        // Consumer approves
+        assert_ok!(SmartContractModule::service_contract_approve(
+            RuntimeOrigin::signed(bob()),
+            service_contract_id,
+        ));
+
+        service_contract.accepted_by_consumer = true;
+        service_contract.last_bill = get_timestamp_in_seconds_for_block(1);
+        service_contract.state = types::ServiceContractState::ApprovedByBoth;
+        assert_eq!(
+            service_contract,
+            SmartContractModule::service_contracts(1).unwrap(),
+        );
+
+        let our_events = System::events();
+        assert_eq!(!our_events.is_empty(), true);
+        assert_eq!(
+            our_events.last().unwrap(),
+            &record(MockEvent::SmartContractModule(SmartContractEvent::<
+                TestRuntime,
+            >::ServiceContractApproved(
+                service_contract
+            ))),
+        );
+    });
+}
+
+#[test]
+fn test_service_contract_approve_agreement_not_ready_fails() {
+    new_test_ext().execute_with(|| {
+        create_service_consumer_contract();
+        let service_contract_id = 1;
+
+        assert_noop!(
+            SmartContractModule::service_contract_approve(
+                RuntimeOrigin::signed(alice()),
+                service_contract_id
+            ),
+            Error::<TestRuntime>::ServiceContractApprovalNotAllowed
+        );
+    });
+}
+
+#[test]
+fn test_service_contract_approve_already_approved_fails() {
+    new_test_ext().execute_with(|| {
+        prepare_service_consumer_contract();
+        let service_contract_id = 1;
+        approve_service_consumer_contract(service_contract_id);
+
+        assert_noop!(
+            SmartContractModule::service_contract_approve(
+                RuntimeOrigin::signed(alice()),
+                service_contract_id
+            ),
+            Error::<TestRuntime>::ServiceContractApprovalNotAllowed
+        );
+    });
+}
+
+#[test]
+fn test_service_contract_approve_by_unauthorized_fails() {
+    new_test_ext().execute_with(|| {
+        prepare_service_consumer_contract();
+        let service_contract_id = 1;
+        create_twin(charlie());
+
+        assert_noop!(
+            SmartContractModule::service_contract_approve(
+                RuntimeOrigin::signed(charlie()),
+                service_contract_id
+            ),
+            Error::<TestRuntime>::TwinNotAuthorized
+        );
+    });
+}
+
+#[test]
+fn test_service_contract_reject_works() {
+    new_test_ext().execute_with(|| {
+        run_to_block(1, None);
+        prepare_service_consumer_contract();
+        let service_contract_id = 1;
+
+        assert_ok!(SmartContractModule::service_contract_reject(
+            RuntimeOrigin::signed(alice()),
+            service_contract_id,
+        ));
+
+        assert_eq!(SmartContractModule::service_contracts(1).is_none(), true);
+
+        let our_events = System::events();
+        assert_eq!(!our_events.is_empty(), true);
+        assert_eq!(
+            our_events.last().unwrap(),
+            &record(MockEvent::SmartContractModule(SmartContractEvent::<
+                TestRuntime,
+            >::ServiceContractCanceled {
+                service_contract_id,
+                cause: types::Cause::CanceledByUser,
+            })),
+        );
+    });
+}
+
+#[test]
+fn test_service_contract_reject_agreement_not_ready_fails() {
+    new_test_ext().execute_with(|| {
+        create_service_consumer_contract();
+        let service_contract_id = 1;
+
+        assert_noop!(
+            SmartContractModule::service_contract_reject(
+                RuntimeOrigin::signed(alice()),
+                service_contract_id
+            ),
+            Error::<TestRuntime>::ServiceContractRejectionNotAllowed
+        );
+    });
+}
+
+#[test]
+fn test_service_contract_reject_already_approved_fails() {
+    new_test_ext().execute_with(|| {
+        prepare_service_consumer_contract();
+        let service_contract_id = 1;
+        approve_service_consumer_contract(service_contract_id);
+
+        assert_noop!(
+            SmartContractModule::service_contract_reject(
+                RuntimeOrigin::signed(alice()),
+                service_contract_id
+            ),
+            Error::<TestRuntime>::ServiceContractRejectionNotAllowed
+        );
+    });
+}
+
+#[test]
+fn test_service_contract_reject_by_unauthorized_fails() {
+    new_test_ext().execute_with(|| {
+        prepare_service_consumer_contract();
+        let service_contract_id = 1;
+        create_twin(charlie());
+
+        assert_noop!(
+            SmartContractModule::service_contract_reject(
+                RuntimeOrigin::signed(charlie()),
+                service_contract_id
+            ),
+            Error::<TestRuntime>::TwinNotAuthorized
+        );
+    });
+}
+
+#[test]
+fn test_service_contract_cancel_works() {
+    new_test_ext().execute_with(|| {
+        run_to_block(1, None);
+        create_service_consumer_contract();
+        let service_contract_id = 1;
+
+        assert_ok!(SmartContractModule::service_contract_cancel(
+            RuntimeOrigin::signed(alice()),
+            service_contract_id,
+        ));
+
+        assert_eq!(SmartContractModule::service_contracts(1).is_none(), true);
+
+        let our_events = System::events();
+        assert_eq!(!our_events.is_empty(), true);
+        assert_eq!(
+            our_events.last().unwrap(),
+            &record(MockEvent::SmartContractModule(SmartContractEvent::<
+                TestRuntime,
+            >::ServiceContractCanceled {
+                service_contract_id,
+                cause: types::Cause::CanceledByUser,
+            })),
+        );
+    });
+}
+
+#[test]
+fn test_service_contract_cancel_by_unauthorized_fails() {
+    new_test_ext().execute_with(|| {
+        create_service_consumer_contract();
+        create_twin(charlie());
+        let service_contract_id = 1;
+
+        assert_noop!(
+            SmartContractModule::service_contract_cancel(
+                RuntimeOrigin::signed(charlie()),
+                service_contract_id
+            ),
+            Error::<TestRuntime>::TwinNotAuthorized
+        );
+    });
+}
+
+#[test]
+fn test_service_contract_bill_works() {
+    let (mut ext, mut pool_state) = new_test_ext_with_pool_state(0);
+    ext.execute_with(|| {
+        run_to_block(1, None);
+        prepare_service_consumer_contract();
+        let service_contract_id = 1;
+
+        let service_contract = SmartContractModule::service_contracts(service_contract_id).unwrap();
+        assert_eq!(service_contract.last_bill, 0);
+
+        approve_service_consumer_contract(service_contract_id);
+
+        let service_contract = SmartContractModule::service_contracts(service_contract_id).unwrap();
+        assert_eq!(
+            service_contract.last_bill,
+            get_timestamp_in_seconds_for_block(1)
+        );
+
+        let consumer_twin = TfgridModule::twins(2).unwrap();
+        let consumer_balance = Balances::free_balance(&consumer_twin.account_id);
+        assert_eq!(consumer_balance, 2500000000);
+
+        // Bill 20 min after contract approval
+        run_to_block(201, Some(&mut pool_state));
+        assert_ok!(SmartContractModule::service_contract_bill(
+            RuntimeOrigin::signed(alice()),
+            1,
+            VARIABLE_AMOUNT,
+            b"bill_metadata_1".to_vec(),
+        ));
+
+        // Base fee billing time is left untouched
+        let service_contract = SmartContractModule::service_contracts(service_contract_id).unwrap();
+        assert_eq!(
+            service_contract.last_bill,
+            get_timestamp_in_seconds_for_block(1)
+        );
+        assert_eq!(
+            SmartContractModule::service_contract_last_variable_bill(service_contract_id),
+            Some(get_timestamp_in_seconds_for_block(201))
+        );
+
+        // Check consumer balance after first billing
+        // Only variable amount is billed
+        let consumer_balance = Balances::free_balance(&consumer_twin.account_id);
+        let bill_1 = types::ServiceContractBill {
+            variable_amount: VARIABLE_AMOUNT,
+            window: 0,
+            metadata: BoundedVec::try_from(b"bill_metadata_1".to_vec()).unwrap(),
+        };
+        let billed_amount_1 = service_contract
+            .calculate_bill_cost_tft::<TestRuntime>(bill_1.clone())
+            .unwrap();
+        assert_eq!(2500000000 - consumer_balance, billed_amount_1);
+
+        // Check event triggering
+        let our_events = System::events();
+        assert_eq!(!our_events.is_empty(), true);
+        assert_eq!(
+            our_events.last().unwrap(),
+            &record(MockEvent::SmartContractModule(SmartContractEvent::<
+                TestRuntime,
+            >::ServiceContractBilled {
+                service_contract,
+                bill: bill_1,
+                amount: billed_amount_1,
+            })),
+        );
+
+        // Bill a second time, 1h10min after first billing
+        run_to_block(901, Some(&mut pool_state));
+        assert_ok!(SmartContractModule::service_contract_bill(
+            RuntimeOrigin::signed(alice()),
+            service_contract_id,
+            VARIABLE_AMOUNT,
+            b"bill_metadata_2".to_vec(),
+        ));
+
+        // Check consumer balance after second billing
+        let service_contract = SmartContractModule::service_contracts(1).unwrap();
+        let consumer_balance = Balances::free_balance(&consumer_twin.account_id);
+        let bill_2 = types::ServiceContractBill {
+            variable_amount: VARIABLE_AMOUNT,
+            window: 0,
+            metadata: BoundedVec::try_from(b"bill_metadata_2".to_vec()).unwrap(),
+        };
+        let billed_amount_2 = service_contract
+            .calculate_bill_cost_tft::<TestRuntime>(bill_2.clone())
+            .unwrap();
+        assert_eq!(
+            2500000000 - consumer_balance - billed_amount_1,
+            billed_amount_2
+        );
+
+        // Check event triggering
+        let our_events = System::events();
+        assert_eq!(!our_events.is_empty(), true);
+        assert_eq!(
+            our_events.last().unwrap(),
+            &record(MockEvent::SmartContractModule(SmartContractEvent::<
+                TestRuntime,
+            >::ServiceContractBilled {
+                service_contract,
+                bill: bill_2,
+                amount: billed_amount_2,
+            })),
+        );
+    });
+}
+
+#[test]
+fn test_service_contract_bill_by_unauthorized_fails() {
+    new_test_ext().execute_with(|| {
+        prepare_service_consumer_contract();
+        let service_contract_id = 1;
+        approve_service_consumer_contract(service_contract_id);
+
+        assert_noop!(
+            SmartContractModule::service_contract_bill(
+                RuntimeOrigin::signed(bob()),
+                service_contract_id,
+                VARIABLE_AMOUNT,
+                b"bill_metadata".to_vec(),
+            ),
+            Error::<TestRuntime>::TwinNotAuthorized
+        );
+    });
+}
+
+#[test]
+fn test_service_contract_bill_not_approved_fails() {
+    new_test_ext().execute_with(|| {
+        prepare_service_consumer_contract();
+        let service_contract_id = 1;
+
+        assert_noop!(
+            SmartContractModule::service_contract_bill(
+                RuntimeOrigin::signed(alice()),
+                service_contract_id,
+                VARIABLE_AMOUNT,
+                b"bill_metadata".to_vec(),
+            ),
+            Error::<TestRuntime>::ServiceContractBillingNotApprovedByBoth
+        );
+    });
+}
+
+#[test]
+fn test_service_contract_bill_variable_amount_too_high_fails() {
+    let (mut ext, mut pool_state) = new_test_ext_with_pool_state(0);
+    ext.execute_with(|| {
+        run_to_block(1, None);
+        prepare_service_consumer_contract();
+        let service_contract_id = 1;
+        approve_service_consumer_contract(service_contract_id);
+
+        // Bill 1h after contract approval
+        run_to_block(601, Some(&mut pool_state));
+        // set variable amount a bit higher than variable fee to trigger error
+        let variable_amount = VARIABLE_FEE + 1;
+        assert_noop!(
+            SmartContractModule::service_contract_bill(
+                RuntimeOrigin::signed(alice()),
+                service_contract_id,
+                variable_amount,
+                b"bill_metadata".to_vec(),
+            ),
+            Error::<TestRuntime>::ServiceContractBillingVariableAmountTooHigh
+        );
+    });
+}
+
+#[test]
+fn test_service_contract_bill_metadata_too_long_fails() {
+    let (mut ext, mut pool_state) = new_test_ext_with_pool_state(0);
+    ext.execute_with(|| {
+        run_to_block(1, None);
+        prepare_service_consumer_contract();
+        let service_contract_id = 1;
+        approve_service_consumer_contract(service_contract_id);
+
+        // Bill 1h after contract approval
+        run_to_block(601, Some(&mut pool_state));
+        assert_noop!(
+            SmartContractModule::service_contract_bill(
+                RuntimeOrigin::signed(alice()),
+                service_contract_id,
+                VARIABLE_AMOUNT,
+                b"very_loooooooooooooooooooooooooooooooooooooooooooooooooong_metadata".to_vec(),
+            ),
+            Error::<TestRuntime>::ServiceContractBillMetadataTooLong
+        );
+    });
+}
+
+#[test]
+fn test_service_contract_bill_out_of_funds_fails() {
+    let (mut ext, mut pool_state) = new_test_ext_with_pool_state(0);
+    ext.execute_with(|| {
+        run_to_block(1, None);
+        prepare_service_consumer_contract();
+        let service_contract_id = 1;
+        approve_service_consumer_contract(service_contract_id);
+
+        // Drain consumer account
+        let consumer_twin = TfgridModule::twins(2).unwrap();
+        let consumer_balance = Balances::free_balance(&consumer_twin.account_id);
+        Balances::transfer(RuntimeOrigin::signed(bob()), alice(), consumer_balance).unwrap();
+        let consumer_balance = Balances::free_balance(&consumer_twin.account_id);
+        assert_eq!(consumer_balance, 0);
+
+        // Bill 1h after contract approval
+        run_to_block(601, Some(&mut pool_state));
+        assert_noop!(
+            SmartContractModule::service_contract_bill(
+                RuntimeOrigin::signed(alice()),
+                service_contract_id,
+                VARIABLE_AMOUNT,
+                b"bill_metadata".to_vec(),
+            ),
+            Error::<TestRuntime>::ServiceContractNotEnoughFundsToPayBill,
+        );
+
+        // Contract is not canceled
+        assert!(SmartContractModule::service_contracts(service_contract_id).is_some());
+    });
+}
+
+#[test]
+fn test_service_contract_set_dispute_window_works() {
+    new_test_ext().execute_with(|| {
+        run_to_block(1, None);
+        prepare_service_consumer_contract();
+        let service_contract_id = 1;
+
+        assert_ok!(SmartContractModule::service_contract_set_dispute_window(
+            RuntimeOrigin::signed(bob()),
+            service_contract_id,
+            Some(SECS_PER_HOUR),
+        ));
+        assert_eq!(
+            SmartContractModule::service_contract_dispute_window(service_contract_id),
+            Some(SECS_PER_HOUR)
+        );
+
+        let our_events = System::events();
+        assert_eq!(
+            our_events.last().unwrap(),
+            &record(MockEvent::SmartContractModule(
+                SmartContractEvent::<TestRuntime>::ServiceContractDisputeWindowSet {
+                    service_contract_id,
+                    dispute_window: Some(SECS_PER_HOUR),
                 }
-            ))),
-            true
+            )),
         );
-    });
-}
-
-//  SOLUTION PROVIDER TESTS //
-// ------------------------ //
-
-#[test]
-fn test_create_solution_provider_works() {
-    new_test_ext().execute_with(|| {
-        let provider1 = super::types::Provider {
-            take: 10,
-            who: alice(),
-        };
-        let provider2 = super::types::Provider {
-            take: 10,
-            who: bob(),
-        };
-        let providers = vec![provider1, provider2];
 
-        assert_ok!(SmartContractModule::create_solution_provider(
+        assert_ok!(SmartContractModule::service_contract_set_dispute_window(
             RuntimeOrigin::signed(alice()),
-            b"some_description".to_vec(),
-            b"some_link".to_vec(),
-            providers
-        ));
-        let provider_id = 1;
-
-        assert_ok!(SmartContractModule::approve_solution_provider(
-            RawOrigin::Root.into(),
-            provider_id,
-            true
+            service_contract_id,
+            None,
         ));
-    })
+        assert_eq!(
+            SmartContractModule::service_contract_dispute_window(service_contract_id),
+            None
+        );
+    });
 }
 
 #[test]
-fn test_create_solution_provider_fails_if_take_to_high() {
+fn test_service_contract_set_dispute_window_invalid_fails() {
     new_test_ext().execute_with(|| {
-        let provider = super::types::Provider {
-            take: 51,
-            who: alice(),
-        };
-        let providers = vec![provider];
+        run_to_block(1, None);
+        prepare_service_consumer_contract();
+        let service_contract_id = 1;
 
         assert_noop!(
-            SmartContractModule::create_solution_provider(
+            SmartContractModule::service_contract_set_dispute_window(
                 RuntimeOrigin::signed(alice()),
-                b"some_description".to_vec(),
-                b"some_link".to_vec(),
-                providers
+                service_contract_id,
+                Some(0),
             ),
-            Error::<TestRuntime>::InvalidProviderConfiguration
+            Error::<TestRuntime>::InvalidDisputeWindow
         );
-    })
+
+        assert_noop!(
+            SmartContractModule::service_contract_set_dispute_window(
+                RuntimeOrigin::signed(alice()),
+                service_contract_id,
+                Some(types::MAX_DISPUTE_WINDOW + 1),
+            ),
+            Error::<TestRuntime>::InvalidDisputeWindow
+        );
+    });
 }
 
 #[test]
-fn test_create_node_contract_with_solution_provider_works() {
+fn test_service_contract_set_dispute_window_after_approval_fails() {
     new_test_ext().execute_with(|| {
         run_to_block(1, None);
-        prepare_farm_and_node();
-        let node_id = 1;
-
-        prepare_solution_provider(alice());
-        let provider_id = 1;
+        prepare_service_consumer_contract();
+        let service_contract_id = 1;
+        approve_service_consumer_contract(service_contract_id);
 
-        assert_ok!(SmartContractModule::create_node_contract(
-            RuntimeOrigin::signed(alice()),
-            node_id,
-            generate_deployment_hash(),
-            get_deployment_data(),
-            0,
-            Some(provider_id)
-        ));
+        assert_noop!(
+            SmartContractModule::service_contract_set_dispute_window(
+                RuntimeOrigin::signed(alice()),
+                service_contract_id,
+                Some(SECS_PER_HOUR),
+            ),
+            Error::<TestRuntime>::ServiceContractModificationNotAllowed
+        );
     });
 }
 
 #[test]
-fn test_create_node_contract_with_solution_provider_fails_if_not_approved() {
-    new_test_ext().execute_with(|| {
-        prepare_farm_and_node();
-        let node_id = 1;
+fn test_service_contract_bill_in_escrow_and_release_works() {
+    let (mut ext, mut pool_state) = new_test_ext_with_pool_state(0);
+    ext.execute_with(|| {
+        run_to_block(1, None);
+        prepare_escrowed_service_consumer_contract();
+        let service_contract_id = 1;
+        let bill_id = 1;
 
-        let provider = super::types::Provider {
-            take: 10,
-            who: alice(),
-        };
-        let providers = vec![provider];
+        let service_twin = TfgridModule::twins(1).unwrap();
+        let consumer_twin = TfgridModule::twins(2).unwrap();
+        let service_balance = Balances::free_balance(&service_twin.account_id);
 
-        assert_ok!(SmartContractModule::create_solution_provider(
+        // Bill 20 min after contract approval
+        run_to_block(201, Some(&mut pool_state));
+        assert_ok!(SmartContractModule::service_contract_bill(
             RuntimeOrigin::signed(alice()),
-            b"some_description".to_vec(),
-            b"some_link".to_vec(),
-            providers
+            service_contract_id,
+            VARIABLE_AMOUNT,
+            b"bill_metadata".to_vec(),
         ));
-        let provider_id = 1;
 
+        // Amount is held on consumer account
+        let escrowed_bill =
+            SmartContractModule::service_contract_escrowed_bills(service_contract_id, bill_id)
+                .unwrap();
+        let amount = escrowed_bill.amount;
+        assert!(amount > 0);
+        assert_eq!(
+            escrowed_bill.dispute_deadline,
+            get_timestamp_in_seconds_for_block(201) + SECS_PER_HOUR
+        );
+        assert_eq!(
+            Balances::reserved_balance(&consumer_twin.account_id),
+            amount
+        );
+        assert_eq!(
+            Balances::free_balance(&service_twin.account_id),
+            service_balance
+        );
+
+        // Service can not release bill during dispute window
         assert_noop!(
-            SmartContractModule::create_node_contract(
+            SmartContractModule::service_contract_release_bill(
                 RuntimeOrigin::signed(alice()),
-                node_id,
-                generate_deployment_hash(),
-                get_deployment_data(),
-                0,
-                Some(provider_id)
+                service_contract_id,
+                bill_id,
             ),
-            Error::<TestRuntime>::SolutionProviderNotApproved
+            Error::<TestRuntime>::ServiceContractDisputeWindowNotClosed
         );
-    });
-}
 
-// SERVICE CONTRACT TESTS //
-// ---------------------- //
-
-#[test]
-fn test_service_contract_create_works() {
-    new_test_ext().execute_with(|| {
-        run_to_block(1, None);
-        create_service_consumer_contract();
-        let service_contract_id = 1;
+        // Release bill once dispute window is over
+        run_to_block(802, Some(&mut pool_state));
+        assert_ok!(SmartContractModule::service_contract_release_bill(
+            RuntimeOrigin::signed(alice()),
+            service_contract_id,
+            bill_id,
+        ));
 
-        let service_contract = get_service_contract();
+        assert!(
+            SmartContractModule::service_contract_escrowed_bills(service_contract_id, bill_id)
+                .is_none()
+        );
+        assert_eq!(Balances::reserved_balance(&consumer_twin.account_id), 0);
         assert_eq!(
-            service_contract,
-            SmartContractModule::service_contracts(service_contract_id).unwrap(),
+            Balances::free_balance(&service_twin.account_id),
+            service_balance + amount
         );
 
         let our_events = System::events();
-        assert_eq!(!our_events.is_empty(), true);
         assert_eq!(
             our_events.last().unwrap(),
             &record(MockEvent::SmartContractModule(
-                SmartContractEvent::ServiceContractCreated(service_contract)
+                SmartContractEvent::<TestRuntime>::ServiceContractBillReleased {
+                    service_contract_id,
+                    bill_id,
+                    amount,
+                }
             )),
         );
     });
 }
 
 #[test]
-fn test_service_contract_create_by_anyone_fails() {
-    new_test_ext().execute_with(|| {
-        create_twin(alice());
-        create_twin(bob());
-        create_twin(charlie());
+fn test_service_contract_release_bill_not_reserved_fails() {
+    let (mut ext, mut pool_state) = new_test_ext_with_pool_state(0);
+    ext.execute_with(|| {
+        run_to_block(1, None);
+        prepare_escrowed_service_consumer_contract();
+        let service_contract_id = 1;
+        let bill_id = 1;
+
+        let consumer_twin = TfgridModule::twins(2).unwrap();
+
+        run_to_block(201, Some(&mut pool_state));
+        assert_ok!(SmartContractModule::service_contract_bill(
+            RuntimeOrigin::signed(alice()),
+            service_contract_id,
+            VARIABLE_AMOUNT,
+            b"bill_metadata".to_vec(),
+        ));
+        let amount =
+            SmartContractModule::service_contract_escrowed_bills(service_contract_id, bill_id)
+                .unwrap()
+                .amount;
+
+        // Part of the escrowed amount is no longer held on consumer account
+        Balances::unreserve(&consumer_twin.account_id, amount / 2);
 
         assert_noop!(
-            SmartContractModule::service_contract_create(
-                RuntimeOrigin::signed(charlie()),
-                alice(),
-                bob(),
+            SmartContractModule::service_contract_release_bill(
+                RuntimeOrigin::signed(bob()),
+                service_contract_id,
+                bill_id,
             ),
-            Error::<TestRuntime>::TwinNotAuthorized
+            Error::<TestRuntime>::ServiceContractEscrowedAmountNotReserved
+        );
+        assert!(
+            SmartContractModule::service_contract_escrowed_bills(service_contract_id, bill_id)
+                .is_some()
         );
     });
 }
 
 #[test]
-fn test_service_contract_create_same_account_fails() {
-    new_test_ext().execute_with(|| {
-        create_twin(alice());
+fn test_service_contract_dispute_bill_and_resolve_works() {
+    let (mut ext, mut pool_state) = new_test_ext_with_pool_state(0);
+    ext.execute_with(|| {
+        run_to_block(1, None);
+        prepare_escrowed_service_consumer_contract();
+        let service_contract_id = 1;
+        let bill_id = 1;
+
+        let service_twin = TfgridModule::twins(1).unwrap();
+        let consumer_twin = TfgridModule::twins(2).unwrap();
+        let service_balance = Balances::free_balance(&service_twin.account_id);
+        let consumer_balance = Balances::free_balance(&consumer_twin.account_id);
 
+        run_to_block(201, Some(&mut pool_state));
+        assert_ok!(SmartContractModule::service_contract_bill(
+            RuntimeOrigin::signed(alice()),
+            service_contract_id,
+            VARIABLE_AMOUNT,
+            b"bill_metadata".to_vec(),
+        ));
+        let amount =
+            SmartContractModule::service_contract_escrowed_bills(service_contract_id, bill_id)
+                .unwrap()
+                .amount;
+
+        // Only consumer can dispute a bill
         assert_noop!(
-            SmartContractModule::service_contract_create(
+            SmartContractModule::service_contract_dispute_bill(
                 RuntimeOrigin::signed(alice()),
-                alice(),
-                alice(),
+                service_contract_id,
+                bill_id,
             ),
-            Error::<TestRuntime>::ServiceContractCreationNotAllowed
+            Error::<TestRuntime>::TwinNotAuthorized
+        );
+        assert_ok!(SmartContractModule::service_contract_dispute_bill(
+            RuntimeOrigin::signed(bob()),
+            service_contract_id,
+            bill_id,
+        ));
+
+        // Disputed bill can not be released
+        run_to_block(802, Some(&mut pool_state));
+        assert_noop!(
+            SmartContractModule::service_contract_release_bill(
+                RuntimeOrigin::signed(alice()),
+                service_contract_id,
+                bill_id,
+            ),
+            Error::<TestRuntime>::ServiceContractBillDisputed
+        );
+
+        // Dispute is resolved once both parties agree on the refund
+        let refund = amount / 4;
+        assert_ok!(SmartContractModule::service_contract_resolve_dispute(
+            RuntimeOrigin::signed(alice()),
+            service_contract_id,
+            bill_id,
+            refund,
+        ));
+        assert_ok!(SmartContractModule::service_contract_resolve_dispute(
+            RuntimeOrigin::signed(bob()),
+            service_contract_id,
+            bill_id,
+            refund / 2,
+        ));
+        assert!(
+            SmartContractModule::service_contract_escrowed_bills(service_contract_id, bill_id)
+                .is_some()
+        );
+        assert_ok!(SmartContractModule::service_contract_resolve_dispute(
+            RuntimeOrigin::signed(bob()),
+            service_contract_id,
+            bill_id,
+            refund,
+        ));
+
+        assert!(
+            SmartContractModule::service_contract_escrowed_bills(service_contract_id, bill_id)
+                .is_none()
+        );
+        assert_eq!(Balances::reserved_balance(&consumer_twin.account_id), 0);
+        assert_eq!(
+            Balances::free_balance(&consumer_twin.account_id),
+            consumer_balance - amount + refund
+        );
+        assert_eq!(
+            Balances::free_balance(&service_twin.account_id),
+            service_balance + amount - refund
+        );
+
+        let our_events = System::events();
+        assert_eq!(
+            our_events.last().unwrap(),
+            &record(MockEvent::SmartContractModule(
+                SmartContractEvent::<TestRuntime>::ServiceContractDisputeResolved {
+                    service_contract_id,
+                    bill_id,
+                    refund,
+                    paid: amount - refund,
+                }
+            )),
         );
     });
 }
 
 #[test]
-fn test_service_contract_set_metadata_works() {
-    new_test_ext().execute_with(|| {
+fn test_service_contract_force_resolve_dispute_works() {
+    let (mut ext, mut pool_state) = new_test_ext_with_pool_state(0);
+    ext.execute_with(|| {
         run_to_block(1, None);
-        create_service_consumer_contract();
+        prepare_escrowed_service_consumer_contract();
         let service_contract_id = 1;
+        let bill_id = 1;
 
-        assert_ok!(SmartContractModule::service_contract_set_metadata(
+        let consumer_twin = TfgridModule::twins(2).unwrap();
+        let consumer_balance = Balances::free_balance(&consumer_twin.account_id);
+
+        run_to_block(201, Some(&mut pool_state));
+        assert_ok!(SmartContractModule::service_contract_bill(
             RuntimeOrigin::signed(alice()),
             service_contract_id,
-            b"some_metadata".to_vec(),
+            VARIABLE_AMOUNT,
+            b"bill_metadata".to_vec(),
         ));
+        let amount =
+            SmartContractModule::service_contract_escrowed_bills(service_contract_id, bill_id)
+                .unwrap()
+                .amount;
 
-        let mut service_contract = get_service_contract();
-        service_contract.metadata = BoundedVec::try_from(b"some_metadata".to_vec()).unwrap();
-        assert_eq!(
-            service_contract,
-            SmartContractModule::service_contracts(1).unwrap(),
+        // Bill has to be disputed first
+        assert_noop!(
+            SmartContractModule::service_contract_force_resolve_dispute(
+                RawOrigin::Root.into(),
+                service_contract_id,
+                bill_id,
+                amount,
+            ),
+            Error::<TestRuntime>::ServiceContractBillNotDisputed
+        );
+
+        assert_ok!(SmartContractModule::service_contract_dispute_bill(
+            RuntimeOrigin::signed(bob()),
+            service_contract_id,
+            bill_id,
+        ));
+
+        assert_noop!(
+            SmartContractModule::service_contract_force_resolve_dispute(
+                RawOrigin::Root.into(),
+                service_contract_id,
+                bill_id,
+                amount + 1,
+            ),
+            Error::<TestRuntime>::InvalidServiceContractBillRefund
         );
 
-        let our_events = System::events();
-        assert_eq!(!our_events.is_empty(), true);
+        // Full refund
+        assert_ok!(SmartContractModule::service_contract_force_resolve_dispute(
+            RawOrigin::Root.into(),
+            service_contract_id,
+            bill_id,
+            amount,
+        ));
+        assert_eq!(Balances::reserved_balance(&consumer_twin.account_id), 0);
         assert_eq!(
-            our_events.last().unwrap(),
-            &record(MockEvent::SmartContractModule(
-                SmartContractEvent::ServiceContractMetadataSet(service_contract)
-            )),
+            Balances::free_balance(&consumer_twin.account_id),
+            consumer_balance
         );
     });
 }
 
 #[test]
-fn test_service_contract_set_metadata_by_unauthorized_fails() {
-    new_test_ext().execute_with(|| {
-        create_service_consumer_contract();
+fn test_service_contract_dispute_bill_after_window_fails() {
+    let (mut ext, mut pool_state) = new_test_ext_with_pool_state(0);
+    ext.execute_with(|| {
+        run_to_block(1, None);
+        prepare_escrowed_service_consumer_contract();
         let service_contract_id = 1;
-        create_twin(charlie());
+        let bill_id = 1;
+
+        run_to_block(201, Some(&mut pool_state));
+        assert_ok!(SmartContractModule::service_contract_bill(
+            RuntimeOrigin::signed(alice()),
+            service_contract_id,
+            VARIABLE_AMOUNT,
+            b"bill_metadata".to_vec(),
+        ));
 
+        run_to_block(802, Some(&mut pool_state));
         assert_noop!(
-            SmartContractModule::service_contract_set_metadata(
-                RuntimeOrigin::signed(charlie()),
+            SmartContractModule::service_contract_dispute_bill(
+                RuntimeOrigin::signed(bob()),
                 service_contract_id,
-                b"some_metadata".to_vec(),
+                bill_id,
             ),
-            Error::<TestRuntime>::TwinNotAuthorized
+            Error::<TestRuntime>::ServiceContractDisputeWindowClosed
         );
     });
 }
 
 #[test]
-fn test_service_contract_set_metadata_already_approved_fails() {
-    new_test_ext().execute_with(|| {
+fn test_service_contract_base_fee_billed_in_billing_loop_works() {
+    let (mut ext, mut pool_state) = new_test_ext_with_pool_state(0);
+    ext.execute_with(|| {
+        run_to_block(1, None);
         prepare_service_consumer_contract();
         let service_contract_id = 1;
         approve_service_consumer_contract(service_contract_id);
 
-        assert_noop!(
-            SmartContractModule::service_contract_set_metadata(
-                RuntimeOrigin::signed(alice()),
-                service_contract_id,
-                b"some_metadata".to_vec(),
-            ),
-            Error::<TestRuntime>::ServiceContractModificationNotAllowed
+        let consumer_twin = TfgridModule::twins(2).unwrap();
+        let consumer_balance = Balances::free_balance(&consumer_twin.account_id);
+
+        pool_state.write().should_call_bill_service_contract(
+            service_contract_id,
+            Ok(Pays::Yes.into()),
+            11,
         );
-    });
-}
+        run_to_block(11, Some(&mut pool_state));
 
-#[test]
-fn test_service_contract_set_metadata_too_long_fails() {
-    new_test_ext().execute_with(|| {
-        create_service_consumer_contract();
-        let service_contract_id = 1;
+        let service_contract = SmartContractModule::service_contracts(service_contract_id).unwrap();
+        assert_eq!(
+            service_contract.last_bill,
+            get_timestamp_in_seconds_for_block(11)
+        );
 
-        assert_noop!(
-            SmartContractModule::service_contract_set_metadata(
-                RuntimeOrigin::signed(alice()),
-                service_contract_id,
-                b"very_loooooooooooooooooooooooooooooooooooooooooooooooooong_metadata".to_vec(),
-            ),
-            Error::<TestRuntime>::ServiceContractMetadataTooLong
+        // Only base fee is billed for time elapsed since approval
+        let bill = types::ServiceContractBill {
+            variable_amount: 0,
+            window: get_timestamp_in_seconds_for_block(11) - get_timestamp_in_seconds_for_block(1),
+            metadata: bounded_vec![],
+        };
+        let billed_amount = service_contract
+            .calculate_bill_cost_tft::<TestRuntime>(bill.clone())
+            .unwrap();
+        assert!(billed_amount > 0);
+        assert_eq!(
+            consumer_balance - Balances::free_balance(&consumer_twin.account_id),
+            billed_amount
+        );
+
+        let our_events = System::events();
+        assert_eq!(
+            our_events.last().unwrap(),
+            &record(MockEvent::SmartContractModule(SmartContractEvent::<
+                TestRuntime,
+            >::ServiceContractBilled {
+                service_contract,
+                bill,
+                amount: billed_amount,
+            })),
         );
     });
 }
 
 #[test]
-fn test_service_contract_set_fees_works() {
-    new_test_ext().execute_with(|| {
+fn test_service_contract_base_fee_grace_period_ends_when_funded() {
+    let (mut ext, mut pool_state) = new_test_ext_with_pool_state(0);
+    ext.execute_with(|| {
         run_to_block(1, None);
-        create_service_consumer_contract();
+        prepare_service_consumer_contract();
         let service_contract_id = 1;
+        approve_service_consumer_contract(service_contract_id);
 
-        assert_ok!(SmartContractModule::service_contract_set_fees(
-            RuntimeOrigin::signed(alice()),
-            1,
-            BASE_FEE,
-            VARIABLE_FEE,
-        ));
+        // Drain consumer account
+        let consumer_twin = TfgridModule::twins(2).unwrap();
+        let consumer_balance = Balances::free_balance(&consumer_twin.account_id);
+        Balances::transfer(RuntimeOrigin::signed(bob()), alice(), consumer_balance).unwrap();
 
-        let mut service_contract = get_service_contract();
-        service_contract.base_fee = BASE_FEE;
-        service_contract.variable_fee = VARIABLE_FEE;
-        assert_eq!(
-            service_contract,
-            SmartContractModule::service_contracts(service_contract_id).unwrap(),
+        pool_state.write().should_call_bill_service_contract(
+            service_contract_id,
+            Ok(Pays::Yes.into()),
+            11,
         );
+        run_to_block(11, Some(&mut pool_state));
 
+        assert_eq!(
+            SmartContractModule::service_contract_grace_period_start(service_contract_id),
+            Some(11)
+        );
         let our_events = System::events();
-        assert_eq!(!our_events.is_empty(), true);
         assert_eq!(
             our_events.last().unwrap(),
             &record(MockEvent::SmartContractModule(
-                SmartContractEvent::ServiceContractFeesSet(service_contract)
+                SmartContractEvent::<TestRuntime>::ServiceContractGracePeriodStarted {
+                    service_contract_id,
+                    block_number: 11,
+                }
             )),
         );
+
+        // Fund consumer account again
+        Balances::transfer(RuntimeOrigin::signed(alice()), bob(), consumer_balance).unwrap();
+
+        pool_state.write().should_call_bill_service_contract(
+            service_contract_id,
+            Ok(Pays::Yes.into()),
+            21,
+        );
+        run_to_block(21, Some(&mut pool_state));
+
+        assert!(
+            SmartContractModule::service_contract_grace_period_start(service_contract_id).is_none()
+        );
+        let our_events = System::events();
+        assert_eq!(
+            our_events.contains(&record(MockEvent::SmartContractModule(
+                SmartContractEvent::<TestRuntime>::ServiceContractGracePeriodEnded {
+                    service_contract_id,
+                }
+            ))),
+            true
+        );
+
+        // What was due during grace period is billed
+        let service_contract = SmartContractModule::service_contracts(service_contract_id).unwrap();
+        let bill = types::ServiceContractBill {
+            variable_amount: 0,
+            window: get_timestamp_in_seconds_for_block(21) - get_timestamp_in_seconds_for_block(1),
+            metadata: bounded_vec![],
+        };
+        let billed_amount = service_contract
+            .calculate_bill_cost_tft::<TestRuntime>(bill)
+            .unwrap();
+        assert_eq!(
+            consumer_balance - Balances::free_balance(&consumer_twin.account_id),
+            billed_amount
+        );
     });
 }
 
 #[test]
-fn test_service_contract_set_fees_by_unauthorized_fails() {
-    new_test_ext().execute_with(|| {
-        create_service_consumer_contract();
+fn test_service_contract_base_fee_grace_period_expired_cancels_contract() {
+    let (mut ext, mut pool_state) = new_test_ext_with_pool_state(0);
+    ext.execute_with(|| {
+        run_to_block(1, None);
+        prepare_service_consumer_contract();
         let service_contract_id = 1;
+        approve_service_consumer_contract(service_contract_id);
 
-        assert_noop!(
-            SmartContractModule::service_contract_set_fees(
-                RuntimeOrigin::signed(bob()),
+        // Drain consumer account
+        let consumer_twin = TfgridModule::twins(2).unwrap();
+        let consumer_balance = Balances::free_balance(&consumer_twin.account_id);
+        Balances::transfer(RuntimeOrigin::signed(bob()), alice(), consumer_balance).unwrap();
+
+        // Grace period starts at block 11 and lasts 100 blocks
+        for block_number in (11..=111).step_by(10) {
+            pool_state.write().should_call_bill_service_contract(
                 service_contract_id,
-                BASE_FEE,
-                VARIABLE_FEE,
-            ),
-            Error::<TestRuntime>::TwinNotAuthorized
+                Ok(Pays::Yes.into()),
+                block_number,
+            );
+        }
+        run_to_block(111, Some(&mut pool_state));
+
+        assert!(SmartContractModule::service_contracts(service_contract_id).is_none());
+        assert!(
+            SmartContractModule::service_contract_grace_period_start(service_contract_id).is_none()
+        );
+        assert!(!SmartContractModule::service_contract_to_bill_at_block(1)
+            .contains(&service_contract_id));
+
+        let our_events = System::events();
+        assert_eq!(
+            our_events.last().unwrap(),
+            &record(MockEvent::SmartContractModule(SmartContractEvent::<
+                TestRuntime,
+            >::ServiceContractCanceled {
+                service_contract_id,
+                cause: types::Cause::OutOfFunds,
+            })),
         );
     });
 }
 
 #[test]
-fn test_service_contract_set_fees_already_approved_fails() {
-    new_test_ext().execute_with(|| {
+fn test_service_contract_grace_period_follows_service_kind_grace_period_works() {
+    let (mut ext, mut pool_state) = new_test_ext_with_pool_state(0);
+    ext.execute_with(|| {
+        run_to_block(1, None);
         prepare_service_consumer_contract();
         let service_contract_id = 1;
         approve_service_consumer_contract(service_contract_id);
 
-        assert_noop!(
-            SmartContractModule::service_contract_set_fees(
-                RuntimeOrigin::signed(alice()),
+        assert_ok!(SmartContractModule::set_contract_kind_grace_period(
+            RawOrigin::Root.into(),
+            types::ContractKind::Service,
+            Some(30)
+        ));
+
+        // Drain consumer account
+        let consumer_twin = TfgridModule::twins(2).unwrap();
+        let consumer_balance = Balances::free_balance(&consumer_twin.account_id);
+        Balances::transfer(RuntimeOrigin::signed(bob()), alice(), consumer_balance).unwrap();
+
+        // Grace period starts at block 11 and lasts 30 blocks
+        for block_number in (11..=41).step_by(10) {
+            pool_state.write().should_call_bill_service_contract(
                 service_contract_id,
-                BASE_FEE,
-                VARIABLE_FEE,
-            ),
-            Error::<TestRuntime>::ServiceContractModificationNotAllowed
+                Ok(Pays::Yes.into()),
+                block_number,
+            );
+        }
+        run_to_block(31, Some(&mut pool_state));
+        assert!(SmartContractModule::service_contracts(service_contract_id).is_some());
+
+        run_to_block(41, Some(&mut pool_state));
+        assert!(SmartContractModule::service_contracts(service_contract_id).is_none());
+
+        let our_events = System::events();
+        assert_eq!(
+            our_events.last().unwrap(),
+            &record(MockEvent::SmartContractModule(SmartContractEvent::<
+                TestRuntime,
+            >::ServiceContractCanceled {
+                service_contract_id,
+                cause: types::Cause::OutOfFunds,
+            })),
         );
     });
 }
 
 #[test]
-fn test_service_contract_approve_works() {
-    new_test_ext().execute_with(|| {
+fn test_service_contract_amendment_works() {
+    let (mut ext, mut pool_state) = new_test_ext_with_pool_state(0);
+    ext.execute_with(|| {
         run_to_block(1, None);
         prepare_service_consumer_contract();
         let service_contract_id = 1;
+        approve_service_consumer_contract(service_contract_id);
 
-        let mut service_contract = get_service_contract();
-        service_contract.base_fee = BASE_FEE;
-        service_contract.variable_fee = VARIABLE_FEE;
-        service_contract.metadata = BoundedVec::try_from(b"some_metadata".to_vec()).unwrap();
-        service_contract.state = types::ServiceContractState::AgreementReady;
-        assert_eq!(
-            service_contract,
-            SmartContractModule::service_contracts(1).unwrap(),
-        );
-
-        // Service approves
-        assert_ok!(SmartContractModule::service_contract_approve(
+        assert_ok!(SmartContractModule::service_contract_propose_amendment(
             RuntimeOrigin::signed(alice()),
             service_contract_id,
+            BASE_FEE * 2,
+            VARIABLE_FEE * 2,
+            b"new_metadata".to_vec(),
         ));
 
-        service_contract.accepted_by_service = true;
+        let amendment = types::ServiceContractAmendment {
+            proposed_by: 1,
+            base_fee: BASE_FEE * 2,
+            variable_fee: VARIABLE_FEE * 2,
+            metadata: BoundedVec::try_from(b"new_metadata".to_vec()).unwrap(),
+        };
         assert_eq!(
-            service_contract,
-            SmartContractModule::service_contracts(1).unwrap(),
+            SmartContractModule::service_contract_amendment(service_contract_id),
+            Some(amendment.clone())
         );
-
         let our_events = System::events();
-        assert_eq!(!our_events.is_empty(), true);
         assert_eq!(
             our_events.last().unwrap(),
-            &record(MockEvent::SmartContractModule(SmartContractEvent::<
-                TestRuntime,
-            >::ServiceContractApproved(
-                service_contract.clone()
-            ))),
+            &record(MockEvent::SmartContractModule(
+                SmartContractEvent::<TestRuntime>::ServiceContractAmendmentProposed {
+                    service_contract_id,
+                    amendment,
+                }
+            )),
         );
 
-        // Consumer approves
-        assert_ok!(SmartContractModule::service_contract_approve(
+        // Old terms apply until the amendment is accepted
+        let service_contract = SmartContractModule::service_contracts(service_contract_id).unwrap();
+        assert_eq!(service_contract.base_fee, BASE_FEE);
+
+        // Proposer can not accept its own amendment
+        assert_noop!(
+            SmartContractModule::service_contract_accept_amendment(
+                RuntimeOrigin::signed(alice()),
+                service_contract_id,
+            ),
+            Error::<TestRuntime>::TwinNotAuthorized
+        );
+
+        let consumer_twin = TfgridModule::twins(2).unwrap();
+        let consumer_balance = Balances::free_balance(&consumer_twin.account_id);
+
+        run_to_block(6, Some(&mut pool_state));
+        assert_ok!(SmartContractModule::service_contract_accept_amendment(
             RuntimeOrigin::signed(bob()),
             service_contract_id,
         ));
 
-        service_contract.accepted_by_consumer = true;
-        service_contract.last_bill = get_timestamp_in_seconds_for_block(1);
-        service_contract.state = types::ServiceContractState::ApprovedByBoth;
+        // Base fee is billed with old terms up to acceptance
+        let bill = types::ServiceContractBill {
+            variable_amount: 0,
+            window: get_timestamp_in_seconds_for_block(6) - get_timestamp_in_seconds_for_block(1),
+            metadata: bounded_vec![],
+        };
+        let billed_amount = service_contract
+            .calculate_bill_cost_tft::<TestRuntime>(bill)
+            .unwrap();
+        assert_eq!(
+            consumer_balance - Balances::free_balance(&consumer_twin.account_id),
+            billed_amount
+        );
+
+        let service_contract = SmartContractModule::service_contracts(service_contract_id).unwrap();
+        assert_eq!(service_contract.base_fee, BASE_FEE * 2);
+        assert_eq!(service_contract.variable_fee, VARIABLE_FEE * 2);
+        assert_eq!(service_contract.metadata, b"new_metadata".to_vec());
         assert_eq!(
-            service_contract,
-            SmartContractModule::service_contracts(1).unwrap(),
+            service_contract.last_bill,
+            get_timestamp_in_seconds_for_block(6)
         );
+        assert!(SmartContractModule::service_contract_amendment(service_contract_id).is_none());
 
         let our_events = System::events();
-        assert_eq!(!our_events.is_empty(), true);
         assert_eq!(
             our_events.last().unwrap(),
             &record(MockEvent::SmartContractModule(SmartContractEvent::<
                 TestRuntime,
-            >::ServiceContractApproved(
+            >::ServiceContractAmended(
                 service_contract
             ))),
         );
@@ -3073,403 +7809,373 @@ fn test_service_contract_approve_works() {
 }
 
 #[test]
-fn test_service_contract_approve_agreement_not_ready_fails() {
-    new_test_ext().execute_with(|| {
-        create_service_consumer_contract();
+fn test_service_contract_amendment_bills_pending_variable_amount_with_old_terms_works() {
+    let (mut ext, mut pool_state) = new_test_ext_with_pool_state(0);
+    ext.execute_with(|| {
+        run_to_block(1, None);
+        prepare_service_consumer_contract();
         let service_contract_id = 1;
+        approve_service_consumer_contract(service_contract_id);
+
+        // New terms drop the variable fee
+        assert_ok!(SmartContractModule::service_contract_propose_amendment(
+            RuntimeOrigin::signed(alice()),
+            service_contract_id,
+            BASE_FEE,
+            0,
+            b"new_metadata".to_vec(),
+        ));
 
+        // Accepted 30 min after approval
+        run_to_block(301, Some(&mut pool_state));
+        assert_ok!(SmartContractModule::service_contract_accept_amendment(
+            RuntimeOrigin::signed(bob()),
+            service_contract_id,
+        ));
+        assert_eq!(
+            SmartContractModule::service_contract_pending_variable_bill(service_contract_id),
+            Some(types::PendingVariableBill {
+                window: 1800,
+                max_variable_amount: VARIABLE_FEE / 2,
+            })
+        );
+
+        // Usage before acceptance is bounded by the old variable fee
+        run_to_block(311, Some(&mut pool_state));
         assert_noop!(
-            SmartContractModule::service_contract_approve(
+            SmartContractModule::service_contract_bill(
                 RuntimeOrigin::signed(alice()),
-                service_contract_id
+                service_contract_id,
+                VARIABLE_FEE / 2 + 1,
+                b"bill_metadata".to_vec(),
             ),
-            Error::<TestRuntime>::ServiceContractApprovalNotAllowed
+            Error::<TestRuntime>::ServiceContractBillingVariableAmountTooHigh
+        );
+        assert_ok!(SmartContractModule::service_contract_bill(
+            RuntimeOrigin::signed(alice()),
+            service_contract_id,
+            VARIABLE_FEE / 2,
+            b"bill_metadata".to_vec(),
+        ));
+        assert!(
+            SmartContractModule::service_contract_pending_variable_bill(service_contract_id)
+                .is_none()
+        );
+
+        // Then only the new terms apply
+        run_to_block(321, Some(&mut pool_state));
+        assert_noop!(
+            SmartContractModule::service_contract_bill(
+                RuntimeOrigin::signed(alice()),
+                service_contract_id,
+                1,
+                b"bill_metadata".to_vec(),
+            ),
+            Error::<TestRuntime>::ServiceContractBillingVariableAmountTooHigh
         );
     });
 }
 
 #[test]
-fn test_service_contract_approve_already_approved_fails() {
+fn test_service_contract_amendment_before_approval_fails() {
     new_test_ext().execute_with(|| {
+        run_to_block(1, None);
         prepare_service_consumer_contract();
         let service_contract_id = 1;
-        approve_service_consumer_contract(service_contract_id);
 
         assert_noop!(
-            SmartContractModule::service_contract_approve(
+            SmartContractModule::service_contract_propose_amendment(
                 RuntimeOrigin::signed(alice()),
-                service_contract_id
+                service_contract_id,
+                BASE_FEE * 2,
+                VARIABLE_FEE * 2,
+                b"new_metadata".to_vec(),
             ),
-            Error::<TestRuntime>::ServiceContractApprovalNotAllowed
+            Error::<TestRuntime>::ServiceContractAmendmentNotAllowed
         );
     });
 }
 
 #[test]
-fn test_service_contract_approve_by_unauthorized_fails() {
+fn test_service_contract_amendment_without_base_fee_fails() {
     new_test_ext().execute_with(|| {
+        run_to_block(1, None);
         prepare_service_consumer_contract();
         let service_contract_id = 1;
-        create_twin(charlie());
+        approve_service_consumer_contract(service_contract_id);
 
         assert_noop!(
-            SmartContractModule::service_contract_approve(
-                RuntimeOrigin::signed(charlie()),
-                service_contract_id
+            SmartContractModule::service_contract_propose_amendment(
+                RuntimeOrigin::signed(bob()),
+                service_contract_id,
+                0,
+                VARIABLE_FEE,
+                b"new_metadata".to_vec(),
             ),
-            Error::<TestRuntime>::TwinNotAuthorized
+            Error::<TestRuntime>::InvalidServiceContractAmendment
         );
     });
 }
 
 #[test]
-fn test_service_contract_reject_works() {
+fn test_service_contract_reject_amendment_works() {
     new_test_ext().execute_with(|| {
         run_to_block(1, None);
         prepare_service_consumer_contract();
         let service_contract_id = 1;
+        approve_service_consumer_contract(service_contract_id);
 
-        assert_ok!(SmartContractModule::service_contract_reject(
+        assert_ok!(SmartContractModule::service_contract_propose_amendment(
+            RuntimeOrigin::signed(bob()),
+            service_contract_id,
+            BASE_FEE / 2,
+            VARIABLE_FEE / 2,
+            b"new_metadata".to_vec(),
+        ));
+
+        assert_ok!(SmartContractModule::service_contract_reject_amendment(
             RuntimeOrigin::signed(alice()),
             service_contract_id,
         ));
 
-        assert_eq!(SmartContractModule::service_contracts(1).is_none(), true);
+        assert!(SmartContractModule::service_contract_amendment(service_contract_id).is_none());
+        let service_contract = SmartContractModule::service_contracts(service_contract_id).unwrap();
+        assert_eq!(service_contract.base_fee, BASE_FEE);
 
         let our_events = System::events();
-        assert_eq!(!our_events.is_empty(), true);
         assert_eq!(
             our_events.last().unwrap(),
-            &record(MockEvent::SmartContractModule(SmartContractEvent::<
-                TestRuntime,
-            >::ServiceContractCanceled {
-                service_contract_id,
-                cause: types::Cause::CanceledByUser,
-            })),
-        );
-    });
-}
-
-#[test]
-fn test_service_contract_reject_agreement_not_ready_fails() {
-    new_test_ext().execute_with(|| {
-        create_service_consumer_contract();
-        let service_contract_id = 1;
-
-        assert_noop!(
-            SmartContractModule::service_contract_reject(
-                RuntimeOrigin::signed(alice()),
-                service_contract_id
-            ),
-            Error::<TestRuntime>::ServiceContractRejectionNotAllowed
+            &record(MockEvent::SmartContractModule(
+                SmartContractEvent::<TestRuntime>::ServiceContractAmendmentRejected {
+                    service_contract_id,
+                    twin_id: 1,
+                }
+            )),
         );
-    });
-}
-
-#[test]
-fn test_service_contract_reject_already_approved_fails() {
-    new_test_ext().execute_with(|| {
-        prepare_service_consumer_contract();
-        let service_contract_id = 1;
-        approve_service_consumer_contract(service_contract_id);
 
         assert_noop!(
-            SmartContractModule::service_contract_reject(
+            SmartContractModule::service_contract_accept_amendment(
                 RuntimeOrigin::signed(alice()),
-                service_contract_id
+                service_contract_id,
             ),
-            Error::<TestRuntime>::ServiceContractRejectionNotAllowed
+            Error::<TestRuntime>::ServiceContractAmendmentNotProposed
         );
     });
 }
 
-#[test]
-fn test_service_contract_reject_by_unauthorized_fails() {
-    new_test_ext().execute_with(|| {
-        prepare_service_consumer_contract();
-        let service_contract_id = 1;
-        create_twin(charlie());
-
-        assert_noop!(
-            SmartContractModule::service_contract_reject(
-                RuntimeOrigin::signed(charlie()),
-                service_contract_id
-            ),
-            Error::<TestRuntime>::TwinNotAuthorized
-        );
-    });
-}
+//  SERVICE PLAN TESTS //
+// ------------------- //
 
 #[test]
-fn test_service_contract_cancel_works() {
+fn test_service_plan_create_works() {
     new_test_ext().execute_with(|| {
         run_to_block(1, None);
-        create_service_consumer_contract();
-        let service_contract_id = 1;
-
-        assert_ok!(SmartContractModule::service_contract_cancel(
-            RuntimeOrigin::signed(alice()),
-            service_contract_id,
-        ));
-
-        assert_eq!(SmartContractModule::service_contracts(1).is_none(), true);
+        create_service_plan();
+
+        let plan = types::ServicePlan {
+            plan_id: 1,
+            service_twin_id: 1,
+            base_fee: BASE_FEE,
+            variable_fee: VARIABLE_FEE,
+            metadata: BoundedVec::try_from(b"some_metadata".to_vec()).unwrap(),
+            retired: false,
+        };
+        assert_eq!(SmartContractModule::service_plans(1), Some(plan.clone()));
 
         let our_events = System::events();
-        assert_eq!(!our_events.is_empty(), true);
         assert_eq!(
             our_events.last().unwrap(),
             &record(MockEvent::SmartContractModule(SmartContractEvent::<
                 TestRuntime,
-            >::ServiceContractCanceled {
-                service_contract_id,
-                cause: types::Cause::CanceledByUser,
-            })),
+            >::ServicePlanCreated(
+                plan
+            ))),
         );
     });
 }
 
 #[test]
-fn test_service_contract_cancel_by_unauthorized_fails() {
+fn test_service_plan_create_without_base_fee_fails() {
     new_test_ext().execute_with(|| {
-        create_service_consumer_contract();
-        create_twin(charlie());
-        let service_contract_id = 1;
+        run_to_block(1, None);
+        create_twin(alice());
 
         assert_noop!(
-            SmartContractModule::service_contract_cancel(
-                RuntimeOrigin::signed(charlie()),
-                service_contract_id
+            SmartContractModule::service_plan_create(
+                RuntimeOrigin::signed(alice()),
+                0,
+                VARIABLE_FEE,
+                b"some_metadata".to_vec(),
             ),
-            Error::<TestRuntime>::TwinNotAuthorized
+            Error::<TestRuntime>::InvalidServicePlan
         );
     });
 }
 
 #[test]
-fn test_service_contract_bill_works() {
+fn test_service_plan_subscribe_works() {
     let (mut ext, mut pool_state) = new_test_ext_with_pool_state(0);
     ext.execute_with(|| {
         run_to_block(1, None);
-        prepare_service_consumer_contract();
-        let service_contract_id = 1;
-
-        let service_contract = SmartContractModule::service_contracts(service_contract_id).unwrap();
-        assert_eq!(service_contract.last_bill, 0);
+        create_service_plan();
+        create_twin(bob());
+        let plan_id = 1;
 
-        approve_service_consumer_contract(service_contract_id);
+        assert_ok!(SmartContractModule::service_plan_subscribe(
+            RuntimeOrigin::signed(bob()),
+            plan_id,
+        ));
 
+        let service_contract_id = 1;
         let service_contract = SmartContractModule::service_contracts(service_contract_id).unwrap();
+        assert_eq!(service_contract.service_twin_id, 1);
+        assert_eq!(service_contract.consumer_twin_id, 2);
+        assert_eq!(service_contract.base_fee, BASE_FEE);
+        assert_eq!(service_contract.variable_fee, VARIABLE_FEE);
+        assert_eq!(
+            service_contract.state,
+            types::ServiceContractState::ApprovedByBoth
+        );
         assert_eq!(
             service_contract.last_bill,
             get_timestamp_in_seconds_for_block(1)
         );
-
-        let consumer_twin = TfgridModule::twins(2).unwrap();
-        let consumer_balance = Balances::free_balance(&consumer_twin.account_id);
-        assert_eq!(consumer_balance, 2500000000);
-
-        // Bill 20 min after contract approval
-        run_to_block(201, Some(&mut pool_state));
-        assert_ok!(SmartContractModule::service_contract_bill(
-            RuntimeOrigin::signed(alice()),
-            1,
-            VARIABLE_AMOUNT,
-            b"bill_metadata_1".to_vec(),
-        ));
-
-        let service_contract = SmartContractModule::service_contracts(service_contract_id).unwrap();
         assert_eq!(
-            service_contract.last_bill,
-            get_timestamp_in_seconds_for_block(201)
+            SmartContractModule::service_contract_plan(service_contract_id),
+            Some(plan_id)
         );
 
-        // Check consumer balance after first billing
-        let consumer_balance = Balances::free_balance(&consumer_twin.account_id);
-        let window =
-            get_timestamp_in_seconds_for_block(201) - get_timestamp_in_seconds_for_block(1);
-        let bill_1 = types::ServiceContractBill {
-            variable_amount: VARIABLE_AMOUNT,
-            window,
-            metadata: BoundedVec::try_from(b"bill_metadata_1".to_vec()).unwrap(),
-        };
-        let billed_amount_1 = service_contract
-            .calculate_bill_cost_tft::<TestRuntime>(bill_1.clone())
-            .unwrap();
-        assert_eq!(2500000000 - consumer_balance, billed_amount_1);
-
-        // Check event triggering
         let our_events = System::events();
-        assert_eq!(!our_events.is_empty(), true);
         assert_eq!(
             our_events.last().unwrap(),
             &record(MockEvent::SmartContractModule(SmartContractEvent::<
                 TestRuntime,
-            >::ServiceContractBilled {
-                service_contract,
-                bill: bill_1,
-                amount: billed_amount_1,
+            >::ServicePlanSubscribed {
+                plan_id,
+                service_contract_id,
+                consumer_twin_id: 2,
             })),
         );
 
-        // Bill a second time, 1h10min after first billing
-        run_to_block(901, Some(&mut pool_state));
-        assert_ok!(SmartContractModule::service_contract_bill(
-            RuntimeOrigin::signed(alice()),
+        // Subscription is billed like any other service contract
+        pool_state.write().should_call_bill_service_contract(
             service_contract_id,
-            VARIABLE_AMOUNT,
-            b"bill_metadata_2".to_vec(),
-        ));
-
-        let service_contract = SmartContractModule::service_contracts(1).unwrap();
-        assert_eq!(
-            service_contract.last_bill,
-            get_timestamp_in_seconds_for_block(901)
+            Ok(Pays::Yes.into()),
+            11,
         );
+        run_to_block(11, Some(&mut pool_state));
 
-        // Check consumer balance after second billing
-        let consumer_balance = Balances::free_balance(&consumer_twin.account_id);
-        let bill_2 = types::ServiceContractBill {
-            variable_amount: VARIABLE_AMOUNT,
-            window: SECS_PER_HOUR, // force a 1h bill here
-            metadata: BoundedVec::try_from(b"bill_metadata_2".to_vec()).unwrap(),
-        };
-        let billed_amount_2 = service_contract
-            .calculate_bill_cost_tft::<TestRuntime>(bill_2.clone())
-            .unwrap();
-        // Second billing was equivalent to a 1h
-        // billing even if window is greater than 1h
+        let service_contract = SmartContractModule::service_contracts(service_contract_id).unwrap();
         assert_eq!(
-            2500000000 - consumer_balance - billed_amount_1,
-            billed_amount_2
+            service_contract.last_bill,
+            get_timestamp_in_seconds_for_block(11)
         );
 
-        // Check event triggering
-        let our_events = System::events();
-        assert_eq!(!our_events.is_empty(), true);
-        assert_eq!(
-            our_events.last().unwrap(),
-            &record(MockEvent::SmartContractModule(SmartContractEvent::<
-                TestRuntime,
-            >::ServiceContractBilled {
-                service_contract,
-                bill: bill_2,
-                amount: billed_amount_2,
-            })),
-        );
+        // Canceling the subscription removes the link to the plan
+        assert_ok!(SmartContractModule::service_contract_cancel(
+            RuntimeOrigin::signed(bob()),
+            service_contract_id,
+        ));
+        assert!(SmartContractModule::service_contract_plan(service_contract_id).is_none());
     });
 }
 
 #[test]
-fn test_service_contract_bill_by_unauthorized_fails() {
+fn test_service_plan_update_keeps_existing_subscriptions_terms() {
     new_test_ext().execute_with(|| {
-        prepare_service_consumer_contract();
-        let service_contract_id = 1;
-        approve_service_consumer_contract(service_contract_id);
+        run_to_block(1, None);
+        create_service_plan();
+        create_twin(bob());
+        create_twin(charlie());
+        let plan_id = 1;
 
+        assert_ok!(SmartContractModule::service_plan_subscribe(
+            RuntimeOrigin::signed(bob()),
+            plan_id,
+        ));
+
+        // Only the service can update its plan
         assert_noop!(
-            SmartContractModule::service_contract_bill(
+            SmartContractModule::service_plan_update(
                 RuntimeOrigin::signed(bob()),
-                service_contract_id,
-                VARIABLE_AMOUNT,
-                b"bill_metadata".to_vec(),
+                plan_id,
+                BASE_FEE * 2,
+                VARIABLE_FEE * 2,
+                b"new_metadata".to_vec(),
             ),
             Error::<TestRuntime>::TwinNotAuthorized
         );
-    });
-}
+        assert_ok!(SmartContractModule::service_plan_update(
+            RuntimeOrigin::signed(alice()),
+            plan_id,
+            BASE_FEE * 2,
+            VARIABLE_FEE * 2,
+            b"new_metadata".to_vec(),
+        ));
 
-#[test]
-fn test_service_contract_bill_not_approved_fails() {
-    new_test_ext().execute_with(|| {
-        prepare_service_consumer_contract();
-        let service_contract_id = 1;
+        assert_ok!(SmartContractModule::service_plan_subscribe(
+            RuntimeOrigin::signed(charlie()),
+            plan_id,
+        ));
 
-        assert_noop!(
-            SmartContractModule::service_contract_bill(
-                RuntimeOrigin::signed(alice()),
-                service_contract_id,
-                VARIABLE_AMOUNT,
-                b"bill_metadata".to_vec(),
-            ),
-            Error::<TestRuntime>::ServiceContractBillingNotApprovedByBoth
-        );
+        let service_contract = SmartContractModule::service_contracts(1).unwrap();
+        assert_eq!(service_contract.base_fee, BASE_FEE);
+        let service_contract = SmartContractModule::service_contracts(2).unwrap();
+        assert_eq!(service_contract.base_fee, BASE_FEE * 2);
+        assert_eq!(service_contract.metadata, b"new_metadata".to_vec());
     });
 }
 
 #[test]
-fn test_service_contract_bill_variable_amount_too_high_fails() {
-    let (mut ext, mut pool_state) = new_test_ext_with_pool_state(0);
-    ext.execute_with(|| {
+fn test_service_plan_retire_works() {
+    new_test_ext().execute_with(|| {
         run_to_block(1, None);
-        prepare_service_consumer_contract();
-        let service_contract_id = 1;
-        approve_service_consumer_contract(service_contract_id);
+        create_service_plan();
+        create_twin(bob());
+        create_twin(charlie());
+        let plan_id = 1;
 
-        // Bill 1h after contract approval
-        run_to_block(601, Some(&mut pool_state));
-        // set variable amount a bit higher than variable fee to trigger error
-        let variable_amount = VARIABLE_FEE + 1;
-        assert_noop!(
-            SmartContractModule::service_contract_bill(
-                RuntimeOrigin::signed(alice()),
-                service_contract_id,
-                variable_amount,
-                b"bill_metadata".to_vec(),
-            ),
-            Error::<TestRuntime>::ServiceContractBillingVariableAmountTooHigh
-        );
-    });
-}
+        assert_ok!(SmartContractModule::service_plan_subscribe(
+            RuntimeOrigin::signed(bob()),
+            plan_id,
+        ));
 
-#[test]
-fn test_service_contract_bill_metadata_too_long_fails() {
-    let (mut ext, mut pool_state) = new_test_ext_with_pool_state(0);
-    ext.execute_with(|| {
-        run_to_block(1, None);
-        prepare_service_consumer_contract();
-        let service_contract_id = 1;
-        approve_service_consumer_contract(service_contract_id);
+        assert_ok!(SmartContractModule::service_plan_retire(
+            RuntimeOrigin::signed(alice()),
+            plan_id,
+        ));
+        assert!(SmartContractModule::service_plans(plan_id).unwrap().retired);
 
-        // Bill 1h after contract approval
-        run_to_block(601, Some(&mut pool_state));
+        let our_events = System::events();
+        assert_eq!(
+            our_events.last().unwrap(),
+            &record(MockEvent::SmartContractModule(SmartContractEvent::<
+                TestRuntime,
+            >::ServicePlanRetired {
+                plan_id
+            })),
+        );
+
+        // Retired plan does not accept new subscriptions
         assert_noop!(
-            SmartContractModule::service_contract_bill(
-                RuntimeOrigin::signed(alice()),
-                service_contract_id,
-                VARIABLE_AMOUNT,
-                b"very_loooooooooooooooooooooooooooooooooooooooooooooooooong_metadata".to_vec(),
-            ),
-            Error::<TestRuntime>::ServiceContractBillMetadataTooLong
+            SmartContractModule::service_plan_subscribe(RuntimeOrigin::signed(charlie()), plan_id),
+            Error::<TestRuntime>::ServicePlanIsRetired
         );
+
+        // Existing subscriptions keep running
+        assert!(SmartContractModule::service_contracts(1).is_some());
     });
 }
 
 #[test]
-fn test_service_contract_bill_out_of_funds_fails() {
-    let (mut ext, mut pool_state) = new_test_ext_with_pool_state(0);
-    ext.execute_with(|| {
+fn test_service_plan_subscribe_to_own_plan_fails() {
+    new_test_ext().execute_with(|| {
         run_to_block(1, None);
-        prepare_service_consumer_contract();
-        let service_contract_id = 1;
-        approve_service_consumer_contract(service_contract_id);
-
-        // Drain consumer account
-        let consumer_twin = TfgridModule::twins(2).unwrap();
-        let consumer_balance = Balances::free_balance(&consumer_twin.account_id);
-        Balances::transfer(RuntimeOrigin::signed(bob()), alice(), consumer_balance).unwrap();
-        let consumer_balance = Balances::free_balance(&consumer_twin.account_id);
-        assert_eq!(consumer_balance, 0);
+        create_service_plan();
 
-        // Bill 1h after contract approval
-        run_to_block(601, Some(&mut pool_state));
         assert_noop!(
-            SmartContractModule::service_contract_bill(
-                RuntimeOrigin::signed(alice()),
-                service_contract_id,
-                VARIABLE_AMOUNT,
-                b"bill_metadata".to_vec(),
-            ),
-            Error::<TestRuntime>::ServiceContractNotEnoughFundsToPayBill,
+            SmartContractModule::service_plan_subscribe(RuntimeOrigin::signed(alice()), 1),
+            Error::<TestRuntime>::ServiceContractCreationNotAllowed
         );
     });
 }
@@ -3620,6 +8326,51 @@ fn test_attach_solution_provider_id() {
     })
 }
 
+#[test]
+fn test_attach_solution_provider_id_replaces_revoked_one() {
+    new_test_ext().execute_with(|| {
+        run_to_block(1, None);
+        prepare_farm_node_and_node_contract();
+        let contract_id = 1;
+
+        prepare_solution_provider(alice());
+        assert_ok!(SmartContractModule::attach_solution_provider_id(
+            RuntimeOrigin::signed(alice()),
+            contract_id,
+            1
+        ));
+
+        prepare_solution_provider(alice());
+        let provider_id = 2;
+        assert_ok!(SmartContractModule::approve_solution_provider(
+            RawOrigin::Root.into(),
+            provider_id,
+            true
+        ));
+        assert_noop!(
+            SmartContractModule::attach_solution_provider_id(
+                RuntimeOrigin::signed(alice()),
+                contract_id,
+                provider_id
+            ),
+            Error::<TestRuntime>::UnauthorizedToChangeSolutionProviderId
+        );
+
+        assert_ok!(SmartContractModule::revoke_solution_provider(
+            RawOrigin::Root.into(),
+            1
+        ));
+        assert_ok!(SmartContractModule::attach_solution_provider_id(
+            RuntimeOrigin::signed(alice()),
+            contract_id,
+            provider_id
+        ));
+
+        let ctr = SmartContractModule::contracts(contract_id).unwrap();
+        assert_eq!(ctr.solution_provider_id, Some(provider_id));
+    })
+}
+
 #[test]
 fn test_attach_solution_provider_id_wrong_origin_fails() {
     new_test_ext().execute_with(|| {
@@ -4052,6 +8803,104 @@ test_calculate_discount! {
     test_calculate_gold_discount_gold_works: (18, types::DiscountLevel::Gold),
 }
 
+#[test]
+fn test_estimate_contract_cost_works() {
+    new_test_ext().execute_with(|| {
+        prepare_farm_and_node();
+
+        let resources = Resources {
+            cru: 2,
+            hru: 0,
+            mru: 2 * GIGABYTE,
+            sru: 60 * GIGABYTE,
+        };
+        let estimate =
+            SmartContractModule::estimate_contract_cost(1, 1, resources.clone(), 1).unwrap();
+
+        let pricing_policy = TfgridModule::pricing_policies(1).unwrap();
+        let cost_units_usd = cost::calculate_resources_cost_units_usd::<TestRuntime>(
+            resources,
+            U64F64::from_num(1),
+            SECS_PER_HOUR,
+            &pricing_policy,
+            true,
+        );
+        let cost_tft =
+            cost::calculate_cost_in_tft_from_units_usd::<TestRuntime>(cost_units_usd).unwrap();
+        let twin = TfgridModule::twins(1).unwrap();
+        let balance = Balances::usable_balance(&twin.account_id);
+        let (amount_due, discount_level) = cost::calculate_discount_tft::<TestRuntime>(
+            cost_tft,
+            SECS_PER_HOUR,
+            balance,
+            NodeCertification::Diy,
+        );
+
+        assert_ne!(estimate.cost_musd, 0);
+        assert_eq!(
+            estimate.cost_musd,
+            (U64F64::from_num(cost_units_usd) / U64F64::from_num(10000))
+                .round()
+                .to_num::<u64>()
+        );
+        assert_eq!(estimate.cost_tft, amount_due);
+        assert_eq!(estimate.discount_level, discount_level);
+    })
+}
+
+#[test]
+fn test_estimate_contract_cost_on_rented_node_only_bills_public_ips_works() {
+    new_test_ext().execute_with(|| {
+        prepare_dedicated_farm_and_node();
+        let node_id = 1;
+        assert_ok!(SmartContractModule::create_rent_contract(
+            RuntimeOrigin::signed(bob()),
+            node_id,
+            None
+        ));
+
+        let resources = Resources {
+            cru: 2,
+            hru: 0,
+            mru: 2 * GIGABYTE,
+            sru: 60 * GIGABYTE,
+        };
+        let estimate =
+            SmartContractModule::estimate_contract_cost(node_id, 2, resources.clone(), 0).unwrap();
+        assert_eq!(estimate.cost_musd, 0);
+        assert_eq!(estimate.cost_tft, 0);
+
+        let estimate =
+            SmartContractModule::estimate_contract_cost(node_id, 2, resources, 1).unwrap();
+        let pricing_policy = TfgridModule::pricing_policies(1).unwrap();
+        let cost_units_usd = cost::calculate_resources_cost_units_usd::<TestRuntime>(
+            Resources::empty(),
+            U64F64::from_num(1),
+            SECS_PER_HOUR,
+            &pricing_policy,
+            false,
+        );
+        assert_eq!(
+            estimate.cost_musd,
+            (U64F64::from_num(cost_units_usd) / U64F64::from_num(10000))
+                .round()
+                .to_num::<u64>()
+        );
+    })
+}
+
+#[test]
+fn test_estimate_contract_cost_undefined_node_fails() {
+    new_test_ext().execute_with(|| {
+        prepare_farm_and_node();
+
+        assert_eq!(
+            SmartContractModule::estimate_contract_cost(2, 1, Resources::empty(), 0),
+            Err(Error::<TestRuntime>::NodeNotExists.into())
+        );
+    })
+}
+
 // ***** HELPER FUNCTIONS ***** //
 // ---------------------------- //
 // ---------------------------- //
@@ -4270,6 +9119,34 @@ pub fn prepare_farm(source: AccountId, dedicated: bool) {
     TfgridModule::set_farm_dedicated(RawOrigin::Root.into(), 1, true).unwrap();
 }
 
+// Creates a pricing policy twice as expensive as the default one
+// and makes the farm follow it
+fn prepare_farm_pricing_policy(farm_id: u32) {
+    let default_pricing_policy = TfgridModule::pricing_policies(1).unwrap();
+    let double = |policy: pallet_tfgrid_types::Policy| pallet_tfgrid_types::Policy {
+        value: policy.value * 2,
+        unit: policy.unit,
+    };
+
+    assert_ok!(TfgridModule::create_pricing_policy(
+        RawOrigin::Root.into(),
+        b"policy_2".to_vec(),
+        double(default_pricing_policy.su),
+        double(default_pricing_policy.cu),
+        double(default_pricing_policy.nu),
+        double(default_pricing_policy.ipu),
+        double(default_pricing_policy.unique_name),
+        double(default_pricing_policy.domain_name),
+        charlie(),
+        dave(),
+        default_pricing_policy.discount_for_dedication_nodes,
+    ));
+
+    pallet_tfgrid::Farms::<TestRuntime>::mutate(farm_id, |farm| {
+        farm.as_mut().unwrap().pricing_policy_id = 2;
+    });
+}
+
 pub fn prepare_farm_and_node() {
     TFTPriceModule::set_prices(RuntimeOrigin::signed(alice()), 50, 101).unwrap();
 
@@ -4436,6 +9313,26 @@ fn create_farming_policies() {
     ));
 }
 
+fn prepare_node_contract_with_solution_provider() -> u64 {
+    run_to_block(1, None);
+    prepare_farm_and_node();
+    prepare_solution_provider(dave());
+    TFTPriceModule::set_prices(RuntimeOrigin::signed(alice()), 50, 101).unwrap();
+
+    assert_ok!(SmartContractModule::create_node_contract(
+        RuntimeOrigin::signed(bob()),
+        1,
+        generate_deployment_hash(),
+        get_deployment_data(),
+        1,
+        Some(1)
+    ));
+    let contract_id = 1;
+    push_contract_resources_used(contract_id);
+
+    contract_id
+}
+
 fn prepare_solution_provider(origin: AccountId) {
     let provider = super::types::Provider {
         take: 10,
@@ -4476,6 +9373,38 @@ fn get_deployment_data() -> crate::DeploymentDataInput<TestRuntime> {
         .unwrap()
 }
 
+fn get_ip6_input(ip6_input: &[u8]) -> pallet_tfgrid::Ip6Input {
+    BoundedVec::try_from(ip6_input.to_vec()).unwrap()
+}
+
+fn add_farm_ip6_prefix(prefix: &[u8]) {
+    assert_ok!(TfgridModule::add_farm_ip6_prefix(
+        RuntimeOrigin::signed(alice()),
+        1,
+        get_ip6_input(prefix),
+        BoundedVec::try_from(b"2a10:b600:1::1".to_vec()).unwrap(),
+    ));
+}
+
+fn get_resources(cru: u64, mru_in_gb: u64) -> Resources {
+    Resources {
+        hru: 0,
+        sru: 0,
+        cru,
+        mru: mru_in_gb * GIGABYTE,
+    }
+}
+
+fn get_node_contract_input(node_id: u32, public_ips: u32) -> types::NodeContractInput<TestRuntime> {
+    types::NodeContractInput {
+        node_id,
+        deployment_hash: generate_deployment_hash(),
+        deployment_data: get_deployment_data(),
+        public_ips,
+        resources: None,
+    }
+}
+
 fn create_service_consumer_contract() {
     create_twin(alice());
     create_twin(bob());
@@ -4519,6 +9448,30 @@ fn approve_service_consumer_contract(service_contract_id: u64) {
     ));
 }
 
+fn prepare_escrowed_service_consumer_contract() {
+    prepare_service_consumer_contract();
+    let service_contract_id = 1;
+
+    assert_ok!(SmartContractModule::service_contract_set_dispute_window(
+        RuntimeOrigin::signed(alice()),
+        service_contract_id,
+        Some(SECS_PER_HOUR),
+    ));
+
+    approve_service_consumer_contract(service_contract_id);
+}
+
+fn create_service_plan() {
+    create_twin(alice());
+
+    assert_ok!(SmartContractModule::service_plan_create(
+        RuntimeOrigin::signed(alice()),
+        BASE_FEE,
+        VARIABLE_FEE,
+        b"some_metadata".to_vec(),
+    ));
+}
+
 fn get_service_contract() -> types::ServiceContract {
     types::ServiceContract {
         service_contract_id: 1,
@@ -0,0 +1,150 @@
+use crate::*;
+use frame_support::{dispatch::DispatchResultWithPostInfo, ensure};
+
+impl<T: Config> Pallet<T> {
+    pub fn _offer_contract_transfer(
+        account_id: T::AccountId,
+        contract_id: u64,
+        to_twin_id: u32,
+    ) -> DispatchResultWithPostInfo {
+        let twin_id = pallet_tfgrid::TwinIdByAccountID::<T>::get(&account_id)
+            .ok_or(Error::<T>::TwinNotExists)?;
+        let contract = Contracts::<T>::get(contract_id).ok_or(Error::<T>::ContractNotExists)?;
+        ensure!(
+            contract.twin_id == twin_id,
+            Error::<T>::TwinNotAuthorizedToUpdateContract
+        );
+        ensure!(
+            matches!(contract.state, types::ContractState::Created),
+            Error::<T>::CannotTransferContractInGraceState
+        );
+        ensure!(
+            to_twin_id != twin_id,
+            Error::<T>::CannotTransferContractToSameTwin
+        );
+        ensure!(
+            pallet_tfgrid::Twins::<T>::contains_key(to_twin_id),
+            Error::<T>::TwinNotExists
+        );
+
+        ContractTransferOffers::<T>::insert(contract_id, to_twin_id);
+
+        Self::deposit_event(Event::ContractTransferOffered {
+            contract_id,
+            from_twin_id: twin_id,
+            to_twin_id,
+        });
+
+        Ok(().into())
+    }
+
+    pub fn _cancel_contract_transfer(
+        account_id: T::AccountId,
+        contract_id: u64,
+    ) -> DispatchResultWithPostInfo {
+        let twin_id = pallet_tfgrid::TwinIdByAccountID::<T>::get(&account_id)
+            .ok_or(Error::<T>::TwinNotExists)?;
+        let contract = Contracts::<T>::get(contract_id).ok_or(Error::<T>::ContractNotExists)?;
+        ensure!(
+            contract.twin_id == twin_id,
+            Error::<T>::TwinNotAuthorizedToUpdateContract
+        );
+        ensure!(
+            ContractTransferOffers::<T>::contains_key(contract_id),
+            Error::<T>::ContractTransferNotOffered
+        );
+
+        ContractTransferOffers::<T>::remove(contract_id);
+
+        Self::deposit_event(Event::ContractTransferCanceled { contract_id });
+
+        Ok(().into())
+    }
+
+    pub fn _accept_contract_transfer(
+        account_id: T::AccountId,
+        contract_id: u64,
+    ) -> DispatchResultWithPostInfo {
+        let twin_id = pallet_tfgrid::TwinIdByAccountID::<T>::get(&account_id)
+            .ok_or(Error::<T>::TwinNotExists)?;
+        ensure!(
+            ContractTransferOffers::<T>::get(contract_id) == Some(twin_id),
+            Error::<T>::ContractTransferNotOffered
+        );
+
+        let contract = Contracts::<T>::get(contract_id).ok_or(Error::<T>::ContractNotExists)?;
+        Self::ensure_contract_transferable_to(&contract, twin_id)?;
+
+        // Settle everything that is due up to now with the current owner:
+        // bill what is due since last billing and pay out the amount locked
+        Self::bill_contract(contract_id)?;
+
+        let mut contract = Contracts::<T>::get(contract_id).ok_or(Error::<T>::ContractNotExists)?;
+        ensure!(
+            matches!(contract.state, types::ContractState::Created),
+            Error::<T>::CannotTransferContractInGraceState
+        );
+
+        let mut contract_lock = ContractLock::<T>::get(contract_id);
+        if contract_lock.has_some_amount_locked() {
+            Self::settle_contract_lock(&contract, &mut contract_lock)?;
+            ContractLock::<T>::insert(contract_id, &contract_lock);
+        }
+
+        // Spending caps were set by the previous owner
+        ContractSpendingCap::<T>::remove(contract_id);
+        ContractTransferOffers::<T>::remove(contract_id);
+
+        let from_twin_id = contract.twin_id;
+        contract.twin_id = twin_id;
+        Contracts::<T>::insert(contract_id, &contract);
+
+        Self::deposit_event(Event::ContractTransferred {
+            contract_id,
+            from_twin_id,
+            to_twin_id: twin_id,
+        });
+
+        Ok(().into())
+    }
+
+    fn ensure_contract_transferable_to(
+        contract: &types::Contract<T>,
+        twin_id: u32,
+    ) -> DispatchResultWithPostInfo {
+        match &contract.contract_type {
+            // A node contract on a rented node can only belong to the twin renting it
+            types::ContractData::NodeContract(node_contract) => {
+                if let Some(rent_contract_id) =
+                    ActiveRentContractForNode::<T>::get(node_contract.node_id)
+                {
+                    let rent_contract = Contracts::<T>::get(rent_contract_id)
+                        .ok_or(Error::<T>::ContractNotExists)?;
+                    ensure!(
+                        rent_contract.twin_id == twin_id,
+                        Error::<T>::NodeNotAvailableToDeploy
+                    );
+                }
+            }
+            // Node contracts of the current owner would end up on a node rented by someone else
+            types::ContractData::RentContract(rent_contract) => {
+                ensure!(
+                    ActiveNodeContracts::<T>::get(rent_contract.node_id).is_empty(),
+                    Error::<T>::NodeHasActiveContracts
+                );
+            }
+            // An expired name is only held for its owner to renew it
+            types::ContractData::NameContract(_) => {
+                ensure!(
+                    !Self::is_name_contract_expired(
+                        contract.contract_id,
+                        Self::get_current_timestamp_in_secs()
+                    ),
+                    Error::<T>::NameContractExpired
+                );
+            }
+        }
+
+        Ok(().into())
+    }
+}
@@ -6,7 +6,10 @@ use core::{convert::TryInto, ops::Add};
 use frame_support::{pallet_prelude::ConstU32, BoundedVec, RuntimeDebugNoBound};
 use parity_scale_codec::{Decode, Encode, MaxEncodedLen};
 use scale_info::TypeInfo;
-use sp_runtime::SaturatedConversion;
+use sp_runtime::{
+    traits::{Saturating, Zero},
+    SaturatedConversion,
+};
 use sp_std::prelude::*;
 use substrate_fixed::types::U64F64;
 use tfchain_support::{resources::Resources, types::PublicIP};
@@ -27,6 +30,10 @@ pub enum StorageVersion {
     V9,
     V10,
     V11,
+    V12,
+    V13,
+    V14,
+    V15,
 }
 
 impl Default for StorageVersion {
@@ -52,6 +59,14 @@ impl<T: Config> Contract<T> {
         matches!(self.state, ContractState::Deleted(_))
     }
 
+    pub fn get_contract_kind(&self) -> ContractKind {
+        match self.contract_type {
+            ContractData::NodeContract(_) => ContractKind::Node,
+            ContractData::NameContract(_) => ContractKind::Name,
+            ContractData::RentContract(_) => ContractKind::Rent,
+        }
+    }
+
     pub fn get_node_id(&self) -> u32 {
         match self.contract_type.clone() {
             ContractData::RentContract(c) => c.node_id,
@@ -77,6 +92,39 @@ pub struct NodeContract<T: Config> {
     pub public_ips_list: BoundedVec<PublicIP, MaxNodeContractPublicIPs<T>>,
 }
 
+// Node contract to create as part of a batch
+#[derive(Clone, Eq, PartialEq, RuntimeDebugNoBound, Encode, Decode, TypeInfo, MaxEncodedLen)]
+#[scale_info(skip_type_params(T))]
+#[codec(mel_bound())]
+pub struct NodeContractInput<T: Config> {
+    pub node_id: u32,
+    pub deployment_hash: HexHash,
+    pub deployment_data: BoundedVec<u8, MaxDeploymentDataLength<T>>,
+    pub public_ips: u32,
+    // Resources to reserve on the node, if any
+    pub resources: Option<Resources>,
+}
+
+// Public ips of the farm to reserve for a node contract
+#[derive(Clone, Eq, PartialEq, RuntimeDebugNoBound, Encode, Decode, TypeInfo, MaxEncodedLen)]
+#[scale_info(skip_type_params(T))]
+#[codec(mel_bound())]
+pub enum PublicIpsRequest<T: Config> {
+    // Specific ips (as listed in the farm)
+    Ips(BoundedVec<pallet_tfgrid::Ip4Input, MaxNodeContractPublicIPs<T>>),
+    // Block of contiguous ips starting on an address aligned to its size,
+    // size must be a power of 2
+    Block(u32),
+}
+
+// /64 subnet delegated to a node contract out of an ipv6 prefix of the farm
+#[derive(PartialEq, Eq, PartialOrd, Ord, Clone, Encode, Decode, Debug, TypeInfo, MaxEncodedLen)]
+pub struct Ip6Delegation {
+    pub prefix: pallet_tfgrid::Ip6Input,
+    // Upper 64 bits of the subnet address
+    pub subnet: u64,
+}
+
 #[derive(Clone, Eq, PartialEq, RuntimeDebugNoBound, Encode, Decode, TypeInfo, MaxEncodedLen)]
 #[scale_info(skip_type_params(T))]
 #[codec(mel_bound())]
@@ -84,6 +132,14 @@ pub struct NameContract<T: Config> {
     pub name: T::NameContractName,
 }
 
+#[derive(PartialEq, Eq, PartialOrd, Ord, Clone, Encode, Decode, Debug, TypeInfo, MaxEncodedLen)]
+pub enum ContractKind {
+    Node,
+    Rent,
+    Name,
+    Service,
+}
+
 #[derive(
     PartialEq,
     Eq,
@@ -137,6 +193,10 @@ pub enum Cause {
     CanceledByUser,
     OutOfFunds,
     CanceledByCollective,
+    // Fixed-term name registration expired and was not renewed
+    Expired,
+    // Node got decommissioned before the contract moved off it
+    NodeDecommissioned,
 }
 
 impl Default for ContractState {
@@ -205,6 +265,16 @@ pub struct ContractBill {
     pub amount_billed: u128,
 }
 
+// Total amount billed to a twin on a single day
+#[derive(
+    PartialEq, Eq, PartialOrd, Ord, Clone, Encode, Decode, Default, Debug, TypeInfo, MaxEncodedLen,
+)]
+pub struct TwinDailyBill {
+    // Number of days elapsed since unix epoch
+    pub day: u64,
+    pub amount_billed: u128,
+}
+
 #[derive(
     PartialEq, Eq, PartialOrd, Ord, Clone, Encode, Decode, Default, Debug, TypeInfo, MaxEncodedLen,
 )]
@@ -237,6 +307,83 @@ impl<BalanceOf: Add<Output = BalanceOf> + Copy + TryInto<u64>> ContractLock<Bala
     }
 }
 
+// Estimated cost of a deployment for one billing reference period (1 hour)
+#[derive(
+    PartialEq, Eq, PartialOrd, Ord, Clone, Encode, Decode, Default, Debug, TypeInfo, MaxEncodedLen,
+)]
+pub struct ContractCostEstimate<BalanceOf> {
+    // Cost in mUSD (USD-mill)
+    pub cost_musd: u64,
+    // Cost in TFT, discount and certification applied
+    pub cost_tft: BalanceOf,
+    // Discount level the twin would get given its current balance
+    pub discount_level: DiscountLevel,
+}
+
+// Contract in grace period as returned by the runtime api
+#[derive(
+    PartialEq, Eq, PartialOrd, Ord, Clone, Encode, Decode, Default, Debug, TypeInfo, MaxEncodedLen,
+)]
+pub struct ContractInGrace {
+    pub contract_id: u64,
+    // Block at which the grace period started
+    pub grace_period_start: u64,
+    // Amount of blocks left before the contract gets removed
+    pub remaining_blocks: u64,
+}
+
+// Maximum amount a twin allows to be billed over a period of time
+#[derive(
+    PartialEq, Eq, PartialOrd, Ord, Clone, Encode, Decode, Default, Debug, TypeInfo, MaxEncodedLen,
+)]
+pub struct SpendingCap<BalanceOf> {
+    pub amount: BalanceOf,
+    // Length of the period in seconds
+    pub period: u64,
+    // Start of the current period in seconds since unix epoch
+    pub period_start: u64,
+    // Amount billed since the start of the current period
+    pub spent: BalanceOf,
+    // What happens to a contract once the cap is reached
+    pub action: SpendingCapAction,
+}
+
+impl<BalanceOf: Copy + PartialOrd + Zero + Saturating> SpendingCap<BalanceOf> {
+    // Starts a new period if the current one is over
+    pub fn refresh(&mut self, now: u64) {
+        if now >= self.period_start.saturating_add(self.period) {
+            self.period_start = now;
+            self.spent = BalanceOf::zero();
+        }
+    }
+
+    pub fn allows(&self, amount: BalanceOf) -> bool {
+        self.spent.saturating_add(amount) <= self.amount
+    }
+}
+
+#[derive(
+    PartialEq, Eq, PartialOrd, Ord, Clone, Copy, Encode, Decode, Debug, TypeInfo, MaxEncodedLen,
+)]
+pub enum SpendingCapAction {
+    // The contract goes into grace and is removed if the cap is still reached at the end of it
+    Grace,
+    // The contract is paused, it is not billed and not removed until the cap allows billing again
+    Pause,
+}
+
+impl Default for SpendingCapAction {
+    fn default() -> SpendingCapAction {
+        SpendingCapAction::Grace
+    }
+}
+
+#[derive(PartialEq, Eq, PartialOrd, Ord, Clone, Encode, Decode, Debug, TypeInfo, MaxEncodedLen)]
+pub enum SpendingCapScope {
+    Contract,
+    Twin,
+}
+
 #[derive(PartialEq, Eq, PartialOrd, Ord, Clone, Encode, Decode, Default, Debug, TypeInfo)]
 pub struct SolutionProvider<AccountId> {
     pub solution_provider_id: u64,
@@ -244,6 +391,22 @@ pub struct SolutionProvider<AccountId> {
     pub description: Vec<u8>,
     pub link: Vec<u8>,
     pub approved: bool,
+    // A revoked solution provider can not be approved again
+    pub revoked: bool,
+    // Time (in seconds) from which the solution provider is no longer valid
+    pub expires_at: Option<u64>,
+}
+
+impl<AccountId> SolutionProvider<AccountId> {
+    // Only an active solution provider can be attached to contracts and gets its take when billing
+    pub fn is_active(&self, now: u64) -> bool {
+        self.approved && !self.revoked && !self.is_expired(now)
+    }
+
+    pub fn is_expired(&self, now: u64) -> bool {
+        self.expires_at
+            .map_or(false, |expires_at| now >= expires_at)
+    }
 }
 
 #[derive(PartialEq, Eq, PartialOrd, Ord, Clone, Encode, Decode, Default, Debug, TypeInfo)]
@@ -252,8 +415,35 @@ pub struct Provider<AccountId> {
     pub take: u8,
 }
 
+// Earnings of a solution provider as returned by the runtime api
+#[derive(PartialEq, Eq, PartialOrd, Ord, Clone, Encode, Decode, Default, Debug, TypeInfo)]
+pub struct SolutionProviderEarnings<AccountId, BalanceOf> {
+    pub solution_provider_id: u64,
+    // Total amount earned by each provider account
+    pub providers: Vec<ProviderEarnings<AccountId, BalanceOf>>,
+    // Total amount earned by the providers from each contract
+    pub contracts: Vec<ContractEarnings<BalanceOf>>,
+}
+
+#[derive(PartialEq, Eq, PartialOrd, Ord, Clone, Encode, Decode, Default, Debug, TypeInfo)]
+pub struct ProviderEarnings<AccountId, BalanceOf> {
+    pub who: AccountId,
+    pub earned: BalanceOf,
+}
+
+#[derive(PartialEq, Eq, PartialOrd, Ord, Clone, Encode, Decode, Default, Debug, TypeInfo)]
+pub struct ContractEarnings<BalanceOf> {
+    pub contract_id: u64,
+    pub earned: BalanceOf,
+}
+
 pub const MAX_METADATA_LENGTH: u32 = 64; // limited to 64 bytes (2 public keys)
 pub const MAX_BILL_METADATA_LENGTH: u32 = 50; // limited to 50 bytes for now
+pub const MAX_DISPUTE_WINDOW: u64 = 30 * 24 * 60 * 60; // limited to 30 days (in seconds)
+pub const MAX_NAME_CONTRACT_TERM: u64 = 10 * 365 * 24 * 60 * 60; // limited to 10 years (in seconds)
+pub const DEFAULT_NAME_CONTRACT_HOLD_PERIOD: u64 = 30 * 24 * 60 * 60; // 30 days (in seconds)
+pub const DEFAULT_PUBLIC_IP_PRICE_MULTIPLIER: u32 = 100; // in percent, ip price of the pricing policy
+pub const MAX_PUBLIC_IP_PRICE_MULTIPLIER: u32 = 1000; // limited to 10 times the ip price of the pricing policy
 
 #[derive(Clone, Eq, PartialEq, RuntimeDebugNoBound, Encode, Decode, TypeInfo, MaxEncodedLen)]
 #[scale_info(skip_type_params(T))]
@@ -286,3 +476,108 @@ pub enum ServiceContractState {
     AgreementReady,
     ApprovedByBoth,
 }
+
+// New terms proposed by a party of a service contract already approved by both
+#[derive(
+    PartialEq, Eq, PartialOrd, Ord, Clone, Encode, Decode, Default, Debug, TypeInfo, MaxEncodedLen,
+)]
+pub struct ServiceContractAmendment {
+    pub proposed_by: u32,
+    pub base_fee: u64,
+    pub variable_fee: u64,
+    pub metadata: BoundedVec<u8, ConstU32<MAX_METADATA_LENGTH>>,
+}
+
+// Variable amount that can still be billed with the variable fee of the terms
+// in place before an amendment, for the time since the last variable bill
+#[derive(
+    PartialEq, Eq, PartialOrd, Ord, Clone, Encode, Decode, Default, Debug, TypeInfo, MaxEncodedLen,
+)]
+pub struct PendingVariableBill {
+    // Time (in seconds) covered, it counts against the maximum bill window
+    pub window: u64,
+    pub max_variable_amount: u64,
+}
+
+// Terms published once by a service, consumers subscribe to it
+// and get a service contract approved by both with these terms
+#[derive(
+    PartialEq, Eq, PartialOrd, Ord, Clone, Encode, Decode, Default, Debug, TypeInfo, MaxEncodedLen,
+)]
+pub struct ServicePlan {
+    pub plan_id: u64,
+    pub service_twin_id: u32,
+    pub base_fee: u64,
+    pub variable_fee: u64,
+    pub metadata: BoundedVec<u8, ConstU32<MAX_METADATA_LENGTH>>,
+    pub retired: bool,
+}
+
+// Bill of a service contract in escrow mode, the amount stays reserved
+// on the consumer balance until it is released to the service
+#[derive(
+    PartialEq, Eq, PartialOrd, Ord, Clone, Encode, Decode, Default, Debug, TypeInfo, MaxEncodedLen,
+)]
+pub struct EscrowedServiceContractBill<BalanceOf> {
+    pub service_twin_id: u32,
+    pub consumer_twin_id: u32,
+    pub bill: ServiceContractBill,
+    pub amount: BalanceOf,
+    // Time (in seconds) until which the consumer can dispute the bill
+    pub dispute_deadline: u64,
+    pub disputed: bool,
+    // Refund each party agrees on to settle a dispute
+    pub refund_proposed_by_service: Option<BalanceOf>,
+    pub refund_proposed_by_consumer: Option<BalanceOf>,
+}
+
+// Availability of a name for a name contract as returned by the runtime api
+#[derive(PartialEq, Eq, PartialOrd, Ord, Clone, Encode, Decode, Debug, TypeInfo, MaxEncodedLen)]
+pub enum NameAvailability {
+    Available,
+    // Not a valid name for a name contract
+    Invalid,
+    // Registered by a name contract, forever or until it expires
+    Registered {
+        contract_id: u64,
+        expires_at: Option<u64>,
+    },
+    // Registration expired, the name is held for its owner until the hold period is over
+    Held {
+        contract_id: u64,
+        released_at: u64,
+    },
+    // Reserved by the council, only the assigned twin (if any) can register it
+    Reserved {
+        twin_id: Option<u32>,
+    },
+    // Blocked by the council, nobody can register it
+    Blocked,
+}
+
+// How the pattern of a name restriction is matched against a name
+#[derive(
+    PartialEq, Eq, PartialOrd, Ord, Clone, Copy, Encode, Decode, Debug, TypeInfo, MaxEncodedLen,
+)]
+pub enum NameMatching {
+    Exact,
+    Prefix,
+}
+
+// Restriction set by the council on the names matching a pattern
+#[derive(
+    PartialEq, Eq, PartialOrd, Ord, Clone, Copy, Encode, Decode, Debug, TypeInfo, MaxEncodedLen,
+)]
+pub enum NameRestriction {
+    // Only the assigned twin can register the name, nobody if not assigned
+    Reserved { twin_id: Option<u32> },
+    Blocked,
+}
+
+// Request of a twin to wake up a standby node it has contracts on
+#[derive(PartialEq, Eq, Clone, Encode, Decode, Default, Debug, TypeInfo, MaxEncodedLen)]
+pub struct NodeWakeupRequest {
+    pub twin_id: u32,
+    // Block at which the request times out if the node did not come back up
+    pub timeout_at: u64,
+}
@@ -56,6 +56,46 @@ pub trait WeightInfo {
 	fn attach_solution_provider_id() -> Weight;
 	fn set_dedicated_node_extra_fee() -> Weight;
 	fn cancel_contract_collective() -> Weight;
+	fn set_contract_spending_cap() -> Weight;
+	fn clear_contract_spending_cap() -> Weight;
+	fn set_twin_spending_cap() -> Weight;
+	fn clear_twin_spending_cap() -> Weight;
+	fn set_contract_kind_grace_period() -> Weight;
+	fn set_solution_provider_grace_period() -> Weight;
+	fn restore_contracts_from_grace(n: u32, ) -> Weight;
+	fn create_node_contracts_batch(n: u32, ) -> Weight;
+	fn create_node_contract_with_resources() -> Weight;
+	fn offer_contract_transfer() -> Weight;
+	fn cancel_contract_transfer() -> Weight;
+	fn accept_contract_transfer() -> Weight;
+	fn service_contract_set_dispute_window() -> Weight;
+	fn service_contract_dispute_bill() -> Weight;
+	fn service_contract_resolve_dispute() -> Weight;
+	fn service_contract_force_resolve_dispute() -> Weight;
+	fn service_contract_release_bill() -> Weight;
+	fn bill_service_contract_for_block() -> Weight;
+	fn service_contract_propose_amendment() -> Weight;
+	fn service_contract_accept_amendment() -> Weight;
+	fn service_contract_reject_amendment() -> Weight;
+	fn service_plan_create() -> Weight;
+	fn service_plan_update() -> Weight;
+	fn service_plan_retire() -> Weight;
+	fn service_plan_subscribe() -> Weight;
+	fn set_solution_provider_rewards_accrual() -> Weight;
+	fn claim_solution_provider_rewards() -> Weight;
+	fn update_solution_provider() -> Weight;
+	fn revoke_solution_provider() -> Weight;
+	fn set_solution_provider_expiry() -> Weight;
+	fn create_name_contract_with_term() -> Weight;
+	fn renew_name_contract() -> Weight;
+	fn set_name_contract_hold_period() -> Weight;
+	fn add_name_restriction() -> Weight;
+	fn remove_name_restriction() -> Weight;
+	fn assign_reserved_name() -> Weight;
+	fn create_node_contract_with_public_ips() -> Weight;
+	fn set_public_ip_price_multiplier() -> Weight;
+	fn create_node_contract_with_ip6_subnets() -> Weight;
+	fn request_node_wakeup() -> Weight;
 }
 
 /// Weights for pallet_smart_contract using the Substrate node and recommended hardware.
@@ -91,13 +131,21 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Proof: `SmartContractModule::ContractBillingInformationByID` (`max_values`: None, `max_size`: None, mode: `Measured`)
 	/// Storage: `SmartContractModule::ContractLock` (r:0 w:1)
 	/// Proof: `SmartContractModule::ContractLock` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `TfgridModule::DecommissioningNodes` (r:1 w:0)
+	/// Proof: `TfgridModule::DecommissioningNodes` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `TfgridModule::NodeMaintenance` (r:1 w:0)
+	/// Proof: `TfgridModule::NodeMaintenance` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `TfgridModule::FarmOvercommitRatio` (r:1 w:0)
+	/// Proof: `TfgridModule::FarmOvercommitRatio` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SmartContractModule::NodeReservedResources` (r:1 w:0)
+	/// Proof: `SmartContractModule::NodeReservedResources` (`max_values`: None, `max_size`: None, mode: `Measured`)
 	fn create_node_contract() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `868`
 		//  Estimated: `4333`
 		// Minimum execution time: 102_203_000 picoseconds.
 		Weight::from_parts(104_197_000, 4333)
-			.saturating_add(T::DbWeight::get().reads(12_u64))
+			.saturating_add(T::DbWeight::get().reads(16_u64))
 			.saturating_add(T::DbWeight::get().writes(8_u64))
 	}
 	/// Storage: `SmartContractModule::Contracts` (r:1 w:1)
@@ -141,14 +189,22 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Proof: `SmartContractModule::NodeContractResources` (`max_values`: None, `max_size`: None, mode: `Measured`)
 	/// Storage: `SmartContractModule::ContractIDByNodeIDAndHash` (r:0 w:1)
 	/// Proof: `SmartContractModule::ContractIDByNodeIDAndHash` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SmartContractModule::ContractReservedResources` (r:1 w:1)
+	/// Proof: `SmartContractModule::ContractReservedResources` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SmartContractModule::ContractIp6Subnets` (r:1 w:1)
+	/// Proof: `SmartContractModule::ContractIp6Subnets` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `TfgridModule::DecommissioningNodes` (r:1 w:0)
+	/// Proof: `TfgridModule::DecommissioningNodes` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `TfgridModule::NodeMaintenance` (r:1 w:0)
+	/// Proof: `TfgridModule::NodeMaintenance` (`max_values`: None, `max_size`: None, mode: `Measured`)
 	fn cancel_contract() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `1203`
 		//  Estimated: `4668`
 		// Minimum execution time: 60_284_000 picoseconds.
 		Weight::from_parts(61_346_000, 4668)
-			.saturating_add(T::DbWeight::get().reads(9_u64))
-			.saturating_add(T::DbWeight::get().writes(7_u64))
+			.saturating_add(T::DbWeight::get().reads(13_u64))
+			.saturating_add(T::DbWeight::get().writes(9_u64))
 	}
 	/// Storage: `TfgridModule::TwinIdByAccountID` (r:1 w:0)
 	/// Proof: `TfgridModule::TwinIdByAccountID` (`max_values`: None, `max_size`: None, mode: `Measured`)
@@ -166,13 +222,15 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Proof: `SmartContractModule::Contracts` (`max_values`: None, `max_size`: None, mode: `Measured`)
 	/// Storage: `SmartContractModule::ContractLock` (r:0 w:1)
 	/// Proof: `SmartContractModule::ContractLock` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SmartContractModule::NameRestrictions` (r:5 w:0)
+	/// Proof: `SmartContractModule::NameRestrictions` (`max_values`: None, `max_size`: None, mode: `Measured`)
 	fn create_name_contract() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `340`
 		//  Estimated: `3805`
 		// Minimum execution time: 24_977_000 picoseconds.
 		Weight::from_parts(25_658_000, 3805)
-			.saturating_add(T::DbWeight::get().reads(6_u64))
+			.saturating_add(T::DbWeight::get().reads(11_u64))
 			.saturating_add(T::DbWeight::get().writes(5_u64))
 	}
 	/// Storage: `SmartContractModule::Contracts` (r:1 w:1)
@@ -233,14 +291,18 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Proof: `SmartContractModule::Contracts` (`max_values`: None, `max_size`: None, mode: `Measured`)
 	/// Storage: `SmartContractModule::NodeContractResources` (r:0 w:1)
 	/// Proof: `SmartContractModule::NodeContractResources` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SmartContractModule::ContractReservedResources` (r:1 w:1)
+	/// Proof: `SmartContractModule::ContractReservedResources` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SmartContractModule::NodeReservedResources` (r:1 w:1)
+	/// Proof: `SmartContractModule::NodeReservedResources` (`max_values`: None, `max_size`: None, mode: `Measured`)
 	fn report_contract_resources() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `771`
 		//  Estimated: `4236`
 		// Minimum execution time: 23_194_000 picoseconds.
 		Weight::from_parts(23_485_000, 4236)
-			.saturating_add(T::DbWeight::get().reads(3_u64))
-			.saturating_add(T::DbWeight::get().writes(1_u64))
+			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
 	}
 	/// Storage: `SmartContractModule::ActiveRentContractForNode` (r:1 w:1)
 	/// Proof: `SmartContractModule::ActiveRentContractForNode` (`max_values`: None, `max_size`: None, mode: `Measured`)
@@ -264,13 +326,17 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Proof: `SmartContractModule::Contracts` (`max_values`: None, `max_size`: None, mode: `Measured`)
 	/// Storage: `SmartContractModule::ContractLock` (r:0 w:1)
 	/// Proof: `SmartContractModule::ContractLock` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `TfgridModule::DecommissioningNodes` (r:1 w:0)
+	/// Proof: `TfgridModule::DecommissioningNodes` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `TfgridModule::NodeMaintenance` (r:1 w:0)
+	/// Proof: `TfgridModule::NodeMaintenance` (`max_values`: None, `max_size`: None, mode: `Measured`)
 	fn create_rent_contract() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `776`
 		//  Estimated: `4241`
 		// Minimum execution time: 34_876_000 picoseconds.
 		Weight::from_parts(35_417_000, 4241)
-			.saturating_add(T::DbWeight::get().reads(9_u64))
+			.saturating_add(T::DbWeight::get().reads(11_u64))
 			.saturating_add(T::DbWeight::get().writes(5_u64))
 	}
 	/// Storage: `SmartContractModule::Contracts` (r:1 w:1)
@@ -299,13 +365,17 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Proof: `SmartContractModule::ContractsToBillAt` (`max_values`: None, `max_size`: None, mode: `Measured`)
 	/// Storage: `SmartContractModule::ActiveRentContractForNode` (r:0 w:1)
 	/// Proof: `SmartContractModule::ActiveRentContractForNode` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `TfgridModule::DecommissioningNodes` (r:1 w:0)
+	/// Proof: `TfgridModule::DecommissioningNodes` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `TfgridModule::NodeMaintenance` (r:1 w:0)
+	/// Proof: `TfgridModule::NodeMaintenance` (`max_values`: None, `max_size`: None, mode: `Measured`)
 	fn cancel_rent_contract() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `1284`
 		//  Estimated: `4749`
 		// Minimum execution time: 60_275_000 picoseconds.
 		Weight::from_parts(60_955_000, 4749)
-			.saturating_add(T::DbWeight::get().reads(12_u64))
+			.saturating_add(T::DbWeight::get().reads(14_u64))
 			.saturating_add(T::DbWeight::get().writes(4_u64))
 	}
 	/// Storage: `SmartContractModule::SolutionProviderID` (r:1 w:1)
@@ -332,7 +402,7 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(T::DbWeight::get().reads(1_u64))
 			.saturating_add(T::DbWeight::get().writes(1_u64))
 	}
-	/// Storage: `SmartContractModule::Contracts` (r:1 w:0)
+	/// Storage: `SmartContractModule::Contracts` (r:1 w:1)
 	/// Proof: `SmartContractModule::Contracts` (`max_values`: None, `max_size`: None, mode: `Measured`)
 	/// Storage: `TfgridModule::Twins` (r:1 w:0)
 	/// Proof: `TfgridModule::Twins` (`max_values`: None, `max_size`: None, mode: `Measured`)
@@ -364,14 +434,36 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Proof: `Balances::Locks` (`max_values`: None, `max_size`: Some(1299), added: 3774, mode: `MaxEncodedLen`)
 	/// Storage: `Balances::Freezes` (r:1 w:0)
 	/// Proof: `Balances::Freezes` (`max_values`: None, `max_size`: Some(49), added: 2524, mode: `MaxEncodedLen`)
+	/// Storage: `SmartContractModule::ContractBillingHistoryCount` (r:1 w:1)
+	/// Proof: `SmartContractModule::ContractBillingHistoryCount` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SmartContractModule::ContractBillingHistory` (r:0 w:1)
+	/// Proof: `SmartContractModule::ContractBillingHistory` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SmartContractModule::TwinBillingHistory` (r:1 w:1)
+	/// Proof: `SmartContractModule::TwinBillingHistory` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SmartContractModule::TwinBillingDays` (r:1 w:1)
+	/// Proof: `SmartContractModule::TwinBillingDays` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SmartContractModule::ContractSpendingCap` (r:1 w:1)
+	/// Proof: `SmartContractModule::ContractSpendingCap` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SmartContractModule::TwinSpendingCap` (r:1 w:1)
+	/// Proof: `SmartContractModule::TwinSpendingCap` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SmartContractModule::SolutionProviderGracePeriod` (r:1 w:0)
+	/// Proof: `SmartContractModule::SolutionProviderGracePeriod` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SmartContractModule::ContractKindGracePeriod` (r:1 w:0)
+	/// Proof: `SmartContractModule::ContractKindGracePeriod` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SmartContractModule::TwinContractsInGrace` (r:1 w:1)
+	/// Proof: `SmartContractModule::TwinContractsInGrace` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SmartContractModule::LastGracePeriodReminder` (r:1 w:1)
+	/// Proof: `SmartContractModule::LastGracePeriodReminder` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `TfgridModule::NodeMaintenance` (r:1 w:0)
+	/// Proof: `TfgridModule::NodeMaintenance` (`max_values`: None, `max_size`: None, mode: `Measured`)
 	fn bill_contract_for_block() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `1608`
 		//  Estimated: `5073`
 		// Minimum execution time: 80_642_000 picoseconds.
 		Weight::from_parts(81_735_000, 5073)
-			.saturating_add(T::DbWeight::get().reads(16_u64))
-			.saturating_add(T::DbWeight::get().writes(4_u64))
+			.saturating_add(T::DbWeight::get().reads(26_u64))
+			.saturating_add(T::DbWeight::get().writes(13_u64))
 	}
 	/// Storage: `TfgridModule::TwinIdByAccountID` (r:2 w:0)
 	/// Proof: `TfgridModule::TwinIdByAccountID` (`max_values`: None, `max_size`: None, mode: `Measured`)
@@ -418,44 +510,72 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Proof: `TfgridModule::TwinIdByAccountID` (`max_values`: None, `max_size`: None, mode: `Measured`)
 	/// Storage: `SmartContractModule::ServiceContracts` (r:1 w:1)
 	/// Proof: `SmartContractModule::ServiceContracts` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SmartContractModule::ServiceContractsToBillAt` (r:1 w:1)
+	/// Proof: `SmartContractModule::ServiceContractsToBillAt` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SmartContractModule::ServiceContractLastVariableBill` (r:0 w:1)
+	/// Proof: `SmartContractModule::ServiceContractLastVariableBill` (`max_values`: None, `max_size`: None, mode: `Measured`)
 	fn service_contract_approve() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `502`
 		//  Estimated: `3967`
 		// Minimum execution time: 16_821_000 picoseconds.
 		Weight::from_parts(17_292_000, 3967)
-			.saturating_add(T::DbWeight::get().reads(2_u64))
-			.saturating_add(T::DbWeight::get().writes(1_u64))
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
 	}
 	/// Storage: `SmartContractModule::ServiceContracts` (r:1 w:1)
 	/// Proof: `SmartContractModule::ServiceContracts` (`max_values`: None, `max_size`: None, mode: `Measured`)
 	/// Storage: `TfgridModule::TwinIdByAccountID` (r:1 w:0)
 	/// Proof: `TfgridModule::TwinIdByAccountID` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SmartContractModule::ServiceContractsToBillAt` (r:1 w:1)
+	/// Proof: `SmartContractModule::ServiceContractsToBillAt` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SmartContractModule::ServiceContractLastVariableBill` (r:0 w:1)
+	/// Proof: `SmartContractModule::ServiceContractLastVariableBill` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SmartContractModule::ServiceContractGracePeriodStart` (r:0 w:1)
+	/// Proof: `SmartContractModule::ServiceContractGracePeriodStart` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SmartContractModule::ServiceContractAmendments` (r:0 w:1)
+	/// Proof: `SmartContractModule::ServiceContractAmendments` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SmartContractModule::ServiceContractPlan` (r:0 w:1)
+	/// Proof: `SmartContractModule::ServiceContractPlan` (`max_values`: None, `max_size`: None, mode: `Measured`)
 	fn service_contract_reject() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `502`
 		//  Estimated: `3967`
 		// Minimum execution time: 17_693_000 picoseconds.
 		Weight::from_parts(18_014_000, 3967)
-			.saturating_add(T::DbWeight::get().reads(2_u64))
-			.saturating_add(T::DbWeight::get().writes(1_u64))
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(6_u64))
 	}
 	/// Storage: `TfgridModule::TwinIdByAccountID` (r:1 w:0)
 	/// Proof: `TfgridModule::TwinIdByAccountID` (`max_values`: None, `max_size`: None, mode: `Measured`)
 	/// Storage: `SmartContractModule::ServiceContracts` (r:1 w:1)
 	/// Proof: `SmartContractModule::ServiceContracts` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SmartContractModule::ServiceContractDisputeWindow` (r:0 w:1)
+	/// Proof: `SmartContractModule::ServiceContractDisputeWindow` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SmartContractModule::ServiceContractsToBillAt` (r:1 w:1)
+	/// Proof: `SmartContractModule::ServiceContractsToBillAt` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SmartContractModule::ServiceContractLastVariableBill` (r:0 w:1)
+	/// Proof: `SmartContractModule::ServiceContractLastVariableBill` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SmartContractModule::ServiceContractGracePeriodStart` (r:0 w:1)
+	/// Proof: `SmartContractModule::ServiceContractGracePeriodStart` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SmartContractModule::ServiceContractAmendments` (r:0 w:1)
+	/// Proof: `SmartContractModule::ServiceContractAmendments` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SmartContractModule::ServiceContractPlan` (r:0 w:1)
+	/// Proof: `SmartContractModule::ServiceContractPlan` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SmartContractModule::ServiceContractPendingVariableBill` (r:0 w:1)
+	/// Proof: `SmartContractModule::ServiceContractPendingVariableBill` (`max_values`: None, `max_size`: None, mode: `Measured`)
 	fn service_contract_cancel() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `502`
 		//  Estimated: `3967`
 		// Minimum execution time: 16_401_000 picoseconds.
 		Weight::from_parts(16_662_000, 3967)
-			.saturating_add(T::DbWeight::get().reads(2_u64))
-			.saturating_add(T::DbWeight::get().writes(1_u64))
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(8_u64))
 	}
 	/// Storage: `TfgridModule::TwinIdByAccountID` (r:1 w:0)
 	/// Proof: `TfgridModule::TwinIdByAccountID` (`max_values`: None, `max_size`: None, mode: `Measured`)
-	/// Storage: `SmartContractModule::ServiceContracts` (r:1 w:1)
+	/// Storage: `SmartContractModule::ServiceContracts` (r:1 w:0)
 	/// Proof: `SmartContractModule::ServiceContracts` (`max_values`: None, `max_size`: None, mode: `Measured`)
 	/// Storage: `Timestamp::Now` (r:1 w:0)
 	/// Proof: `Timestamp::Now` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
@@ -463,14 +583,24 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Proof: `TfgridModule::Twins` (`max_values`: None, `max_size`: None, mode: `Measured`)
 	/// Storage: `System::Account` (r:1 w:0)
 	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `SmartContractModule::ServiceContractDisputeWindow` (r:1 w:0)
+	/// Proof: `SmartContractModule::ServiceContractDisputeWindow` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SmartContractModule::ServiceContractBillID` (r:1 w:1)
+	/// Proof: `SmartContractModule::ServiceContractBillID` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `SmartContractModule::ServiceContractEscrowedBills` (r:0 w:1)
+	/// Proof: `SmartContractModule::ServiceContractEscrowedBills` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SmartContractModule::ServiceContractLastVariableBill` (r:1 w:1)
+	/// Proof: `SmartContractModule::ServiceContractLastVariableBill` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SmartContractModule::ServiceContractPendingVariableBill` (r:1 w:1)
+	/// Proof: `SmartContractModule::ServiceContractPendingVariableBill` (`max_values`: None, `max_size`: None, mode: `Measured`)
 	fn service_contract_bill() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `841`
 		//  Estimated: `6781`
 		// Minimum execution time: 30_327_000 picoseconds.
 		Weight::from_parts(31_189_000, 6781)
-			.saturating_add(T::DbWeight::get().reads(6_u64))
-			.saturating_add(T::DbWeight::get().writes(1_u64))
+			.saturating_add(T::DbWeight::get().reads(10_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
 	}
 	/// Storage: `SmartContractModule::BillingFrequency` (r:1 w:1)
 	/// Proof: `SmartContractModule::BillingFrequency` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
@@ -543,19 +673,202 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Proof: `SmartContractModule::NodeContractResources` (`max_values`: None, `max_size`: None, mode: `Measured`)
 	/// Storage: `SmartContractModule::ContractIDByNodeIDAndHash` (r:0 w:1)
 	/// Proof: `SmartContractModule::ContractIDByNodeIDAndHash` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `TfgridModule::DecommissioningNodes` (r:1 w:0)
+	/// Proof: `TfgridModule::DecommissioningNodes` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `TfgridModule::NodeMaintenance` (r:1 w:0)
+	/// Proof: `TfgridModule::NodeMaintenance` (`max_values`: None, `max_size`: None, mode: `Measured`)
 	fn cancel_contract_collective() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `1203`
 		//  Estimated: `4668`
 		// Minimum execution time: 58_861_000 picoseconds.
 		Weight::from_parts(59_413_000, 4668)
-			.saturating_add(T::DbWeight::get().reads(9_u64))
+			.saturating_add(T::DbWeight::get().reads(11_u64))
 			.saturating_add(T::DbWeight::get().writes(7_u64))
 	}
-}
-
-// For backwards compatibility and tests
-impl WeightInfo for () {
+	/// Storage: `TfgridModule::TwinIdByAccountID` (r:1 w:0)
+	/// Proof: `TfgridModule::TwinIdByAccountID` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SmartContractModule::Contracts` (r:1 w:0)
+	/// Proof: `SmartContractModule::Contracts` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SmartContractModule::ContractSpendingCap` (r:1 w:1)
+	/// Proof: `SmartContractModule::ContractSpendingCap` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Timestamp::Now` (r:1 w:0)
+	/// Proof: `Timestamp::Now` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	fn set_contract_spending_cap() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `512`
+		//  Estimated: `3977`
+		// Minimum execution time: 17_263_000 picoseconds.
+		Weight::from_parts(17_609_000, 3977)
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `TfgridModule::TwinIdByAccountID` (r:1 w:0)
+	/// Proof: `TfgridModule::TwinIdByAccountID` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SmartContractModule::Contracts` (r:1 w:0)
+	/// Proof: `SmartContractModule::Contracts` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SmartContractModule::ContractSpendingCap` (r:1 w:1)
+	/// Proof: `SmartContractModule::ContractSpendingCap` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn clear_contract_spending_cap() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `598`
+		//  Estimated: `4063`
+		// Minimum execution time: 16_102_000 picoseconds.
+		Weight::from_parts(16_487_000, 4063)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `TfgridModule::TwinIdByAccountID` (r:1 w:0)
+	/// Proof: `TfgridModule::TwinIdByAccountID` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SmartContractModule::TwinSpendingCap` (r:1 w:1)
+	/// Proof: `SmartContractModule::TwinSpendingCap` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Timestamp::Now` (r:1 w:0)
+	/// Proof: `Timestamp::Now` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	fn set_twin_spending_cap() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `385`
+		//  Estimated: `3850`
+		// Minimum execution time: 12_840_000 picoseconds.
+		Weight::from_parts(13_125_000, 3850)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `TfgridModule::TwinIdByAccountID` (r:1 w:0)
+	/// Proof: `TfgridModule::TwinIdByAccountID` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SmartContractModule::TwinSpendingCap` (r:1 w:1)
+	/// Proof: `SmartContractModule::TwinSpendingCap` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn clear_twin_spending_cap() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `449`
+		//  Estimated: `3914`
+		// Minimum execution time: 11_573_000 picoseconds.
+		Weight::from_parts(11_902_000, 3914)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `SmartContractModule::ContractKindGracePeriod` (r:0 w:1)
+	/// Proof: `SmartContractModule::ContractKindGracePeriod` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn set_contract_kind_grace_period() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 6_092_000 picoseconds.
+		Weight::from_parts(6_345_000, 0)
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `SmartContractModule::SolutionProviders` (r:1 w:0)
+	/// Proof: `SmartContractModule::SolutionProviders` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SmartContractModule::SolutionProviderGracePeriod` (r:0 w:1)
+	/// Proof: `SmartContractModule::SolutionProviderGracePeriod` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn set_solution_provider_grace_period() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `286`
+		//  Estimated: `3751`
+		// Minimum execution time: 10_418_000 picoseconds.
+		Weight::from_parts(10_790_000, 3751)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `TfgridModule::Twins` (r:1 w:0)
+	/// Proof: `TfgridModule::Twins` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SmartContractModule::TwinContractsInGrace` (r:1 w:1)
+	/// Proof: `SmartContractModule::TwinContractsInGrace` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SmartContractModule::Contracts` (r:n w:n)
+	/// Proof: `SmartContractModule::Contracts` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Timestamp::Now` (r:1 w:0)
+	/// Proof: `Timestamp::Now` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	/// Storage: `SmartContractModule::ContractLock` (r:n w:n)
+	/// Proof: `SmartContractModule::ContractLock` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `TfgridModule::Nodes` (r:n w:0)
+	/// Proof: `TfgridModule::Nodes` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `TfgridModule::Farms` (r:n w:0)
+	/// Proof: `TfgridModule::Farms` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `TfgridModule::PricingPolicies` (r:n w:0)
+	/// Proof: `TfgridModule::PricingPolicies` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `TFTPriceModule::AverageTftPrice` (r:1 w:0)
+	/// Proof: `TFTPriceModule::AverageTftPrice` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `Balances::Locks` (r:n w:n)
+	/// Proof: `Balances::Locks` (`max_values`: None, `max_size`: Some(1299), added: 3774, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Freezes` (r:n w:0)
+	/// Proof: `Balances::Freezes` (`max_values`: None, `max_size`: Some(49), added: 2524, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:n w:n)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `SmartContractModule::ContractBillingHistoryCount` (r:n w:n)
+	/// Proof: `SmartContractModule::ContractBillingHistoryCount` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SmartContractModule::ContractBillingHistory` (r:0 w:n)
+	/// Proof: `SmartContractModule::ContractBillingHistory` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SmartContractModule::TwinBillingHistory` (r:n w:n)
+	/// Proof: `SmartContractModule::TwinBillingHistory` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SmartContractModule::TwinBillingDays` (r:n w:n)
+	/// Proof: `SmartContractModule::TwinBillingDays` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SmartContractModule::LastGracePeriodReminder` (r:0 w:n)
+	/// Proof: `SmartContractModule::LastGracePeriodReminder` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// The range of component `n` is `[1, 50]`.
+	fn restore_contracts_from_grace(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1052 + n * (392 ±0)`
+		//  Estimated: `4517 + n * (3774 ±0)`
+		// Minimum execution time: 71_354_000 picoseconds.
+		Weight::from_parts(38_107_412, 4517)
+			// Standard Error: 41_562
+			.saturating_add(Weight::from_parts(46_215_830, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().reads((11_u64).saturating_mul(n.into())))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+			.saturating_add(T::DbWeight::get().writes((9_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 3774).saturating_mul(n.into()))
+	}
+	/// Storage: `TfgridModule::TwinIdByAccountID` (r:1 w:0)
+	/// Proof: `TfgridModule::TwinIdByAccountID` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `TfgridModule::Nodes` (r:n w:0)
+	/// Proof: `TfgridModule::Nodes` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `TfgridModule::NodePower` (r:n w:0)
+	/// Proof: `TfgridModule::NodePower` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `TfgridModule::Farms` (r:1 w:1)
+	/// Proof: `TfgridModule::Farms` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SmartContractModule::DedicatedNodesExtraFee` (r:n w:0)
+	/// Proof: `SmartContractModule::DedicatedNodesExtraFee` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SmartContractModule::ActiveRentContractForNode` (r:n w:0)
+	/// Proof: `SmartContractModule::ActiveRentContractForNode` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SmartContractModule::ContractIDByNodeIDAndHash` (r:n w:n)
+	/// Proof: `SmartContractModule::ContractIDByNodeIDAndHash` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SmartContractModule::ContractID` (r:1 w:1)
+	/// Proof: `SmartContractModule::ContractID` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `SmartContractModule::BillingFrequency` (r:1 w:0)
+	/// Proof: `SmartContractModule::BillingFrequency` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `SmartContractModule::ContractsToBillAt` (r:1 w:1)
+	/// Proof: `SmartContractModule::ContractsToBillAt` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Timestamp::Now` (r:1 w:0)
+	/// Proof: `Timestamp::Now` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	/// Storage: `SmartContractModule::ActiveNodeContracts` (r:n w:n)
+	/// Proof: `SmartContractModule::ActiveNodeContracts` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SmartContractModule::Contracts` (r:0 w:n)
+	/// Proof: `SmartContractModule::Contracts` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SmartContractModule::ContractBillingInformationByID` (r:0 w:n)
+	/// Proof: `SmartContractModule::ContractBillingInformationByID` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SmartContractModule::ContractLock` (r:0 w:n)
+	/// Proof: `SmartContractModule::ContractLock` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `TfgridModule::FarmOvercommitRatio` (r:n w:0)
+	/// Proof: `TfgridModule::FarmOvercommitRatio` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SmartContractModule::NodeReservedResources` (r:n w:n)
+	/// Proof: `SmartContractModule::NodeReservedResources` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SmartContractModule::ContractReservedResources` (r:0 w:n)
+	/// Proof: `SmartContractModule::ContractReservedResources` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// The range of component `n` is `[1, 20]`.
+	fn create_node_contracts_batch(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `868 + n * (96 ±0)`
+		//  Estimated: `4333 + n * (2571 ±0)`
+		// Minimum execution time: 104_512_000 picoseconds.
+		Weight::from_parts(58_932_104, 4333)
+			// Standard Error: 36_208
+			.saturating_add(Weight::from_parts(47_186_327, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(6_u64))
+			.saturating_add(T::DbWeight::get().reads((8_u64).saturating_mul(n.into())))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+			.saturating_add(T::DbWeight::get().writes((7_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 2571).saturating_mul(n.into()))
+	}
 	/// Storage: `TfgridModule::TwinIdByAccountID` (r:1 w:0)
 	/// Proof: `TfgridModule::TwinIdByAccountID` (`max_values`: None, `max_size`: None, mode: `Measured`)
 	/// Storage: `TfgridModule::Nodes` (r:1 w:0)
@@ -570,6 +883,10 @@ impl WeightInfo for () {
 	/// Proof: `SmartContractModule::ActiveRentContractForNode` (`max_values`: None, `max_size`: None, mode: `Measured`)
 	/// Storage: `SmartContractModule::ContractIDByNodeIDAndHash` (r:1 w:1)
 	/// Proof: `SmartContractModule::ContractIDByNodeIDAndHash` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `TfgridModule::FarmOvercommitRatio` (r:1 w:0)
+	/// Proof: `TfgridModule::FarmOvercommitRatio` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SmartContractModule::NodeReservedResources` (r:1 w:1)
+	/// Proof: `SmartContractModule::NodeReservedResources` (`max_values`: None, `max_size`: None, mode: `Measured`)
 	/// Storage: `SmartContractModule::ContractID` (r:1 w:1)
 	/// Proof: `SmartContractModule::ContractID` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
 	/// Storage: `SmartContractModule::BillingFrequency` (r:1 w:0)
@@ -586,168 +903,1352 @@ impl WeightInfo for () {
 	/// Proof: `SmartContractModule::ContractBillingInformationByID` (`max_values`: None, `max_size`: None, mode: `Measured`)
 	/// Storage: `SmartContractModule::ContractLock` (r:0 w:1)
 	/// Proof: `SmartContractModule::ContractLock` (`max_values`: None, `max_size`: None, mode: `Measured`)
-	fn create_node_contract() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `868`
-		//  Estimated: `4333`
-		// Minimum execution time: 102_203_000 picoseconds.
-		Weight::from_parts(104_197_000, 4333)
-			.saturating_add(RocksDbWeight::get().reads(12_u64))
-			.saturating_add(RocksDbWeight::get().writes(8_u64))
-	}
-	/// Storage: `SmartContractModule::Contracts` (r:1 w:1)
-	/// Proof: `SmartContractModule::Contracts` (`max_values`: None, `max_size`: None, mode: `Measured`)
-	/// Storage: `TfgridModule::Twins` (r:1 w:0)
-	/// Proof: `TfgridModule::Twins` (`max_values`: None, `max_size`: None, mode: `Measured`)
-	/// Storage: `SmartContractModule::ActiveNodeContracts` (r:1 w:1)
-	/// Proof: `SmartContractModule::ActiveNodeContracts` (`max_values`: None, `max_size`: None, mode: `Measured`)
-	/// Storage: `SmartContractModule::ContractIDByNodeIDAndHash` (r:0 w:2)
-	/// Proof: `SmartContractModule::ContractIDByNodeIDAndHash` (`max_values`: None, `max_size`: None, mode: `Measured`)
-	fn update_node_contract() -> Weight {
+	/// Storage: `SmartContractModule::ContractReservedResources` (r:0 w:1)
+	/// Proof: `SmartContractModule::ContractReservedResources` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `TfgridModule::DecommissioningNodes` (r:1 w:0)
+	/// Proof: `TfgridModule::DecommissioningNodes` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `TfgridModule::NodeMaintenance` (r:1 w:0)
+	/// Proof: `TfgridModule::NodeMaintenance` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn create_node_contract_with_resources() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `892`
-		//  Estimated: `4357`
-		// Minimum execution time: 26_891_000 picoseconds.
-		Weight::from_parts(27_472_000, 4357)
-			.saturating_add(RocksDbWeight::get().reads(3_u64))
-			.saturating_add(RocksDbWeight::get().writes(4_u64))
+		//  Measured:  `1012`
+		//  Estimated: `4477`
+		// Minimum execution time: 109_874_000 picoseconds.
+		Weight::from_parts(112_046_000, 4477)
+			.saturating_add(T::DbWeight::get().reads(16_u64))
+			.saturating_add(T::DbWeight::get().writes(10_u64))
 	}
-	/// Storage: `SmartContractModule::Contracts` (r:1 w:1)
+	/// Storage: `TfgridModule::TwinIdByAccountID` (r:1 w:0)
+	/// Proof: `TfgridModule::TwinIdByAccountID` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SmartContractModule::Contracts` (r:1 w:0)
 	/// Proof: `SmartContractModule::Contracts` (`max_values`: None, `max_size`: None, mode: `Measured`)
 	/// Storage: `TfgridModule::Twins` (r:1 w:0)
 	/// Proof: `TfgridModule::Twins` (`max_values`: None, `max_size`: None, mode: `Measured`)
-	/// Storage: `SmartContractModule::ActiveNodeContracts` (r:1 w:1)
-	/// Proof: `SmartContractModule::ActiveNodeContracts` (`max_values`: None, `max_size`: None, mode: `Measured`)
-	/// Storage: `TfgridModule::TwinBoundedAccountID` (r:1 w:0)
-	/// Proof: `TfgridModule::TwinBoundedAccountID` (`max_values`: None, `max_size`: None, mode: `Measured`)
-	/// Storage: `Timestamp::Now` (r:1 w:0)
-	/// Proof: `Timestamp::Now` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
-	/// Storage: `SmartContractModule::ContractLock` (r:1 w:1)
-	/// Proof: `SmartContractModule::ContractLock` (`max_values`: None, `max_size`: None, mode: `Measured`)
-	/// Storage: `TfgridModule::PricingPolicies` (r:1 w:0)
-	/// Proof: `TfgridModule::PricingPolicies` (`max_values`: None, `max_size`: None, mode: `Measured`)
-	/// Storage: `SmartContractModule::BillingFrequency` (r:1 w:0)
-	/// Proof: `SmartContractModule::BillingFrequency` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
-	/// Storage: `SmartContractModule::ContractsToBillAt` (r:1 w:1)
-	/// Proof: `SmartContractModule::ContractsToBillAt` (`max_values`: None, `max_size`: None, mode: `Measured`)
-	/// Storage: `SmartContractModule::ContractBillingInformationByID` (r:0 w:1)
-	/// Proof: `SmartContractModule::ContractBillingInformationByID` (`max_values`: None, `max_size`: None, mode: `Measured`)
-	/// Storage: `SmartContractModule::NodeContractResources` (r:0 w:1)
-	/// Proof: `SmartContractModule::NodeContractResources` (`max_values`: None, `max_size`: None, mode: `Measured`)
-	/// Storage: `SmartContractModule::ContractIDByNodeIDAndHash` (r:0 w:1)
-	/// Proof: `SmartContractModule::ContractIDByNodeIDAndHash` (`max_values`: None, `max_size`: None, mode: `Measured`)
-	fn cancel_contract() -> Weight {
+	/// Storage: `SmartContractModule::ContractTransferOffers` (r:0 w:1)
+	/// Proof: `SmartContractModule::ContractTransferOffers` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn offer_contract_transfer() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `1203`
-		//  Estimated: `4668`
-		// Minimum execution time: 60_284_000 picoseconds.
-		Weight::from_parts(61_346_000, 4668)
-			.saturating_add(RocksDbWeight::get().reads(9_u64))
-			.saturating_add(RocksDbWeight::get().writes(7_u64))
+		//  Measured:  `659`
+		//  Estimated: `4124`
+		// Minimum execution time: 24_117_000 picoseconds.
+		Weight::from_parts(24_785_000, 4124)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
 	}
 	/// Storage: `TfgridModule::TwinIdByAccountID` (r:1 w:0)
 	/// Proof: `TfgridModule::TwinIdByAccountID` (`max_values`: None, `max_size`: None, mode: `Measured`)
-	/// Storage: `SmartContractModule::ContractIDByNameRegistration` (r:1 w:1)
-	/// Proof: `SmartContractModule::ContractIDByNameRegistration` (`max_values`: None, `max_size`: None, mode: `Measured`)
-	/// Storage: `SmartContractModule::ContractID` (r:1 w:1)
-	/// Proof: `SmartContractModule::ContractID` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
-	/// Storage: `SmartContractModule::BillingFrequency` (r:1 w:0)
-	/// Proof: `SmartContractModule::BillingFrequency` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
-	/// Storage: `SmartContractModule::ContractsToBillAt` (r:1 w:1)
-	/// Proof: `SmartContractModule::ContractsToBillAt` (`max_values`: None, `max_size`: None, mode: `Measured`)
-	/// Storage: `Timestamp::Now` (r:1 w:0)
-	/// Proof: `Timestamp::Now` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
-	/// Storage: `SmartContractModule::Contracts` (r:0 w:1)
+	/// Storage: `SmartContractModule::Contracts` (r:1 w:0)
 	/// Proof: `SmartContractModule::Contracts` (`max_values`: None, `max_size`: None, mode: `Measured`)
-	/// Storage: `SmartContractModule::ContractLock` (r:0 w:1)
-	/// Proof: `SmartContractModule::ContractLock` (`max_values`: None, `max_size`: None, mode: `Measured`)
-	fn create_name_contract() -> Weight {
+	/// Storage: `SmartContractModule::ContractTransferOffers` (r:1 w:1)
+	/// Proof: `SmartContractModule::ContractTransferOffers` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn cancel_contract_transfer() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `340`
-		//  Estimated: `3805`
-		// Minimum execution time: 24_977_000 picoseconds.
-		Weight::from_parts(25_658_000, 3805)
-			.saturating_add(RocksDbWeight::get().reads(6_u64))
-			.saturating_add(RocksDbWeight::get().writes(5_u64))
+		//  Measured:  `702`
+		//  Estimated: `4167`
+		// Minimum execution time: 20_432_000 picoseconds.
+		Weight::from_parts(21_018_000, 4167)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
 	}
+	/// Storage: `TfgridModule::TwinIdByAccountID` (r:1 w:0)
+	/// Proof: `TfgridModule::TwinIdByAccountID` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SmartContractModule::ContractTransferOffers` (r:1 w:1)
+	/// Proof: `SmartContractModule::ContractTransferOffers` (`max_values`: None, `max_size`: None, mode: `Measured`)
 	/// Storage: `SmartContractModule::Contracts` (r:1 w:1)
 	/// Proof: `SmartContractModule::Contracts` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SmartContractModule::ActiveRentContractForNode` (r:1 w:0)
+	/// Proof: `SmartContractModule::ActiveRentContractForNode` (`max_values`: None, `max_size`: None, mode: `Measured`)
 	/// Storage: `TfgridModule::Twins` (r:1 w:0)
 	/// Proof: `TfgridModule::Twins` (`max_values`: None, `max_size`: None, mode: `Measured`)
-	/// Storage: `TfgridModule::TwinBoundedAccountID` (r:1 w:0)
-	/// Proof: `TfgridModule::TwinBoundedAccountID` (`max_values`: None, `max_size`: None, mode: `Measured`)
 	/// Storage: `Timestamp::Now` (r:1 w:0)
 	/// Proof: `Timestamp::Now` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
 	/// Storage: `SmartContractModule::ContractLock` (r:1 w:1)
 	/// Proof: `SmartContractModule::ContractLock` (`max_values`: None, `max_size`: None, mode: `Measured`)
-	/// Storage: `TfgridModule::PricingPolicies` (r:1 w:0)
-	/// Proof: `TfgridModule::PricingPolicies` (`max_values`: None, `max_size`: None, mode: `Measured`)
-	/// Storage: `SmartContractModule::BillingFrequency` (r:1 w:0)
-	/// Proof: `SmartContractModule::BillingFrequency` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
-	/// Storage: `SmartContractModule::ContractsToBillAt` (r:1 w:1)
-	/// Proof: `SmartContractModule::ContractsToBillAt` (`max_values`: None, `max_size`: None, mode: `Measured`)
-	/// Storage: `SmartContractModule::ContractIDByNameRegistration` (r:0 w:1)
-	/// Proof: `SmartContractModule::ContractIDByNameRegistration` (`max_values`: None, `max_size`: None, mode: `Measured`)
-	fn cancel_name_contract() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `919`
-		//  Estimated: `4384`
-		// Minimum execution time: 46_538_000 picoseconds.
-		Weight::from_parts(47_230_000, 4384)
-			.saturating_add(RocksDbWeight::get().reads(8_u64))
-			.saturating_add(RocksDbWeight::get().writes(4_u64))
-	}
-	/// Storage: `TfgridModule::TwinIdByAccountID` (r:1 w:0)
-	/// Proof: `TfgridModule::TwinIdByAccountID` (`max_values`: None, `max_size`: None, mode: `Measured`)
-	/// Storage: `TfgridModule::NodeIdByTwinID` (r:1 w:0)
-	/// Proof: `TfgridModule::NodeIdByTwinID` (`max_values`: None, `max_size`: None, mode: `Measured`)
 	/// Storage: `TfgridModule::Nodes` (r:1 w:0)
 	/// Proof: `TfgridModule::Nodes` (`max_values`: None, `max_size`: None, mode: `Measured`)
 	/// Storage: `TfgridModule::Farms` (r:1 w:0)
 	/// Proof: `TfgridModule::Farms` (`max_values`: None, `max_size`: None, mode: `Measured`)
 	/// Storage: `TfgridModule::PricingPolicies` (r:1 w:0)
 	/// Proof: `TfgridModule::PricingPolicies` (`max_values`: None, `max_size`: None, mode: `Measured`)
-	/// Storage: `SmartContractModule::Contracts` (r:1 w:0)
-	/// Proof: `SmartContractModule::Contracts` (`max_values`: None, `max_size`: None, mode: `Measured`)
-	/// Storage: `SmartContractModule::ContractBillingInformationByID` (r:1 w:1)
-	/// Proof: `SmartContractModule::ContractBillingInformationByID` (`max_values`: None, `max_size`: None, mode: `Measured`)
-	fn add_nru_reports() -> Weight {
+	/// Storage: `TFTPriceModule::AverageTftPrice` (r:1 w:0)
+	/// Proof: `TFTPriceModule::AverageTftPrice` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `SmartContractModule::ContractSpendingCap` (r:1 w:1)
+	/// Proof: `SmartContractModule::ContractSpendingCap` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SmartContractModule::ContractBillingHistoryCount` (r:1 w:1)
+	/// Proof: `SmartContractModule::ContractBillingHistoryCount` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SmartContractModule::ContractBillingHistory` (r:0 w:1)
+	/// Proof: `SmartContractModule::ContractBillingHistory` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SmartContractModule::TwinBillingHistory` (r:1 w:1)
+	/// Proof: `SmartContractModule::TwinBillingHistory` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SmartContractModule::TwinBillingDays` (r:1 w:1)
+	/// Proof: `SmartContractModule::TwinBillingDays` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `System::Account` (r:2 w:2)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	fn accept_contract_transfer() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `1292`
-		//  Estimated: `4757`
-		// Minimum execution time: 38_072_000 picoseconds.
-		Weight::from_parts(38_763_000, 4757)
-			.saturating_add(RocksDbWeight::get().reads(7_u64))
-			.saturating_add(RocksDbWeight::get().writes(1_u64))
+		//  Measured:  `1854`
+		//  Estimated: `5319`
+		// Minimum execution time: 118_930_000 picoseconds.
+		Weight::from_parts(121_477_000, 5319)
+			.saturating_add(T::DbWeight::get().reads(17_u64))
+			.saturating_add(T::DbWeight::get().writes(10_u64))
 	}
 	/// Storage: `TfgridModule::TwinIdByAccountID` (r:1 w:0)
 	/// Proof: `TfgridModule::TwinIdByAccountID` (`max_values`: None, `max_size`: None, mode: `Measured`)
-	/// Storage: `TfgridModule::NodeIdByTwinID` (r:1 w:0)
-	/// Proof: `TfgridModule::NodeIdByTwinID` (`max_values`: None, `max_size`: None, mode: `Measured`)
-	/// Storage: `SmartContractModule::Contracts` (r:1 w:0)
-	/// Proof: `SmartContractModule::Contracts` (`max_values`: None, `max_size`: None, mode: `Measured`)
-	/// Storage: `SmartContractModule::NodeContractResources` (r:0 w:1)
-	/// Proof: `SmartContractModule::NodeContractResources` (`max_values`: None, `max_size`: None, mode: `Measured`)
-	fn report_contract_resources() -> Weight {
+	/// Storage: `SmartContractModule::ServiceContracts` (r:1 w:0)
+	/// Proof: `SmartContractModule::ServiceContracts` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SmartContractModule::ServiceContractDisputeWindow` (r:0 w:1)
+	/// Proof: `SmartContractModule::ServiceContractDisputeWindow` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn service_contract_set_dispute_window() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `771`
-		//  Estimated: `4236`
-		// Minimum execution time: 23_194_000 picoseconds.
-		Weight::from_parts(23_485_000, 4236)
-			.saturating_add(RocksDbWeight::get().reads(3_u64))
-			.saturating_add(RocksDbWeight::get().writes(1_u64))
+		//  Measured:  `502`
+		//  Estimated: `3967`
+		// Minimum execution time: 16_903_000 picoseconds.
+		Weight::from_parts(17_322_000, 3967)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
 	}
-	/// Storage: `SmartContractModule::ActiveRentContractForNode` (r:1 w:1)
-	/// Proof: `SmartContractModule::ActiveRentContractForNode` (`max_values`: None, `max_size`: None, mode: `Measured`)
-	/// Storage: `TfgridModule::Nodes` (r:1 w:0)
-	/// Proof: `TfgridModule::Nodes` (`max_values`: None, `max_size`: None, mode: `Measured`)
-	/// Storage: `TfgridModule::Farms` (r:1 w:0)
-	/// Proof: `TfgridModule::Farms` (`max_values`: None, `max_size`: None, mode: `Measured`)
-	/// Storage: `SmartContractModule::ActiveNodeContracts` (r:1 w:0)
-	/// Proof: `SmartContractModule::ActiveNodeContracts` (`max_values`: None, `max_size`: None, mode: `Measured`)
 	/// Storage: `TfgridModule::TwinIdByAccountID` (r:1 w:0)
 	/// Proof: `TfgridModule::TwinIdByAccountID` (`max_values`: None, `max_size`: None, mode: `Measured`)
-	/// Storage: `SmartContractModule::ContractID` (r:1 w:1)
+	/// Storage: `SmartContractModule::ServiceContractEscrowedBills` (r:1 w:1)
+	/// Proof: `SmartContractModule::ServiceContractEscrowedBills` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Timestamp::Now` (r:1 w:0)
+	/// Proof: `Timestamp::Now` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	fn service_contract_dispute_bill() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `418`
+		//  Estimated: `3883`
+		// Minimum execution time: 15_482_000 picoseconds.
+		Weight::from_parts(15_911_000, 3883)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `TfgridModule::TwinIdByAccountID` (r:1 w:0)
+	/// Proof: `TfgridModule::TwinIdByAccountID` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SmartContractModule::ServiceContractEscrowedBills` (r:1 w:1)
+	/// Proof: `SmartContractModule::ServiceContractEscrowedBills` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `TfgridModule::Twins` (r:2 w:0)
+	/// Proof: `TfgridModule::Twins` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `System::Account` (r:2 w:2)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	fn service_contract_resolve_dispute() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `731`
+		//  Estimated: `6671`
+		// Minimum execution time: 38_120_000 picoseconds.
+		Weight::from_parts(39_004_000, 6671)
+			.saturating_add(T::DbWeight::get().reads(6_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	/// Storage: `SmartContractModule::ServiceContractEscrowedBills` (r:1 w:1)
+	/// Proof: `SmartContractModule::ServiceContractEscrowedBills` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `TfgridModule::Twins` (r:2 w:0)
+	/// Proof: `TfgridModule::Twins` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `System::Account` (r:2 w:2)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	fn service_contract_force_resolve_dispute() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `663`
+		//  Estimated: `6603`
+		// Minimum execution time: 35_274_000 picoseconds.
+		Weight::from_parts(36_018_000, 6603)
+			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	/// Storage: `TfgridModule::TwinIdByAccountID` (r:1 w:0)
+	/// Proof: `TfgridModule::TwinIdByAccountID` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SmartContractModule::ServiceContractEscrowedBills` (r:1 w:1)
+	/// Proof: `SmartContractModule::ServiceContractEscrowedBills` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Timestamp::Now` (r:1 w:0)
+	/// Proof: `Timestamp::Now` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	/// Storage: `TfgridModule::Twins` (r:2 w:0)
+	/// Proof: `TfgridModule::Twins` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `System::Account` (r:2 w:2)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	fn service_contract_release_bill() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `731`
+		//  Estimated: `6671`
+		// Minimum execution time: 36_551_000 picoseconds.
+		Weight::from_parts(37_240_000, 6671)
+			.saturating_add(T::DbWeight::get().reads(7_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	/// Storage: `SmartContractModule::ServiceContracts` (r:1 w:1)
+	/// Proof: `SmartContractModule::ServiceContracts` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Timestamp::Now` (r:1 w:0)
+	/// Proof: `Timestamp::Now` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	/// Storage: `TFTPriceModule::AverageTftPrice` (r:1 w:0)
+	/// Proof: `TFTPriceModule::AverageTftPrice` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `TfgridModule::Twins` (r:2 w:0)
+	/// Proof: `TfgridModule::Twins` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `System::Account` (r:2 w:2)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `SmartContractModule::ServiceContractDisputeWindow` (r:1 w:0)
+	/// Proof: `SmartContractModule::ServiceContractDisputeWindow` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SmartContractModule::ServiceContractGracePeriodStart` (r:1 w:1)
+	/// Proof: `SmartContractModule::ServiceContractGracePeriodStart` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SmartContractModule::ContractKindGracePeriod` (r:1 w:0)
+	/// Proof: `SmartContractModule::ContractKindGracePeriod` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn bill_service_contract_for_block() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `893`
+		//  Estimated: `6833`
+		// Minimum execution time: 41_733_000 picoseconds.
+		Weight::from_parts(42_517_000, 6833)
+			.saturating_add(T::DbWeight::get().reads(10_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+	}
+	/// Storage: `TfgridModule::TwinIdByAccountID` (r:1 w:0)
+	/// Proof: `TfgridModule::TwinIdByAccountID` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SmartContractModule::ServiceContracts` (r:1 w:0)
+	/// Proof: `SmartContractModule::ServiceContracts` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SmartContractModule::ServiceContractAmendments` (r:0 w:1)
+	/// Proof: `SmartContractModule::ServiceContractAmendments` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn service_contract_propose_amendment() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `502`
+		//  Estimated: `3967`
+		// Minimum execution time: 17_268_000 picoseconds.
+		Weight::from_parts(17_735_000, 3967)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `TfgridModule::TwinIdByAccountID` (r:1 w:0)
+	/// Proof: `TfgridModule::TwinIdByAccountID` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SmartContractModule::ServiceContracts` (r:1 w:1)
+	/// Proof: `SmartContractModule::ServiceContracts` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SmartContractModule::ServiceContractAmendments` (r:1 w:1)
+	/// Proof: `SmartContractModule::ServiceContractAmendments` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Timestamp::Now` (r:1 w:0)
+	/// Proof: `Timestamp::Now` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	/// Storage: `TFTPriceModule::AverageTftPrice` (r:1 w:0)
+	/// Proof: `TFTPriceModule::AverageTftPrice` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `TfgridModule::Twins` (r:2 w:0)
+	/// Proof: `TfgridModule::Twins` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `System::Account` (r:2 w:2)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `SmartContractModule::ServiceContractDisputeWindow` (r:1 w:0)
+	/// Proof: `SmartContractModule::ServiceContractDisputeWindow` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SmartContractModule::ServiceContractGracePeriodStart` (r:1 w:1)
+	/// Proof: `SmartContractModule::ServiceContractGracePeriodStart` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SmartContractModule::ServiceContractLastVariableBill` (r:1 w:1)
+	/// Proof: `SmartContractModule::ServiceContractLastVariableBill` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SmartContractModule::ServiceContractPendingVariableBill` (r:1 w:1)
+	/// Proof: `SmartContractModule::ServiceContractPendingVariableBill` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn service_contract_accept_amendment() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1010`
+		//  Estimated: `6950`
+		// Minimum execution time: 48_391_000 picoseconds.
+		Weight::from_parts(49_262_000, 6950)
+			.saturating_add(T::DbWeight::get().reads(13_u64))
+			.saturating_add(T::DbWeight::get().writes(7_u64))
+	}
+	/// Storage: `TfgridModule::TwinIdByAccountID` (r:1 w:0)
+	/// Proof: `TfgridModule::TwinIdByAccountID` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SmartContractModule::ServiceContracts` (r:1 w:0)
+	/// Proof: `SmartContractModule::ServiceContracts` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SmartContractModule::ServiceContractAmendments` (r:1 w:1)
+	/// Proof: `SmartContractModule::ServiceContractAmendments` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn service_contract_reject_amendment() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `601`
+		//  Estimated: `4066`
+		// Minimum execution time: 18_547_000 picoseconds.
+		Weight::from_parts(19_030_000, 4066)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `TfgridModule::TwinIdByAccountID` (r:1 w:0)
+	/// Proof: `TfgridModule::TwinIdByAccountID` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SmartContractModule::ServicePlanID` (r:1 w:1)
+	/// Proof: `SmartContractModule::ServicePlanID` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `SmartContractModule::ServicePlans` (r:0 w:1)
+	/// Proof: `SmartContractModule::ServicePlans` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn service_plan_create() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `283`
+		//  Estimated: `3748`
+		// Minimum execution time: 14_672_000 picoseconds.
+		Weight::from_parts(15_108_000, 3748)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: `TfgridModule::TwinIdByAccountID` (r:1 w:0)
+	/// Proof: `TfgridModule::TwinIdByAccountID` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SmartContractModule::ServicePlans` (r:1 w:1)
+	/// Proof: `SmartContractModule::ServicePlans` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn service_plan_update() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `471`
+		//  Estimated: `3936`
+		// Minimum execution time: 16_215_000 picoseconds.
+		Weight::from_parts(16_630_000, 3936)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `TfgridModule::TwinIdByAccountID` (r:1 w:0)
+	/// Proof: `TfgridModule::TwinIdByAccountID` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SmartContractModule::ServicePlans` (r:1 w:1)
+	/// Proof: `SmartContractModule::ServicePlans` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn service_plan_retire() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `471`
+		//  Estimated: `3936`
+		// Minimum execution time: 15_842_000 picoseconds.
+		Weight::from_parts(16_291_000, 3936)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `TfgridModule::TwinIdByAccountID` (r:1 w:0)
+	/// Proof: `TfgridModule::TwinIdByAccountID` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SmartContractModule::ServicePlans` (r:1 w:0)
+	/// Proof: `SmartContractModule::ServicePlans` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SmartContractModule::ServiceContractID` (r:1 w:1)
+	/// Proof: `SmartContractModule::ServiceContractID` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `Timestamp::Now` (r:1 w:0)
+	/// Proof: `Timestamp::Now` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	/// Storage: `SmartContractModule::ServiceContractsToBillAt` (r:1 w:1)
+	/// Proof: `SmartContractModule::ServiceContractsToBillAt` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SmartContractModule::ServiceContracts` (r:0 w:1)
+	/// Proof: `SmartContractModule::ServiceContracts` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SmartContractModule::ServiceContractPlan` (r:0 w:1)
+	/// Proof: `SmartContractModule::ServiceContractPlan` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SmartContractModule::ServiceContractLastVariableBill` (r:0 w:1)
+	/// Proof: `SmartContractModule::ServiceContractLastVariableBill` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn service_plan_subscribe() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `564`
+		//  Estimated: `4029`
+		// Minimum execution time: 24_937_000 picoseconds.
+		Weight::from_parts(25_580_000, 4029)
+			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(T::DbWeight::get().writes(5_u64))
+	}
+	/// Storage: `SmartContractModule::SolutionProviderRewardsAccrual` (r:0 w:1)
+	/// Proof: `SmartContractModule::SolutionProviderRewardsAccrual` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn set_solution_provider_rewards_accrual() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 5_861_000 picoseconds.
+		Weight::from_parts(6_092_000, 0)
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `SmartContractModule::UnclaimedSolutionProviderRewards` (r:1 w:1)
+	/// Proof: `SmartContractModule::UnclaimedSolutionProviderRewards` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `System::Account` (r:2 w:2)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	fn claim_solution_provider_rewards() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `244`
+		//  Estimated: `6196`
+		// Minimum execution time: 52_304_000 picoseconds.
+		Weight::from_parts(53_671_000, 6196)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	/// Storage: `SmartContractModule::SolutionProviders` (r:1 w:1)
+	/// Proof: `SmartContractModule::SolutionProviders` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn update_solution_provider() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `222`
+		//  Estimated: `3687`
+		// Minimum execution time: 14_277_000 picoseconds.
+		Weight::from_parts(14_838_000, 3687)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `SmartContractModule::SolutionProviders` (r:1 w:1)
+	/// Proof: `SmartContractModule::SolutionProviders` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn revoke_solution_provider() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `222`
+		//  Estimated: `3687`
+		// Minimum execution time: 12_914_000 picoseconds.
+		Weight::from_parts(13_395_000, 3687)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `SmartContractModule::SolutionProviders` (r:1 w:1)
+	/// Proof: `SmartContractModule::SolutionProviders` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Timestamp::Now` (r:1 w:0)
+	/// Proof: `Timestamp::Now` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	fn set_solution_provider_expiry() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `222`
+		//  Estimated: `3687`
+		// Minimum execution time: 14_087_000 picoseconds.
+		Weight::from_parts(14_618_000, 3687)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `TfgridModule::TwinIdByAccountID` (r:1 w:0)
+	/// Proof: `TfgridModule::TwinIdByAccountID` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SmartContractModule::ContractIDByNameRegistration` (r:1 w:1)
+	/// Proof: `SmartContractModule::ContractIDByNameRegistration` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SmartContractModule::ContractID` (r:1 w:1)
+	/// Proof: `SmartContractModule::ContractID` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `SmartContractModule::BillingFrequency` (r:1 w:0)
+	/// Proof: `SmartContractModule::BillingFrequency` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `SmartContractModule::ContractsToBillAt` (r:1 w:1)
+	/// Proof: `SmartContractModule::ContractsToBillAt` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Timestamp::Now` (r:1 w:0)
+	/// Proof: `Timestamp::Now` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	/// Storage: `SmartContractModule::Contracts` (r:0 w:1)
+	/// Proof: `SmartContractModule::Contracts` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SmartContractModule::ContractLock` (r:0 w:1)
+	/// Proof: `SmartContractModule::ContractLock` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SmartContractModule::NameContractExpiry` (r:0 w:1)
+	/// Proof: `SmartContractModule::NameContractExpiry` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SmartContractModule::NameRestrictions` (r:5 w:0)
+	/// Proof: `SmartContractModule::NameRestrictions` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn create_name_contract_with_term() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `340`
+		//  Estimated: `3805`
+		// Minimum execution time: 26_433_000 picoseconds.
+		Weight::from_parts(27_104_000, 3805)
+			.saturating_add(T::DbWeight::get().reads(11_u64))
+			.saturating_add(T::DbWeight::get().writes(6_u64))
+	}
+	/// Storage: `TfgridModule::TwinIdByAccountID` (r:1 w:0)
+	/// Proof: `TfgridModule::TwinIdByAccountID` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SmartContractModule::Contracts` (r:1 w:0)
+	/// Proof: `SmartContractModule::Contracts` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SmartContractModule::NameContractExpiry` (r:1 w:1)
+	/// Proof: `SmartContractModule::NameContractExpiry` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Timestamp::Now` (r:1 w:0)
+	/// Proof: `Timestamp::Now` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	/// Storage: `SmartContractModule::ContractLock` (r:1 w:1)
+	/// Proof: `SmartContractModule::ContractLock` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn renew_name_contract() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `512`
+		//  Estimated: `3977`
+		// Minimum execution time: 16_052_000 picoseconds.
+		Weight::from_parts(16_593_000, 3977)
+			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: `SmartContractModule::NameContractHoldPeriod` (r:0 w:1)
+	/// Proof: `SmartContractModule::NameContractHoldPeriod` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	fn set_name_contract_hold_period() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 5_470_000 picoseconds.
+		Weight::from_parts(5_741_000, 0)
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `SmartContractModule::NameRestrictions` (r:1 w:1)
+	/// Proof: `SmartContractModule::NameRestrictions` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn add_name_restriction() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `6`
+		//  Estimated: `3471`
+		// Minimum execution time: 9_684_000 picoseconds.
+		Weight::from_parts(10_112_000, 3471)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `SmartContractModule::NameRestrictions` (r:1 w:1)
+	/// Proof: `SmartContractModule::NameRestrictions` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn remove_name_restriction() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `140`
+		//  Estimated: `3605`
+		// Minimum execution time: 11_912_000 picoseconds.
+		Weight::from_parts(12_463_000, 3605)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `SmartContractModule::NameRestrictions` (r:1 w:1)
+	/// Proof: `SmartContractModule::NameRestrictions` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `TfgridModule::Twins` (r:1 w:0)
+	/// Proof: `TfgridModule::Twins` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn assign_reserved_name() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `523`
+		//  Estimated: `3988`
+		// Minimum execution time: 15_381_000 picoseconds.
+		Weight::from_parts(15_907_000, 3988)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `TfgridModule::TwinIdByAccountID` (r:1 w:0)
+	/// Proof: `TfgridModule::TwinIdByAccountID` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `TfgridModule::Nodes` (r:1 w:0)
+	/// Proof: `TfgridModule::Nodes` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `TfgridModule::Farms` (r:1 w:1)
+	/// Proof: `TfgridModule::Farms` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `TfgridModule::NodePower` (r:1 w:0)
+	/// Proof: `TfgridModule::NodePower` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SmartContractModule::DedicatedNodesExtraFee` (r:1 w:0)
+	/// Proof: `SmartContractModule::DedicatedNodesExtraFee` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SmartContractModule::ActiveRentContractForNode` (r:1 w:0)
+	/// Proof: `SmartContractModule::ActiveRentContractForNode` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SmartContractModule::ContractIDByNodeIDAndHash` (r:1 w:1)
+	/// Proof: `SmartContractModule::ContractIDByNodeIDAndHash` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `TfgridModule::FarmOvercommitRatio` (r:1 w:0)
+	/// Proof: `TfgridModule::FarmOvercommitRatio` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SmartContractModule::NodeReservedResources` (r:1 w:1)
+	/// Proof: `SmartContractModule::NodeReservedResources` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SmartContractModule::ContractID` (r:1 w:1)
+	/// Proof: `SmartContractModule::ContractID` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `SmartContractModule::BillingFrequency` (r:1 w:0)
+	/// Proof: `SmartContractModule::BillingFrequency` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `SmartContractModule::ContractsToBillAt` (r:1 w:1)
+	/// Proof: `SmartContractModule::ContractsToBillAt` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Timestamp::Now` (r:1 w:0)
+	/// Proof: `Timestamp::Now` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	/// Storage: `SmartContractModule::ActiveNodeContracts` (r:1 w:1)
+	/// Proof: `SmartContractModule::ActiveNodeContracts` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SmartContractModule::Contracts` (r:0 w:1)
+	/// Proof: `SmartContractModule::Contracts` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SmartContractModule::ContractBillingInformationByID` (r:0 w:1)
+	/// Proof: `SmartContractModule::ContractBillingInformationByID` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SmartContractModule::ContractLock` (r:0 w:1)
+	/// Proof: `SmartContractModule::ContractLock` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SmartContractModule::ContractReservedResources` (r:0 w:1)
+	/// Proof: `SmartContractModule::ContractReservedResources` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `TfgridModule::DecommissioningNodes` (r:1 w:0)
+	/// Proof: `TfgridModule::DecommissioningNodes` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `TfgridModule::NodeMaintenance` (r:1 w:0)
+	/// Proof: `TfgridModule::NodeMaintenance` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn create_node_contract_with_public_ips() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1012`
+		//  Estimated: `4477`
+		// Minimum execution time: 112_481_000 picoseconds.
+		Weight::from_parts(114_830_000, 4477)
+			.saturating_add(T::DbWeight::get().reads(16_u64))
+			.saturating_add(T::DbWeight::get().writes(10_u64))
+	}
+	/// Storage: `TfgridModule::TwinIdByAccountID` (r:1 w:0)
+	/// Proof: `TfgridModule::TwinIdByAccountID` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `TfgridModule::Farms` (r:1 w:0)
+	/// Proof: `TfgridModule::Farms` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SmartContractModule::PublicIpPriceMultiplier` (r:0 w:1)
+	/// Proof: `SmartContractModule::PublicIpPriceMultiplier` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn set_public_ip_price_multiplier() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `568`
+		//  Estimated: `4033`
+		// Minimum execution time: 17_212_000 picoseconds.
+		Weight::from_parts(17_756_000, 4033)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `TfgridModule::TwinIdByAccountID` (r:1 w:0)
+	/// Proof: `TfgridModule::TwinIdByAccountID` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `TfgridModule::Nodes` (r:1 w:0)
+	/// Proof: `TfgridModule::Nodes` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `TfgridModule::Farms` (r:1 w:0)
+	/// Proof: `TfgridModule::Farms` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `TfgridModule::NodePower` (r:1 w:0)
+	/// Proof: `TfgridModule::NodePower` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SmartContractModule::DedicatedNodesExtraFee` (r:1 w:0)
+	/// Proof: `SmartContractModule::DedicatedNodesExtraFee` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SmartContractModule::ActiveRentContractForNode` (r:1 w:0)
+	/// Proof: `SmartContractModule::ActiveRentContractForNode` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SmartContractModule::ContractIDByNodeIDAndHash` (r:1 w:1)
+	/// Proof: `SmartContractModule::ContractIDByNodeIDAndHash` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SmartContractModule::ContractID` (r:1 w:1)
+	/// Proof: `SmartContractModule::ContractID` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `SmartContractModule::BillingFrequency` (r:1 w:0)
+	/// Proof: `SmartContractModule::BillingFrequency` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `SmartContractModule::ContractsToBillAt` (r:1 w:1)
+	/// Proof: `SmartContractModule::ContractsToBillAt` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `TfgridModule::FarmIp6Prefixes` (r:1 w:1)
+	/// Proof: `TfgridModule::FarmIp6Prefixes` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SmartContractModule::DelegatedIp6Subnets` (r:1 w:1)
+	/// Proof: `SmartContractModule::DelegatedIp6Subnets` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Timestamp::Now` (r:1 w:0)
+	/// Proof: `Timestamp::Now` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	/// Storage: `SmartContractModule::ActiveNodeContracts` (r:1 w:1)
+	/// Proof: `SmartContractModule::ActiveNodeContracts` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SmartContractModule::Contracts` (r:0 w:1)
+	/// Proof: `SmartContractModule::Contracts` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SmartContractModule::ContractBillingInformationByID` (r:0 w:1)
+	/// Proof: `SmartContractModule::ContractBillingInformationByID` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SmartContractModule::ContractLock` (r:0 w:1)
+	/// Proof: `SmartContractModule::ContractLock` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SmartContractModule::ContractIp6Subnets` (r:0 w:1)
+	/// Proof: `SmartContractModule::ContractIp6Subnets` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `TfgridModule::DecommissioningNodes` (r:1 w:0)
+	/// Proof: `TfgridModule::DecommissioningNodes` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `TfgridModule::NodeMaintenance` (r:1 w:0)
+	/// Proof: `TfgridModule::NodeMaintenance` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn create_node_contract_with_ip6_subnets() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1046`
+		//  Estimated: `4511`
+		// Minimum execution time: 104_772_000 picoseconds.
+		Weight::from_parts(106_935_000, 4511)
+			.saturating_add(T::DbWeight::get().reads(16_u64))
+			.saturating_add(T::DbWeight::get().writes(10_u64))
+	}
+	/// Storage: `TfgridModule::TwinIdByAccountID` (r:1 w:0)
+	/// Proof: `TfgridModule::TwinIdByAccountID` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `TfgridModule::Nodes` (r:1 w:0)
+	/// Proof: `TfgridModule::Nodes` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SmartContractModule::ActiveRentContractForNode` (r:1 w:0)
+	/// Proof: `SmartContractModule::ActiveRentContractForNode` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SmartContractModule::Contracts` (r:1 w:0)
+	/// Proof: `SmartContractModule::Contracts` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `TfgridModule::NodePower` (r:1 w:1)
+	/// Proof: `TfgridModule::NodePower` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SmartContractModule::NodeWakeupRequests` (r:1 w:1)
+	/// Proof: `SmartContractModule::NodeWakeupRequests` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SmartContractModule::NodeWakeupTimeouts` (r:0 w:1)
+	/// Proof: `SmartContractModule::NodeWakeupTimeouts` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn request_node_wakeup() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1187`
+		//  Estimated: `4652`
+		// Minimum execution time: 31_208_000 picoseconds.
+		Weight::from_parts(32_045_000, 4652)
+			.saturating_add(T::DbWeight::get().reads(6_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+}
+
+// For backwards compatibility and tests
+impl WeightInfo for () {
+	/// Storage: `TfgridModule::TwinIdByAccountID` (r:1 w:0)
+	/// Proof: `TfgridModule::TwinIdByAccountID` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `TfgridModule::Nodes` (r:1 w:0)
+	/// Proof: `TfgridModule::Nodes` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `TfgridModule::NodePower` (r:1 w:0)
+	/// Proof: `TfgridModule::NodePower` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `TfgridModule::Farms` (r:1 w:1)
+	/// Proof: `TfgridModule::Farms` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SmartContractModule::DedicatedNodesExtraFee` (r:1 w:0)
+	/// Proof: `SmartContractModule::DedicatedNodesExtraFee` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SmartContractModule::ActiveRentContractForNode` (r:1 w:0)
+	/// Proof: `SmartContractModule::ActiveRentContractForNode` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SmartContractModule::ContractIDByNodeIDAndHash` (r:1 w:1)
+	/// Proof: `SmartContractModule::ContractIDByNodeIDAndHash` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SmartContractModule::ContractID` (r:1 w:1)
+	/// Proof: `SmartContractModule::ContractID` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `SmartContractModule::BillingFrequency` (r:1 w:0)
+	/// Proof: `SmartContractModule::BillingFrequency` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `SmartContractModule::ContractsToBillAt` (r:1 w:1)
+	/// Proof: `SmartContractModule::ContractsToBillAt` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Timestamp::Now` (r:1 w:0)
+	/// Proof: `Timestamp::Now` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	/// Storage: `SmartContractModule::ActiveNodeContracts` (r:1 w:1)
+	/// Proof: `SmartContractModule::ActiveNodeContracts` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SmartContractModule::Contracts` (r:0 w:1)
+	/// Proof: `SmartContractModule::Contracts` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SmartContractModule::ContractBillingInformationByID` (r:0 w:1)
+	/// Proof: `SmartContractModule::ContractBillingInformationByID` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SmartContractModule::ContractLock` (r:0 w:1)
+	/// Proof: `SmartContractModule::ContractLock` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `TfgridModule::DecommissioningNodes` (r:1 w:0)
+	/// Proof: `TfgridModule::DecommissioningNodes` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `TfgridModule::NodeMaintenance` (r:1 w:0)
+	/// Proof: `TfgridModule::NodeMaintenance` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `TfgridModule::FarmOvercommitRatio` (r:1 w:0)
+	/// Proof: `TfgridModule::FarmOvercommitRatio` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SmartContractModule::NodeReservedResources` (r:1 w:0)
+	/// Proof: `SmartContractModule::NodeReservedResources` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn create_node_contract() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `868`
+		//  Estimated: `4333`
+		// Minimum execution time: 102_203_000 picoseconds.
+		Weight::from_parts(104_197_000, 4333)
+			.saturating_add(RocksDbWeight::get().reads(16_u64))
+			.saturating_add(RocksDbWeight::get().writes(8_u64))
+	}
+	/// Storage: `SmartContractModule::Contracts` (r:1 w:1)
+	/// Proof: `SmartContractModule::Contracts` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `TfgridModule::Twins` (r:1 w:0)
+	/// Proof: `TfgridModule::Twins` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SmartContractModule::ActiveNodeContracts` (r:1 w:1)
+	/// Proof: `SmartContractModule::ActiveNodeContracts` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SmartContractModule::ContractIDByNodeIDAndHash` (r:0 w:2)
+	/// Proof: `SmartContractModule::ContractIDByNodeIDAndHash` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn update_node_contract() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `892`
+		//  Estimated: `4357`
+		// Minimum execution time: 26_891_000 picoseconds.
+		Weight::from_parts(27_472_000, 4357)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
+	}
+	/// Storage: `SmartContractModule::Contracts` (r:1 w:1)
+	/// Proof: `SmartContractModule::Contracts` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `TfgridModule::Twins` (r:1 w:0)
+	/// Proof: `TfgridModule::Twins` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SmartContractModule::ActiveNodeContracts` (r:1 w:1)
+	/// Proof: `SmartContractModule::ActiveNodeContracts` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `TfgridModule::TwinBoundedAccountID` (r:1 w:0)
+	/// Proof: `TfgridModule::TwinBoundedAccountID` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Timestamp::Now` (r:1 w:0)
+	/// Proof: `Timestamp::Now` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	/// Storage: `SmartContractModule::ContractLock` (r:1 w:1)
+	/// Proof: `SmartContractModule::ContractLock` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `TfgridModule::PricingPolicies` (r:1 w:0)
+	/// Proof: `TfgridModule::PricingPolicies` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SmartContractModule::BillingFrequency` (r:1 w:0)
+	/// Proof: `SmartContractModule::BillingFrequency` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `SmartContractModule::ContractsToBillAt` (r:1 w:1)
+	/// Proof: `SmartContractModule::ContractsToBillAt` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SmartContractModule::ContractBillingInformationByID` (r:0 w:1)
+	/// Proof: `SmartContractModule::ContractBillingInformationByID` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SmartContractModule::NodeContractResources` (r:0 w:1)
+	/// Proof: `SmartContractModule::NodeContractResources` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SmartContractModule::ContractIDByNodeIDAndHash` (r:0 w:1)
+	/// Proof: `SmartContractModule::ContractIDByNodeIDAndHash` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SmartContractModule::ContractReservedResources` (r:1 w:1)
+	/// Proof: `SmartContractModule::ContractReservedResources` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SmartContractModule::ContractIp6Subnets` (r:1 w:1)
+	/// Proof: `SmartContractModule::ContractIp6Subnets` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `TfgridModule::DecommissioningNodes` (r:1 w:0)
+	/// Proof: `TfgridModule::DecommissioningNodes` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `TfgridModule::NodeMaintenance` (r:1 w:0)
+	/// Proof: `TfgridModule::NodeMaintenance` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn cancel_contract() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1203`
+		//  Estimated: `4668`
+		// Minimum execution time: 60_284_000 picoseconds.
+		Weight::from_parts(61_346_000, 4668)
+			.saturating_add(RocksDbWeight::get().reads(13_u64))
+			.saturating_add(RocksDbWeight::get().writes(9_u64))
+	}
+	/// Storage: `TfgridModule::TwinIdByAccountID` (r:1 w:0)
+	/// Proof: `TfgridModule::TwinIdByAccountID` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SmartContractModule::ContractIDByNameRegistration` (r:1 w:1)
+	/// Proof: `SmartContractModule::ContractIDByNameRegistration` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SmartContractModule::ContractID` (r:1 w:1)
+	/// Proof: `SmartContractModule::ContractID` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `SmartContractModule::BillingFrequency` (r:1 w:0)
+	/// Proof: `SmartContractModule::BillingFrequency` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `SmartContractModule::ContractsToBillAt` (r:1 w:1)
+	/// Proof: `SmartContractModule::ContractsToBillAt` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Timestamp::Now` (r:1 w:0)
+	/// Proof: `Timestamp::Now` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	/// Storage: `SmartContractModule::Contracts` (r:0 w:1)
+	/// Proof: `SmartContractModule::Contracts` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SmartContractModule::ContractLock` (r:0 w:1)
+	/// Proof: `SmartContractModule::ContractLock` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SmartContractModule::NameRestrictions` (r:5 w:0)
+	/// Proof: `SmartContractModule::NameRestrictions` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn create_name_contract() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `340`
+		//  Estimated: `3805`
+		// Minimum execution time: 24_977_000 picoseconds.
+		Weight::from_parts(25_658_000, 3805)
+			.saturating_add(RocksDbWeight::get().reads(11_u64))
+			.saturating_add(RocksDbWeight::get().writes(5_u64))
+	}
+	/// Storage: `SmartContractModule::Contracts` (r:1 w:1)
+	/// Proof: `SmartContractModule::Contracts` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `TfgridModule::Twins` (r:1 w:0)
+	/// Proof: `TfgridModule::Twins` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `TfgridModule::TwinBoundedAccountID` (r:1 w:0)
+	/// Proof: `TfgridModule::TwinBoundedAccountID` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Timestamp::Now` (r:1 w:0)
+	/// Proof: `Timestamp::Now` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	/// Storage: `SmartContractModule::ContractLock` (r:1 w:1)
+	/// Proof: `SmartContractModule::ContractLock` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `TfgridModule::PricingPolicies` (r:1 w:0)
+	/// Proof: `TfgridModule::PricingPolicies` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SmartContractModule::BillingFrequency` (r:1 w:0)
+	/// Proof: `SmartContractModule::BillingFrequency` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `SmartContractModule::ContractsToBillAt` (r:1 w:1)
+	/// Proof: `SmartContractModule::ContractsToBillAt` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SmartContractModule::ContractIDByNameRegistration` (r:0 w:1)
+	/// Proof: `SmartContractModule::ContractIDByNameRegistration` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn cancel_name_contract() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `919`
+		//  Estimated: `4384`
+		// Minimum execution time: 46_538_000 picoseconds.
+		Weight::from_parts(47_230_000, 4384)
+			.saturating_add(RocksDbWeight::get().reads(8_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
+	}
+	/// Storage: `TfgridModule::TwinIdByAccountID` (r:1 w:0)
+	/// Proof: `TfgridModule::TwinIdByAccountID` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `TfgridModule::NodeIdByTwinID` (r:1 w:0)
+	/// Proof: `TfgridModule::NodeIdByTwinID` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `TfgridModule::Nodes` (r:1 w:0)
+	/// Proof: `TfgridModule::Nodes` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `TfgridModule::Farms` (r:1 w:0)
+	/// Proof: `TfgridModule::Farms` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `TfgridModule::PricingPolicies` (r:1 w:0)
+	/// Proof: `TfgridModule::PricingPolicies` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SmartContractModule::Contracts` (r:1 w:0)
+	/// Proof: `SmartContractModule::Contracts` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SmartContractModule::ContractBillingInformationByID` (r:1 w:1)
+	/// Proof: `SmartContractModule::ContractBillingInformationByID` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn add_nru_reports() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1292`
+		//  Estimated: `4757`
+		// Minimum execution time: 38_072_000 picoseconds.
+		Weight::from_parts(38_763_000, 4757)
+			.saturating_add(RocksDbWeight::get().reads(7_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `TfgridModule::TwinIdByAccountID` (r:1 w:0)
+	/// Proof: `TfgridModule::TwinIdByAccountID` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `TfgridModule::NodeIdByTwinID` (r:1 w:0)
+	/// Proof: `TfgridModule::NodeIdByTwinID` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SmartContractModule::Contracts` (r:1 w:0)
+	/// Proof: `SmartContractModule::Contracts` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SmartContractModule::NodeContractResources` (r:0 w:1)
+	/// Proof: `SmartContractModule::NodeContractResources` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SmartContractModule::ContractReservedResources` (r:1 w:1)
+	/// Proof: `SmartContractModule::ContractReservedResources` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SmartContractModule::NodeReservedResources` (r:1 w:1)
+	/// Proof: `SmartContractModule::NodeReservedResources` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn report_contract_resources() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `771`
+		//  Estimated: `4236`
+		// Minimum execution time: 23_194_000 picoseconds.
+		Weight::from_parts(23_485_000, 4236)
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+	/// Storage: `SmartContractModule::ActiveRentContractForNode` (r:1 w:1)
+	/// Proof: `SmartContractModule::ActiveRentContractForNode` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `TfgridModule::Nodes` (r:1 w:0)
+	/// Proof: `TfgridModule::Nodes` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `TfgridModule::Farms` (r:1 w:0)
+	/// Proof: `TfgridModule::Farms` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SmartContractModule::ActiveNodeContracts` (r:1 w:0)
+	/// Proof: `SmartContractModule::ActiveNodeContracts` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `TfgridModule::TwinIdByAccountID` (r:1 w:0)
+	/// Proof: `TfgridModule::TwinIdByAccountID` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SmartContractModule::ContractID` (r:1 w:1)
+	/// Proof: `SmartContractModule::ContractID` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `SmartContractModule::BillingFrequency` (r:1 w:0)
+	/// Proof: `SmartContractModule::BillingFrequency` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `SmartContractModule::ContractsToBillAt` (r:1 w:1)
+	/// Proof: `SmartContractModule::ContractsToBillAt` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Timestamp::Now` (r:1 w:0)
+	/// Proof: `Timestamp::Now` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	/// Storage: `SmartContractModule::Contracts` (r:0 w:1)
+	/// Proof: `SmartContractModule::Contracts` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SmartContractModule::ContractLock` (r:0 w:1)
+	/// Proof: `SmartContractModule::ContractLock` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `TfgridModule::DecommissioningNodes` (r:1 w:0)
+	/// Proof: `TfgridModule::DecommissioningNodes` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `TfgridModule::NodeMaintenance` (r:1 w:0)
+	/// Proof: `TfgridModule::NodeMaintenance` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn create_rent_contract() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `776`
+		//  Estimated: `4241`
+		// Minimum execution time: 34_876_000 picoseconds.
+		Weight::from_parts(35_417_000, 4241)
+			.saturating_add(RocksDbWeight::get().reads(11_u64))
+			.saturating_add(RocksDbWeight::get().writes(5_u64))
+	}
+	/// Storage: `SmartContractModule::Contracts` (r:1 w:1)
+	/// Proof: `SmartContractModule::Contracts` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `TfgridModule::Twins` (r:1 w:0)
+	/// Proof: `TfgridModule::Twins` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SmartContractModule::ActiveNodeContracts` (r:1 w:0)
+	/// Proof: `SmartContractModule::ActiveNodeContracts` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `TfgridModule::Nodes` (r:1 w:0)
+	/// Proof: `TfgridModule::Nodes` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `TfgridModule::NodePower` (r:1 w:0)
+	/// Proof: `TfgridModule::NodePower` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `TfgridModule::TwinBoundedAccountID` (r:1 w:0)
+	/// Proof: `TfgridModule::TwinBoundedAccountID` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Timestamp::Now` (r:1 w:0)
+	/// Proof: `Timestamp::Now` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	/// Storage: `SmartContractModule::ContractLock` (r:1 w:1)
+	/// Proof: `SmartContractModule::ContractLock` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `TfgridModule::PricingPolicies` (r:1 w:0)
+	/// Proof: `TfgridModule::PricingPolicies` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SmartContractModule::DedicatedNodesExtraFee` (r:1 w:0)
+	/// Proof: `SmartContractModule::DedicatedNodesExtraFee` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SmartContractModule::BillingFrequency` (r:1 w:0)
+	/// Proof: `SmartContractModule::BillingFrequency` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `SmartContractModule::ContractsToBillAt` (r:1 w:1)
+	/// Proof: `SmartContractModule::ContractsToBillAt` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SmartContractModule::ActiveRentContractForNode` (r:0 w:1)
+	/// Proof: `SmartContractModule::ActiveRentContractForNode` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `TfgridModule::DecommissioningNodes` (r:1 w:0)
+	/// Proof: `TfgridModule::DecommissioningNodes` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `TfgridModule::NodeMaintenance` (r:1 w:0)
+	/// Proof: `TfgridModule::NodeMaintenance` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn cancel_rent_contract() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1284`
+		//  Estimated: `4749`
+		// Minimum execution time: 60_275_000 picoseconds.
+		Weight::from_parts(60_955_000, 4749)
+			.saturating_add(RocksDbWeight::get().reads(14_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
+	}
+	/// Storage: `SmartContractModule::SolutionProviderID` (r:1 w:1)
+	/// Proof: `SmartContractModule::SolutionProviderID` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `SmartContractModule::SolutionProviders` (r:0 w:1)
+	/// Proof: `SmartContractModule::SolutionProviders` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn create_solution_provider() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `37`
+		//  Estimated: `1522`
+		// Minimum execution time: 10_290_000 picoseconds.
+		Weight::from_parts(10_560_000, 1522)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// Storage: `SmartContractModule::SolutionProviders` (r:1 w:1)
+	/// Proof: `SmartContractModule::SolutionProviders` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn approve_solution_provider() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `215`
+		//  Estimated: `3680`
+		// Minimum execution time: 13_215_000 picoseconds.
+		Weight::from_parts(13_535_000, 3680)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `SmartContractModule::Contracts` (r:1 w:1)
+	/// Proof: `SmartContractModule::Contracts` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `TfgridModule::Twins` (r:1 w:0)
+	/// Proof: `TfgridModule::Twins` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `TfgridModule::TwinBoundedAccountID` (r:1 w:0)
+	/// Proof: `TfgridModule::TwinBoundedAccountID` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Timestamp::Now` (r:1 w:0)
+	/// Proof: `Timestamp::Now` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	/// Storage: `SmartContractModule::ContractLock` (r:1 w:1)
+	/// Proof: `SmartContractModule::ContractLock` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `TfgridModule::PricingPolicies` (r:1 w:0)
+	/// Proof: `TfgridModule::PricingPolicies` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SmartContractModule::ContractBillingInformationByID` (r:1 w:1)
+	/// Proof: `SmartContractModule::ContractBillingInformationByID` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `TfgridModule::Nodes` (r:1 w:0)
+	/// Proof: `TfgridModule::Nodes` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SmartContractModule::NodeContractResources` (r:1 w:0)
+	/// Proof: `SmartContractModule::NodeContractResources` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SmartContractModule::ActiveRentContractForNode` (r:1 w:0)
+	/// Proof: `SmartContractModule::ActiveRentContractForNode` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `TFTPriceModule::AverageTftPrice` (r:1 w:0)
+	/// Proof: `TFTPriceModule::AverageTftPrice` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `TFTPriceModule::MinTftPrice` (r:1 w:0)
+	/// Proof: `TFTPriceModule::MinTftPrice` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `TFTPriceModule::MaxTftPrice` (r:1 w:0)
+	/// Proof: `TFTPriceModule::MaxTftPrice` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `Balances::Locks` (r:1 w:1)
+	/// Proof: `Balances::Locks` (`max_values`: None, `max_size`: Some(1299), added: 3774, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Freezes` (r:1 w:0)
+	/// Proof: `Balances::Freezes` (`max_values`: None, `max_size`: Some(49), added: 2524, mode: `MaxEncodedLen`)
+	/// Storage: `SmartContractModule::ContractBillingHistoryCount` (r:1 w:1)
+	/// Proof: `SmartContractModule::ContractBillingHistoryCount` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SmartContractModule::ContractBillingHistory` (r:0 w:1)
+	/// Proof: `SmartContractModule::ContractBillingHistory` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SmartContractModule::TwinBillingHistory` (r:1 w:1)
+	/// Proof: `SmartContractModule::TwinBillingHistory` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SmartContractModule::TwinBillingDays` (r:1 w:1)
+	/// Proof: `SmartContractModule::TwinBillingDays` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SmartContractModule::ContractSpendingCap` (r:1 w:1)
+	/// Proof: `SmartContractModule::ContractSpendingCap` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SmartContractModule::TwinSpendingCap` (r:1 w:1)
+	/// Proof: `SmartContractModule::TwinSpendingCap` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SmartContractModule::SolutionProviderGracePeriod` (r:1 w:0)
+	/// Proof: `SmartContractModule::SolutionProviderGracePeriod` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SmartContractModule::ContractKindGracePeriod` (r:1 w:0)
+	/// Proof: `SmartContractModule::ContractKindGracePeriod` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SmartContractModule::TwinContractsInGrace` (r:1 w:1)
+	/// Proof: `SmartContractModule::TwinContractsInGrace` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SmartContractModule::LastGracePeriodReminder` (r:1 w:1)
+	/// Proof: `SmartContractModule::LastGracePeriodReminder` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `TfgridModule::NodeMaintenance` (r:1 w:0)
+	/// Proof: `TfgridModule::NodeMaintenance` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn bill_contract_for_block() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1608`
+		//  Estimated: `5073`
+		// Minimum execution time: 80_642_000 picoseconds.
+		Weight::from_parts(81_735_000, 5073)
+			.saturating_add(RocksDbWeight::get().reads(26_u64))
+			.saturating_add(RocksDbWeight::get().writes(13_u64))
+	}
+	/// Storage: `TfgridModule::TwinIdByAccountID` (r:2 w:0)
+	/// Proof: `TfgridModule::TwinIdByAccountID` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SmartContractModule::ServiceContractID` (r:1 w:1)
+	/// Proof: `SmartContractModule::ServiceContractID` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `SmartContractModule::ServiceContracts` (r:0 w:1)
+	/// Proof: `SmartContractModule::ServiceContracts` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn service_contract_create() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `395`
+		//  Estimated: `6335`
+		// Minimum execution time: 18_926_000 picoseconds.
+		Weight::from_parts(19_386_000, 6335)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// Storage: `TfgridModule::TwinIdByAccountID` (r:1 w:0)
+	/// Proof: `TfgridModule::TwinIdByAccountID` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SmartContractModule::ServiceContracts` (r:1 w:1)
+	/// Proof: `SmartContractModule::ServiceContracts` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn service_contract_set_metadata() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `489`
+		//  Estimated: `3954`
+		// Minimum execution time: 16_892_000 picoseconds.
+		Weight::from_parts(17_293_000, 3954)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `TfgridModule::TwinIdByAccountID` (r:1 w:0)
+	/// Proof: `TfgridModule::TwinIdByAccountID` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SmartContractModule::ServiceContracts` (r:1 w:1)
+	/// Proof: `SmartContractModule::ServiceContracts` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn service_contract_set_fees() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `489`
+		//  Estimated: `3954`
+		// Minimum execution time: 16_562_000 picoseconds.
+		Weight::from_parts(16_732_000, 3954)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `TfgridModule::TwinIdByAccountID` (r:1 w:0)
+	/// Proof: `TfgridModule::TwinIdByAccountID` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SmartContractModule::ServiceContracts` (r:1 w:1)
+	/// Proof: `SmartContractModule::ServiceContracts` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SmartContractModule::ServiceContractsToBillAt` (r:1 w:1)
+	/// Proof: `SmartContractModule::ServiceContractsToBillAt` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SmartContractModule::ServiceContractLastVariableBill` (r:0 w:1)
+	/// Proof: `SmartContractModule::ServiceContractLastVariableBill` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn service_contract_approve() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `502`
+		//  Estimated: `3967`
+		// Minimum execution time: 16_821_000 picoseconds.
+		Weight::from_parts(17_292_000, 3967)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+	/// Storage: `SmartContractModule::ServiceContracts` (r:1 w:1)
+	/// Proof: `SmartContractModule::ServiceContracts` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `TfgridModule::TwinIdByAccountID` (r:1 w:0)
+	/// Proof: `TfgridModule::TwinIdByAccountID` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SmartContractModule::ServiceContractsToBillAt` (r:1 w:1)
+	/// Proof: `SmartContractModule::ServiceContractsToBillAt` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SmartContractModule::ServiceContractLastVariableBill` (r:0 w:1)
+	/// Proof: `SmartContractModule::ServiceContractLastVariableBill` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SmartContractModule::ServiceContractGracePeriodStart` (r:0 w:1)
+	/// Proof: `SmartContractModule::ServiceContractGracePeriodStart` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SmartContractModule::ServiceContractAmendments` (r:0 w:1)
+	/// Proof: `SmartContractModule::ServiceContractAmendments` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SmartContractModule::ServiceContractPlan` (r:0 w:1)
+	/// Proof: `SmartContractModule::ServiceContractPlan` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn service_contract_reject() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `502`
+		//  Estimated: `3967`
+		// Minimum execution time: 17_693_000 picoseconds.
+		Weight::from_parts(18_014_000, 3967)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(6_u64))
+	}
+	/// Storage: `TfgridModule::TwinIdByAccountID` (r:1 w:0)
+	/// Proof: `TfgridModule::TwinIdByAccountID` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SmartContractModule::ServiceContracts` (r:1 w:1)
+	/// Proof: `SmartContractModule::ServiceContracts` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SmartContractModule::ServiceContractDisputeWindow` (r:0 w:1)
+	/// Proof: `SmartContractModule::ServiceContractDisputeWindow` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SmartContractModule::ServiceContractsToBillAt` (r:1 w:1)
+	/// Proof: `SmartContractModule::ServiceContractsToBillAt` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SmartContractModule::ServiceContractLastVariableBill` (r:0 w:1)
+	/// Proof: `SmartContractModule::ServiceContractLastVariableBill` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SmartContractModule::ServiceContractGracePeriodStart` (r:0 w:1)
+	/// Proof: `SmartContractModule::ServiceContractGracePeriodStart` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SmartContractModule::ServiceContractAmendments` (r:0 w:1)
+	/// Proof: `SmartContractModule::ServiceContractAmendments` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SmartContractModule::ServiceContractPlan` (r:0 w:1)
+	/// Proof: `SmartContractModule::ServiceContractPlan` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SmartContractModule::ServiceContractPendingVariableBill` (r:0 w:1)
+	/// Proof: `SmartContractModule::ServiceContractPendingVariableBill` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn service_contract_cancel() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `502`
+		//  Estimated: `3967`
+		// Minimum execution time: 16_401_000 picoseconds.
+		Weight::from_parts(16_662_000, 3967)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(8_u64))
+	}
+	/// Storage: `TfgridModule::TwinIdByAccountID` (r:1 w:0)
+	/// Proof: `TfgridModule::TwinIdByAccountID` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SmartContractModule::ServiceContracts` (r:1 w:0)
+	/// Proof: `SmartContractModule::ServiceContracts` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Timestamp::Now` (r:1 w:0)
+	/// Proof: `Timestamp::Now` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	/// Storage: `TfgridModule::Twins` (r:2 w:0)
+	/// Proof: `TfgridModule::Twins` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `System::Account` (r:1 w:0)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `SmartContractModule::ServiceContractDisputeWindow` (r:1 w:0)
+	/// Proof: `SmartContractModule::ServiceContractDisputeWindow` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SmartContractModule::ServiceContractBillID` (r:1 w:1)
+	/// Proof: `SmartContractModule::ServiceContractBillID` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `SmartContractModule::ServiceContractEscrowedBills` (r:0 w:1)
+	/// Proof: `SmartContractModule::ServiceContractEscrowedBills` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SmartContractModule::ServiceContractLastVariableBill` (r:1 w:1)
+	/// Proof: `SmartContractModule::ServiceContractLastVariableBill` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SmartContractModule::ServiceContractPendingVariableBill` (r:1 w:1)
+	/// Proof: `SmartContractModule::ServiceContractPendingVariableBill` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn service_contract_bill() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `841`
+		//  Estimated: `6781`
+		// Minimum execution time: 30_327_000 picoseconds.
+		Weight::from_parts(31_189_000, 6781)
+			.saturating_add(RocksDbWeight::get().reads(10_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
+	}
+	/// Storage: `SmartContractModule::BillingFrequency` (r:1 w:1)
+	/// Proof: `SmartContractModule::BillingFrequency` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	fn change_billing_frequency() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `37`
+		//  Estimated: `1522`
+		// Minimum execution time: 7_855_000 picoseconds.
+		Weight::from_parts(8_076_000, 1522)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `SmartContractModule::SolutionProviders` (r:1 w:0)
+	/// Proof: `SmartContractModule::SolutionProviders` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SmartContractModule::Contracts` (r:1 w:1)
+	/// Proof: `SmartContractModule::Contracts` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `TfgridModule::TwinIdByAccountID` (r:1 w:0)
+	/// Proof: `TfgridModule::TwinIdByAccountID` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn attach_solution_provider_id() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `971`
+		//  Estimated: `4436`
+		// Minimum execution time: 22_222_000 picoseconds.
+		Weight::from_parts(22_462_000, 4436)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `TfgridModule::TwinIdByAccountID` (r:1 w:0)
+	/// Proof: `TfgridModule::TwinIdByAccountID` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `TfgridModule::Nodes` (r:1 w:0)
+	/// Proof: `TfgridModule::Nodes` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `TfgridModule::Farms` (r:1 w:0)
+	/// Proof: `TfgridModule::Farms` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SmartContractModule::ActiveNodeContracts` (r:1 w:0)
+	/// Proof: `SmartContractModule::ActiveNodeContracts` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SmartContractModule::ActiveRentContractForNode` (r:1 w:0)
+	/// Proof: `SmartContractModule::ActiveRentContractForNode` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SmartContractModule::DedicatedNodesExtraFee` (r:0 w:1)
+	/// Proof: `SmartContractModule::DedicatedNodesExtraFee` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn set_dedicated_node_extra_fee() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `733`
+		//  Estimated: `4198`
+		// Minimum execution time: 23_424_000 picoseconds.
+		Weight::from_parts(23_775_000, 4198)
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `SmartContractModule::Contracts` (r:1 w:1)
+	/// Proof: `SmartContractModule::Contracts` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SmartContractModule::ActiveNodeContracts` (r:1 w:1)
+	/// Proof: `SmartContractModule::ActiveNodeContracts` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `TfgridModule::Twins` (r:1 w:0)
+	/// Proof: `TfgridModule::Twins` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `TfgridModule::TwinBoundedAccountID` (r:1 w:0)
+	/// Proof: `TfgridModule::TwinBoundedAccountID` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Timestamp::Now` (r:1 w:0)
+	/// Proof: `Timestamp::Now` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	/// Storage: `SmartContractModule::ContractLock` (r:1 w:1)
+	/// Proof: `SmartContractModule::ContractLock` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `TfgridModule::PricingPolicies` (r:1 w:0)
+	/// Proof: `TfgridModule::PricingPolicies` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SmartContractModule::BillingFrequency` (r:1 w:0)
+	/// Proof: `SmartContractModule::BillingFrequency` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `SmartContractModule::ContractsToBillAt` (r:1 w:1)
+	/// Proof: `SmartContractModule::ContractsToBillAt` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SmartContractModule::ContractBillingInformationByID` (r:0 w:1)
+	/// Proof: `SmartContractModule::ContractBillingInformationByID` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SmartContractModule::NodeContractResources` (r:0 w:1)
+	/// Proof: `SmartContractModule::NodeContractResources` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SmartContractModule::ContractIDByNodeIDAndHash` (r:0 w:1)
+	/// Proof: `SmartContractModule::ContractIDByNodeIDAndHash` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `TfgridModule::DecommissioningNodes` (r:1 w:0)
+	/// Proof: `TfgridModule::DecommissioningNodes` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `TfgridModule::NodeMaintenance` (r:1 w:0)
+	/// Proof: `TfgridModule::NodeMaintenance` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn cancel_contract_collective() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1203`
+		//  Estimated: `4668`
+		// Minimum execution time: 58_861_000 picoseconds.
+		Weight::from_parts(59_413_000, 4668)
+			.saturating_add(RocksDbWeight::get().reads(11_u64))
+			.saturating_add(RocksDbWeight::get().writes(7_u64))
+	}
+	/// Storage: `TfgridModule::TwinIdByAccountID` (r:1 w:0)
+	/// Proof: `TfgridModule::TwinIdByAccountID` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SmartContractModule::Contracts` (r:1 w:0)
+	/// Proof: `SmartContractModule::Contracts` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SmartContractModule::ContractSpendingCap` (r:1 w:1)
+	/// Proof: `SmartContractModule::ContractSpendingCap` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Timestamp::Now` (r:1 w:0)
+	/// Proof: `Timestamp::Now` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	fn set_contract_spending_cap() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `512`
+		//  Estimated: `3977`
+		// Minimum execution time: 17_263_000 picoseconds.
+		Weight::from_parts(17_609_000, 3977)
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `TfgridModule::TwinIdByAccountID` (r:1 w:0)
+	/// Proof: `TfgridModule::TwinIdByAccountID` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SmartContractModule::Contracts` (r:1 w:0)
+	/// Proof: `SmartContractModule::Contracts` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SmartContractModule::ContractSpendingCap` (r:1 w:1)
+	/// Proof: `SmartContractModule::ContractSpendingCap` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn clear_contract_spending_cap() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `598`
+		//  Estimated: `4063`
+		// Minimum execution time: 16_102_000 picoseconds.
+		Weight::from_parts(16_487_000, 4063)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `TfgridModule::TwinIdByAccountID` (r:1 w:0)
+	/// Proof: `TfgridModule::TwinIdByAccountID` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SmartContractModule::TwinSpendingCap` (r:1 w:1)
+	/// Proof: `SmartContractModule::TwinSpendingCap` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Timestamp::Now` (r:1 w:0)
+	/// Proof: `Timestamp::Now` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	fn set_twin_spending_cap() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `385`
+		//  Estimated: `3850`
+		// Minimum execution time: 12_840_000 picoseconds.
+		Weight::from_parts(13_125_000, 3850)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `TfgridModule::TwinIdByAccountID` (r:1 w:0)
+	/// Proof: `TfgridModule::TwinIdByAccountID` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SmartContractModule::TwinSpendingCap` (r:1 w:1)
+	/// Proof: `SmartContractModule::TwinSpendingCap` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn clear_twin_spending_cap() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `449`
+		//  Estimated: `3914`
+		// Minimum execution time: 11_573_000 picoseconds.
+		Weight::from_parts(11_902_000, 3914)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `SmartContractModule::ContractKindGracePeriod` (r:0 w:1)
+	/// Proof: `SmartContractModule::ContractKindGracePeriod` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn set_contract_kind_grace_period() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 6_092_000 picoseconds.
+		Weight::from_parts(6_345_000, 0)
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `SmartContractModule::SolutionProviders` (r:1 w:0)
+	/// Proof: `SmartContractModule::SolutionProviders` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SmartContractModule::SolutionProviderGracePeriod` (r:0 w:1)
+	/// Proof: `SmartContractModule::SolutionProviderGracePeriod` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn set_solution_provider_grace_period() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `286`
+		//  Estimated: `3751`
+		// Minimum execution time: 10_418_000 picoseconds.
+		Weight::from_parts(10_790_000, 3751)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `TfgridModule::Twins` (r:1 w:0)
+	/// Proof: `TfgridModule::Twins` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SmartContractModule::TwinContractsInGrace` (r:1 w:1)
+	/// Proof: `SmartContractModule::TwinContractsInGrace` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SmartContractModule::Contracts` (r:n w:n)
+	/// Proof: `SmartContractModule::Contracts` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Timestamp::Now` (r:1 w:0)
+	/// Proof: `Timestamp::Now` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	/// Storage: `SmartContractModule::ContractLock` (r:n w:n)
+	/// Proof: `SmartContractModule::ContractLock` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `TfgridModule::Nodes` (r:n w:0)
+	/// Proof: `TfgridModule::Nodes` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `TfgridModule::Farms` (r:n w:0)
+	/// Proof: `TfgridModule::Farms` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `TfgridModule::PricingPolicies` (r:n w:0)
+	/// Proof: `TfgridModule::PricingPolicies` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `TFTPriceModule::AverageTftPrice` (r:1 w:0)
+	/// Proof: `TFTPriceModule::AverageTftPrice` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `Balances::Locks` (r:n w:n)
+	/// Proof: `Balances::Locks` (`max_values`: None, `max_size`: Some(1299), added: 3774, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Freezes` (r:n w:0)
+	/// Proof: `Balances::Freezes` (`max_values`: None, `max_size`: Some(49), added: 2524, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:n w:n)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `SmartContractModule::ContractBillingHistoryCount` (r:n w:n)
+	/// Proof: `SmartContractModule::ContractBillingHistoryCount` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SmartContractModule::ContractBillingHistory` (r:0 w:n)
+	/// Proof: `SmartContractModule::ContractBillingHistory` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SmartContractModule::TwinBillingHistory` (r:n w:n)
+	/// Proof: `SmartContractModule::TwinBillingHistory` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SmartContractModule::TwinBillingDays` (r:n w:n)
+	/// Proof: `SmartContractModule::TwinBillingDays` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SmartContractModule::LastGracePeriodReminder` (r:0 w:n)
+	/// Proof: `SmartContractModule::LastGracePeriodReminder` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// The range of component `n` is `[1, 50]`.
+	fn restore_contracts_from_grace(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1052 + n * (392 ±0)`
+		//  Estimated: `4517 + n * (3774 ±0)`
+		// Minimum execution time: 71_354_000 picoseconds.
+		Weight::from_parts(38_107_412, 4517)
+			// Standard Error: 41_562
+			.saturating_add(Weight::from_parts(46_215_830, 0).saturating_mul(n.into()))
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().reads((11_u64).saturating_mul(n.into())))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+			.saturating_add(RocksDbWeight::get().writes((9_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 3774).saturating_mul(n.into()))
+	}
+	/// Storage: `TfgridModule::TwinIdByAccountID` (r:1 w:0)
+	/// Proof: `TfgridModule::TwinIdByAccountID` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `TfgridModule::Nodes` (r:n w:0)
+	/// Proof: `TfgridModule::Nodes` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `TfgridModule::NodePower` (r:n w:0)
+	/// Proof: `TfgridModule::NodePower` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `TfgridModule::Farms` (r:1 w:1)
+	/// Proof: `TfgridModule::Farms` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SmartContractModule::DedicatedNodesExtraFee` (r:n w:0)
+	/// Proof: `SmartContractModule::DedicatedNodesExtraFee` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SmartContractModule::ActiveRentContractForNode` (r:n w:0)
+	/// Proof: `SmartContractModule::ActiveRentContractForNode` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SmartContractModule::ContractIDByNodeIDAndHash` (r:n w:n)
+	/// Proof: `SmartContractModule::ContractIDByNodeIDAndHash` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SmartContractModule::ContractID` (r:1 w:1)
 	/// Proof: `SmartContractModule::ContractID` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
 	/// Storage: `SmartContractModule::BillingFrequency` (r:1 w:0)
 	/// Proof: `SmartContractModule::BillingFrequency` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
@@ -755,144 +2256,277 @@ impl WeightInfo for () {
 	/// Proof: `SmartContractModule::ContractsToBillAt` (`max_values`: None, `max_size`: None, mode: `Measured`)
 	/// Storage: `Timestamp::Now` (r:1 w:0)
 	/// Proof: `Timestamp::Now` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
-	/// Storage: `SmartContractModule::Contracts` (r:0 w:1)
+	/// Storage: `SmartContractModule::ActiveNodeContracts` (r:n w:n)
+	/// Proof: `SmartContractModule::ActiveNodeContracts` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SmartContractModule::Contracts` (r:0 w:n)
 	/// Proof: `SmartContractModule::Contracts` (`max_values`: None, `max_size`: None, mode: `Measured`)
-	/// Storage: `SmartContractModule::ContractLock` (r:0 w:1)
+	/// Storage: `SmartContractModule::ContractBillingInformationByID` (r:0 w:n)
+	/// Proof: `SmartContractModule::ContractBillingInformationByID` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SmartContractModule::ContractLock` (r:0 w:n)
 	/// Proof: `SmartContractModule::ContractLock` (`max_values`: None, `max_size`: None, mode: `Measured`)
-	fn create_rent_contract() -> Weight {
+	/// Storage: `TfgridModule::FarmOvercommitRatio` (r:n w:0)
+	/// Proof: `TfgridModule::FarmOvercommitRatio` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SmartContractModule::NodeReservedResources` (r:n w:n)
+	/// Proof: `SmartContractModule::NodeReservedResources` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SmartContractModule::ContractReservedResources` (r:0 w:n)
+	/// Proof: `SmartContractModule::ContractReservedResources` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// The range of component `n` is `[1, 20]`.
+	fn create_node_contracts_batch(n: u32, ) -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `776`
-		//  Estimated: `4241`
-		// Minimum execution time: 34_876_000 picoseconds.
-		Weight::from_parts(35_417_000, 4241)
-			.saturating_add(RocksDbWeight::get().reads(9_u64))
-			.saturating_add(RocksDbWeight::get().writes(5_u64))
+		//  Measured:  `868 + n * (96 ±0)`
+		//  Estimated: `4333 + n * (2571 ±0)`
+		// Minimum execution time: 104_512_000 picoseconds.
+		Weight::from_parts(58_932_104, 4333)
+			// Standard Error: 36_208
+			.saturating_add(Weight::from_parts(47_186_327, 0).saturating_mul(n.into()))
+			.saturating_add(RocksDbWeight::get().reads(6_u64))
+			.saturating_add(RocksDbWeight::get().reads((8_u64).saturating_mul(n.into())))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+			.saturating_add(RocksDbWeight::get().writes((7_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 2571).saturating_mul(n.into()))
 	}
-	/// Storage: `SmartContractModule::Contracts` (r:1 w:1)
-	/// Proof: `SmartContractModule::Contracts` (`max_values`: None, `max_size`: None, mode: `Measured`)
-	/// Storage: `TfgridModule::Twins` (r:1 w:0)
-	/// Proof: `TfgridModule::Twins` (`max_values`: None, `max_size`: None, mode: `Measured`)
-	/// Storage: `SmartContractModule::ActiveNodeContracts` (r:1 w:0)
-	/// Proof: `SmartContractModule::ActiveNodeContracts` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `TfgridModule::TwinIdByAccountID` (r:1 w:0)
+	/// Proof: `TfgridModule::TwinIdByAccountID` (`max_values`: None, `max_size`: None, mode: `Measured`)
 	/// Storage: `TfgridModule::Nodes` (r:1 w:0)
 	/// Proof: `TfgridModule::Nodes` (`max_values`: None, `max_size`: None, mode: `Measured`)
 	/// Storage: `TfgridModule::NodePower` (r:1 w:0)
 	/// Proof: `TfgridModule::NodePower` (`max_values`: None, `max_size`: None, mode: `Measured`)
-	/// Storage: `TfgridModule::TwinBoundedAccountID` (r:1 w:0)
-	/// Proof: `TfgridModule::TwinBoundedAccountID` (`max_values`: None, `max_size`: None, mode: `Measured`)
-	/// Storage: `Timestamp::Now` (r:1 w:0)
-	/// Proof: `Timestamp::Now` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
-	/// Storage: `SmartContractModule::ContractLock` (r:1 w:1)
-	/// Proof: `SmartContractModule::ContractLock` (`max_values`: None, `max_size`: None, mode: `Measured`)
-	/// Storage: `TfgridModule::PricingPolicies` (r:1 w:0)
-	/// Proof: `TfgridModule::PricingPolicies` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `TfgridModule::Farms` (r:1 w:1)
+	/// Proof: `TfgridModule::Farms` (`max_values`: None, `max_size`: None, mode: `Measured`)
 	/// Storage: `SmartContractModule::DedicatedNodesExtraFee` (r:1 w:0)
 	/// Proof: `SmartContractModule::DedicatedNodesExtraFee` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SmartContractModule::ActiveRentContractForNode` (r:1 w:0)
+	/// Proof: `SmartContractModule::ActiveRentContractForNode` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SmartContractModule::ContractIDByNodeIDAndHash` (r:1 w:1)
+	/// Proof: `SmartContractModule::ContractIDByNodeIDAndHash` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `TfgridModule::FarmOvercommitRatio` (r:1 w:0)
+	/// Proof: `TfgridModule::FarmOvercommitRatio` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SmartContractModule::NodeReservedResources` (r:1 w:1)
+	/// Proof: `SmartContractModule::NodeReservedResources` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SmartContractModule::ContractID` (r:1 w:1)
+	/// Proof: `SmartContractModule::ContractID` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
 	/// Storage: `SmartContractModule::BillingFrequency` (r:1 w:0)
 	/// Proof: `SmartContractModule::BillingFrequency` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
 	/// Storage: `SmartContractModule::ContractsToBillAt` (r:1 w:1)
 	/// Proof: `SmartContractModule::ContractsToBillAt` (`max_values`: None, `max_size`: None, mode: `Measured`)
-	/// Storage: `SmartContractModule::ActiveRentContractForNode` (r:0 w:1)
-	/// Proof: `SmartContractModule::ActiveRentContractForNode` (`max_values`: None, `max_size`: None, mode: `Measured`)
-	fn cancel_rent_contract() -> Weight {
+	/// Storage: `Timestamp::Now` (r:1 w:0)
+	/// Proof: `Timestamp::Now` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	/// Storage: `SmartContractModule::ActiveNodeContracts` (r:1 w:1)
+	/// Proof: `SmartContractModule::ActiveNodeContracts` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SmartContractModule::Contracts` (r:0 w:1)
+	/// Proof: `SmartContractModule::Contracts` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SmartContractModule::ContractBillingInformationByID` (r:0 w:1)
+	/// Proof: `SmartContractModule::ContractBillingInformationByID` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SmartContractModule::ContractLock` (r:0 w:1)
+	/// Proof: `SmartContractModule::ContractLock` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SmartContractModule::ContractReservedResources` (r:0 w:1)
+	/// Proof: `SmartContractModule::ContractReservedResources` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `TfgridModule::DecommissioningNodes` (r:1 w:0)
+	/// Proof: `TfgridModule::DecommissioningNodes` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `TfgridModule::NodeMaintenance` (r:1 w:0)
+	/// Proof: `TfgridModule::NodeMaintenance` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn create_node_contract_with_resources() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `1284`
-		//  Estimated: `4749`
-		// Minimum execution time: 60_275_000 picoseconds.
-		Weight::from_parts(60_955_000, 4749)
-			.saturating_add(RocksDbWeight::get().reads(12_u64))
-			.saturating_add(RocksDbWeight::get().writes(4_u64))
+		//  Measured:  `1012`
+		//  Estimated: `4477`
+		// Minimum execution time: 109_874_000 picoseconds.
+		Weight::from_parts(112_046_000, 4477)
+			.saturating_add(RocksDbWeight::get().reads(16_u64))
+			.saturating_add(RocksDbWeight::get().writes(10_u64))
 	}
-	/// Storage: `SmartContractModule::SolutionProviderID` (r:1 w:1)
-	/// Proof: `SmartContractModule::SolutionProviderID` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
-	/// Storage: `SmartContractModule::SolutionProviders` (r:0 w:1)
-	/// Proof: `SmartContractModule::SolutionProviders` (`max_values`: None, `max_size`: None, mode: `Measured`)
-	fn create_solution_provider() -> Weight {
+	/// Storage: `TfgridModule::TwinIdByAccountID` (r:1 w:0)
+	/// Proof: `TfgridModule::TwinIdByAccountID` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SmartContractModule::Contracts` (r:1 w:0)
+	/// Proof: `SmartContractModule::Contracts` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `TfgridModule::Twins` (r:1 w:0)
+	/// Proof: `TfgridModule::Twins` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SmartContractModule::ContractTransferOffers` (r:0 w:1)
+	/// Proof: `SmartContractModule::ContractTransferOffers` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn offer_contract_transfer() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `37`
-		//  Estimated: `1522`
-		// Minimum execution time: 10_290_000 picoseconds.
-		Weight::from_parts(10_560_000, 1522)
-			.saturating_add(RocksDbWeight::get().reads(1_u64))
-			.saturating_add(RocksDbWeight::get().writes(2_u64))
+		//  Measured:  `659`
+		//  Estimated: `4124`
+		// Minimum execution time: 24_117_000 picoseconds.
+		Weight::from_parts(24_785_000, 4124)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
 	}
-	/// Storage: `SmartContractModule::SolutionProviders` (r:1 w:1)
-	/// Proof: `SmartContractModule::SolutionProviders` (`max_values`: None, `max_size`: None, mode: `Measured`)
-	fn approve_solution_provider() -> Weight {
+	/// Storage: `TfgridModule::TwinIdByAccountID` (r:1 w:0)
+	/// Proof: `TfgridModule::TwinIdByAccountID` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SmartContractModule::Contracts` (r:1 w:0)
+	/// Proof: `SmartContractModule::Contracts` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SmartContractModule::ContractTransferOffers` (r:1 w:1)
+	/// Proof: `SmartContractModule::ContractTransferOffers` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn cancel_contract_transfer() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `215`
-		//  Estimated: `3680`
-		// Minimum execution time: 13_215_000 picoseconds.
-		Weight::from_parts(13_535_000, 3680)
-			.saturating_add(RocksDbWeight::get().reads(1_u64))
+		//  Measured:  `702`
+		//  Estimated: `4167`
+		// Minimum execution time: 20_432_000 picoseconds.
+		Weight::from_parts(21_018_000, 4167)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
 			.saturating_add(RocksDbWeight::get().writes(1_u64))
 	}
-	/// Storage: `SmartContractModule::Contracts` (r:1 w:0)
+	/// Storage: `TfgridModule::TwinIdByAccountID` (r:1 w:0)
+	/// Proof: `TfgridModule::TwinIdByAccountID` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SmartContractModule::ContractTransferOffers` (r:1 w:1)
+	/// Proof: `SmartContractModule::ContractTransferOffers` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SmartContractModule::Contracts` (r:1 w:1)
 	/// Proof: `SmartContractModule::Contracts` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SmartContractModule::ActiveRentContractForNode` (r:1 w:0)
+	/// Proof: `SmartContractModule::ActiveRentContractForNode` (`max_values`: None, `max_size`: None, mode: `Measured`)
 	/// Storage: `TfgridModule::Twins` (r:1 w:0)
 	/// Proof: `TfgridModule::Twins` (`max_values`: None, `max_size`: None, mode: `Measured`)
-	/// Storage: `System::Account` (r:1 w:1)
-	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
-	/// Storage: `TfgridModule::TwinBoundedAccountID` (r:1 w:0)
-	/// Proof: `TfgridModule::TwinBoundedAccountID` (`max_values`: None, `max_size`: None, mode: `Measured`)
 	/// Storage: `Timestamp::Now` (r:1 w:0)
 	/// Proof: `Timestamp::Now` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
 	/// Storage: `SmartContractModule::ContractLock` (r:1 w:1)
 	/// Proof: `SmartContractModule::ContractLock` (`max_values`: None, `max_size`: None, mode: `Measured`)
-	/// Storage: `TfgridModule::PricingPolicies` (r:1 w:0)
-	/// Proof: `TfgridModule::PricingPolicies` (`max_values`: None, `max_size`: None, mode: `Measured`)
-	/// Storage: `SmartContractModule::ContractBillingInformationByID` (r:1 w:1)
-	/// Proof: `SmartContractModule::ContractBillingInformationByID` (`max_values`: None, `max_size`: None, mode: `Measured`)
 	/// Storage: `TfgridModule::Nodes` (r:1 w:0)
 	/// Proof: `TfgridModule::Nodes` (`max_values`: None, `max_size`: None, mode: `Measured`)
-	/// Storage: `SmartContractModule::NodeContractResources` (r:1 w:0)
-	/// Proof: `SmartContractModule::NodeContractResources` (`max_values`: None, `max_size`: None, mode: `Measured`)
-	/// Storage: `SmartContractModule::ActiveRentContractForNode` (r:1 w:0)
-	/// Proof: `SmartContractModule::ActiveRentContractForNode` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `TfgridModule::Farms` (r:1 w:0)
+	/// Proof: `TfgridModule::Farms` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `TfgridModule::PricingPolicies` (r:1 w:0)
+	/// Proof: `TfgridModule::PricingPolicies` (`max_values`: None, `max_size`: None, mode: `Measured`)
 	/// Storage: `TFTPriceModule::AverageTftPrice` (r:1 w:0)
 	/// Proof: `TFTPriceModule::AverageTftPrice` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
-	/// Storage: `TFTPriceModule::MinTftPrice` (r:1 w:0)
-	/// Proof: `TFTPriceModule::MinTftPrice` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
-	/// Storage: `TFTPriceModule::MaxTftPrice` (r:1 w:0)
-	/// Proof: `TFTPriceModule::MaxTftPrice` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
-	/// Storage: `Balances::Locks` (r:1 w:1)
-	/// Proof: `Balances::Locks` (`max_values`: None, `max_size`: Some(1299), added: 3774, mode: `MaxEncodedLen`)
-	/// Storage: `Balances::Freezes` (r:1 w:0)
-	/// Proof: `Balances::Freezes` (`max_values`: None, `max_size`: Some(49), added: 2524, mode: `MaxEncodedLen`)
-	fn bill_contract_for_block() -> Weight {
+	/// Storage: `SmartContractModule::ContractSpendingCap` (r:1 w:1)
+	/// Proof: `SmartContractModule::ContractSpendingCap` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SmartContractModule::ContractBillingHistoryCount` (r:1 w:1)
+	/// Proof: `SmartContractModule::ContractBillingHistoryCount` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SmartContractModule::ContractBillingHistory` (r:0 w:1)
+	/// Proof: `SmartContractModule::ContractBillingHistory` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SmartContractModule::TwinBillingHistory` (r:1 w:1)
+	/// Proof: `SmartContractModule::TwinBillingHistory` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SmartContractModule::TwinBillingDays` (r:1 w:1)
+	/// Proof: `SmartContractModule::TwinBillingDays` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `System::Account` (r:2 w:2)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	fn accept_contract_transfer() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `1608`
-		//  Estimated: `5073`
-		// Minimum execution time: 80_642_000 picoseconds.
-		Weight::from_parts(81_735_000, 5073)
-			.saturating_add(RocksDbWeight::get().reads(16_u64))
-			.saturating_add(RocksDbWeight::get().writes(4_u64))
+		//  Measured:  `1854`
+		//  Estimated: `5319`
+		// Minimum execution time: 118_930_000 picoseconds.
+		Weight::from_parts(121_477_000, 5319)
+			.saturating_add(RocksDbWeight::get().reads(17_u64))
+			.saturating_add(RocksDbWeight::get().writes(10_u64))
 	}
-	/// Storage: `TfgridModule::TwinIdByAccountID` (r:2 w:0)
+	/// Storage: `TfgridModule::TwinIdByAccountID` (r:1 w:0)
 	/// Proof: `TfgridModule::TwinIdByAccountID` (`max_values`: None, `max_size`: None, mode: `Measured`)
-	/// Storage: `SmartContractModule::ServiceContractID` (r:1 w:1)
-	/// Proof: `SmartContractModule::ServiceContractID` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
-	/// Storage: `SmartContractModule::ServiceContracts` (r:0 w:1)
+	/// Storage: `SmartContractModule::ServiceContracts` (r:1 w:0)
 	/// Proof: `SmartContractModule::ServiceContracts` (`max_values`: None, `max_size`: None, mode: `Measured`)
-	fn service_contract_create() -> Weight {
+	/// Storage: `SmartContractModule::ServiceContractDisputeWindow` (r:0 w:1)
+	/// Proof: `SmartContractModule::ServiceContractDisputeWindow` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn service_contract_set_dispute_window() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `502`
+		//  Estimated: `3967`
+		// Minimum execution time: 16_903_000 picoseconds.
+		Weight::from_parts(17_322_000, 3967)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `TfgridModule::TwinIdByAccountID` (r:1 w:0)
+	/// Proof: `TfgridModule::TwinIdByAccountID` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SmartContractModule::ServiceContractEscrowedBills` (r:1 w:1)
+	/// Proof: `SmartContractModule::ServiceContractEscrowedBills` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Timestamp::Now` (r:1 w:0)
+	/// Proof: `Timestamp::Now` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	fn service_contract_dispute_bill() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `418`
+		//  Estimated: `3883`
+		// Minimum execution time: 15_482_000 picoseconds.
+		Weight::from_parts(15_911_000, 3883)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `TfgridModule::TwinIdByAccountID` (r:1 w:0)
+	/// Proof: `TfgridModule::TwinIdByAccountID` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SmartContractModule::ServiceContractEscrowedBills` (r:1 w:1)
+	/// Proof: `SmartContractModule::ServiceContractEscrowedBills` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `TfgridModule::Twins` (r:2 w:0)
+	/// Proof: `TfgridModule::Twins` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `System::Account` (r:2 w:2)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	fn service_contract_resolve_dispute() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `731`
+		//  Estimated: `6671`
+		// Minimum execution time: 38_120_000 picoseconds.
+		Weight::from_parts(39_004_000, 6671)
+			.saturating_add(RocksDbWeight::get().reads(6_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+	/// Storage: `SmartContractModule::ServiceContractEscrowedBills` (r:1 w:1)
+	/// Proof: `SmartContractModule::ServiceContractEscrowedBills` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `TfgridModule::Twins` (r:2 w:0)
+	/// Proof: `TfgridModule::Twins` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `System::Account` (r:2 w:2)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	fn service_contract_force_resolve_dispute() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `663`
+		//  Estimated: `6603`
+		// Minimum execution time: 35_274_000 picoseconds.
+		Weight::from_parts(36_018_000, 6603)
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+	/// Storage: `TfgridModule::TwinIdByAccountID` (r:1 w:0)
+	/// Proof: `TfgridModule::TwinIdByAccountID` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SmartContractModule::ServiceContractEscrowedBills` (r:1 w:1)
+	/// Proof: `SmartContractModule::ServiceContractEscrowedBills` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Timestamp::Now` (r:1 w:0)
+	/// Proof: `Timestamp::Now` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	/// Storage: `TfgridModule::Twins` (r:2 w:0)
+	/// Proof: `TfgridModule::Twins` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `System::Account` (r:2 w:2)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	fn service_contract_release_bill() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `731`
+		//  Estimated: `6671`
+		// Minimum execution time: 36_551_000 picoseconds.
+		Weight::from_parts(37_240_000, 6671)
+			.saturating_add(RocksDbWeight::get().reads(7_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+	/// Storage: `SmartContractModule::ServiceContracts` (r:1 w:1)
+	/// Proof: `SmartContractModule::ServiceContracts` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Timestamp::Now` (r:1 w:0)
+	/// Proof: `Timestamp::Now` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	/// Storage: `TFTPriceModule::AverageTftPrice` (r:1 w:0)
+	/// Proof: `TFTPriceModule::AverageTftPrice` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `TfgridModule::Twins` (r:2 w:0)
+	/// Proof: `TfgridModule::Twins` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `System::Account` (r:2 w:2)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `SmartContractModule::ServiceContractDisputeWindow` (r:1 w:0)
+	/// Proof: `SmartContractModule::ServiceContractDisputeWindow` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SmartContractModule::ServiceContractGracePeriodStart` (r:1 w:1)
+	/// Proof: `SmartContractModule::ServiceContractGracePeriodStart` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SmartContractModule::ContractKindGracePeriod` (r:1 w:0)
+	/// Proof: `SmartContractModule::ContractKindGracePeriod` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn bill_service_contract_for_block() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `395`
-		//  Estimated: `6335`
-		// Minimum execution time: 18_926_000 picoseconds.
-		Weight::from_parts(19_386_000, 6335)
-			.saturating_add(RocksDbWeight::get().reads(3_u64))
-			.saturating_add(RocksDbWeight::get().writes(2_u64))
+		//  Measured:  `893`
+		//  Estimated: `6833`
+		// Minimum execution time: 41_733_000 picoseconds.
+		Weight::from_parts(42_517_000, 6833)
+			.saturating_add(RocksDbWeight::get().reads(10_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
 	}
 	/// Storage: `TfgridModule::TwinIdByAccountID` (r:1 w:0)
 	/// Proof: `TfgridModule::TwinIdByAccountID` (`max_values`: None, `max_size`: None, mode: `Measured`)
-	/// Storage: `SmartContractModule::ServiceContracts` (r:1 w:1)
+	/// Storage: `SmartContractModule::ServiceContracts` (r:1 w:0)
 	/// Proof: `SmartContractModule::ServiceContracts` (`max_values`: None, `max_size`: None, mode: `Measured`)
-	fn service_contract_set_metadata() -> Weight {
+	/// Storage: `SmartContractModule::ServiceContractAmendments` (r:0 w:1)
+	/// Proof: `SmartContractModule::ServiceContractAmendments` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn service_contract_propose_amendment() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `489`
-		//  Estimated: `3954`
-		// Minimum execution time: 16_892_000 picoseconds.
-		Weight::from_parts(17_293_000, 3954)
+		//  Measured:  `502`
+		//  Estimated: `3967`
+		// Minimum execution time: 17_268_000 picoseconds.
+		Weight::from_parts(17_735_000, 3967)
 			.saturating_add(RocksDbWeight::get().reads(2_u64))
 			.saturating_add(RocksDbWeight::get().writes(1_u64))
 	}
@@ -900,151 +2534,399 @@ impl WeightInfo for () {
 	/// Proof: `TfgridModule::TwinIdByAccountID` (`max_values`: None, `max_size`: None, mode: `Measured`)
 	/// Storage: `SmartContractModule::ServiceContracts` (r:1 w:1)
 	/// Proof: `SmartContractModule::ServiceContracts` (`max_values`: None, `max_size`: None, mode: `Measured`)
-	fn service_contract_set_fees() -> Weight {
+	/// Storage: `SmartContractModule::ServiceContractAmendments` (r:1 w:1)
+	/// Proof: `SmartContractModule::ServiceContractAmendments` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Timestamp::Now` (r:1 w:0)
+	/// Proof: `Timestamp::Now` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	/// Storage: `TFTPriceModule::AverageTftPrice` (r:1 w:0)
+	/// Proof: `TFTPriceModule::AverageTftPrice` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `TfgridModule::Twins` (r:2 w:0)
+	/// Proof: `TfgridModule::Twins` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `System::Account` (r:2 w:2)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `SmartContractModule::ServiceContractDisputeWindow` (r:1 w:0)
+	/// Proof: `SmartContractModule::ServiceContractDisputeWindow` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SmartContractModule::ServiceContractGracePeriodStart` (r:1 w:1)
+	/// Proof: `SmartContractModule::ServiceContractGracePeriodStart` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SmartContractModule::ServiceContractLastVariableBill` (r:1 w:1)
+	/// Proof: `SmartContractModule::ServiceContractLastVariableBill` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SmartContractModule::ServiceContractPendingVariableBill` (r:1 w:1)
+	/// Proof: `SmartContractModule::ServiceContractPendingVariableBill` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn service_contract_accept_amendment() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `489`
-		//  Estimated: `3954`
-		// Minimum execution time: 16_562_000 picoseconds.
-		Weight::from_parts(16_732_000, 3954)
-			.saturating_add(RocksDbWeight::get().reads(2_u64))
-			.saturating_add(RocksDbWeight::get().writes(1_u64))
+		//  Measured:  `1010`
+		//  Estimated: `6950`
+		// Minimum execution time: 48_391_000 picoseconds.
+		Weight::from_parts(49_262_000, 6950)
+			.saturating_add(RocksDbWeight::get().reads(13_u64))
+			.saturating_add(RocksDbWeight::get().writes(7_u64))
 	}
 	/// Storage: `TfgridModule::TwinIdByAccountID` (r:1 w:0)
 	/// Proof: `TfgridModule::TwinIdByAccountID` (`max_values`: None, `max_size`: None, mode: `Measured`)
-	/// Storage: `SmartContractModule::ServiceContracts` (r:1 w:1)
+	/// Storage: `SmartContractModule::ServiceContracts` (r:1 w:0)
 	/// Proof: `SmartContractModule::ServiceContracts` (`max_values`: None, `max_size`: None, mode: `Measured`)
-	fn service_contract_approve() -> Weight {
+	/// Storage: `SmartContractModule::ServiceContractAmendments` (r:1 w:1)
+	/// Proof: `SmartContractModule::ServiceContractAmendments` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn service_contract_reject_amendment() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `502`
-		//  Estimated: `3967`
-		// Minimum execution time: 16_821_000 picoseconds.
-		Weight::from_parts(17_292_000, 3967)
-			.saturating_add(RocksDbWeight::get().reads(2_u64))
+		//  Measured:  `601`
+		//  Estimated: `4066`
+		// Minimum execution time: 18_547_000 picoseconds.
+		Weight::from_parts(19_030_000, 4066)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
 			.saturating_add(RocksDbWeight::get().writes(1_u64))
 	}
-	/// Storage: `SmartContractModule::ServiceContracts` (r:1 w:1)
-	/// Proof: `SmartContractModule::ServiceContracts` (`max_values`: None, `max_size`: None, mode: `Measured`)
 	/// Storage: `TfgridModule::TwinIdByAccountID` (r:1 w:0)
 	/// Proof: `TfgridModule::TwinIdByAccountID` (`max_values`: None, `max_size`: None, mode: `Measured`)
-	fn service_contract_reject() -> Weight {
+	/// Storage: `SmartContractModule::ServicePlanID` (r:1 w:1)
+	/// Proof: `SmartContractModule::ServicePlanID` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `SmartContractModule::ServicePlans` (r:0 w:1)
+	/// Proof: `SmartContractModule::ServicePlans` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn service_plan_create() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `502`
-		//  Estimated: `3967`
-		// Minimum execution time: 17_693_000 picoseconds.
-		Weight::from_parts(18_014_000, 3967)
+		//  Measured:  `283`
+		//  Estimated: `3748`
+		// Minimum execution time: 14_672_000 picoseconds.
+		Weight::from_parts(15_108_000, 3748)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// Storage: `TfgridModule::TwinIdByAccountID` (r:1 w:0)
+	/// Proof: `TfgridModule::TwinIdByAccountID` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SmartContractModule::ServicePlans` (r:1 w:1)
+	/// Proof: `SmartContractModule::ServicePlans` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn service_plan_update() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `471`
+		//  Estimated: `3936`
+		// Minimum execution time: 16_215_000 picoseconds.
+		Weight::from_parts(16_630_000, 3936)
 			.saturating_add(RocksDbWeight::get().reads(2_u64))
 			.saturating_add(RocksDbWeight::get().writes(1_u64))
 	}
 	/// Storage: `TfgridModule::TwinIdByAccountID` (r:1 w:0)
 	/// Proof: `TfgridModule::TwinIdByAccountID` (`max_values`: None, `max_size`: None, mode: `Measured`)
-	/// Storage: `SmartContractModule::ServiceContracts` (r:1 w:1)
-	/// Proof: `SmartContractModule::ServiceContracts` (`max_values`: None, `max_size`: None, mode: `Measured`)
-	fn service_contract_cancel() -> Weight {
+	/// Storage: `SmartContractModule::ServicePlans` (r:1 w:1)
+	/// Proof: `SmartContractModule::ServicePlans` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn service_plan_retire() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `502`
-		//  Estimated: `3967`
-		// Minimum execution time: 16_401_000 picoseconds.
-		Weight::from_parts(16_662_000, 3967)
+		//  Measured:  `471`
+		//  Estimated: `3936`
+		// Minimum execution time: 15_842_000 picoseconds.
+		Weight::from_parts(16_291_000, 3936)
 			.saturating_add(RocksDbWeight::get().reads(2_u64))
 			.saturating_add(RocksDbWeight::get().writes(1_u64))
 	}
 	/// Storage: `TfgridModule::TwinIdByAccountID` (r:1 w:0)
 	/// Proof: `TfgridModule::TwinIdByAccountID` (`max_values`: None, `max_size`: None, mode: `Measured`)
-	/// Storage: `SmartContractModule::ServiceContracts` (r:1 w:1)
-	/// Proof: `SmartContractModule::ServiceContracts` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SmartContractModule::ServicePlans` (r:1 w:0)
+	/// Proof: `SmartContractModule::ServicePlans` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SmartContractModule::ServiceContractID` (r:1 w:1)
+	/// Proof: `SmartContractModule::ServiceContractID` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
 	/// Storage: `Timestamp::Now` (r:1 w:0)
 	/// Proof: `Timestamp::Now` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
-	/// Storage: `TfgridModule::Twins` (r:2 w:0)
-	/// Proof: `TfgridModule::Twins` (`max_values`: None, `max_size`: None, mode: `Measured`)
-	/// Storage: `System::Account` (r:1 w:0)
+	/// Storage: `SmartContractModule::ServiceContractsToBillAt` (r:1 w:1)
+	/// Proof: `SmartContractModule::ServiceContractsToBillAt` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SmartContractModule::ServiceContracts` (r:0 w:1)
+	/// Proof: `SmartContractModule::ServiceContracts` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SmartContractModule::ServiceContractPlan` (r:0 w:1)
+	/// Proof: `SmartContractModule::ServiceContractPlan` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SmartContractModule::ServiceContractLastVariableBill` (r:0 w:1)
+	/// Proof: `SmartContractModule::ServiceContractLastVariableBill` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn service_plan_subscribe() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `564`
+		//  Estimated: `4029`
+		// Minimum execution time: 24_937_000 picoseconds.
+		Weight::from_parts(25_580_000, 4029)
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
+			.saturating_add(RocksDbWeight::get().writes(5_u64))
+	}
+	/// Storage: `SmartContractModule::SolutionProviderRewardsAccrual` (r:0 w:1)
+	/// Proof: `SmartContractModule::SolutionProviderRewardsAccrual` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn set_solution_provider_rewards_accrual() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 5_861_000 picoseconds.
+		Weight::from_parts(6_092_000, 0)
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `SmartContractModule::UnclaimedSolutionProviderRewards` (r:1 w:1)
+	/// Proof: `SmartContractModule::UnclaimedSolutionProviderRewards` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `System::Account` (r:2 w:2)
 	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
-	fn service_contract_bill() -> Weight {
+	fn claim_solution_provider_rewards() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `841`
-		//  Estimated: `6781`
-		// Minimum execution time: 30_327_000 picoseconds.
-		Weight::from_parts(31_189_000, 6781)
-			.saturating_add(RocksDbWeight::get().reads(6_u64))
+		//  Measured:  `244`
+		//  Estimated: `6196`
+		// Minimum execution time: 52_304_000 picoseconds.
+		Weight::from_parts(53_671_000, 6196)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+	/// Storage: `SmartContractModule::SolutionProviders` (r:1 w:1)
+	/// Proof: `SmartContractModule::SolutionProviders` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn update_solution_provider() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `222`
+		//  Estimated: `3687`
+		// Minimum execution time: 14_277_000 picoseconds.
+		Weight::from_parts(14_838_000, 3687)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
 			.saturating_add(RocksDbWeight::get().writes(1_u64))
 	}
-	/// Storage: `SmartContractModule::BillingFrequency` (r:1 w:1)
-	/// Proof: `SmartContractModule::BillingFrequency` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
-	fn change_billing_frequency() -> Weight {
+	/// Storage: `SmartContractModule::SolutionProviders` (r:1 w:1)
+	/// Proof: `SmartContractModule::SolutionProviders` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn revoke_solution_provider() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `37`
-		//  Estimated: `1522`
-		// Minimum execution time: 7_855_000 picoseconds.
-		Weight::from_parts(8_076_000, 1522)
+		//  Measured:  `222`
+		//  Estimated: `3687`
+		// Minimum execution time: 12_914_000 picoseconds.
+		Weight::from_parts(13_395_000, 3687)
 			.saturating_add(RocksDbWeight::get().reads(1_u64))
 			.saturating_add(RocksDbWeight::get().writes(1_u64))
 	}
-	/// Storage: `SmartContractModule::SolutionProviders` (r:1 w:0)
+	/// Storage: `SmartContractModule::SolutionProviders` (r:1 w:1)
 	/// Proof: `SmartContractModule::SolutionProviders` (`max_values`: None, `max_size`: None, mode: `Measured`)
-	/// Storage: `SmartContractModule::Contracts` (r:1 w:1)
+	/// Storage: `Timestamp::Now` (r:1 w:0)
+	/// Proof: `Timestamp::Now` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	fn set_solution_provider_expiry() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `222`
+		//  Estimated: `3687`
+		// Minimum execution time: 14_087_000 picoseconds.
+		Weight::from_parts(14_618_000, 3687)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `TfgridModule::TwinIdByAccountID` (r:1 w:0)
+	/// Proof: `TfgridModule::TwinIdByAccountID` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SmartContractModule::ContractIDByNameRegistration` (r:1 w:1)
+	/// Proof: `SmartContractModule::ContractIDByNameRegistration` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SmartContractModule::ContractID` (r:1 w:1)
+	/// Proof: `SmartContractModule::ContractID` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `SmartContractModule::BillingFrequency` (r:1 w:0)
+	/// Proof: `SmartContractModule::BillingFrequency` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `SmartContractModule::ContractsToBillAt` (r:1 w:1)
+	/// Proof: `SmartContractModule::ContractsToBillAt` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Timestamp::Now` (r:1 w:0)
+	/// Proof: `Timestamp::Now` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	/// Storage: `SmartContractModule::Contracts` (r:0 w:1)
 	/// Proof: `SmartContractModule::Contracts` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SmartContractModule::ContractLock` (r:0 w:1)
+	/// Proof: `SmartContractModule::ContractLock` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SmartContractModule::NameContractExpiry` (r:0 w:1)
+	/// Proof: `SmartContractModule::NameContractExpiry` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SmartContractModule::NameRestrictions` (r:5 w:0)
+	/// Proof: `SmartContractModule::NameRestrictions` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn create_name_contract_with_term() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `340`
+		//  Estimated: `3805`
+		// Minimum execution time: 26_433_000 picoseconds.
+		Weight::from_parts(27_104_000, 3805)
+			.saturating_add(RocksDbWeight::get().reads(11_u64))
+			.saturating_add(RocksDbWeight::get().writes(6_u64))
+	}
 	/// Storage: `TfgridModule::TwinIdByAccountID` (r:1 w:0)
 	/// Proof: `TfgridModule::TwinIdByAccountID` (`max_values`: None, `max_size`: None, mode: `Measured`)
-	fn attach_solution_provider_id() -> Weight {
+	/// Storage: `SmartContractModule::Contracts` (r:1 w:0)
+	/// Proof: `SmartContractModule::Contracts` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SmartContractModule::NameContractExpiry` (r:1 w:1)
+	/// Proof: `SmartContractModule::NameContractExpiry` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Timestamp::Now` (r:1 w:0)
+	/// Proof: `Timestamp::Now` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	/// Storage: `SmartContractModule::ContractLock` (r:1 w:1)
+	/// Proof: `SmartContractModule::ContractLock` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn renew_name_contract() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `971`
-		//  Estimated: `4436`
-		// Minimum execution time: 22_222_000 picoseconds.
-		Weight::from_parts(22_462_000, 4436)
-			.saturating_add(RocksDbWeight::get().reads(3_u64))
+		//  Measured:  `512`
+		//  Estimated: `3977`
+		// Minimum execution time: 16_052_000 picoseconds.
+		Weight::from_parts(16_593_000, 3977)
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// Storage: `SmartContractModule::NameContractHoldPeriod` (r:0 w:1)
+	/// Proof: `SmartContractModule::NameContractHoldPeriod` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	fn set_name_contract_hold_period() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 5_470_000 picoseconds.
+		Weight::from_parts(5_741_000, 0)
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `SmartContractModule::NameRestrictions` (r:1 w:1)
+	/// Proof: `SmartContractModule::NameRestrictions` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn add_name_restriction() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `6`
+		//  Estimated: `3471`
+		// Minimum execution time: 9_684_000 picoseconds.
+		Weight::from_parts(10_112_000, 3471)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `SmartContractModule::NameRestrictions` (r:1 w:1)
+	/// Proof: `SmartContractModule::NameRestrictions` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn remove_name_restriction() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `140`
+		//  Estimated: `3605`
+		// Minimum execution time: 11_912_000 picoseconds.
+		Weight::from_parts(12_463_000, 3605)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `SmartContractModule::NameRestrictions` (r:1 w:1)
+	/// Proof: `SmartContractModule::NameRestrictions` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `TfgridModule::Twins` (r:1 w:0)
+	/// Proof: `TfgridModule::Twins` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn assign_reserved_name() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `523`
+		//  Estimated: `3988`
+		// Minimum execution time: 15_381_000 picoseconds.
+		Weight::from_parts(15_907_000, 3988)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
 			.saturating_add(RocksDbWeight::get().writes(1_u64))
 	}
 	/// Storage: `TfgridModule::TwinIdByAccountID` (r:1 w:0)
 	/// Proof: `TfgridModule::TwinIdByAccountID` (`max_values`: None, `max_size`: None, mode: `Measured`)
 	/// Storage: `TfgridModule::Nodes` (r:1 w:0)
 	/// Proof: `TfgridModule::Nodes` (`max_values`: None, `max_size`: None, mode: `Measured`)
-	/// Storage: `TfgridModule::Farms` (r:1 w:0)
+	/// Storage: `TfgridModule::Farms` (r:1 w:1)
 	/// Proof: `TfgridModule::Farms` (`max_values`: None, `max_size`: None, mode: `Measured`)
-	/// Storage: `SmartContractModule::ActiveNodeContracts` (r:1 w:0)
-	/// Proof: `SmartContractModule::ActiveNodeContracts` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `TfgridModule::NodePower` (r:1 w:0)
+	/// Proof: `TfgridModule::NodePower` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SmartContractModule::DedicatedNodesExtraFee` (r:1 w:0)
+	/// Proof: `SmartContractModule::DedicatedNodesExtraFee` (`max_values`: None, `max_size`: None, mode: `Measured`)
 	/// Storage: `SmartContractModule::ActiveRentContractForNode` (r:1 w:0)
 	/// Proof: `SmartContractModule::ActiveRentContractForNode` (`max_values`: None, `max_size`: None, mode: `Measured`)
-	/// Storage: `SmartContractModule::DedicatedNodesExtraFee` (r:0 w:1)
-	/// Proof: `SmartContractModule::DedicatedNodesExtraFee` (`max_values`: None, `max_size`: None, mode: `Measured`)
-	fn set_dedicated_node_extra_fee() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `733`
-		//  Estimated: `4198`
-		// Minimum execution time: 23_424_000 picoseconds.
-		Weight::from_parts(23_775_000, 4198)
-			.saturating_add(RocksDbWeight::get().reads(5_u64))
-			.saturating_add(RocksDbWeight::get().writes(1_u64))
-	}
-	/// Storage: `SmartContractModule::Contracts` (r:1 w:1)
-	/// Proof: `SmartContractModule::Contracts` (`max_values`: None, `max_size`: None, mode: `Measured`)
-	/// Storage: `SmartContractModule::ActiveNodeContracts` (r:1 w:1)
-	/// Proof: `SmartContractModule::ActiveNodeContracts` (`max_values`: None, `max_size`: None, mode: `Measured`)
-	/// Storage: `TfgridModule::Twins` (r:1 w:0)
-	/// Proof: `TfgridModule::Twins` (`max_values`: None, `max_size`: None, mode: `Measured`)
-	/// Storage: `TfgridModule::TwinBoundedAccountID` (r:1 w:0)
-	/// Proof: `TfgridModule::TwinBoundedAccountID` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SmartContractModule::ContractIDByNodeIDAndHash` (r:1 w:1)
+	/// Proof: `SmartContractModule::ContractIDByNodeIDAndHash` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `TfgridModule::FarmOvercommitRatio` (r:1 w:0)
+	/// Proof: `TfgridModule::FarmOvercommitRatio` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SmartContractModule::NodeReservedResources` (r:1 w:1)
+	/// Proof: `SmartContractModule::NodeReservedResources` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SmartContractModule::ContractID` (r:1 w:1)
+	/// Proof: `SmartContractModule::ContractID` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `SmartContractModule::BillingFrequency` (r:1 w:0)
+	/// Proof: `SmartContractModule::BillingFrequency` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `SmartContractModule::ContractsToBillAt` (r:1 w:1)
+	/// Proof: `SmartContractModule::ContractsToBillAt` (`max_values`: None, `max_size`: None, mode: `Measured`)
 	/// Storage: `Timestamp::Now` (r:1 w:0)
 	/// Proof: `Timestamp::Now` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
-	/// Storage: `SmartContractModule::ContractLock` (r:1 w:1)
+	/// Storage: `SmartContractModule::ActiveNodeContracts` (r:1 w:1)
+	/// Proof: `SmartContractModule::ActiveNodeContracts` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SmartContractModule::Contracts` (r:0 w:1)
+	/// Proof: `SmartContractModule::Contracts` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SmartContractModule::ContractBillingInformationByID` (r:0 w:1)
+	/// Proof: `SmartContractModule::ContractBillingInformationByID` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SmartContractModule::ContractLock` (r:0 w:1)
 	/// Proof: `SmartContractModule::ContractLock` (`max_values`: None, `max_size`: None, mode: `Measured`)
-	/// Storage: `TfgridModule::PricingPolicies` (r:1 w:0)
-	/// Proof: `TfgridModule::PricingPolicies` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SmartContractModule::ContractReservedResources` (r:0 w:1)
+	/// Proof: `SmartContractModule::ContractReservedResources` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `TfgridModule::DecommissioningNodes` (r:1 w:0)
+	/// Proof: `TfgridModule::DecommissioningNodes` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `TfgridModule::NodeMaintenance` (r:1 w:0)
+	/// Proof: `TfgridModule::NodeMaintenance` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn create_node_contract_with_public_ips() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1012`
+		//  Estimated: `4477`
+		// Minimum execution time: 112_481_000 picoseconds.
+		Weight::from_parts(114_830_000, 4477)
+			.saturating_add(RocksDbWeight::get().reads(16_u64))
+			.saturating_add(RocksDbWeight::get().writes(10_u64))
+	}
+	/// Storage: `TfgridModule::TwinIdByAccountID` (r:1 w:0)
+	/// Proof: `TfgridModule::TwinIdByAccountID` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `TfgridModule::Farms` (r:1 w:0)
+	/// Proof: `TfgridModule::Farms` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SmartContractModule::PublicIpPriceMultiplier` (r:0 w:1)
+	/// Proof: `SmartContractModule::PublicIpPriceMultiplier` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn set_public_ip_price_multiplier() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `568`
+		//  Estimated: `4033`
+		// Minimum execution time: 17_212_000 picoseconds.
+		Weight::from_parts(17_756_000, 4033)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `TfgridModule::TwinIdByAccountID` (r:1 w:0)
+	/// Proof: `TfgridModule::TwinIdByAccountID` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `TfgridModule::Nodes` (r:1 w:0)
+	/// Proof: `TfgridModule::Nodes` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `TfgridModule::Farms` (r:1 w:0)
+	/// Proof: `TfgridModule::Farms` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `TfgridModule::NodePower` (r:1 w:0)
+	/// Proof: `TfgridModule::NodePower` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SmartContractModule::DedicatedNodesExtraFee` (r:1 w:0)
+	/// Proof: `SmartContractModule::DedicatedNodesExtraFee` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SmartContractModule::ActiveRentContractForNode` (r:1 w:0)
+	/// Proof: `SmartContractModule::ActiveRentContractForNode` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SmartContractModule::ContractIDByNodeIDAndHash` (r:1 w:1)
+	/// Proof: `SmartContractModule::ContractIDByNodeIDAndHash` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SmartContractModule::ContractID` (r:1 w:1)
+	/// Proof: `SmartContractModule::ContractID` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
 	/// Storage: `SmartContractModule::BillingFrequency` (r:1 w:0)
 	/// Proof: `SmartContractModule::BillingFrequency` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
 	/// Storage: `SmartContractModule::ContractsToBillAt` (r:1 w:1)
 	/// Proof: `SmartContractModule::ContractsToBillAt` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `TfgridModule::FarmIp6Prefixes` (r:1 w:1)
+	/// Proof: `TfgridModule::FarmIp6Prefixes` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SmartContractModule::DelegatedIp6Subnets` (r:1 w:1)
+	/// Proof: `SmartContractModule::DelegatedIp6Subnets` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Timestamp::Now` (r:1 w:0)
+	/// Proof: `Timestamp::Now` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	/// Storage: `SmartContractModule::ActiveNodeContracts` (r:1 w:1)
+	/// Proof: `SmartContractModule::ActiveNodeContracts` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SmartContractModule::Contracts` (r:0 w:1)
+	/// Proof: `SmartContractModule::Contracts` (`max_values`: None, `max_size`: None, mode: `Measured`)
 	/// Storage: `SmartContractModule::ContractBillingInformationByID` (r:0 w:1)
 	/// Proof: `SmartContractModule::ContractBillingInformationByID` (`max_values`: None, `max_size`: None, mode: `Measured`)
-	/// Storage: `SmartContractModule::NodeContractResources` (r:0 w:1)
-	/// Proof: `SmartContractModule::NodeContractResources` (`max_values`: None, `max_size`: None, mode: `Measured`)
-	/// Storage: `SmartContractModule::ContractIDByNodeIDAndHash` (r:0 w:1)
-	/// Proof: `SmartContractModule::ContractIDByNodeIDAndHash` (`max_values`: None, `max_size`: None, mode: `Measured`)
-	fn cancel_contract_collective() -> Weight {
+	/// Storage: `SmartContractModule::ContractLock` (r:0 w:1)
+	/// Proof: `SmartContractModule::ContractLock` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SmartContractModule::ContractIp6Subnets` (r:0 w:1)
+	/// Proof: `SmartContractModule::ContractIp6Subnets` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `TfgridModule::DecommissioningNodes` (r:1 w:0)
+	/// Proof: `TfgridModule::DecommissioningNodes` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `TfgridModule::NodeMaintenance` (r:1 w:0)
+	/// Proof: `TfgridModule::NodeMaintenance` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn create_node_contract_with_ip6_subnets() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `1203`
-		//  Estimated: `4668`
-		// Minimum execution time: 58_861_000 picoseconds.
-		Weight::from_parts(59_413_000, 4668)
-			.saturating_add(RocksDbWeight::get().reads(9_u64))
-			.saturating_add(RocksDbWeight::get().writes(7_u64))
+		//  Measured:  `1046`
+		//  Estimated: `4511`
+		// Minimum execution time: 104_772_000 picoseconds.
+		Weight::from_parts(106_935_000, 4511)
+			.saturating_add(RocksDbWeight::get().reads(16_u64))
+			.saturating_add(RocksDbWeight::get().writes(10_u64))
+	}
+	/// Storage: `TfgridModule::TwinIdByAccountID` (r:1 w:0)
+	/// Proof: `TfgridModule::TwinIdByAccountID` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `TfgridModule::Nodes` (r:1 w:0)
+	/// Proof: `TfgridModule::Nodes` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SmartContractModule::ActiveRentContractForNode` (r:1 w:0)
+	/// Proof: `SmartContractModule::ActiveRentContractForNode` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SmartContractModule::Contracts` (r:1 w:0)
+	/// Proof: `SmartContractModule::Contracts` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `TfgridModule::NodePower` (r:1 w:1)
+	/// Proof: `TfgridModule::NodePower` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SmartContractModule::NodeWakeupRequests` (r:1 w:1)
+	/// Proof: `SmartContractModule::NodeWakeupRequests` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SmartContractModule::NodeWakeupTimeouts` (r:0 w:1)
+	/// Proof: `SmartContractModule::NodeWakeupTimeouts` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn request_node_wakeup() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1187`
+		//  Estimated: `4652`
+		// Minimum execution time: 31_208_000 picoseconds.
+		Weight::from_parts(32_045_000, 4652)
+			.saturating_add(RocksDbWeight::get().reads(6_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
 	}
 }
@@ -0,0 +1,13 @@
+//! Runtime API definition for the tfgrid pallet.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+pub use pallet_tfgrid::types::NodeUptime;
+
+sp_api::decl_runtime_apis! {
+    pub trait TfgridApi {
+        /// Get the uptime of a node over a period, the current period if none is given.
+        /// Returns `None` if the node does not exist or did not exist yet during the period.
+        fn node_uptime(node_id: u32, period: Option<u64>) -> Option<NodeUptime>;
+    }
+}
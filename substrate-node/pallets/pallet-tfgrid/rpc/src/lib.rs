@@ -0,0 +1,109 @@
+//! RPC interface for the tfgrid pallet.
+
+use std::sync::Arc;
+
+use jsonrpsee::{
+    core::{async_trait, RpcResult},
+    proc_macros::rpc,
+    types::error::{CallError, ErrorObject},
+};
+use serde::{Deserialize, Serialize};
+use sp_api::ProvideRuntimeApi;
+use sp_blockchain::HeaderBackend;
+use sp_runtime::traits::Block as BlockT;
+
+pub use pallet_tfgrid_rpc_runtime_api::TfgridApi as TfgridRuntimeApi;
+
+/// Uptime of a node over a period.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct NodeUptime {
+    /// Index of the period.
+    pub period: u64,
+    /// Time in seconds the node was up or in standby during the period.
+    pub uptime: u64,
+    /// Time in seconds the node existed during the period so far.
+    pub duration: u64,
+    /// Uptime over duration in percent, rounded down.
+    pub percentage: u8,
+}
+
+#[rpc(client, server)]
+pub trait TfgridApi<BlockHash> {
+    /// Get the uptime of a node over a period, the current period if none is given.
+    #[method(name = "tfgrid_nodeUptime")]
+    fn node_uptime(
+        &self,
+        node_id: u32,
+        period: Option<u64>,
+        at: Option<BlockHash>,
+    ) -> RpcResult<Option<NodeUptime>>;
+}
+
+/// Provides RPC methods to query tfgrid related data.
+pub struct Tfgrid<C, P> {
+    client: Arc<C>,
+    _marker: std::marker::PhantomData<P>,
+}
+
+impl<C, P> Tfgrid<C, P> {
+    /// Creates a new instance of the Tfgrid Rpc helper.
+    pub fn new(client: Arc<C>) -> Self {
+        Self {
+            client,
+            _marker: Default::default(),
+        }
+    }
+}
+
+/// Error type of this RPC api.
+pub enum Error {
+    /// The call to runtime failed.
+    RuntimeError,
+}
+
+impl From<Error> for i32 {
+    fn from(e: Error) -> i32 {
+        match e {
+            Error::RuntimeError => 1,
+        }
+    }
+}
+
+#[async_trait]
+impl<C, Block> TfgridApiServer<<Block as BlockT>::Hash> for Tfgrid<C, Block>
+where
+    Block: BlockT,
+    C: ProvideRuntimeApi<Block> + HeaderBackend<Block> + Send + Sync + 'static,
+    C::Api: TfgridRuntimeApi<Block>,
+{
+    fn node_uptime(
+        &self,
+        node_id: u32,
+        period: Option<u64>,
+        at: Option<Block::Hash>,
+    ) -> RpcResult<Option<NodeUptime>> {
+        let api = self.client.runtime_api();
+        let at_hash = at.unwrap_or_else(|| self.client.info().best_hash);
+
+        let uptime = api
+            .node_uptime(at_hash, node_id, period)
+            .map_err(runtime_error_into_rpc_error)?;
+
+        Ok(uptime.map(|uptime| NodeUptime {
+            period: uptime.period,
+            uptime: uptime.uptime,
+            duration: uptime.duration,
+            percentage: uptime.percentage,
+        }))
+    }
+}
+
+/// Converts a runtime trap into an RPC error.
+fn runtime_error_into_rpc_error(err: impl std::fmt::Display) -> CallError {
+    CallError::Custom(ErrorObject::owned(
+        Error::RuntimeError.into(),
+        "Runtime error",
+        Some(err.to_string()),
+    ))
+}
@@ -737,6 +737,143 @@ benchmarks! {
         assert_last_event::<T>(Event::NodeUptimeReported(node_id, now, uptime).into());
     }
 
+    // set_farm_overcommit_ratio()
+    set_farm_overcommit_ratio {
+        let caller: T::AccountId = whitelisted_caller();
+        _prepare_farm::<T>(caller.clone());
+        let farm_id = 1;
+        let ratio = 150;
+    }: _(RawOrigin::Signed(caller), farm_id, ratio)
+    verify {
+        assert_eq!(TfgridModule::<T>::farm_overcommit_ratio(farm_id), ratio);
+        assert_last_event::<T>(Event::FarmOvercommitRatioSet { farm_id, ratio }.into());
+    }
+
+    // add_farm_ip6_prefix()
+    add_farm_ip6_prefix {
+        let caller: T::AccountId = whitelisted_caller();
+        _prepare_farm::<T>(caller.clone());
+        let farm_id = 1;
+        let prefix = get_pub_config_ip6_input(b"2a10:b600:1::/48");
+        let gw = get_pub_config_gw6_input(b"2a10:b600:1::1");
+    }: _(RawOrigin::Signed(caller), farm_id, prefix.clone(), gw.clone())
+    verify {
+        assert_eq!(TfgridModule::<T>::farm_ip6_prefixes(farm_id).len(), 1);
+        assert_last_event::<T>(Event::FarmIp6PrefixAdded { farm_id, prefix, gateway: gw }.into());
+    }
+
+    // remove_farm_ip6_prefix()
+    remove_farm_ip6_prefix {
+        let caller: T::AccountId = whitelisted_caller();
+        _prepare_farm::<T>(caller.clone());
+        let farm_id = 1;
+        let prefix = get_pub_config_ip6_input(b"2a10:b600:1::/48");
+        _add_farm_ip6_prefix::<T>(caller.clone(), farm_id, prefix.clone());
+    }: _(RawOrigin::Signed(caller), farm_id, prefix.clone())
+    verify {
+        assert!(TfgridModule::<T>::farm_ip6_prefixes(farm_id).is_empty());
+        assert_last_event::<T>(Event::FarmIp6PrefixRemoved { farm_id, prefix }.into());
+    }
+
+    // force_remove_farm_ip6_prefix()
+    force_remove_farm_ip6_prefix {
+        let caller: T::AccountId = whitelisted_caller();
+        _prepare_farm::<T>(caller.clone());
+        let farm_id = 1;
+        let prefix = get_pub_config_ip6_input(b"2a10:b600:1::/48");
+        _add_farm_ip6_prefix::<T>(caller, farm_id, prefix.clone());
+    }: _(RawOrigin::Root, farm_id, prefix.clone())
+    verify {
+        assert!(TfgridModule::<T>::farm_ip6_prefixes(farm_id).is_empty());
+        assert_last_event::<T>(Event::FarmIp6PrefixRemoved { farm_id, prefix }.into());
+    }
+
+    // set_node_power_schedule()
+    set_node_power_schedule {
+        let caller: T::AccountId = whitelisted_caller();
+        _prepare_farm_with_node::<T>(caller.clone());
+        let farm_id = 1;
+        let node_id = 1;
+
+        // Windows with distinct minutes, the first one powers the node down right away
+        let windows: PowerWindowsInput = vec![
+            types::PowerWindow { down_from: 1380, down_until: 60 },
+            types::PowerWindow { down_from: 120, down_until: 180 },
+            types::PowerWindow { down_from: 240, down_until: 300 },
+            types::PowerWindow { down_from: 360, down_until: 420 },
+        ].try_into().unwrap();
+    }: _(RawOrigin::Signed(caller), node_id, windows.clone())
+    verify {
+        assert_eq!(TfgridModule::<T>::node_power_schedules(node_id), windows);
+        assert_last_event::<T>(Event::PowerTargetChanged {
+            farm_id,
+            node_id,
+            power_target: Power::Down,
+        }.into());
+    }
+
+    // decommission_node()
+    decommission_node {
+        let caller: T::AccountId = whitelisted_caller();
+        _prepare_farm_with_node::<T>(caller.clone());
+        let twin_id = 1;
+        let node_id = 1;
+    }: _(RawOrigin::Signed(caller), node_id)
+    verify {
+        assert_eq!(TfgridModule::<T>::node_by_twin_id(twin_id), 0);
+        assert!(TfgridModule::<T>::nodes(node_id).is_none());
+        assert_last_event::<T>(Event::NodeDeleted(node_id).into());
+    }
+
+    // schedule_node_maintenance()
+    schedule_node_maintenance {
+        let caller: T::AccountId = whitelisted_caller();
+        _prepare_farm_with_node::<T>(caller.clone());
+        let farm_id = 1;
+        let node_id = 1;
+        let now = Timestamp::<T>::get().saturated_into::<u64>() / 1000;
+        let start = now + 3600;
+        let end = start + 3600;
+    }: _(RawOrigin::Signed(caller), node_id, start, end)
+    verify {
+        assert_eq!(
+            TfgridModule::<T>::node_maintenance(node_id),
+            vec![types::NodeMaintenanceWindow { start, end }]
+        );
+        assert_last_event::<T>(Event::NodeMaintenanceScheduled {
+            farm_id,
+            node_id,
+            start,
+            end,
+        }.into());
+    }
+
+    // apply_node_power_schedule()
+    apply_node_power_schedule {
+        let caller: T::AccountId = whitelisted_caller();
+        _prepare_farm_with_node::<T>(caller.clone());
+        let farm_id = 1;
+        let node_id = 1;
+
+        // Node is powered down, which checks its contracts
+        let minute = 60;
+        let windows: PowerWindowsInput = vec![
+            types::PowerWindow { down_from: minute, down_until: minute + 60 },
+        ].try_into().unwrap();
+        NodePowerSchedules::<T>::insert(node_id, windows);
+        PowerScheduleNodes::<T>::insert(minute, node_id, ());
+    }: {
+        TfgridModule::<T>::apply_node_power_schedule(node_id, minute);
+    }
+    verify {
+        assert_eq!(TfgridModule::<T>::node_power_state(node_id).target, Power::Down);
+        assert_last_event::<T>(Event::PowerTargetChanged {
+            farm_id,
+            node_id,
+            power_target: Power::Down,
+        }.into());
+    }
+
     // Calling the `impl_benchmark_test_suite` macro inside the `benchmarks`
     // block will generate one #[test] function per benchmark
     impl_benchmark_test_suite!(TfgridModule, crate::mock::new_test_ext(), crate::mock::TestRuntime)
@@ -760,6 +897,15 @@ pub fn _prepare_farm<T: Config>(source: T::AccountId) {
     _create_farm::<T>(source);
 }
 
+fn _add_farm_ip6_prefix<T: Config>(source: T::AccountId, farm_id: u32, prefix: Ip6Input) {
+    assert_ok!(TfgridModule::<T>::add_farm_ip6_prefix(
+        RawOrigin::Signed(source).into(),
+        farm_id,
+        prefix,
+        get_pub_config_gw6_input(b"2a10:b600:1::1"),
+    ));
+}
+
 fn _create_pricing_policy<T: Config>() {
     let name = b"pricing_policy_name".to_vec();
     let su_policy = super::types::Policy {
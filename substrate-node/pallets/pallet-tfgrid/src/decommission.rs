@@ -0,0 +1,139 @@
+use crate::{weights::WeightInfo, *};
+use frame_support::{dispatch::DispatchResultWithPostInfo, ensure, weights::Weight};
+use frame_system::pallet_prelude::BlockNumberFor;
+use sp_core::Get;
+use sp_runtime::SaturatedConversion;
+use tfchain_support::traits::{ChangeNode, NodeActiveContracts};
+
+impl<T: Config> Pallet<T> {
+    pub fn _decommission_node(
+        account_id: &T::AccountId,
+        node_id: u32,
+    ) -> DispatchResultWithPostInfo {
+        let twin_id = TwinIdByAccountID::<T>::get(account_id).ok_or(Error::<T>::TwinNotExists)?;
+        let node = Nodes::<T>::get(node_id).ok_or(Error::<T>::NodeNotExists)?;
+        let farm = Farms::<T>::get(node.farm_id).ok_or(Error::<T>::FarmNotExists)?;
+
+        // Make sure the caller is the farmer
+        ensure!(twin_id == farm.twin_id, Error::<T>::FarmerNotAuthorized);
+        ensure!(
+            !DecommissioningNodes::<T>::contains_key(node_id),
+            Error::<T>::NodeAlreadyDecommissioning
+        );
+
+        // Nothing has to move off an empty node
+        if T::NodeActiveContracts::node_has_no_active_contracts(node_id) {
+            Self::remove_node(&node);
+            return Ok(().into());
+        }
+
+        let now = frame_system::Pallet::<T>::block_number().saturated_into::<u64>();
+        let deadline = now + T::NodeDecommissionPeriod::get();
+        DecommissioningNodes::<T>::insert(node_id, deadline);
+        NodeDecommissionsAt::<T>::append(deadline, node_id);
+
+        T::NodeChanged::node_decommissioning(&node, deadline);
+
+        Self::deposit_event(Event::NodeDecommissioning {
+            farm_id: node.farm_id,
+            node_id,
+            deadline,
+        });
+
+        Ok(().into())
+    }
+
+    // Deletes a node being decommissioned once its last contract is gone
+    pub fn decommission_node_if_drained(node_id: u32) {
+        if !DecommissioningNodes::<T>::contains_key(node_id)
+            || !T::NodeActiveContracts::node_has_no_active_contracts(node_id)
+        {
+            return;
+        }
+
+        // Entry is removed first so the node is not decommissioned twice
+        DecommissioningNodes::<T>::remove(node_id);
+        if let Some(node) = Nodes::<T>::get(node_id) {
+            Self::remove_node(&node);
+        }
+    }
+
+    // Deletes the nodes whose decommission deadline is reached,
+    // the contracts still on them are canceled over as many blocks as needed
+    pub fn complete_node_decommissions(block: BlockNumberFor<T>) -> Weight {
+        let mut weight = T::DbWeight::get().reads_writes(1, 1);
+
+        let block = block.saturated_into::<u64>();
+        let mut node_ids = NodeDecommissionsAt::<T>::take(block);
+        // Nodes that do not fit in this block are deleted in the next one
+        if node_ids.len() > types::MAX_NODE_DECOMMISSIONS_PER_BLOCK as usize {
+            let deferred = node_ids.split_off(types::MAX_NODE_DECOMMISSIONS_PER_BLOCK as usize);
+            NodeDecommissionsAt::<T>::mutate(block + 1, |ids| ids.extend(deferred));
+            weight.saturating_accrue(T::DbWeight::get().reads_writes(1, 1));
+        }
+
+        for node_id in node_ids {
+            weight.saturating_accrue(T::DbWeight::get().reads(1));
+            // Node got drained or deleted before its deadline
+            match DecommissioningNodes::<T>::get(node_id) {
+                Some(deadline) if deadline <= block => (),
+                _ => continue,
+            }
+
+            weight.saturating_accrue(T::DbWeight::get().reads(1));
+            let node = match Nodes::<T>::get(node_id) {
+                Some(node) => node,
+                None => {
+                    DecommissioningNodes::<T>::remove(node_id);
+                    weight.saturating_accrue(T::DbWeight::get().writes(1));
+                    continue;
+                }
+            };
+
+            // Node stays in DecommissioningNodes so the removal of its last
+            // contract deletes it, like for a node drained before its deadline
+            weight.saturating_accrue(T::NodeChanged::node_decommissioned(
+                &node,
+                types::MAX_NODE_DECOMMISSION_CONTRACTS_PER_BLOCK,
+            ));
+
+            weight.saturating_accrue(T::DbWeight::get().reads(3));
+            if !Nodes::<T>::contains_key(node_id) {
+                weight.saturating_accrue(<T as Config>::WeightInfo::delete_node_farm());
+            } else if T::NodeActiveContracts::node_has_no_active_contracts(node_id) {
+                Self::remove_node(&node);
+                weight.saturating_accrue(<T as Config>::WeightInfo::delete_node_farm());
+            } else {
+                // Contracts left are canceled in the next block
+                NodeDecommissionsAt::<T>::append(block + 1, node_id);
+                weight.saturating_accrue(T::DbWeight::get().reads_writes(1, 1));
+            }
+        }
+
+        weight
+    }
+
+    pub fn remove_node(node: &TfgridNode<T>) {
+        // Remove node id from "nodes in farm" list
+        let mut nodes_by_farm = NodesByFarmID::<T>::get(node.farm_id);
+        if let Ok(position) = nodes_by_farm.binary_search(&node.id) {
+            nodes_by_farm.remove(position);
+            NodesByFarmID::<T>::insert(node.farm_id, nodes_by_farm);
+        }
+
+        // Removing the contracts of a node being decommissioned must not delete it again
+        DecommissioningNodes::<T>::remove(node.id);
+
+        // Call node deleted
+        T::NodeChanged::node_deleted(node);
+
+        Nodes::<T>::remove(node.id);
+        NodeIdByTwinID::<T>::remove(node.twin_id);
+        NodeUptimeReports::<T>::remove(node.id);
+        NodeUptimePeriods::<T>::remove(node.id);
+        NodeMaintenance::<T>::remove(node.id);
+        Self::remove_node_power_schedule(node.id);
+
+        Self::deposit_event(Event::NodeDeleted(node.id));
+    }
+}
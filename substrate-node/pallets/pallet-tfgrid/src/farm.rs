@@ -11,8 +11,8 @@ use parity_scale_codec::{Decode, Encode, MaxEncodedLen};
 use scale_info::TypeInfo;
 use sp_std::{marker::PhantomData, vec, vec::Vec};
 use tfchain_support::{
-    traits::ChangeNode,
-    types::{Farm, FarmCertification, FarmingPolicyLimit, PublicIP, IP4},
+    traits::PublicIpModifier,
+    types::{Farm, FarmCertification, FarmingPolicyLimit, PublicIP, PublicIp6Prefix, IP4, IP6},
 };
 
 impl<T: Config> Pallet<T> {
@@ -44,7 +44,7 @@ impl<T: Config> Pallet<T> {
             id,
             twin_id,
             name: farm_name,
-            pricing_policy_id: 1,
+            pricing_policy_id: DEFAULT_PRICING_POLICY_ID,
             certification: FarmCertification::NotCertified,
             public_ips,
             dedicated_farm: false,
@@ -139,6 +139,31 @@ impl<T: Config> Pallet<T> {
         Ok(().into())
     }
 
+    pub fn _set_farm_overcommit_ratio(
+        account_id: T::AccountId,
+        farm_id: u32,
+        ratio: u32,
+    ) -> DispatchResultWithPostInfo {
+        let twin_id = TwinIdByAccountID::<T>::get(&account_id).ok_or(Error::<T>::TwinNotExists)?;
+
+        let farm = Farms::<T>::get(farm_id).ok_or(Error::<T>::FarmNotExists)?;
+
+        ensure!(
+            farm.twin_id == twin_id,
+            Error::<T>::CannotUpdateFarmWrongTwin
+        );
+        ensure!(
+            (DEFAULT_OVERCOMMIT_RATIO..=MAX_OVERCOMMIT_RATIO).contains(&ratio),
+            Error::<T>::InvalidOvercommitRatio
+        );
+
+        FarmOvercommitRatio::<T>::insert(farm_id, ratio);
+
+        Self::deposit_event(Event::FarmOvercommitRatioSet { farm_id, ratio });
+
+        Ok(().into())
+    }
+
     pub fn _add_farm_ip(
         account_id: T::AccountId,
         farm_id: u32,
@@ -208,6 +233,106 @@ impl<T: Config> Pallet<T> {
         }
     }
 
+    pub fn _add_farm_ip6_prefix(
+        account_id: T::AccountId,
+        farm_id: u32,
+        prefix: Ip6Input,
+        gw: Gw6Input,
+    ) -> DispatchResultWithPostInfo {
+        let farm = Farms::<T>::get(farm_id).ok_or(Error::<T>::FarmNotExists)?;
+
+        let twin = Twins::<T>::get(farm.twin_id).ok_or(Error::<T>::TwinNotExists)?;
+        ensure!(
+            twin.account_id == account_id,
+            Error::<T>::CannotUpdateFarmWrongTwin
+        );
+
+        // Check if it's a valid IP6 prefix out of which /64 subnets can be delegated
+        let ip6 = IP6 { ip: prefix, gw };
+        ip6.is_valid().map_err(|_| Error::<T>::InvalidIp6Prefix)?;
+
+        let new_prefix = PublicIp6Prefix {
+            prefix: ip6.ip,
+            gateway: ip6.gw,
+            delegated: 0,
+        };
+        let (first, count) = new_prefix.subnets().ok_or(Error::<T>::InvalidIp6Prefix)?;
+
+        // Prefixes of a farm can not overlap so a subnet belongs to a single prefix
+        let mut prefixes = FarmIp6Prefixes::<T>::get(farm_id);
+        for (other_first, other_count) in prefixes.iter().filter_map(|p| p.subnets()) {
+            ensure!(
+                first.saturating_add(count) <= other_first
+                    || other_first.saturating_add(other_count) <= first,
+                Error::<T>::Ip6PrefixExists
+            );
+        }
+
+        prefixes
+            .try_push(new_prefix.clone())
+            .map_err(|_| Error::<T>::FarmHasTooManyIp6Prefixes)?;
+        FarmIp6Prefixes::<T>::insert(farm_id, prefixes);
+
+        Self::deposit_event(Event::FarmIp6PrefixAdded {
+            farm_id,
+            prefix: new_prefix.prefix,
+            gateway: new_prefix.gateway,
+        });
+
+        Ok(().into())
+    }
+
+    pub fn _remove_farm_ip6_prefix(
+        account_id: T::AccountId,
+        farm_id: u32,
+        prefix: Ip6Input,
+    ) -> DispatchResultWithPostInfo {
+        let farm = Farms::<T>::get(farm_id).ok_or(Error::<T>::FarmNotExists)?;
+
+        let twin = Twins::<T>::get(farm.twin_id).ok_or(Error::<T>::TwinNotExists)?;
+        ensure!(
+            twin.account_id == account_id,
+            Error::<T>::CannotUpdateFarmWrongTwin
+        );
+
+        let mut prefixes = FarmIp6Prefixes::<T>::get(farm_id);
+        let index = prefixes
+            .iter()
+            .position(|p| p.prefix == prefix)
+            .ok_or(Error::<T>::Ip6PrefixNotExists)?;
+
+        // The farmer can only remove a prefix once its subnets are freed
+        ensure!(prefixes[index].delegated == 0, Error::<T>::Ip6PrefixInUse);
+
+        prefixes.remove(index);
+        FarmIp6Prefixes::<T>::insert(farm_id, prefixes);
+
+        Self::deposit_event(Event::FarmIp6PrefixRemoved { farm_id, prefix });
+
+        Ok(().into())
+    }
+
+    pub fn _force_remove_farm_ip6_prefix(
+        farm_id: u32,
+        prefix: Ip6Input,
+    ) -> DispatchResultWithPostInfo {
+        let mut prefixes = FarmIp6Prefixes::<T>::get(farm_id);
+        let index = prefixes
+            .iter()
+            .position(|p| p.prefix == prefix)
+            .ok_or(Error::<T>::Ip6PrefixNotExists)?;
+
+        let removed_prefix = prefixes.remove(index);
+        FarmIp6Prefixes::<T>::insert(farm_id, prefixes);
+
+        // Free the subnets contracts still have in the prefix
+        T::PublicIpModifier::ip6_prefix_removed(farm_id, &removed_prefix);
+
+        Self::deposit_event(Event::FarmIp6PrefixRemoved { farm_id, prefix });
+
+        Ok(().into())
+    }
+
     pub fn _delete_node_farm(account_id: T::AccountId, node_id: u32) -> DispatchResultWithPostInfo {
         let twin_id = TwinIdByAccountID::<T>::get(&account_id).ok_or(Error::<T>::TwinNotExists)?;
         let node = Nodes::<T>::get(&node_id).ok_or(Error::<T>::NodeNotExists)?;
@@ -216,20 +341,7 @@ impl<T: Config> Pallet<T> {
         // Make sure the caller is the farmer
         ensure!(twin_id == farm.twin_id, Error::<T>::FarmerNotAuthorized);
 
-        // Remove node id from "nodes in farm" list
-        let mut nodes_by_farm = NodesByFarmID::<T>::get(node.farm_id);
-        if let Ok(position) = nodes_by_farm.binary_search(&node_id) {
-            nodes_by_farm.remove(position);
-            NodesByFarmID::<T>::insert(node.farm_id, nodes_by_farm);
-        }
-
-        // Call node deleted
-        T::NodeChanged::node_deleted(&node);
-
-        Nodes::<T>::remove(node_id);
-        NodeIdByTwinID::<T>::remove(node.twin_id);
-
-        Self::deposit_event(Event::NodeDeleted(node_id));
+        Self::remove_node(&node);
 
         Ok(().into())
     }
@@ -2,14 +2,18 @@
 // `construct_runtime!` does a lot of recursion and requires us to increase the limit to 256.
 #![recursion_limit = "256"]
 
+pub mod decommission;
 pub mod farm;
 pub mod interface;
+pub mod maintenance;
 pub mod migrations;
 pub mod node;
+pub mod power_schedule;
 pub mod pricing;
 pub mod terms_cond;
 pub mod twin;
 pub mod types;
+pub mod uptime;
 pub mod weights;
 
 #[cfg(test)]
@@ -58,6 +62,16 @@ pub mod pallet {
     pub const TFGRID_CERTIFICATION_CODE_VERSION: u32 = 1;
     pub const TFGRID_FARMING_POLICY_VERSION: u32 = 2;
 
+    // Pricing policy assigned to farms by default
+    pub const DEFAULT_PRICING_POLICY_ID: u32 = 1;
+
+    // Bounds (in percent) of the share of node resources that can be reserved by contracts
+    pub const DEFAULT_OVERCOMMIT_RATIO: u32 = 100;
+    pub const MAX_OVERCOMMIT_RATIO: u32 = 1000;
+
+    // Maximum amount of ipv6 prefixes a farm can register
+    pub const MAX_FARM_IP6_PREFIXES: u32 = 16;
+
     // Input type for Farm Name
     pub type FarmNameInput<T> = BoundedVec<u8, <T as Config>::MaxFarmNameLength>;
     // Concrete Farm Name type
@@ -76,6 +90,10 @@ pub mod pallet {
     // Concrete type for public ip list type
     pub type PublicIpListOf = BoundedVec<PublicIP, ConstU32<256>>;
 
+    // Input type for node power schedule
+    pub type PowerWindowsInput =
+        BoundedVec<types::PowerWindow, ConstU32<{ types::MAX_NODE_POWER_WINDOWS }>>;
+
     // Farm information type
     pub type FarmInfoOf<T> = Farm<<T as Config>::FarmName>;
 
@@ -263,6 +281,85 @@ pub mod pallet {
         ValueQuery,
     >;
 
+    #[pallet::type_value]
+    pub fn DefaultOvercommitRatio() -> u32 {
+        DEFAULT_OVERCOMMIT_RATIO
+    }
+
+    // Percentage of the resources of its nodes a farm allows contracts to reserve,
+    // above 100 means the farm accepts to overcommit its nodes
+    #[pallet::storage]
+    #[pallet::getter(fn farm_overcommit_ratio)]
+    pub type FarmOvercommitRatio<T> =
+        StorageMap<_, Blake2_128Concat, u32, u32, ValueQuery, DefaultOvercommitRatio>;
+
+    // IPv6 prefixes of a farm out of which /64 subnets are delegated to contracts
+    #[pallet::storage]
+    #[pallet::getter(fn farm_ip6_prefixes)]
+    pub type FarmIp6Prefixes<T> = StorageMap<
+        _,
+        Blake2_128Concat,
+        u32,
+        BoundedVec<PublicIp6Prefix, ConstU32<MAX_FARM_IP6_PREFIXES>>,
+        ValueQuery,
+    >;
+
+    // Last uptime report of a node
+    #[pallet::storage]
+    #[pallet::getter(fn node_uptime_reports)]
+    pub type NodeUptimeReports<T> =
+        StorageMap<_, Blake2_128Concat, u32, types::NodeUptimeState, OptionQuery>;
+
+    // Uptime of a node over its last periods, oldest first
+    #[pallet::storage]
+    #[pallet::getter(fn node_uptime_periods)]
+    pub type NodeUptimePeriods<T> = StorageMap<
+        _,
+        Blake2_128Concat,
+        u32,
+        BoundedVec<types::NodePeriodUptime, ConstU32<{ types::MAX_NODE_UPTIME_PERIODS }>>,
+        ValueQuery,
+    >;
+
+    // Recurring windows during which a node is powered down
+    #[pallet::storage]
+    #[pallet::getter(fn node_power_schedules)]
+    pub type NodePowerSchedules<T> =
+        StorageMap<_, Blake2_128Concat, u32, PowerWindowsInput, ValueQuery>;
+
+    // Nodes of which the power schedule changes at a minute of the day
+    #[pallet::storage]
+    pub type PowerScheduleNodes<T> =
+        StorageDoubleMap<_, Blake2_128Concat, u32, Blake2_128Concat, u32, (), OptionQuery>;
+
+    // Last minute (since epoch) the power schedules were applied for
+    #[pallet::storage]
+    #[pallet::getter(fn last_power_schedule_minute)]
+    pub type LastPowerScheduleMinute<T> = StorageValue<_, u64, ValueQuery>;
+
+    // Minute (since epoch) being applied and last node it was applied to,
+    // when the nodes of that minute did not all fit in a block
+    #[pallet::storage]
+    #[pallet::getter(fn power_schedule_cursor)]
+    pub type PowerScheduleCursor<T> = StorageValue<_, (u64, u32), OptionQuery>;
+
+    // Nodes being decommissioned with the block their remaining contracts are canceled at
+    #[pallet::storage]
+    #[pallet::getter(fn decommissioning_nodes)]
+    pub type DecommissioningNodes<T> = StorageMap<_, Blake2_128Concat, u32, u64, OptionQuery>;
+
+    // Nodes of which the decommission completes at a block
+    #[pallet::storage]
+    #[pallet::getter(fn node_decommissions_at)]
+    pub type NodeDecommissionsAt<T> = StorageMap<_, Blake2_128Concat, u64, Vec<u32>, ValueQuery>;
+
+    // Maintenance windows of a node ordered by start, the last one is the announced one
+    // and the ended ones are kept until the contracts on the node are billed past them
+    #[pallet::storage]
+    #[pallet::getter(fn node_maintenance)]
+    pub type NodeMaintenance<T> =
+        StorageMap<_, Blake2_128Concat, u32, Vec<types::NodeMaintenanceWindow>, ValueQuery>;
+
     #[pallet::config]
     pub trait Config: frame_system::Config + pallet_timestamp::Config {
         type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
@@ -386,6 +483,10 @@ pub mod pallet {
 
         #[pallet::constant]
         type TimestampHintDrift: Get<u64>;
+
+        /// Amount of blocks contracts have to move off a node being decommissioned
+        #[pallet::constant]
+        type NodeDecommissionPeriod: Get<u64>;
     }
 
     #[pallet::event]
@@ -438,6 +539,44 @@ pub mod pallet {
             node_id: u32,
             power_state: PowerState<BlockNumberFor<T>>,
         },
+        FarmOvercommitRatioSet {
+            farm_id: u32,
+            ratio: u32,
+        },
+        FarmIp6PrefixAdded {
+            farm_id: u32,
+            prefix: Ip6Input,
+            gateway: Gw6Input,
+        },
+        FarmIp6PrefixRemoved {
+            farm_id: u32,
+            prefix: Ip6Input,
+        },
+        NodePowerScheduleSet {
+            farm_id: u32,
+            node_id: u32,
+            windows: PowerWindowsInput,
+        },
+        /// A node was not powered down by its schedule because it has active contracts
+        NodePowerScheduleSkipped {
+            farm_id: u32,
+            node_id: u32,
+        },
+        /// A node is being decommissioned, it is deleted once it has no contracts left
+        /// or at the deadline (block)
+        NodeDecommissioning {
+            farm_id: u32,
+            node_id: u32,
+            deadline: u64,
+        },
+        /// A node will be in maintenance between start and end (timestamps in seconds),
+        /// its contracts are not billed during the window
+        NodeMaintenanceScheduled {
+            farm_id: u32,
+            node_id: u32,
+            start: u64,
+            end: u64,
+        },
     }
 
     #[pallet::error]
@@ -572,6 +711,17 @@ pub mod pallet {
         NodeHasActiveContracts,
         InvalidRelayAddress,
         InvalidTimestampHint,
+        InvalidOvercommitRatio,
+        InvalidIp6Prefix,
+        Ip6PrefixExists,
+        Ip6PrefixNotExists,
+        Ip6PrefixInUse,
+        FarmHasTooManyIp6Prefixes,
+        InvalidPowerSchedule,
+        NodeAlreadyDecommissioning,
+        InvalidMaintenanceWindow,
+        NodeMaintenanceAlreadyScheduled,
+        TooManyNodeMaintenanceWindows,
     }
 
     #[pallet::genesis_config]
@@ -1236,5 +1386,89 @@ pub mod pallet {
         // Deprecated! Use index 40 for next extrinsic
         // #[pallet::call_index(39)]
         // #[pallet::weight(<T as Config>::WeightInfo::set_node_gpu_status())]
+
+        #[pallet::call_index(40)]
+        #[pallet::weight(<T as Config>::WeightInfo::set_farm_overcommit_ratio())]
+        pub fn set_farm_overcommit_ratio(
+            origin: OriginFor<T>,
+            farm_id: u32,
+            ratio: u32,
+        ) -> DispatchResultWithPostInfo {
+            let account_id = ensure_signed(origin)?;
+            Self::_set_farm_overcommit_ratio(account_id, farm_id, ratio)
+        }
+
+        #[pallet::call_index(41)]
+        #[pallet::weight(<T as Config>::WeightInfo::add_farm_ip6_prefix())]
+        pub fn add_farm_ip6_prefix(
+            origin: OriginFor<T>,
+            farm_id: u32,
+            prefix: Ip6Input,
+            gw: Gw6Input,
+        ) -> DispatchResultWithPostInfo {
+            let account_id = ensure_signed(origin)?;
+            Self::_add_farm_ip6_prefix(account_id, farm_id, prefix, gw)
+        }
+
+        #[pallet::call_index(42)]
+        #[pallet::weight(<T as Config>::WeightInfo::remove_farm_ip6_prefix())]
+        pub fn remove_farm_ip6_prefix(
+            origin: OriginFor<T>,
+            farm_id: u32,
+            prefix: Ip6Input,
+        ) -> DispatchResultWithPostInfo {
+            let account_id = ensure_signed(origin)?;
+            Self::_remove_farm_ip6_prefix(account_id, farm_id, prefix)
+        }
+
+        #[pallet::call_index(43)]
+        #[pallet::weight(<T as Config>::WeightInfo::force_remove_farm_ip6_prefix())]
+        pub fn force_remove_farm_ip6_prefix(
+            origin: OriginFor<T>,
+            farm_id: u32,
+            prefix: Ip6Input,
+        ) -> DispatchResultWithPostInfo {
+            T::RestrictedOrigin::ensure_origin(origin)?;
+            Self::_force_remove_farm_ip6_prefix(farm_id, prefix)
+        }
+
+        #[pallet::call_index(44)]
+        #[pallet::weight(<T as Config>::WeightInfo::set_node_power_schedule())]
+        pub fn set_node_power_schedule(
+            origin: OriginFor<T>,
+            node_id: u32,
+            windows: PowerWindowsInput,
+        ) -> DispatchResultWithPostInfo {
+            let account_id = ensure_signed(origin)?;
+            Self::_set_node_power_schedule(&account_id, node_id, windows)
+        }
+
+        #[pallet::call_index(45)]
+        #[pallet::weight(<T as Config>::WeightInfo::decommission_node())]
+        pub fn decommission_node(origin: OriginFor<T>, node_id: u32) -> DispatchResultWithPostInfo {
+            let account_id = ensure_signed(origin)?;
+            Self::_decommission_node(&account_id, node_id)
+        }
+
+        #[pallet::call_index(46)]
+        #[pallet::weight(<T as Config>::WeightInfo::schedule_node_maintenance())]
+        pub fn schedule_node_maintenance(
+            origin: OriginFor<T>,
+            node_id: u32,
+            start: u64,
+            end: u64,
+        ) -> DispatchResultWithPostInfo {
+            let account_id = ensure_signed(origin)?;
+            Self::_schedule_node_maintenance(&account_id, node_id, start, end)
+        }
+    }
+
+    #[pallet::hooks]
+    impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+        fn on_initialize(n: BlockNumberFor<T>) -> Weight {
+            let mut weight_used = Self::apply_power_schedules();
+            weight_used.saturating_accrue(Self::complete_node_decommissions(n));
+            weight_used
+        }
     }
 }
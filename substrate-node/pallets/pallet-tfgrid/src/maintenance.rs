@@ -0,0 +1,59 @@
+use crate::*;
+use frame_support::{dispatch::DispatchResultWithPostInfo, ensure};
+use sp_runtime::SaturatedConversion;
+
+impl<T: Config> Pallet<T> {
+    pub fn _schedule_node_maintenance(
+        account_id: &T::AccountId,
+        node_id: u32,
+        start: u64,
+        end: u64,
+    ) -> DispatchResultWithPostInfo {
+        let twin_id = TwinIdByAccountID::<T>::get(account_id).ok_or(Error::<T>::TwinNotExists)?;
+        let node = Nodes::<T>::get(node_id).ok_or(Error::<T>::NodeNotExists)?;
+        let farm = Farms::<T>::get(node.farm_id).ok_or(Error::<T>::FarmNotExists)?;
+
+        // Make sure the caller is the farmer
+        ensure!(twin_id == farm.twin_id, Error::<T>::FarmerNotAuthorized);
+
+        let now = <pallet_timestamp::Pallet<T>>::get().saturated_into::<u64>() / 1000;
+        ensure!(
+            start >= now && start < end && end - start <= types::MAX_NODE_MAINTENANCE_DURATION,
+            Error::<T>::InvalidMaintenanceWindow
+        );
+
+        // A window can not be moved once announced
+        let mut windows = NodeMaintenance::<T>::get(node_id);
+        if let Some(window) = windows.last() {
+            ensure!(
+                window.end <= now,
+                Error::<T>::NodeMaintenanceAlreadyScheduled
+            );
+        }
+
+        // Ended windows are still taken off the next bill of the contracts on the node
+        windows.retain(|window| window.end.saturating_add(types::NODE_MAINTENANCE_RETENTION) > now);
+        ensure!(
+            windows.len() < types::MAX_NODE_MAINTENANCE_WINDOWS as usize,
+            Error::<T>::TooManyNodeMaintenanceWindows
+        );
+        windows.push(types::NodeMaintenanceWindow { start, end });
+        NodeMaintenance::<T>::insert(node_id, windows);
+
+        Self::deposit_event(Event::NodeMaintenanceScheduled {
+            farm_id: node.farm_id,
+            node_id,
+            start,
+            end,
+        });
+
+        Ok(().into())
+    }
+
+    pub fn is_node_in_maintenance(node_id: u32) -> bool {
+        let now = <pallet_timestamp::Pallet<T>>::get().saturated_into::<u64>() / 1000;
+        NodeMaintenance::<T>::get(node_id)
+            .last()
+            .map_or(false, |window| window.contains(now))
+    }
+}
@@ -7,3 +7,4 @@ pub mod v14;
 //pub mod v15;
 pub mod v16;
 pub mod v17;
+pub mod v18;
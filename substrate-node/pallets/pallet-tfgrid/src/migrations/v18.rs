@@ -0,0 +1,108 @@
+use crate::*;
+use frame_support::{traits::Get, traits::OnRuntimeUpgrade, weights::Weight};
+use log::{debug, info};
+use sp_runtime::Saturating;
+use sp_std::marker::PhantomData;
+
+#[cfg(feature = "try-runtime")]
+use frame_support::{dispatch::DispatchError, ensure};
+#[cfg(feature = "try-runtime")]
+use parity_scale_codec::{Decode, Encode};
+#[cfg(feature = "try-runtime")]
+use sp_std::vec::Vec;
+
+pub struct FixFarmPricingPolicy<T: Config>(PhantomData<T>);
+
+impl<T: Config> OnRuntimeUpgrade for FixFarmPricingPolicy<T> {
+    #[cfg(feature = "try-runtime")]
+    fn pre_upgrade() -> Result<Vec<u8>, sp_runtime::TryRuntimeError> {
+        info!("current pallet version: {:?}", PalletVersion::<T>::get());
+        ensure!(
+            PalletVersion::<T>::get() >= types::StorageVersion::V17Struct,
+            DispatchError::Other("Unexpected pallet version")
+        );
+
+        let farms_count: u64 = Farms::<T>::iter().count() as u64;
+        info!(
+            "🔎 FixFarmPricingPolicy pre migration: Number of existing farms {:?}",
+            farms_count
+        );
+
+        info!("👥  TFGrid pallet to V18 passes PRE migrate checks ✅",);
+        Ok(farms_count.encode())
+    }
+
+    fn on_runtime_upgrade() -> Weight {
+        if PalletVersion::<T>::get() == types::StorageVersion::V17Struct {
+            fix_farm_pricing_policy::<T>()
+        } else {
+            info!(" >>> Unused TFGrid pallet V18 migration");
+            Weight::zero()
+        }
+    }
+
+    #[cfg(feature = "try-runtime")]
+    fn post_upgrade(pre_farms_count: Vec<u8>) -> Result<(), sp_runtime::TryRuntimeError> {
+        info!("current pallet version: {:?}", PalletVersion::<T>::get());
+        ensure!(
+            PalletVersion::<T>::get() >= types::StorageVersion::V18Struct,
+            DispatchError::Other("Unexpected pallet version")
+        );
+
+        // Check number of farms against pre-check result
+        let pre_farms_count: u64 = Decode::decode(&mut pre_farms_count.as_slice())
+            .expect("the state parameter should be something that was generated by pre_upgrade");
+        ensure!(
+            Farms::<T>::iter().count() as u64 == pre_farms_count,
+            DispatchError::Other("Number of farms migrated does not match")
+        );
+
+        // Check that every farm now refers to an existing pricing policy
+        for (_, farm) in Farms::<T>::iter() {
+            ensure!(
+                PricingPolicies::<T>::contains_key(farm.pricing_policy_id),
+                DispatchError::Other("Farm refers to an unknown pricing policy")
+            );
+        }
+
+        info!(
+            "👥  TFGrid pallet migration to {:?} passes POST migrate checks ✅",
+            Pallet::<T>::pallet_version()
+        );
+
+        Ok(())
+    }
+}
+
+// Billing now resolves the pricing policy from the farm hosting the node,
+// make sure no farm points to a pricing policy that does not exist.
+pub fn fix_farm_pricing_policy<T: Config>() -> frame_support::weights::Weight {
+    info!(" >>> Migrating farms storage...");
+
+    let mut r = 0u64;
+    let mut w = 0u64;
+
+    for (_, mut farm) in Farms::<T>::iter() {
+        r.saturating_accrue(2);
+
+        if !PricingPolicies::<T>::contains_key(farm.pricing_policy_id) {
+            debug!(
+                "Farm #{:?}: unknown pricing policy {:?}, set back to default",
+                farm.id, farm.pricing_policy_id
+            );
+            farm.pricing_policy_id = DEFAULT_PRICING_POLICY_ID;
+            Farms::<T>::insert(farm.id, &farm);
+            w.saturating_inc();
+        }
+    }
+
+    info!(" <<< Farms storage updated! Migrated {} Farms ✅", w);
+
+    // Update pallet storage version
+    PalletVersion::<T>::set(types::StorageVersion::V18Struct);
+    w.saturating_inc();
+    info!(" <<< Storage version upgraded");
+
+    // Return the weight consumed by the migration.
+    T::DbWeight::get().reads_writes(r, w)
+}
@@ -9,7 +9,9 @@ use crate::{
     InterfaceNameInput, Ip4Input, Ip6Input, LatitudeInput, LongitudeInput, PkInput, RelayInput,
 };
 use env_logger;
-use frame_support::{construct_runtime, parameter_types, traits::ConstU32, BoundedVec};
+use frame_support::{
+    construct_runtime, parameter_types, traits::ConstU32, weights::Weight, BoundedVec,
+};
 use frame_system::EnsureRoot;
 use sp_core::{ed25519, sr25519, Pair, Public, H256};
 use sp_io::TestExternalities;
@@ -22,7 +24,7 @@ use sp_std::prelude::*;
 use hex;
 use tfchain_support::{
     traits::{ChangeNode, NodeActiveContracts, PublicIpModifier},
-    types::PublicIP,
+    types::{PublicIP, PublicIp6Prefix},
 };
 
 pub type Signature = MultiSignature;
@@ -85,12 +87,17 @@ pub struct NodeChanged;
 impl ChangeNode<Loc, Interface, Serial> for NodeChanged {
     fn node_changed(_old_node: Option<&TfgridNode>, _new_node: &TfgridNode) {}
     fn node_deleted(_node: &TfgridNode) {}
+    fn node_decommissioning(_node: &TfgridNode, _deadline: u64) {}
+    fn node_decommissioned(_node: &TfgridNode, _max_contracts: u32) -> Weight {
+        Weight::zero()
+    }
     fn node_power_state_changed(_node: &TfgridNode) {}
 }
 
 pub struct PublicIpModifierType;
 impl PublicIpModifier for PublicIpModifierType {
     fn ip_removed(_ip: &PublicIP) {}
+    fn ip6_prefix_removed(_farm_id: u32, _prefix: &PublicIp6Prefix) {}
 }
 
 pub struct NodeActiveContractsType;
@@ -106,6 +113,7 @@ parameter_types! {
     pub const MaxInterfacesLength: u32 = 10;
     pub const MaxFarmPublicIps: u32 = 512;
     pub const TimestampHintDrift: u64 = 60;
+    pub const NodeDecommissionPeriod: u64 = 10;
 }
 
 pub(crate) type TestTermsAndConditions = TermsAndConditions<TestRuntime>;
@@ -142,6 +150,7 @@ impl Config for TestRuntime {
     type Location = TestLocation;
     type SerialNumber = TestSerialNumber;
     type TimestampHintDrift = TimestampHintDrift;
+    type NodeDecommissionPeriod = NodeDecommissionPeriod;
 }
 
 parameter_types! {
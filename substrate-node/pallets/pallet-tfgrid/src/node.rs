@@ -201,7 +201,7 @@ impl<T: Config> Pallet<T> {
         );
         let node_id = NodeIdByTwinID::<T>::get(twin_id);
 
-        ensure!(Nodes::<T>::contains_key(node_id), Error::<T>::NodeNotExists);
+        let node = Nodes::<T>::get(node_id).ok_or(Error::<T>::NodeNotExists)?;
 
         let now = <pallet_timestamp::Pallet<T>>::get().saturated_into::<u64>() / 1000;
         // check if timestamp hint is within the acceptable range of the current timestamp (now) and the drift value
@@ -214,6 +214,8 @@ impl<T: Config> Pallet<T> {
             Error::<T>::InvalidTimestampHint
         );
 
+        Self::account_uptime_report(node_id, node.created, uptime, now);
+
         Self::deposit_event(Event::NodeUptimeReported(node_id, now, uptime));
 
         Ok(Pays::No.into())
@@ -263,19 +265,15 @@ impl<T: Config> Pallet<T> {
         let twin_id = TwinIdByAccountID::<T>::get(account_id).ok_or(Error::<T>::TwinNotExists)?;
         ensure!(node.twin_id == twin_id, Error::<T>::NodeUpdateNotAuthorized);
 
-        let mut nodes_by_farm = NodesByFarmID::<T>::get(node.farm_id);
-        let location = nodes_by_farm
-            .binary_search(&node_id)
-            .or(Err(Error::<T>::NodeNotExists))?;
-        nodes_by_farm.remove(location);
-        NodesByFarmID::<T>::insert(node.farm_id, nodes_by_farm);
-
-        // Call node deleted
-        T::NodeChanged::node_deleted(&node);
-
-        Nodes::<T>::remove(node_id);
+        // A node missing from the nodes of its farm is not deleted
+        ensure!(
+            NodesByFarmID::<T>::get(node.farm_id)
+                .binary_search(&node_id)
+                .is_ok(),
+            Error::<T>::NodeNotExists
+        );
 
-        Self::deposit_event(Event::NodeDeleted(node_id));
+        Self::remove_node(&node);
 
         Ok(().into())
     }
@@ -328,7 +326,7 @@ impl<T: Config> Pallet<T> {
         let node_id = NodeIdByTwinID::<T>::get(twin_id);
         let node = Nodes::<T>::get(node_id).ok_or(Error::<T>::NodeNotExists)?;
 
-        let power_state = match power_state {
+        let new_power_state = match power_state {
             Power::Up => PowerState::Up,
             Power::Down => PowerState::Down(frame_system::Pallet::<T>::block_number()),
         };
@@ -336,17 +334,20 @@ impl<T: Config> Pallet<T> {
         let mut node_power = NodePower::<T>::get(node_id);
 
         // If the power state is different from what is set, change it and emit event
-        if node_power.state != power_state {
-            node_power.state = power_state.clone();
+        if node_power.state != new_power_state {
+            node_power.state = new_power_state.clone();
             NodePower::<T>::insert(node_id, node_power);
 
+            let now = <pallet_timestamp::Pallet<T>>::get().saturated_into::<u64>() / 1000;
+            Self::account_power_state_change(node_id, &power_state, now);
+
             // Call node power state changed
             T::NodeChanged::node_power_state_changed(&node);
 
             Self::deposit_event(Event::PowerStateChanged {
                 farm_id: node.farm_id,
                 node_id,
-                power_state,
+                power_state: new_power_state,
             });
         }
 
@@ -376,17 +377,22 @@ impl<T: Config> Pallet<T> {
             );
         }
 
-        let mut node_power = NodePower::<T>::get(node_id);
+        Self::set_node_power_target(&node, power_target);
+
+        Ok(().into())
+    }
+
+    // Sets the power target of a node and lets it know through the event
+    pub fn set_node_power_target(node: &TfgridNode<T>, power_target: Power) {
+        let mut node_power = NodePower::<T>::get(node.id);
         node_power.target = power_target.clone();
-        NodePower::<T>::insert(node_id, &node_power);
+        NodePower::<T>::insert(node.id, &node_power);
 
         Self::deposit_event(Event::PowerTargetChanged {
             farm_id: node.farm_id,
-            node_id,
+            node_id: node.id,
             power_target,
         });
-
-        Ok(().into())
     }
 
     fn get_resources(
@@ -0,0 +1,153 @@
+use crate::{weights::WeightInfo, *};
+use frame_support::{dispatch::DispatchResultWithPostInfo, ensure, weights::Weight};
+use sp_core::Get;
+use sp_runtime::SaturatedConversion;
+use sp_std::vec::Vec;
+use tfchain_support::{traits::NodeActiveContracts, types::Power};
+
+impl<T: Config> Pallet<T> {
+    pub fn _set_node_power_schedule(
+        account_id: &T::AccountId,
+        node_id: u32,
+        windows: PowerWindowsInput,
+    ) -> DispatchResultWithPostInfo {
+        let twin_id = TwinIdByAccountID::<T>::get(account_id).ok_or(Error::<T>::TwinNotExists)?;
+        let node = Nodes::<T>::get(node_id).ok_or(Error::<T>::NodeNotExists)?;
+        let farm = Farms::<T>::get(node.farm_id).ok_or(Error::<T>::FarmNotExists)?;
+
+        // Make sure only the farmer that owns this node can schedule its power
+        ensure!(
+            twin_id == farm.twin_id,
+            Error::<T>::UnauthorizedToChangePowerTarget
+        );
+        ensure!(
+            windows.iter().all(|window| window.is_valid()),
+            Error::<T>::InvalidPowerSchedule
+        );
+
+        Self::remove_node_power_schedule(node_id);
+
+        for minute in Self::power_schedule_minutes(&windows) {
+            PowerScheduleNodes::<T>::insert(minute, node_id, ());
+        }
+
+        if !windows.is_empty() {
+            NodePowerSchedules::<T>::insert(node_id, &windows);
+        }
+
+        Self::deposit_event(Event::NodePowerScheduleSet {
+            farm_id: node.farm_id,
+            node_id,
+            windows,
+        });
+
+        // Power target follows the new schedule from now on
+        if NodePowerSchedules::<T>::contains_key(node_id) {
+            let now = <pallet_timestamp::Pallet<T>>::get().saturated_into::<u64>() / 1000 / 60;
+            Self::apply_node_power_schedule(node_id, (now % types::MINUTES_PER_DAY as u64) as u32);
+        }
+
+        Ok(().into())
+    }
+
+    pub fn remove_node_power_schedule(node_id: u32) {
+        let windows = NodePowerSchedules::<T>::take(node_id);
+        for minute in Self::power_schedule_minutes(&windows) {
+            PowerScheduleNodes::<T>::remove(minute, node_id);
+        }
+    }
+
+    // Minutes of the day at which the power target of a node changes
+    fn power_schedule_minutes(windows: &PowerWindowsInput) -> Vec<u32> {
+        let mut minutes: Vec<u32> = windows
+            .iter()
+            .flat_map(|window| [window.down_from, window.down_until])
+            .collect();
+        minutes.sort();
+        minutes.dedup();
+        minutes
+    }
+
+    // Applies the power schedules of the nodes for the minutes passed since last block,
+    // at most MAX_POWER_SCHEDULE_NODES_PER_BLOCK nodes are handled and the rest in the next blocks
+    pub fn apply_power_schedules() -> Weight {
+        let mut weight = T::DbWeight::get().reads(2);
+
+        let now = <pallet_timestamp::Pallet<T>>::get().saturated_into::<u64>() / 1000 / 60;
+        let last = LastPowerScheduleMinute::<T>::get();
+        if now <= last {
+            return weight;
+        }
+
+        weight.saturating_accrue(T::DbWeight::get().reads_writes(1, 2));
+        let mut cursor = PowerScheduleCursor::<T>::take();
+        let mut remaining = types::MAX_POWER_SCHEDULE_NODES_PER_BLOCK;
+
+        let from = last.max(now.saturating_sub(types::MAX_POWER_SCHEDULE_CATCH_UP)) + 1;
+        for minute in from..=now {
+            let minute_of_day = (minute % types::MINUTES_PER_DAY as u64) as u32;
+            // Continue after the last node handled for this minute in a previous block
+            let nodes = match cursor.take() {
+                Some((cursor_minute, node_id)) if cursor_minute == minute => {
+                    PowerScheduleNodes::<T>::iter_key_prefix_from(
+                        minute_of_day,
+                        PowerScheduleNodes::<T>::hashed_key_for(minute_of_day, node_id),
+                    )
+                }
+                _ => PowerScheduleNodes::<T>::iter_key_prefix(minute_of_day),
+            };
+
+            let mut last_node_id = None;
+            for node_id in nodes {
+                if remaining == 0 {
+                    if let Some(last_node_id) = last_node_id {
+                        PowerScheduleCursor::<T>::put((minute, last_node_id));
+                    }
+                    LastPowerScheduleMinute::<T>::put(minute - 1);
+                    return weight;
+                }
+
+                Self::apply_node_power_schedule(node_id, minute_of_day);
+                weight.saturating_accrue(<T as Config>::WeightInfo::apply_node_power_schedule());
+                remaining -= 1;
+                last_node_id = Some(node_id);
+            }
+        }
+
+        LastPowerScheduleMinute::<T>::put(now);
+
+        weight
+    }
+
+    // Sets the power target of a node to what its schedule wants at this minute of the day,
+    // a node with active contracts is not powered down
+    pub fn apply_node_power_schedule(node_id: u32, minute: u32) {
+        let windows = NodePowerSchedules::<T>::get(node_id);
+        let power_target = if windows.iter().any(|window| window.contains(minute)) {
+            Power::Down
+        } else {
+            Power::Up
+        };
+
+        if NodePower::<T>::get(node_id).target == power_target {
+            return;
+        }
+
+        let node = match Nodes::<T>::get(node_id) {
+            Some(node) => node,
+            None => return,
+        };
+
+        if power_target == Power::Down
+            && !T::NodeActiveContracts::node_has_no_active_contracts(node_id)
+        {
+            Self::deposit_event(Event::NodePowerScheduleSkipped {
+                farm_id: node.farm_id,
+                node_id,
+            });
+            return;
+        }
+
+        Self::set_node_power_target(&node, power_target);
+    }
+}
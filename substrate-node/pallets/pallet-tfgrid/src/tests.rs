@@ -1,9 +1,18 @@
 use super::Event as TfgridEvent;
 use crate::{
-    mock::RuntimeEvent as MockEvent, mock::*, types::LocationInput, Error, InterfaceInput,
-    InterfaceIpsInput, PublicIpListInput, ResourcesInput,
+    mock::RuntimeEvent as MockEvent,
+    mock::*,
+    types::{
+        LocationInput, NodeMaintenanceWindow, NodeUptime, PowerWindow,
+        MAX_NODE_DECOMMISSIONS_PER_BLOCK, MAX_NODE_MAINTENANCE_DURATION,
+        MAX_NODE_MAINTENANCE_WINDOWS, MAX_POWER_SCHEDULE_NODES_PER_BLOCK, MINUTES_PER_DAY,
+        NODE_MAINTENANCE_RETENTION, UPTIME_PERIOD_LENGTH,
+    },
+    DecommissioningNodes, Error, InterfaceInput, InterfaceIpsInput, NodeDecommissionsAt,
+    NodesByFarmID, PowerScheduleNodes, PowerWindowsInput, PublicIpListInput, ResourcesInput,
+    DEFAULT_OVERCOMMIT_RATIO, MAX_OVERCOMMIT_RATIO,
 };
-use frame_support::{assert_noop, assert_ok};
+use frame_support::{assert_noop, assert_ok, traits::Hooks};
 use frame_system::{EventRecord, Phase, RawOrigin};
 use sp_core::bounded_vec;
 use sp_core::H256;
@@ -196,6 +205,106 @@ fn test_delete_node_fails_if_not_authorized() {
     });
 }
 
+#[test]
+fn test_delete_node_by_node_twin_works() {
+    ExternalityBuilder::build().execute_with(|| {
+        create_twin();
+        create_farm();
+        create_node();
+
+        assert_ok!(TfgridModule::delete_node(RuntimeOrigin::signed(alice()), 1));
+        assert_eq!(TfgridModule::nodes(1), None);
+        assert_eq!(TfgridModule::nodes_by_farm_id(1).len(), 0);
+
+        // Twin of the deleted node can register a new one
+        assert_eq!(TfgridModule::node_by_twin_id(1), 0);
+        create_node();
+        assert_eq!(TfgridModule::node_by_twin_id(1), 2);
+    });
+}
+
+#[test]
+fn test_delete_node_not_in_farm_fails() {
+    ExternalityBuilder::build().execute_with(|| {
+        create_twin();
+        create_farm();
+        create_node();
+
+        NodesByFarmID::<TestRuntime>::insert(1, Vec::<u32>::new());
+
+        assert_noop!(
+            TfgridModule::delete_node(RuntimeOrigin::signed(alice()), 1),
+            Error::<TestRuntime>::NodeNotExists
+        );
+    });
+}
+
+#[test]
+fn test_decommission_empty_node_deletes_it() {
+    ExternalityBuilder::build().execute_with(|| {
+        create_twin();
+        create_farm();
+        create_node();
+
+        assert_ok!(TfgridModule::decommission_node(
+            RuntimeOrigin::signed(alice()),
+            1
+        ));
+
+        assert!(TfgridModule::nodes(1).is_none());
+        assert_eq!(TfgridModule::nodes_by_farm_id(1).len(), 0);
+        assert_eq!(TfgridModule::decommissioning_nodes(1), None);
+        assert_eq!(
+            System::events().last().unwrap(),
+            &record(MockEvent::TfgridModule(
+                TfgridEvent::<TestRuntime>::NodeDeleted(1)
+            ))
+        );
+    });
+}
+
+#[test]
+fn test_decommission_deadline_spreads_nodes_over_blocks_works() {
+    ExternalityBuilder::build().execute_with(|| {
+        let deadline = 5;
+        let nodes = MAX_NODE_DECOMMISSIONS_PER_BLOCK + 2;
+        for node_id in 1..=nodes {
+            DecommissioningNodes::<TestRuntime>::insert(node_id, deadline);
+            NodeDecommissionsAt::<TestRuntime>::append(deadline, node_id);
+        }
+
+        TfgridModule::on_initialize(deadline);
+        for node_id in 1..=MAX_NODE_DECOMMISSIONS_PER_BLOCK {
+            assert_eq!(TfgridModule::decommissioning_nodes(node_id), None);
+        }
+        assert_eq!(
+            TfgridModule::node_decommissions_at(deadline + 1),
+            vec![nodes - 1, nodes]
+        );
+        assert_eq!(TfgridModule::decommissioning_nodes(nodes), Some(deadline));
+
+        // Nodes left over are handled in the next block
+        TfgridModule::on_initialize(deadline + 1);
+        assert_eq!(TfgridModule::decommissioning_nodes(nodes - 1), None);
+        assert_eq!(TfgridModule::decommissioning_nodes(nodes), None);
+    });
+}
+
+#[test]
+fn test_decommission_node_fails_if_not_authorized() {
+    ExternalityBuilder::build().execute_with(|| {
+        create_twin();
+        create_twin_bob();
+        create_farm();
+        create_node();
+
+        assert_noop!(
+            TfgridModule::decommission_node(RuntimeOrigin::signed(bob()), 1),
+            Error::<TestRuntime>::FarmerNotAuthorized
+        );
+    });
+}
+
 #[test]
 fn test_add_entity_to_twin() {
     ExternalityBuilder::build().execute_with(|| {
@@ -666,6 +775,219 @@ fn test_set_farm_certification_works() {
     });
 }
 
+#[test]
+fn test_set_farm_overcommit_ratio_works() {
+    ExternalityBuilder::build().execute_with(|| {
+        create_twin();
+        create_farm();
+        let farm_id = 1;
+
+        assert_eq!(
+            TfgridModule::farm_overcommit_ratio(farm_id),
+            DEFAULT_OVERCOMMIT_RATIO
+        );
+
+        assert_ok!(TfgridModule::set_farm_overcommit_ratio(
+            RuntimeOrigin::signed(alice()),
+            farm_id,
+            150
+        ));
+        assert_eq!(TfgridModule::farm_overcommit_ratio(farm_id), 150);
+    });
+}
+
+#[test]
+fn test_set_farm_overcommit_ratio_unauthorized_fails() {
+    ExternalityBuilder::build().execute_with(|| {
+        create_twin();
+        create_farm();
+        let farm_id = 1;
+
+        create_twin_bob();
+
+        assert_noop!(
+            TfgridModule::set_farm_overcommit_ratio(RuntimeOrigin::signed(bob()), farm_id, 150),
+            Error::<TestRuntime>::CannotUpdateFarmWrongTwin
+        );
+    });
+}
+
+#[test]
+fn test_set_farm_overcommit_ratio_out_of_bounds_fails() {
+    ExternalityBuilder::build().execute_with(|| {
+        create_twin();
+        create_farm();
+        let farm_id = 1;
+
+        assert_noop!(
+            TfgridModule::set_farm_overcommit_ratio(
+                RuntimeOrigin::signed(alice()),
+                farm_id,
+                DEFAULT_OVERCOMMIT_RATIO - 1
+            ),
+            Error::<TestRuntime>::InvalidOvercommitRatio
+        );
+
+        assert_noop!(
+            TfgridModule::set_farm_overcommit_ratio(
+                RuntimeOrigin::signed(alice()),
+                farm_id,
+                MAX_OVERCOMMIT_RATIO + 1
+            ),
+            Error::<TestRuntime>::InvalidOvercommitRatio
+        );
+    });
+}
+
+#[test]
+fn test_add_farm_ip6_prefix_works() {
+    ExternalityBuilder::build().execute_with(|| {
+        create_twin();
+        create_farm();
+        let farm_id = 1;
+
+        assert_ok!(TfgridModule::add_farm_ip6_prefix(
+            RuntimeOrigin::signed(alice()),
+            farm_id,
+            get_pub_config_ip6_input(b"2a10:b600:1::/48"),
+            get_pub_config_gw6_input(b"2a10:b600:1::1"),
+        ));
+
+        let prefixes = TfgridModule::farm_ip6_prefixes(farm_id);
+        assert_eq!(prefixes.len(), 1);
+        assert_eq!(
+            prefixes[0].prefix,
+            get_pub_config_ip6_input(b"2a10:b600:1::/48")
+        );
+        assert_eq!(prefixes[0].delegated, 0);
+
+        // Overlapping prefix
+        assert_noop!(
+            TfgridModule::add_farm_ip6_prefix(
+                RuntimeOrigin::signed(alice()),
+                farm_id,
+                get_pub_config_ip6_input(b"2a10:b600:1:2::/64"),
+                get_pub_config_gw6_input(b"2a10:b600:1:2::1"),
+            ),
+            Error::<TestRuntime>::Ip6PrefixExists
+        );
+    });
+}
+
+#[test]
+fn test_add_farm_ip6_prefix_invalid_prefix_fails() {
+    ExternalityBuilder::build().execute_with(|| {
+        create_twin();
+        create_farm();
+        let farm_id = 1;
+
+        // Prefix too small to delegate a /64 out of it
+        assert_noop!(
+            TfgridModule::add_farm_ip6_prefix(
+                RuntimeOrigin::signed(alice()),
+                farm_id,
+                get_pub_config_ip6_input(b"2a10:b600:1::/72"),
+                get_pub_config_gw6_input(b"2a10:b600:1::1"),
+            ),
+            Error::<TestRuntime>::InvalidIp6Prefix
+        );
+
+        // Gateway outside of the prefix
+        assert_noop!(
+            TfgridModule::add_farm_ip6_prefix(
+                RuntimeOrigin::signed(alice()),
+                farm_id,
+                get_pub_config_ip6_input(b"2a10:b600:1::/48"),
+                get_pub_config_gw6_input(b"2a10:b600:2::1"),
+            ),
+            Error::<TestRuntime>::InvalidIp6Prefix
+        );
+    });
+}
+
+#[test]
+fn test_add_farm_ip6_prefix_unauthorized_fails() {
+    ExternalityBuilder::build().execute_with(|| {
+        create_twin();
+        create_farm();
+        let farm_id = 1;
+
+        create_twin_bob();
+
+        assert_noop!(
+            TfgridModule::add_farm_ip6_prefix(
+                RuntimeOrigin::signed(bob()),
+                farm_id,
+                get_pub_config_ip6_input(b"2a10:b600:1::/48"),
+                get_pub_config_gw6_input(b"2a10:b600:1::1"),
+            ),
+            Error::<TestRuntime>::CannotUpdateFarmWrongTwin
+        );
+    });
+}
+
+#[test]
+fn test_remove_farm_ip6_prefix_works() {
+    ExternalityBuilder::build().execute_with(|| {
+        create_twin();
+        create_farm();
+        let farm_id = 1;
+        let prefix = get_pub_config_ip6_input(b"2a10:b600:1::/48");
+
+        assert_ok!(TfgridModule::add_farm_ip6_prefix(
+            RuntimeOrigin::signed(alice()),
+            farm_id,
+            prefix.clone(),
+            get_pub_config_gw6_input(b"2a10:b600:1::1"),
+        ));
+
+        assert_ok!(TfgridModule::remove_farm_ip6_prefix(
+            RuntimeOrigin::signed(alice()),
+            farm_id,
+            prefix.clone(),
+        ));
+        assert!(TfgridModule::farm_ip6_prefixes(farm_id).is_empty());
+
+        assert_noop!(
+            TfgridModule::remove_farm_ip6_prefix(RuntimeOrigin::signed(alice()), farm_id, prefix),
+            Error::<TestRuntime>::Ip6PrefixNotExists
+        );
+    });
+}
+
+#[test]
+fn test_force_remove_farm_ip6_prefix_works() {
+    ExternalityBuilder::build().execute_with(|| {
+        create_twin();
+        create_farm();
+        let farm_id = 1;
+        let prefix = get_pub_config_ip6_input(b"2a10:b600:1::/48");
+
+        assert_ok!(TfgridModule::add_farm_ip6_prefix(
+            RuntimeOrigin::signed(alice()),
+            farm_id,
+            prefix.clone(),
+            get_pub_config_gw6_input(b"2a10:b600:1::1"),
+        ));
+
+        assert_noop!(
+            TfgridModule::force_remove_farm_ip6_prefix(
+                RuntimeOrigin::signed(alice()),
+                farm_id,
+                prefix.clone(),
+            ),
+            sp_runtime::DispatchError::BadOrigin
+        );
+
+        assert_ok!(TfgridModule::force_remove_farm_ip6_prefix(
+            RawOrigin::Root.into(),
+            farm_id,
+            prefix,
+        ));
+        assert!(TfgridModule::farm_ip6_prefixes(farm_id).is_empty());
+    });
+}
+
 #[test]
 fn create_node_works() {
     ExternalityBuilder::build().execute_with(|| {
@@ -1093,6 +1415,122 @@ fn node_report_uptime_v2_fails_with_invalid_timestamp_hint() {
     });
 }
 
+#[test]
+fn node_uptime_accounts_reports_and_reboots_works() {
+    ExternalityBuilder::build().execute_with(|| {
+        let period_start = TfgridModule::get_uptime_period_start(40);
+        Timestamp::set_timestamp(period_start * 1000);
+        create_entity();
+        create_twin();
+        create_farm();
+        create_node();
+
+        Timestamp::set_timestamp((period_start + 1000) * 1000);
+        assert_ok!(TfgridModule::report_uptime(
+            RuntimeOrigin::signed(alice()),
+            1000
+        ));
+
+        // Node rebooted 500 seconds before reporting again
+        Timestamp::set_timestamp((period_start + 2000) * 1000);
+        assert_ok!(TfgridModule::report_uptime(
+            RuntimeOrigin::signed(alice()),
+            500
+        ));
+
+        assert_eq!(
+            TfgridModule::get_node_uptime(1, None),
+            Some(NodeUptime {
+                period: 40,
+                uptime: 1500,
+                duration: 2000,
+                percentage: 75,
+            })
+        );
+    });
+}
+
+#[test]
+fn node_uptime_standby_is_not_downtime_works() {
+    ExternalityBuilder::build().execute_with(|| {
+        let period_start = TfgridModule::get_uptime_period_start(40);
+        Timestamp::set_timestamp(period_start * 1000);
+        create_entity();
+        create_twin();
+        create_farm();
+        create_node();
+
+        Timestamp::set_timestamp((period_start + 1000) * 1000);
+        assert_ok!(TfgridModule::report_uptime(
+            RuntimeOrigin::signed(alice()),
+            1000
+        ));
+        assert_ok!(TfgridModule::change_power_state(
+            RuntimeOrigin::signed(alice()),
+            Power::Down
+        ));
+
+        Timestamp::set_timestamp((period_start + 5000) * 1000);
+        assert_ok!(TfgridModule::change_power_state(
+            RuntimeOrigin::signed(alice()),
+            Power::Up
+        ));
+
+        Timestamp::set_timestamp((period_start + 5100) * 1000);
+        assert_ok!(TfgridModule::report_uptime(
+            RuntimeOrigin::signed(alice()),
+            100
+        ));
+
+        let uptime = TfgridModule::get_node_uptime(1, None).unwrap();
+        assert_eq!(uptime.uptime, 5100);
+        assert_eq!(uptime.percentage, 100);
+    });
+}
+
+#[test]
+fn node_uptime_keeps_last_periods_works() {
+    ExternalityBuilder::build().execute_with(|| {
+        let period_start = TfgridModule::get_uptime_period_start(40);
+        Timestamp::set_timestamp(period_start * 1000);
+        create_entity();
+        create_twin();
+        create_farm();
+        create_node();
+
+        Timestamp::set_timestamp((period_start + 1000) * 1000);
+        assert_ok!(TfgridModule::report_uptime(
+            RuntimeOrigin::signed(alice()),
+            1000
+        ));
+
+        // Node stayed up over the next periods
+        let uptime = 3 * UPTIME_PERIOD_LENGTH + 1000;
+        Timestamp::set_timestamp((period_start + uptime) * 1000);
+        assert_ok!(TfgridModule::report_uptime(
+            RuntimeOrigin::signed(alice()),
+            uptime
+        ));
+
+        let periods: Vec<u64> = TfgridModule::node_uptime_periods(1)
+            .iter()
+            .map(|p| p.period)
+            .collect();
+        assert_eq!(periods, vec![41, 42, 43]);
+        assert_eq!(
+            TfgridModule::get_node_uptime(1, Some(42))
+                .unwrap()
+                .percentage,
+            100
+        );
+        assert_eq!(
+            TfgridModule::get_node_uptime(1, Some(40)).unwrap().uptime,
+            0
+        );
+        assert_eq!(TfgridModule::get_node_uptime(1, Some(44)), None);
+    });
+}
+
 #[test]
 fn change_power_state_works() {
     ExternalityBuilder::build().execute_with(|| {
@@ -1190,6 +1628,330 @@ fn change_power_target_unauthorized_fails() {
     });
 }
 
+#[test]
+fn schedule_node_maintenance_works() {
+    ExternalityBuilder::build().execute_with(|| {
+        Timestamp::set_timestamp(1628082000000);
+        create_twin();
+        create_farm();
+        create_node();
+
+        let start = 1628082000 + 3600;
+        let end = start + 7200;
+        assert_ok!(TfgridModule::schedule_node_maintenance(
+            RuntimeOrigin::signed(alice()),
+            1,
+            start,
+            end
+        ));
+        assert_eq!(
+            TfgridModule::node_maintenance(1),
+            vec![NodeMaintenanceWindow { start, end }]
+        );
+        assert!(!TfgridModule::is_node_in_maintenance(1));
+
+        Timestamp::set_timestamp(start * 1000);
+        assert!(TfgridModule::is_node_in_maintenance(1));
+
+        // Window can not be moved while it is not over
+        assert_noop!(
+            TfgridModule::schedule_node_maintenance(
+                RuntimeOrigin::signed(alice()),
+                1,
+                end,
+                end + 3600
+            ),
+            Error::<TestRuntime>::NodeMaintenanceAlreadyScheduled
+        );
+
+        Timestamp::set_timestamp(end * 1000);
+        assert!(!TfgridModule::is_node_in_maintenance(1));
+        assert_ok!(TfgridModule::schedule_node_maintenance(
+            RuntimeOrigin::signed(alice()),
+            1,
+            end,
+            end + 3600
+        ));
+
+        // Ended window is kept for the contracts not billed past it yet
+        assert_eq!(
+            TfgridModule::node_maintenance(1),
+            vec![
+                NodeMaintenanceWindow { start, end },
+                NodeMaintenanceWindow {
+                    start: end,
+                    end: end + 3600
+                }
+            ]
+        );
+    });
+}
+
+#[test]
+fn schedule_node_maintenance_drops_old_windows_works() {
+    ExternalityBuilder::build().execute_with(|| {
+        Timestamp::set_timestamp(1628082000000);
+        create_twin();
+        create_farm();
+        create_node();
+
+        let mut start = 1628082000;
+        for _ in 0..MAX_NODE_MAINTENANCE_WINDOWS {
+            assert_ok!(TfgridModule::schedule_node_maintenance(
+                RuntimeOrigin::signed(alice()),
+                1,
+                start,
+                start + 60
+            ));
+            start += 60;
+            Timestamp::set_timestamp(start * 1000);
+        }
+
+        assert_noop!(
+            TfgridModule::schedule_node_maintenance(
+                RuntimeOrigin::signed(alice()),
+                1,
+                start,
+                start + 60
+            ),
+            Error::<TestRuntime>::TooManyNodeMaintenanceWindows
+        );
+
+        // Windows ended long enough ago are dropped
+        let now = 1628082000 + 60 + NODE_MAINTENANCE_RETENTION;
+        Timestamp::set_timestamp(now * 1000);
+        assert_ok!(TfgridModule::schedule_node_maintenance(
+            RuntimeOrigin::signed(alice()),
+            1,
+            now,
+            now + 60
+        ));
+        let windows = TfgridModule::node_maintenance(1);
+        assert_eq!(windows.len(), MAX_NODE_MAINTENANCE_WINDOWS as usize);
+        assert_eq!(
+            windows.last(),
+            Some(&NodeMaintenanceWindow {
+                start: now,
+                end: now + 60
+            })
+        );
+    });
+}
+
+#[test]
+fn schedule_node_maintenance_invalid_window_fails() {
+    ExternalityBuilder::build().execute_with(|| {
+        Timestamp::set_timestamp(1628082000000);
+        create_twin();
+        create_twin_bob();
+        create_farm();
+        create_node();
+
+        let now = 1628082000;
+        // Starts in the past
+        assert_noop!(
+            TfgridModule::schedule_node_maintenance(
+                RuntimeOrigin::signed(alice()),
+                1,
+                now - 1,
+                now + 3600
+            ),
+            Error::<TestRuntime>::InvalidMaintenanceWindow
+        );
+        // Ends before it starts
+        assert_noop!(
+            TfgridModule::schedule_node_maintenance(
+                RuntimeOrigin::signed(alice()),
+                1,
+                now + 3600,
+                now
+            ),
+            Error::<TestRuntime>::InvalidMaintenanceWindow
+        );
+        // Too long
+        assert_noop!(
+            TfgridModule::schedule_node_maintenance(
+                RuntimeOrigin::signed(alice()),
+                1,
+                now,
+                now + MAX_NODE_MAINTENANCE_DURATION + 1
+            ),
+            Error::<TestRuntime>::InvalidMaintenanceWindow
+        );
+        assert_noop!(
+            TfgridModule::schedule_node_maintenance(
+                RuntimeOrigin::signed(bob()),
+                1,
+                now,
+                now + 3600
+            ),
+            Error::<TestRuntime>::FarmerNotAuthorized
+        );
+    });
+}
+
+#[test]
+fn set_node_power_schedule_works() {
+    ExternalityBuilder::build().execute_with(|| {
+        create_entity();
+        create_twin();
+        create_farm();
+        create_node();
+
+        let windows: PowerWindowsInput = bounded_vec![PowerWindow {
+            down_from: 60,
+            down_until: 420,
+        }];
+        assert_ok!(TfgridModule::set_node_power_schedule(
+            RuntimeOrigin::signed(alice()),
+            1,
+            windows.clone(),
+        ));
+
+        assert_eq!(TfgridModule::node_power_schedules(1), windows);
+        assert!(PowerScheduleNodes::<TestRuntime>::contains_key(60, 1));
+        assert!(PowerScheduleNodes::<TestRuntime>::contains_key(420, 1));
+
+        // Schedule is dropped with the node
+        assert_ok!(TfgridModule::delete_node_farm(
+            RuntimeOrigin::signed(alice()),
+            1
+        ));
+        assert_eq!(TfgridModule::node_power_schedules(1).len(), 0);
+        assert!(!PowerScheduleNodes::<TestRuntime>::contains_key(60, 1));
+        assert!(!PowerScheduleNodes::<TestRuntime>::contains_key(420, 1));
+    });
+}
+
+#[test]
+fn set_node_power_schedule_invalid_window_fails() {
+    ExternalityBuilder::build().execute_with(|| {
+        create_entity();
+        create_twin();
+        create_farm();
+        create_node();
+
+        assert_noop!(
+            TfgridModule::set_node_power_schedule(
+                RuntimeOrigin::signed(alice()),
+                1,
+                bounded_vec![PowerWindow {
+                    down_from: 60,
+                    down_until: 60,
+                }],
+            ),
+            Error::<TestRuntime>::InvalidPowerSchedule
+        );
+
+        assert_noop!(
+            TfgridModule::set_node_power_schedule(
+                RuntimeOrigin::signed(alice()),
+                1,
+                bounded_vec![PowerWindow {
+                    down_from: 60,
+                    down_until: MINUTES_PER_DAY,
+                }],
+            ),
+            Error::<TestRuntime>::InvalidPowerSchedule
+        );
+    });
+}
+
+#[test]
+fn set_node_power_schedule_unauthorized_fails() {
+    ExternalityBuilder::build().execute_with(|| {
+        create_entity();
+        create_twin();
+        create_farm();
+        create_node();
+        create_twin_bob();
+
+        assert_noop!(
+            TfgridModule::set_node_power_schedule(
+                RuntimeOrigin::signed(bob()),
+                1,
+                bounded_vec![PowerWindow {
+                    down_from: 60,
+                    down_until: 420,
+                }],
+            ),
+            Error::<TestRuntime>::UnauthorizedToChangePowerTarget
+        );
+    });
+}
+
+#[test]
+fn power_schedule_changes_power_target_works() {
+    ExternalityBuilder::build().execute_with(|| {
+        create_entity();
+        create_twin();
+        create_farm();
+        create_node();
+
+        // Midnight UTC
+        let day_start: u64 = 1_699_920_000;
+        Timestamp::set_timestamp((day_start + 30 * 60) * 1000);
+        TfgridModule::on_initialize(1);
+
+        assert_ok!(TfgridModule::set_node_power_schedule(
+            RuntimeOrigin::signed(alice()),
+            1,
+            bounded_vec![PowerWindow {
+                down_from: 60,
+                down_until: 420,
+            }],
+        ));
+        assert_eq!(TfgridModule::node_power_state(1).target, Power::Up);
+
+        Timestamp::set_timestamp((day_start + 60 * 60) * 1000);
+        TfgridModule::on_initialize(2);
+        assert_eq!(TfgridModule::node_power_state(1).target, Power::Down);
+        assert_eq!(
+            System::events().contains(&record(MockEvent::TfgridModule(
+                TfgridEvent::<TestRuntime>::PowerTargetChanged {
+                    farm_id: 1,
+                    node_id: 1,
+                    power_target: Power::Down
+                }
+            ))),
+            true
+        );
+
+        Timestamp::set_timestamp((day_start + 420 * 60) * 1000);
+        TfgridModule::on_initialize(3);
+        assert_eq!(TfgridModule::node_power_state(1).target, Power::Up);
+    });
+}
+
+#[test]
+fn power_schedule_spreads_nodes_over_blocks_works() {
+    ExternalityBuilder::build().execute_with(|| {
+        // Midnight UTC
+        let day_start: u64 = 1_699_920_000;
+        Timestamp::set_timestamp((day_start + 30 * 60) * 1000);
+        TfgridModule::on_initialize(1);
+        let start_minute = (day_start + 30 * 60) / 60;
+        assert_eq!(TfgridModule::last_power_schedule_minute(), start_minute);
+
+        // More nodes change at 00:31 than can be handled in a block
+        let nodes = MAX_POWER_SCHEDULE_NODES_PER_BLOCK + 10;
+        for node_id in 1..=nodes {
+            PowerScheduleNodes::<TestRuntime>::insert(31, node_id, ());
+        }
+
+        Timestamp::set_timestamp((day_start + 31 * 60) * 1000);
+        TfgridModule::on_initialize(2);
+        assert_eq!(TfgridModule::last_power_schedule_minute(), start_minute);
+        let (cursor_minute, _) = TfgridModule::power_schedule_cursor().unwrap();
+        assert_eq!(cursor_minute, start_minute + 1);
+
+        // The rest of the nodes is handled in the next block
+        TfgridModule::on_initialize(3);
+        assert_eq!(TfgridModule::last_power_schedule_minute(), start_minute + 1);
+        assert_eq!(TfgridModule::power_schedule_cursor(), None);
+    });
+}
+
 #[test]
 fn node_add_public_config_works() {
     ExternalityBuilder::build().execute_with(|| {
@@ -25,11 +25,12 @@ pub enum StorageVersion {
     V15Struct,
     V16Struct,
     V17Struct,
+    V18Struct,
 }
 
 impl Default for StorageVersion {
     fn default() -> StorageVersion {
-        StorageVersion::V17Struct
+        StorageVersion::V18Struct
     }
 }
 
@@ -208,3 +209,96 @@ pub struct LocationInput<City, Country, Latitude, Longitude> {
     pub latitude: Latitude,
     pub longitude: Longitude,
 }
+
+pub const UPTIME_PERIOD_OFFSET: u64 = 1_522_501_000; // start of the first uptime period (in seconds)
+pub const UPTIME_PERIOD_LENGTH: u64 = 2_630_880; // same length as a minting period (in seconds)
+pub const MAX_NODE_UPTIME_PERIODS: u32 = 3; // current period and the 2 previous ones
+
+// Last uptime report of a node, uptime since then is accounted on the next report
+#[derive(PartialEq, Eq, Clone, Encode, Decode, Default, Debug, TypeInfo)]
+pub struct NodeUptimeState {
+    // Time of the last report (in seconds)
+    pub last_report: u64,
+    // Uptime reported by the node (in seconds)
+    pub last_uptime: u64,
+    // Time the node went to standby, if it is in standby
+    pub standby_since: Option<u64>,
+}
+
+#[derive(PartialEq, Eq, Clone, Encode, Decode, Default, Debug, TypeInfo)]
+pub struct NodePeriodUptime {
+    pub period: u64,
+    // Time the node was up or in standby during the period (in seconds)
+    pub uptime: u64,
+}
+
+// Uptime of a node over a period, as returned by the runtime api
+#[derive(PartialEq, Eq, Clone, Encode, Decode, Default, Debug, TypeInfo)]
+pub struct NodeUptime {
+    pub period: u64,
+    // Time the node was up or in standby during the period (in seconds)
+    pub uptime: u64,
+    // Time the node existed during the period so far (in seconds)
+    pub duration: u64,
+    // Uptime over duration, rounded down
+    pub percentage: u8,
+}
+
+pub const MINUTES_PER_DAY: u32 = 24 * 60;
+pub const MAX_NODE_POWER_WINDOWS: u32 = 4;
+// Nodes deleted per block once their decommission deadline is reached
+pub const MAX_NODE_DECOMMISSIONS_PER_BLOCK: u32 = 10;
+// Contracts canceled per decommissioned node per block, the rest is left for the next blocks
+pub const MAX_NODE_DECOMMISSION_CONTRACTS_PER_BLOCK: u32 = 10;
+// Nodes the power schedules are applied to per block, the rest is left for the next blocks
+pub const MAX_POWER_SCHEDULE_NODES_PER_BLOCK: u32 = 100;
+// Minutes caught up by the power schedules after blocks were not produced for a while
+pub const MAX_POWER_SCHEDULE_CATCH_UP: u64 = 10;
+
+// Recurring daily window during which a node is powered down,
+// in minutes since midnight UTC. A window can wrap around midnight
+#[derive(PartialEq, Eq, Clone, Copy, Encode, Decode, Default, Debug, TypeInfo)]
+pub struct PowerWindow {
+    pub down_from: u32,
+    pub down_until: u32,
+}
+
+impl PowerWindow {
+    pub fn is_valid(&self) -> bool {
+        self.down_from < MINUTES_PER_DAY
+            && self.down_until < MINUTES_PER_DAY
+            && self.down_from != self.down_until
+    }
+
+    pub fn contains(&self, minute: u32) -> bool {
+        if self.down_from < self.down_until {
+            minute >= self.down_from && minute < self.down_until
+        } else {
+            minute >= self.down_from || minute < self.down_until
+        }
+    }
+}
+
+pub const MAX_NODE_MAINTENANCE_DURATION: u64 = 7 * 24 * 60 * 60; // 7 days (in seconds)
+                                                                 // Ended windows are kept this long so the contracts on the node, billed every hour,
+                                                                 // are billed past them before they are dropped
+pub const NODE_MAINTENANCE_RETENTION: u64 = 7 * 24 * 60 * 60; // 7 days (in seconds)
+pub const MAX_NODE_MAINTENANCE_WINDOWS: u32 = 10;
+
+// Announced window during which a node is in maintenance, in seconds since epoch
+#[derive(PartialEq, Eq, Clone, Copy, Encode, Decode, Default, Debug, TypeInfo)]
+pub struct NodeMaintenanceWindow {
+    pub start: u64,
+    pub end: u64,
+}
+
+impl NodeMaintenanceWindow {
+    pub fn contains(&self, timestamp: u64) -> bool {
+        timestamp >= self.start && timestamp < self.end
+    }
+
+    // Amount of seconds of the window that fall between from and to
+    pub fn overlap(&self, from: u64, to: u64) -> u64 {
+        to.min(self.end).saturating_sub(from.max(self.start))
+    }
+}
@@ -0,0 +1,131 @@
+use crate::*;
+use frame_support::sp_runtime::SaturatedConversion;
+use tfchain_support::types::Power;
+
+impl<T: Config> Pallet<T> {
+    pub fn account_uptime_report(node_id: u32, created: u64, uptime: u64, now: u64) {
+        let mut report = NodeUptimeReports::<T>::get(node_id).unwrap_or_default();
+
+        match report.standby_since.take() {
+            // Standby is not downtime, the node is accounted as up until it reported again
+            Some(standby_since) => Self::credit_node_uptime(node_id, standby_since, now),
+            // The uptime of a node went backwards when it rebooted since its last report,
+            // it was then only up since it booted. Time it did not report while its uptime
+            // kept going is accounted as well
+            None if report.last_report > 0 => {
+                let elapsed = now.saturating_sub(report.last_report);
+                let up = match uptime.checked_sub(report.last_uptime) {
+                    Some(gained) => gained,
+                    None => uptime,
+                };
+                Self::credit_node_uptime(node_id, now - up.min(elapsed), now);
+            }
+            // First report of the node
+            None => {
+                let up = uptime.min(now.saturating_sub(created));
+                Self::credit_node_uptime(node_id, now - up, now);
+            }
+        }
+
+        report.last_report = now;
+        report.last_uptime = uptime;
+        NodeUptimeReports::<T>::insert(node_id, report);
+    }
+
+    pub fn account_power_state_change(node_id: u32, power_state: &Power, now: u64) {
+        let mut report = NodeUptimeReports::<T>::get(node_id).unwrap_or_default();
+
+        match power_state {
+            // The node reports itself it goes to standby so it was up since its last report
+            Power::Down if report.standby_since.is_none() => {
+                if report.last_report > 0 {
+                    Self::credit_node_uptime(node_id, report.last_report, now);
+                }
+                report.standby_since = Some(now);
+            }
+            // The node just booted, its next report is accounted from now on
+            Power::Up => {
+                if let Some(standby_since) = report.standby_since.take() {
+                    Self::credit_node_uptime(node_id, standby_since, now);
+                }
+                report.last_uptime = 0;
+            }
+            _ => return,
+        }
+
+        report.last_report = now;
+        NodeUptimeReports::<T>::insert(node_id, report);
+    }
+
+    // Split the time a node was up over the periods it falls in,
+    // only the last periods are kept
+    fn credit_node_uptime(node_id: u32, from: u64, to: u64) {
+        let oldest_period =
+            Self::get_uptime_period(to).saturating_sub(types::MAX_NODE_UPTIME_PERIODS as u64 - 1);
+        let mut start = from.max(Self::get_uptime_period_start(oldest_period));
+        if start >= to {
+            return;
+        }
+
+        let mut periods = NodeUptimePeriods::<T>::get(node_id);
+        while start < to {
+            let period = Self::get_uptime_period(start);
+            let end = Self::get_uptime_period_start(period + 1).min(to);
+
+            match periods.iter_mut().find(|p| p.period == period) {
+                Some(period_uptime) => period_uptime.uptime += end - start,
+                None => {
+                    // Oldest period is dropped first
+                    if periods.is_full() {
+                        periods.remove(0);
+                    }
+                    let _ = periods.try_push(types::NodePeriodUptime {
+                        period,
+                        uptime: end - start,
+                    });
+                }
+            }
+
+            start = end;
+        }
+
+        NodeUptimePeriods::<T>::insert(node_id, periods);
+    }
+
+    pub fn get_uptime_period(timestamp: u64) -> u64 {
+        timestamp.saturating_sub(types::UPTIME_PERIOD_OFFSET) / types::UPTIME_PERIOD_LENGTH
+    }
+
+    pub fn get_uptime_period_start(period: u64) -> u64 {
+        types::UPTIME_PERIOD_OFFSET
+            .saturating_add(period.saturating_mul(types::UPTIME_PERIOD_LENGTH))
+    }
+
+    // Uptime of a node over a period (current one by default), as accounted
+    // up to its last report. Returns `None` if the node did not exist yet
+    pub fn get_node_uptime(node_id: u32, period: Option<u64>) -> Option<types::NodeUptime> {
+        let node = Nodes::<T>::get(node_id)?;
+        let now = <pallet_timestamp::Pallet<T>>::get().saturated_into::<u64>() / 1000;
+        let period = period.unwrap_or_else(|| Self::get_uptime_period(now));
+
+        let start = Self::get_uptime_period_start(period).max(node.created);
+        let end = Self::get_uptime_period_start(period.saturating_add(1)).min(now);
+        if end <= start {
+            return None;
+        }
+
+        let duration = end - start;
+        let uptime = NodeUptimePeriods::<T>::get(node_id)
+            .iter()
+            .find(|p| p.period == period)
+            .map_or(0, |p| p.uptime)
+            .min(duration);
+
+        Some(types::NodeUptime {
+            period,
+            uptime,
+            duration,
+            percentage: (uptime * 100 / duration) as u8,
+        })
+    }
+}
@@ -65,6 +65,14 @@ pub trait WeightInfo {
 	fn change_power_target() -> Weight;
 	fn bond_twin_account() -> Weight;
 	fn report_uptime_v2() -> Weight;
+	fn set_farm_overcommit_ratio() -> Weight;
+	fn add_farm_ip6_prefix() -> Weight;
+	fn remove_farm_ip6_prefix() -> Weight;
+	fn force_remove_farm_ip6_prefix() -> Weight;
+	fn set_node_power_schedule() -> Weight;
+	fn decommission_node() -> Weight;
+	fn schedule_node_maintenance() -> Weight;
+	fn apply_node_power_schedule() -> Weight;
 }
 
 /// Weights for pallet_tfgrid using the Substrate node and recommended hardware.
@@ -235,13 +243,18 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Proof: `TfgridModule::NodeIdByTwinID` (`max_values`: None, `max_size`: None, mode: `Measured`)
 	/// Storage: `TfgridModule::Nodes` (r:1 w:0)
 	/// Proof: `TfgridModule::Nodes` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `TfgridModule::NodeUptimeReports` (r:1 w:1)
+	/// Proof: `TfgridModule::NodeUptimeReports` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `TfgridModule::NodeUptimePeriods` (r:1 w:1)
+	/// Proof: `TfgridModule::NodeUptimePeriods` (`max_values`: None, `max_size`: None, mode: `Measured`)
 	fn report_uptime() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `454`
 		//  Estimated: `3919`
 		// Minimum execution time: 18_635_000 picoseconds.
 		Weight::from_parts(19_006_000, 3919)
-			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().reads(6_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
 	}
 	/// Storage: `TfgridModule::Farms` (r:1 w:0)
 	/// Proof: `TfgridModule::Farms` (`max_values`: None, `max_size`: None, mode: `Measured`)
@@ -270,14 +283,30 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Proof: `SmartContractModule::ActiveRentContractForNode` (`max_values`: None, `max_size`: None, mode: `Measured`)
 	/// Storage: `Dao::FarmWeight` (r:1 w:1)
 	/// Proof: `Dao::FarmWeight` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `TfgridModule::NodeUptimeReports` (r:0 w:1)
+	/// Proof: `TfgridModule::NodeUptimeReports` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `TfgridModule::NodeUptimePeriods` (r:0 w:1)
+	/// Proof: `TfgridModule::NodeUptimePeriods` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `TfgridModule::NodePowerSchedules` (r:1 w:1)
+	/// Proof: `TfgridModule::NodePowerSchedules` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `TfgridModule::PowerScheduleNodes` (r:0 w:4)
+	/// Proof: `TfgridModule::PowerScheduleNodes` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SmartContractModule::NodeWakeupRequests` (r:0 w:1)
+	/// Proof: `SmartContractModule::NodeWakeupRequests` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `TfgridModule::DecommissioningNodes` (r:0 w:1)
+	/// Proof: `TfgridModule::DecommissioningNodes` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `TfgridModule::NodeMaintenance` (r:0 w:1)
+	/// Proof: `TfgridModule::NodeMaintenance` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `TfgridModule::NodeIdByTwinID` (r:0 w:1)
+	/// Proof: `TfgridModule::NodeIdByTwinID` (`max_values`: None, `max_size`: None, mode: `Measured`)
 	fn delete_node() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `682`
 		//  Estimated: `4147`
 		// Minimum execution time: 27_362_000 picoseconds.
 		Weight::from_parts(27_943_000, 4147)
-			.saturating_add(T::DbWeight::get().reads(6_u64))
-			.saturating_add(T::DbWeight::get().writes(3_u64))
+			.saturating_add(T::DbWeight::get().reads(7_u64))
+			.saturating_add(T::DbWeight::get().writes(14_u64))
 	}
 	/// Storage: `TfgridModule::UsersTermsAndConditions` (r:1 w:0)
 	/// Proof: `TfgridModule::UsersTermsAndConditions` (`max_values`: None, `max_size`: None, mode: `Measured`)
@@ -381,14 +410,28 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Proof: `Dao::FarmWeight` (`max_values`: None, `max_size`: None, mode: `Measured`)
 	/// Storage: `TfgridModule::NodeIdByTwinID` (r:0 w:1)
 	/// Proof: `TfgridModule::NodeIdByTwinID` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `TfgridModule::NodeUptimeReports` (r:0 w:1)
+	/// Proof: `TfgridModule::NodeUptimeReports` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `TfgridModule::NodeUptimePeriods` (r:0 w:1)
+	/// Proof: `TfgridModule::NodeUptimePeriods` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `TfgridModule::NodePowerSchedules` (r:1 w:1)
+	/// Proof: `TfgridModule::NodePowerSchedules` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `TfgridModule::PowerScheduleNodes` (r:0 w:4)
+	/// Proof: `TfgridModule::PowerScheduleNodes` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SmartContractModule::NodeWakeupRequests` (r:0 w:1)
+	/// Proof: `SmartContractModule::NodeWakeupRequests` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `TfgridModule::DecommissioningNodes` (r:0 w:1)
+	/// Proof: `TfgridModule::DecommissioningNodes` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `TfgridModule::NodeMaintenance` (r:0 w:1)
+	/// Proof: `TfgridModule::NodeMaintenance` (`max_values`: None, `max_size`: None, mode: `Measured`)
 	fn delete_node_farm() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `828`
 		//  Estimated: `4293`
 		// Minimum execution time: 32_582_000 picoseconds.
 		Weight::from_parts(33_443_000, 4293)
-			.saturating_add(T::DbWeight::get().reads(7_u64))
-			.saturating_add(T::DbWeight::get().writes(4_u64))
+			.saturating_add(T::DbWeight::get().reads(8_u64))
+			.saturating_add(T::DbWeight::get().writes(14_u64))
 	}
 	/// Storage: `TfgridModule::Farms` (r:1 w:1)
 	/// Proof: `TfgridModule::Farms` (`max_values`: None, `max_size`: None, mode: `Measured`)
@@ -489,14 +532,22 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Proof: `TfgridModule::Nodes` (`max_values`: None, `max_size`: None, mode: `Measured`)
 	/// Storage: `TfgridModule::NodePower` (r:1 w:1)
 	/// Proof: `TfgridModule::NodePower` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Timestamp::Now` (r:1 w:0)
+	/// Proof: `Timestamp::Now` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	/// Storage: `TfgridModule::NodeUptimeReports` (r:1 w:1)
+	/// Proof: `TfgridModule::NodeUptimeReports` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `TfgridModule::NodeUptimePeriods` (r:1 w:1)
+	/// Proof: `TfgridModule::NodeUptimePeriods` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SmartContractModule::NodeWakeupRequests` (r:1 w:1)
+	/// Proof: `SmartContractModule::NodeWakeupRequests` (`max_values`: None, `max_size`: None, mode: `Measured`)
 	fn change_power_state() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `621`
 		//  Estimated: `4086`
 		// Minimum execution time: 22_723_000 picoseconds.
 		Weight::from_parts(23_154_000, 4086)
-			.saturating_add(T::DbWeight::get().reads(4_u64))
-			.saturating_add(T::DbWeight::get().writes(1_u64))
+			.saturating_add(T::DbWeight::get().reads(8_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
 	}
 	/// Storage: `TfgridModule::TwinIdByAccountID` (r:1 w:0)
 	/// Proof: `TfgridModule::TwinIdByAccountID` (`max_values`: None, `max_size`: None, mode: `Measured`)
@@ -540,13 +591,178 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Proof: `TfgridModule::Nodes` (`max_values`: None, `max_size`: None, mode: `Measured`)
 	/// Storage: `Timestamp::Now` (r:1 w:0)
 	/// Proof: `Timestamp::Now` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	/// Storage: `TfgridModule::NodeUptimeReports` (r:1 w:1)
+	/// Proof: `TfgridModule::NodeUptimeReports` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `TfgridModule::NodeUptimePeriods` (r:1 w:1)
+	/// Proof: `TfgridModule::NodeUptimePeriods` (`max_values`: None, `max_size`: None, mode: `Measured`)
 	fn report_uptime_v2() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `454`
 		//  Estimated: `3919`
 		// Minimum execution time: 18_144_000 picoseconds.
 		Weight::from_parts(18_385_000, 3919)
-			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().reads(6_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: `TfgridModule::TwinIdByAccountID` (r:1 w:0)
+	/// Proof: `TfgridModule::TwinIdByAccountID` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `TfgridModule::Farms` (r:1 w:0)
+	/// Proof: `TfgridModule::Farms` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `TfgridModule::FarmOvercommitRatio` (r:0 w:1)
+	/// Proof: `TfgridModule::FarmOvercommitRatio` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn set_farm_overcommit_ratio() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `537`
+		//  Estimated: `4002`
+		// Minimum execution time: 15_621_000 picoseconds.
+		Weight::from_parts(16_205_000, 4002)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `TfgridModule::Farms` (r:1 w:0)
+	/// Proof: `TfgridModule::Farms` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `TfgridModule::Twins` (r:1 w:0)
+	/// Proof: `TfgridModule::Twins` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `TfgridModule::FarmIp6Prefixes` (r:1 w:1)
+	/// Proof: `TfgridModule::FarmIp6Prefixes` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn add_farm_ip6_prefix() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `569`
+		//  Estimated: `4034`
+		// Minimum execution time: 21_410_000 picoseconds.
+		Weight::from_parts(22_013_000, 4034)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `TfgridModule::Farms` (r:1 w:0)
+	/// Proof: `TfgridModule::Farms` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `TfgridModule::Twins` (r:1 w:0)
+	/// Proof: `TfgridModule::Twins` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `TfgridModule::FarmIp6Prefixes` (r:1 w:1)
+	/// Proof: `TfgridModule::FarmIp6Prefixes` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn remove_farm_ip6_prefix() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `733`
+		//  Estimated: `4198`
+		// Minimum execution time: 19_876_000 picoseconds.
+		Weight::from_parts(20_352_000, 4198)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `TfgridModule::FarmIp6Prefixes` (r:1 w:1)
+	/// Proof: `TfgridModule::FarmIp6Prefixes` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn force_remove_farm_ip6_prefix() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `312`
+		//  Estimated: `3777`
+		// Minimum execution time: 12_637_000 picoseconds.
+		Weight::from_parts(13_104_000, 3777)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `TfgridModule::TwinIdByAccountID` (r:1 w:0)
+	/// Proof: `TfgridModule::TwinIdByAccountID` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `TfgridModule::Nodes` (r:1 w:0)
+	/// Proof: `TfgridModule::Nodes` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `TfgridModule::Farms` (r:1 w:0)
+	/// Proof: `TfgridModule::Farms` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `TfgridModule::NodePowerSchedules` (r:1 w:1)
+	/// Proof: `TfgridModule::NodePowerSchedules` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `TfgridModule::PowerScheduleNodes` (r:0 w:8)
+	/// Proof: `TfgridModule::PowerScheduleNodes` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Timestamp::Now` (r:1 w:0)
+	/// Proof: `Timestamp::Now` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	/// Storage: `TfgridModule::NodePower` (r:1 w:1)
+	/// Proof: `TfgridModule::NodePower` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SmartContractModule::ActiveNodeContracts` (r:1 w:0)
+	/// Proof: `SmartContractModule::ActiveNodeContracts` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SmartContractModule::ActiveRentContractForNode` (r:1 w:0)
+	/// Proof: `SmartContractModule::ActiveRentContractForNode` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn set_node_power_schedule() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1002`
+		//  Estimated: `4467`
+		// Minimum execution time: 32_614_000 picoseconds.
+		Weight::from_parts(33_402_000, 4467)
+			.saturating_add(T::DbWeight::get().reads(8_u64))
+			.saturating_add(T::DbWeight::get().writes(10_u64))
+	}
+	/// Storage: `TfgridModule::TwinIdByAccountID` (r:1 w:0)
+	/// Proof: `TfgridModule::TwinIdByAccountID` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `TfgridModule::Nodes` (r:1 w:1)
+	/// Proof: `TfgridModule::Nodes` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `TfgridModule::Farms` (r:1 w:0)
+	/// Proof: `TfgridModule::Farms` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `TfgridModule::DecommissioningNodes` (r:1 w:1)
+	/// Proof: `TfgridModule::DecommissioningNodes` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SmartContractModule::ActiveNodeContracts` (r:1 w:0)
+	/// Proof: `SmartContractModule::ActiveNodeContracts` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SmartContractModule::ActiveRentContractForNode` (r:1 w:0)
+	/// Proof: `SmartContractModule::ActiveRentContractForNode` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `TfgridModule::NodesByFarmID` (r:1 w:1)
+	/// Proof: `TfgridModule::NodesByFarmID` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Dao::FarmWeight` (r:1 w:1)
+	/// Proof: `Dao::FarmWeight` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `TfgridModule::NodeIdByTwinID` (r:0 w:1)
+	/// Proof: `TfgridModule::NodeIdByTwinID` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `TfgridModule::NodeUptimeReports` (r:0 w:1)
+	/// Proof: `TfgridModule::NodeUptimeReports` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `TfgridModule::NodeUptimePeriods` (r:0 w:1)
+	/// Proof: `TfgridModule::NodeUptimePeriods` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `TfgridModule::NodePowerSchedules` (r:1 w:1)
+	/// Proof: `TfgridModule::NodePowerSchedules` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SmartContractModule::NodeWakeupRequests` (r:0 w:1)
+	/// Proof: `SmartContractModule::NodeWakeupRequests` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `TfgridModule::NodeMaintenance` (r:0 w:1)
+	/// Proof: `TfgridModule::NodeMaintenance` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn decommission_node() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `862`
+		//  Estimated: `4327`
+		// Minimum execution time: 34_112_000 picoseconds.
+		Weight::from_parts(35_021_000, 4327)
+			.saturating_add(T::DbWeight::get().reads(9_u64))
+			.saturating_add(T::DbWeight::get().writes(10_u64))
+	}
+	/// Storage: `TfgridModule::TwinIdByAccountID` (r:1 w:0)
+	/// Proof: `TfgridModule::TwinIdByAccountID` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `TfgridModule::Nodes` (r:1 w:0)
+	/// Proof: `TfgridModule::Nodes` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `TfgridModule::Farms` (r:1 w:0)
+	/// Proof: `TfgridModule::Farms` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Timestamp::Now` (r:1 w:0)
+	/// Proof: `Timestamp::Now` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	/// Storage: `TfgridModule::NodeMaintenance` (r:1 w:1)
+	/// Proof: `TfgridModule::NodeMaintenance` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn schedule_node_maintenance() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `529`
+		//  Estimated: `3994`
+		// Minimum execution time: 18_233_000 picoseconds.
+		Weight::from_parts(18_905_000, 3994)
+			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `TfgridModule::PowerScheduleNodes` (r:1 w:0)
+	/// Proof: `TfgridModule::PowerScheduleNodes` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `TfgridModule::NodePowerSchedules` (r:1 w:0)
+	/// Proof: `TfgridModule::NodePowerSchedules` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `TfgridModule::NodePower` (r:1 w:1)
+	/// Proof: `TfgridModule::NodePower` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `TfgridModule::Nodes` (r:1 w:0)
+	/// Proof: `TfgridModule::Nodes` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SmartContractModule::ActiveNodeContracts` (r:1 w:0)
+	/// Proof: `SmartContractModule::ActiveNodeContracts` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SmartContractModule::ActiveRentContractForNode` (r:1 w:0)
+	/// Proof: `SmartContractModule::ActiveRentContractForNode` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn apply_node_power_schedule() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `904`
+		//  Estimated: `4369`
+		// Minimum execution time: 21_870_000 picoseconds.
+		Weight::from_parts(22_413_000, 4369)
+			.saturating_add(T::DbWeight::get().reads(6_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
 	}
 }
 
@@ -717,13 +933,18 @@ impl WeightInfo for () {
 	/// Proof: `TfgridModule::NodeIdByTwinID` (`max_values`: None, `max_size`: None, mode: `Measured`)
 	/// Storage: `TfgridModule::Nodes` (r:1 w:0)
 	/// Proof: `TfgridModule::Nodes` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `TfgridModule::NodeUptimeReports` (r:1 w:1)
+	/// Proof: `TfgridModule::NodeUptimeReports` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `TfgridModule::NodeUptimePeriods` (r:1 w:1)
+	/// Proof: `TfgridModule::NodeUptimePeriods` (`max_values`: None, `max_size`: None, mode: `Measured`)
 	fn report_uptime() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `454`
 		//  Estimated: `3919`
 		// Minimum execution time: 18_635_000 picoseconds.
 		Weight::from_parts(19_006_000, 3919)
-			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().reads(6_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
 	}
 	/// Storage: `TfgridModule::Farms` (r:1 w:0)
 	/// Proof: `TfgridModule::Farms` (`max_values`: None, `max_size`: None, mode: `Measured`)
@@ -752,14 +973,30 @@ impl WeightInfo for () {
 	/// Proof: `SmartContractModule::ActiveRentContractForNode` (`max_values`: None, `max_size`: None, mode: `Measured`)
 	/// Storage: `Dao::FarmWeight` (r:1 w:1)
 	/// Proof: `Dao::FarmWeight` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `TfgridModule::NodeUptimeReports` (r:0 w:1)
+	/// Proof: `TfgridModule::NodeUptimeReports` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `TfgridModule::NodeUptimePeriods` (r:0 w:1)
+	/// Proof: `TfgridModule::NodeUptimePeriods` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `TfgridModule::NodePowerSchedules` (r:1 w:1)
+	/// Proof: `TfgridModule::NodePowerSchedules` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `TfgridModule::PowerScheduleNodes` (r:0 w:4)
+	/// Proof: `TfgridModule::PowerScheduleNodes` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SmartContractModule::NodeWakeupRequests` (r:0 w:1)
+	/// Proof: `SmartContractModule::NodeWakeupRequests` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `TfgridModule::DecommissioningNodes` (r:0 w:1)
+	/// Proof: `TfgridModule::DecommissioningNodes` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `TfgridModule::NodeMaintenance` (r:0 w:1)
+	/// Proof: `TfgridModule::NodeMaintenance` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `TfgridModule::NodeIdByTwinID` (r:0 w:1)
+	/// Proof: `TfgridModule::NodeIdByTwinID` (`max_values`: None, `max_size`: None, mode: `Measured`)
 	fn delete_node() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `682`
 		//  Estimated: `4147`
 		// Minimum execution time: 27_362_000 picoseconds.
 		Weight::from_parts(27_943_000, 4147)
-			.saturating_add(RocksDbWeight::get().reads(6_u64))
-			.saturating_add(RocksDbWeight::get().writes(3_u64))
+			.saturating_add(RocksDbWeight::get().reads(7_u64))
+			.saturating_add(RocksDbWeight::get().writes(14_u64))
 	}
 	/// Storage: `TfgridModule::UsersTermsAndConditions` (r:1 w:0)
 	/// Proof: `TfgridModule::UsersTermsAndConditions` (`max_values`: None, `max_size`: None, mode: `Measured`)
@@ -863,14 +1100,28 @@ impl WeightInfo for () {
 	/// Proof: `Dao::FarmWeight` (`max_values`: None, `max_size`: None, mode: `Measured`)
 	/// Storage: `TfgridModule::NodeIdByTwinID` (r:0 w:1)
 	/// Proof: `TfgridModule::NodeIdByTwinID` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `TfgridModule::NodeUptimeReports` (r:0 w:1)
+	/// Proof: `TfgridModule::NodeUptimeReports` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `TfgridModule::NodeUptimePeriods` (r:0 w:1)
+	/// Proof: `TfgridModule::NodeUptimePeriods` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `TfgridModule::NodePowerSchedules` (r:1 w:1)
+	/// Proof: `TfgridModule::NodePowerSchedules` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `TfgridModule::PowerScheduleNodes` (r:0 w:4)
+	/// Proof: `TfgridModule::PowerScheduleNodes` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SmartContractModule::NodeWakeupRequests` (r:0 w:1)
+	/// Proof: `SmartContractModule::NodeWakeupRequests` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `TfgridModule::DecommissioningNodes` (r:0 w:1)
+	/// Proof: `TfgridModule::DecommissioningNodes` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `TfgridModule::NodeMaintenance` (r:0 w:1)
+	/// Proof: `TfgridModule::NodeMaintenance` (`max_values`: None, `max_size`: None, mode: `Measured`)
 	fn delete_node_farm() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `828`
 		//  Estimated: `4293`
 		// Minimum execution time: 32_582_000 picoseconds.
 		Weight::from_parts(33_443_000, 4293)
-			.saturating_add(RocksDbWeight::get().reads(7_u64))
-			.saturating_add(RocksDbWeight::get().writes(4_u64))
+			.saturating_add(RocksDbWeight::get().reads(8_u64))
+			.saturating_add(RocksDbWeight::get().writes(14_u64))
 	}
 	/// Storage: `TfgridModule::Farms` (r:1 w:1)
 	/// Proof: `TfgridModule::Farms` (`max_values`: None, `max_size`: None, mode: `Measured`)
@@ -971,14 +1222,22 @@ impl WeightInfo for () {
 	/// Proof: `TfgridModule::Nodes` (`max_values`: None, `max_size`: None, mode: `Measured`)
 	/// Storage: `TfgridModule::NodePower` (r:1 w:1)
 	/// Proof: `TfgridModule::NodePower` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Timestamp::Now` (r:1 w:0)
+	/// Proof: `Timestamp::Now` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	/// Storage: `TfgridModule::NodeUptimeReports` (r:1 w:1)
+	/// Proof: `TfgridModule::NodeUptimeReports` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `TfgridModule::NodeUptimePeriods` (r:1 w:1)
+	/// Proof: `TfgridModule::NodeUptimePeriods` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SmartContractModule::NodeWakeupRequests` (r:1 w:1)
+	/// Proof: `SmartContractModule::NodeWakeupRequests` (`max_values`: None, `max_size`: None, mode: `Measured`)
 	fn change_power_state() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `621`
 		//  Estimated: `4086`
 		// Minimum execution time: 22_723_000 picoseconds.
 		Weight::from_parts(23_154_000, 4086)
-			.saturating_add(RocksDbWeight::get().reads(4_u64))
-			.saturating_add(RocksDbWeight::get().writes(1_u64))
+			.saturating_add(RocksDbWeight::get().reads(8_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
 	}
 	/// Storage: `TfgridModule::TwinIdByAccountID` (r:1 w:0)
 	/// Proof: `TfgridModule::TwinIdByAccountID` (`max_values`: None, `max_size`: None, mode: `Measured`)
@@ -1022,12 +1281,177 @@ impl WeightInfo for () {
 	/// Proof: `TfgridModule::Nodes` (`max_values`: None, `max_size`: None, mode: `Measured`)
 	/// Storage: `Timestamp::Now` (r:1 w:0)
 	/// Proof: `Timestamp::Now` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	/// Storage: `TfgridModule::NodeUptimeReports` (r:1 w:1)
+	/// Proof: `TfgridModule::NodeUptimeReports` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `TfgridModule::NodeUptimePeriods` (r:1 w:1)
+	/// Proof: `TfgridModule::NodeUptimePeriods` (`max_values`: None, `max_size`: None, mode: `Measured`)
 	fn report_uptime_v2() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `454`
 		//  Estimated: `3919`
 		// Minimum execution time: 18_144_000 picoseconds.
 		Weight::from_parts(18_385_000, 3919)
-			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().reads(6_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// Storage: `TfgridModule::TwinIdByAccountID` (r:1 w:0)
+	/// Proof: `TfgridModule::TwinIdByAccountID` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `TfgridModule::Farms` (r:1 w:0)
+	/// Proof: `TfgridModule::Farms` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `TfgridModule::FarmOvercommitRatio` (r:0 w:1)
+	/// Proof: `TfgridModule::FarmOvercommitRatio` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn set_farm_overcommit_ratio() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `537`
+		//  Estimated: `4002`
+		// Minimum execution time: 15_621_000 picoseconds.
+		Weight::from_parts(16_205_000, 4002)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `TfgridModule::Farms` (r:1 w:0)
+	/// Proof: `TfgridModule::Farms` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `TfgridModule::Twins` (r:1 w:0)
+	/// Proof: `TfgridModule::Twins` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `TfgridModule::FarmIp6Prefixes` (r:1 w:1)
+	/// Proof: `TfgridModule::FarmIp6Prefixes` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn add_farm_ip6_prefix() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `569`
+		//  Estimated: `4034`
+		// Minimum execution time: 21_410_000 picoseconds.
+		Weight::from_parts(22_013_000, 4034)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `TfgridModule::Farms` (r:1 w:0)
+	/// Proof: `TfgridModule::Farms` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `TfgridModule::Twins` (r:1 w:0)
+	/// Proof: `TfgridModule::Twins` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `TfgridModule::FarmIp6Prefixes` (r:1 w:1)
+	/// Proof: `TfgridModule::FarmIp6Prefixes` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn remove_farm_ip6_prefix() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `733`
+		//  Estimated: `4198`
+		// Minimum execution time: 19_876_000 picoseconds.
+		Weight::from_parts(20_352_000, 4198)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `TfgridModule::FarmIp6Prefixes` (r:1 w:1)
+	/// Proof: `TfgridModule::FarmIp6Prefixes` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn force_remove_farm_ip6_prefix() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `312`
+		//  Estimated: `3777`
+		// Minimum execution time: 12_637_000 picoseconds.
+		Weight::from_parts(13_104_000, 3777)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `TfgridModule::TwinIdByAccountID` (r:1 w:0)
+	/// Proof: `TfgridModule::TwinIdByAccountID` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `TfgridModule::Nodes` (r:1 w:0)
+	/// Proof: `TfgridModule::Nodes` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `TfgridModule::Farms` (r:1 w:0)
+	/// Proof: `TfgridModule::Farms` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `TfgridModule::NodePowerSchedules` (r:1 w:1)
+	/// Proof: `TfgridModule::NodePowerSchedules` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `TfgridModule::PowerScheduleNodes` (r:0 w:8)
+	/// Proof: `TfgridModule::PowerScheduleNodes` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Timestamp::Now` (r:1 w:0)
+	/// Proof: `Timestamp::Now` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	/// Storage: `TfgridModule::NodePower` (r:1 w:1)
+	/// Proof: `TfgridModule::NodePower` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SmartContractModule::ActiveNodeContracts` (r:1 w:0)
+	/// Proof: `SmartContractModule::ActiveNodeContracts` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SmartContractModule::ActiveRentContractForNode` (r:1 w:0)
+	/// Proof: `SmartContractModule::ActiveRentContractForNode` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn set_node_power_schedule() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1002`
+		//  Estimated: `4467`
+		// Minimum execution time: 32_614_000 picoseconds.
+		Weight::from_parts(33_402_000, 4467)
+			.saturating_add(RocksDbWeight::get().reads(8_u64))
+			.saturating_add(RocksDbWeight::get().writes(10_u64))
+	}
+	/// Storage: `TfgridModule::TwinIdByAccountID` (r:1 w:0)
+	/// Proof: `TfgridModule::TwinIdByAccountID` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `TfgridModule::Nodes` (r:1 w:1)
+	/// Proof: `TfgridModule::Nodes` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `TfgridModule::Farms` (r:1 w:0)
+	/// Proof: `TfgridModule::Farms` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `TfgridModule::DecommissioningNodes` (r:1 w:1)
+	/// Proof: `TfgridModule::DecommissioningNodes` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SmartContractModule::ActiveNodeContracts` (r:1 w:0)
+	/// Proof: `SmartContractModule::ActiveNodeContracts` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SmartContractModule::ActiveRentContractForNode` (r:1 w:0)
+	/// Proof: `SmartContractModule::ActiveRentContractForNode` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `TfgridModule::NodesByFarmID` (r:1 w:1)
+	/// Proof: `TfgridModule::NodesByFarmID` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Dao::FarmWeight` (r:1 w:1)
+	/// Proof: `Dao::FarmWeight` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `TfgridModule::NodeIdByTwinID` (r:0 w:1)
+	/// Proof: `TfgridModule::NodeIdByTwinID` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `TfgridModule::NodeUptimeReports` (r:0 w:1)
+	/// Proof: `TfgridModule::NodeUptimeReports` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `TfgridModule::NodeUptimePeriods` (r:0 w:1)
+	/// Proof: `TfgridModule::NodeUptimePeriods` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `TfgridModule::NodePowerSchedules` (r:1 w:1)
+	/// Proof: `TfgridModule::NodePowerSchedules` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SmartContractModule::NodeWakeupRequests` (r:0 w:1)
+	/// Proof: `SmartContractModule::NodeWakeupRequests` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `TfgridModule::NodeMaintenance` (r:0 w:1)
+	/// Proof: `TfgridModule::NodeMaintenance` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn decommission_node() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `862`
+		//  Estimated: `4327`
+		// Minimum execution time: 34_112_000 picoseconds.
+		Weight::from_parts(35_021_000, 4327)
+			.saturating_add(RocksDbWeight::get().reads(9_u64))
+			.saturating_add(RocksDbWeight::get().writes(10_u64))
+	}
+	/// Storage: `TfgridModule::TwinIdByAccountID` (r:1 w:0)
+	/// Proof: `TfgridModule::TwinIdByAccountID` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `TfgridModule::Nodes` (r:1 w:0)
+	/// Proof: `TfgridModule::Nodes` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `TfgridModule::Farms` (r:1 w:0)
+	/// Proof: `TfgridModule::Farms` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Timestamp::Now` (r:1 w:0)
+	/// Proof: `Timestamp::Now` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	/// Storage: `TfgridModule::NodeMaintenance` (r:1 w:1)
+	/// Proof: `TfgridModule::NodeMaintenance` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn schedule_node_maintenance() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `529`
+		//  Estimated: `3994`
+		// Minimum execution time: 18_233_000 picoseconds.
+		Weight::from_parts(18_905_000, 3994)
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `TfgridModule::PowerScheduleNodes` (r:1 w:0)
+	/// Proof: `TfgridModule::PowerScheduleNodes` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `TfgridModule::NodePowerSchedules` (r:1 w:0)
+	/// Proof: `TfgridModule::NodePowerSchedules` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `TfgridModule::NodePower` (r:1 w:1)
+	/// Proof: `TfgridModule::NodePower` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `TfgridModule::Nodes` (r:1 w:0)
+	/// Proof: `TfgridModule::Nodes` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SmartContractModule::ActiveNodeContracts` (r:1 w:0)
+	/// Proof: `SmartContractModule::ActiveNodeContracts` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SmartContractModule::ActiveRentContractForNode` (r:1 w:0)
+	/// Proof: `SmartContractModule::ActiveRentContractForNode` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn apply_node_power_schedule() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `904`
+		//  Estimated: `4369`
+		// Minimum execution time: 21_870_000 picoseconds.
+		Weight::from_parts(22_413_000, 4369)
+			.saturating_add(RocksDbWeight::get().reads(6_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
 	}
 }
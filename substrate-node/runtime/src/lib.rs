@@ -29,7 +29,7 @@ use sp_version::RuntimeVersion;
 use tfchain_support::{
     constants::time::*,
     traits::{ChangeNode, NodeActiveContracts, PublicIpModifier},
-    types::PublicIP,
+    types::{PublicIP, PublicIp6Prefix},
 };
 
 // A few exports that help ease life for downstream crates.
@@ -331,6 +331,14 @@ impl ChangeNode<Loc, Interface, Serial> for NodeChanged {
         Dao::node_deleted(node);
     }
 
+    fn node_decommissioning(node: &TfgridNode, deadline: u64) {
+        SmartContractModule::node_decommissioning(node, deadline);
+    }
+
+    fn node_decommissioned(node: &TfgridNode, max_contracts: u32) -> Weight {
+        SmartContractModule::node_decommissioned(node, max_contracts)
+    }
+
     fn node_power_state_changed(node: &TfgridNode) {
         SmartContractModule::node_power_state_changed(node);
     }
@@ -341,6 +349,9 @@ impl PublicIpModifier for PublicIpModifierType {
     fn ip_removed(ip: &PublicIP) {
         SmartContractModule::ip_removed(ip);
     }
+    fn ip6_prefix_removed(farm_id: u32, prefix: &PublicIp6Prefix) {
+        SmartContractModule::ip6_prefix_removed(farm_id, prefix);
+    }
 }
 
 pub struct NodeActiveContractsType;
@@ -356,6 +367,7 @@ parameter_types! {
     pub const MaxInterfacesLength: u32 = 10;
     pub const MaxFarmPublicIps: u32 = 512;
     pub const TimestampHintDrift: u64 = 60;
+    pub NodeDecommissionPeriod: u64 = (7 * DAYS).into();
 }
 
 impl pallet_tfgrid::Config for Runtime {
@@ -379,6 +391,7 @@ impl pallet_tfgrid::Config for Runtime {
     type Location = pallet_tfgrid::node::Location<Runtime>;
     type SerialNumber = pallet_tfgrid::node::SerialNumber<Runtime>;
     type TimestampHintDrift = TimestampHintDrift;
+    type NodeDecommissionPeriod = NodeDecommissionPeriod;
 }
 
 parameter_types! {
@@ -386,10 +399,16 @@ parameter_types! {
     pub BillingFrequency: u64 = 600;
     pub BillingReferencePeriod: u64 = SECS_PER_HOUR;
     pub GracePeriod: u64 = (14 * DAYS).into();
+    pub GracePeriodReminderFrequency: u64 = DAYS.into();
+    pub MaxContractsRestoredFromGrace: u32 = 50;
+    pub MaxNodeContractsInBatch: u32 = 20;
     pub DistributionFrequency: u16 = 24;
     pub RetryInterval: u32 = 20;
     pub MaxNameContractNameLength: u32 = 64;
     pub MaxDeploymentDataLength: u32 = 512;
+    pub MaxContractBillingHistory: u32 = 720;
+    pub MaxTwinBillingHistoryDays: u32 = 90;
+    pub NodeWakeupTimeout: u64 = HOURS.into();
 }
 
 pub fn get_staking_pool_account() -> AccountId {
@@ -408,6 +427,9 @@ impl pallet_smart_contract::Config for Runtime {
     type BillingReferencePeriod = BillingReferencePeriod;
     type DistributionFrequency = DistributionFrequency;
     type GracePeriod = GracePeriod;
+    type GracePeriodReminderFrequency = GracePeriodReminderFrequency;
+    type MaxContractsRestoredFromGrace = MaxContractsRestoredFromGrace;
+    type MaxNodeContractsInBatch = MaxNodeContractsInBatch;
     type WeightInfo = pallet_smart_contract::weights::SubstrateWeight<Runtime>;
     type PublicIpModifier = PublicIpModifierType;
     type AuthorityId = pallet_smart_contract::crypto::AuthId;
@@ -417,6 +439,9 @@ impl pallet_smart_contract::Config for Runtime {
     type RestrictedOrigin = EnsureRootOrCouncilApproval;
     type MaxDeploymentDataLength = MaxDeploymentDataLength;
     type MaxNodeContractPublicIps = MaxFarmPublicIps;
+    type MaxContractBillingHistory = MaxContractBillingHistory;
+    type MaxTwinBillingHistoryDays = MaxTwinBillingHistoryDays;
+    type NodeWakeupTimeout = NodeWakeupTimeout;
     type Burn = ();
 }
 
@@ -789,6 +814,11 @@ type Migrations = (
     pallet_scheduler::migration::v3::MigrateToV4<Runtime>,
     migrations::update_storage_version::PalletBalancesToV1<Runtime>,
     migrations::update_storage_version::PalletSessionToV1<Runtime>,
+    pallet_tfgrid::migrations::v18::FixFarmPricingPolicy<Runtime>,
+    pallet_smart_contract::migrations::v12::IndexContractsInGrace<Runtime>,
+    pallet_smart_contract::migrations::v13::InsertServiceContractsInBillingLoop<Runtime>,
+    pallet_smart_contract::migrations::v14::ExtendSolutionProviders<Runtime>,
+    pallet_smart_contract::migrations::v15::ReserveReportedResources<Runtime>,
 );
 
 // follows Substrate's non destructive way of eliminating  otherwise required
@@ -966,6 +996,49 @@ impl_runtime_apis! {
         }
     }
 
+    impl pallet_smart_contract_rpc_runtime_api::SmartContractApi<Block, AccountId, Balance> for Runtime {
+        fn estimate_contract_cost(
+            node_id: u32,
+            twin_id: u32,
+            resources: tfchain_support::resources::Resources,
+            public_ips: u32,
+        ) -> Result<pallet_smart_contract::types::ContractCostEstimate<Balance>, sp_runtime::DispatchError> {
+            SmartContractModule::estimate_contract_cost(node_id, twin_id, resources, public_ips)
+        }
+
+        fn contract_billing_history(contract_id: u64) -> Vec<pallet_smart_contract::types::ContractBill> {
+            SmartContractModule::contract_billing_history(contract_id)
+        }
+
+        fn twin_billing_history(twin_id: u32) -> Vec<pallet_smart_contract::types::TwinDailyBill> {
+            SmartContractModule::twin_billing_history(twin_id)
+        }
+
+        fn contracts_in_grace(twin_id: u32) -> Vec<pallet_smart_contract::types::ContractInGrace> {
+            SmartContractModule::get_contracts_in_grace(twin_id)
+        }
+
+        fn solution_provider_earnings(
+            solution_provider_id: u64,
+        ) -> pallet_smart_contract::types::SolutionProviderEarnings<AccountId, Balance> {
+            SmartContractModule::get_solution_provider_earnings(solution_provider_id)
+        }
+
+        fn unclaimed_solution_provider_rewards(account_id: AccountId) -> Balance {
+            SmartContractModule::unclaimed_solution_provider_rewards(account_id)
+        }
+
+        fn name_availability(name: Vec<u8>) -> pallet_smart_contract::types::NameAvailability {
+            SmartContractModule::get_name_availability(name)
+        }
+    }
+
+    impl pallet_tfgrid_rpc_runtime_api::TfgridApi<Block> for Runtime {
+        fn node_uptime(node_id: u32, period: Option<u64>) -> Option<pallet_tfgrid::types::NodeUptime> {
+            TfgridModule::get_node_uptime(node_id, period)
+        }
+    }
+
     #[cfg(feature = "runtime-benchmarks")]
     impl frame_benchmarking::Benchmark<Block> for Runtime {
         fn benchmark_metadata(extra: bool) -> (
@@ -34,6 +34,22 @@ impl Resources {
         self
     }
 
+    pub fn saturating_add(mut self, other: &Resources) -> Resources {
+        self.cru = self.cru.saturating_add(other.cru);
+        self.sru = self.sru.saturating_add(other.sru);
+        self.hru = self.hru.saturating_add(other.hru);
+        self.mru = self.mru.saturating_add(other.mru);
+        self
+    }
+
+    pub fn saturating_sub(mut self, other: &Resources) -> Resources {
+        self.cru = self.cru.saturating_sub(other.cru);
+        self.sru = self.sru.saturating_sub(other.sru);
+        self.hru = self.hru.saturating_sub(other.hru);
+        self.mru = self.mru.saturating_sub(other.mru);
+        self
+    }
+
     pub fn validate_hru(&self) -> bool {
         // No HRU minimun requirement
         true
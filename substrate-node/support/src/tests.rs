@@ -1,5 +1,5 @@
 use crate::resources::{Resources, GIGABYTE};
-use crate::types::{PublicIpError, IP4, IP6};
+use crate::types::{PublicIP, PublicIp6Prefix, PublicIpError, IP4, IP6};
 use frame_support::storage::bounded_vec::BoundedVec;
 use frame_support::{assert_err, assert_ok};
 
@@ -188,3 +188,57 @@ fn test_ip6_is_valid() {
     };
     assert_err!(ip6.is_valid(), PublicIpError::InvalidPublicIp);
 }
+
+#[test]
+fn test_public_ip_address() {
+    let public_ip = PublicIP {
+        ip: BoundedVec::try_from(b"185.206.122.33/24".to_vec()).unwrap(),
+        gateway: BoundedVec::try_from(b"185.206.122.1".to_vec()).unwrap(),
+        contract_id: 0,
+    };
+    assert_eq!(public_ip.address(), Some(0xb9ce7a21));
+    // Invalid ip
+    let public_ip = PublicIP {
+        ip: BoundedVec::try_from(b"185.206.122.256/24".to_vec()).unwrap(),
+        gateway: BoundedVec::try_from(b"185.206.122.1".to_vec()).unwrap(),
+        contract_id: 0,
+    };
+    assert_eq!(public_ip.address(), None);
+    let public_ip = PublicIP {
+        ip: BoundedVec::try_from(b"185.206.122/24".to_vec()).unwrap(),
+        gateway: BoundedVec::try_from(b"185.206.122.1".to_vec()).unwrap(),
+        contract_id: 0,
+    };
+    assert_eq!(public_ip.address(), None);
+}
+
+#[test]
+fn test_public_ip6_prefix_subnets() {
+    let prefix = PublicIp6Prefix {
+        prefix: BoundedVec::try_from(b"2a10:b600:1::/48".to_vec()).unwrap(),
+        gateway: BoundedVec::try_from(b"2a10:b600:1::1".to_vec()).unwrap(),
+        delegated: 0,
+    };
+    assert_eq!(prefix.subnets(), Some((0x2a10b60000010000, 1 << 16)));
+    // Host bits are ignored
+    let prefix = PublicIp6Prefix {
+        prefix: BoundedVec::try_from(b"2a10:b600:1:0:0cc4:7a30:65b5:1/64".to_vec()).unwrap(),
+        gateway: BoundedVec::try_from(b"2a10:b600:1::1".to_vec()).unwrap(),
+        delegated: 0,
+    };
+    assert_eq!(prefix.subnets(), Some((0x2a10b60000010000, 1)));
+    // Prefix smaller than a /64
+    let prefix = PublicIp6Prefix {
+        prefix: BoundedVec::try_from(b"2a10:b600:1::/72".to_vec()).unwrap(),
+        gateway: BoundedVec::try_from(b"2a10:b600:1::1".to_vec()).unwrap(),
+        delegated: 0,
+    };
+    assert_eq!(prefix.subnets(), None);
+    // Invalid address
+    let prefix = PublicIp6Prefix {
+        prefix: BoundedVec::try_from(b"2a10::b600::1/48".to_vec()).unwrap(),
+        gateway: BoundedVec::try_from(b"2a10:b600:1::1".to_vec()).unwrap(),
+        delegated: 0,
+    };
+    assert_eq!(prefix.subnets(), None);
+}
@@ -1,4 +1,6 @@
-use crate::types::PublicIP;
+use crate::types::{PublicIP, PublicIp6Prefix};
+use frame_support::weights::Weight;
+
 pub trait Tfgrid<AccountId, Name> {
     fn is_farm_owner(farm_id: u32, who: AccountId) -> bool;
 }
@@ -9,11 +11,19 @@ pub trait ChangeNode<Loc, If, Serial> {
         new_node: &super::types::Node<Loc, If, Serial>,
     );
     fn node_deleted(node: &super::types::Node<Loc, If, Serial>);
+    // Contracts have until the deadline (block) to move off the node
+    fn node_decommissioning(node: &super::types::Node<Loc, If, Serial>, deadline: u64);
+    // Cancels at most max_contracts contracts still on the node, returns the weight used
+    fn node_decommissioned(
+        node: &super::types::Node<Loc, If, Serial>,
+        max_contracts: u32,
+    ) -> Weight;
     fn node_power_state_changed(node: &super::types::Node<Loc, If, Serial>);
 }
 
 pub trait PublicIpModifier {
     fn ip_removed(ip: &PublicIP);
+    fn ip6_prefix_removed(farm_id: u32, prefix: &PublicIp6Prefix);
 }
 
 pub trait NodeActiveContracts {
@@ -38,6 +38,93 @@ pub struct PublicIP {
     pub contract_id: u64,
 }
 
+impl PublicIP {
+    // Address of the ip as a number, without its prefix length
+    pub fn address(&self) -> Option<u32> {
+        let address = self.ip.split(|c| *c == b'/').next()?;
+        let mut octets = address.split(|c| *c == b'.');
+        let mut value: u32 = 0;
+        for _ in 0..4 {
+            let octet = core::str::from_utf8(octets.next()?)
+                .ok()?
+                .parse::<u8>()
+                .ok()?;
+            value = (value << 8) | octet as u32;
+        }
+
+        octets.next().is_none().then_some(value)
+    }
+}
+
+#[derive(
+    PartialEq, Eq, PartialOrd, Ord, Clone, Encode, Decode, Default, Debug, TypeInfo, MaxEncodedLen,
+)]
+pub struct PublicIp6Prefix {
+    pub prefix: BoundedVec<u8, ConstU32<MAX_IP6_LENGTH>>,
+    pub gateway: BoundedVec<u8, ConstU32<MAX_GW6_LENGTH>>,
+    // Amount of /64 subnets of the prefix delegated to contracts
+    pub delegated: u64,
+}
+
+impl PublicIp6Prefix {
+    // First /64 subnet of the prefix (upper 64 bits of its address)
+    // and the amount of /64 subnets it holds
+    pub fn subnets(&self) -> Option<(u64, u64)> {
+        let mut parts = self.prefix.split(|c| *c == b'/');
+        let address = parse_ip6_address(parts.next()?)?;
+        let length = core::str::from_utf8(parts.next()?)
+            .ok()?
+            .parse::<u32>()
+            .ok()?;
+        if parts.next().is_some() || length == 0 || length > 64 {
+            return None;
+        }
+
+        let count = 1u64 << (64 - length);
+        let first = ((address >> 64) as u64) & !(count - 1);
+
+        Some((first, count))
+    }
+}
+
+fn parse_ip6_address(input: &[u8]) -> Option<u128> {
+    let input = core::str::from_utf8(input).ok()?;
+    let parse_groups = |part: &str| -> Option<Vec<u16>> {
+        if part.is_empty() {
+            return Some(Vec::new());
+        }
+        part.split(':')
+            .map(|group| match group.len() {
+                1..=4 => u16::from_str_radix(group, 16).ok(),
+                _ => None,
+            })
+            .collect()
+    };
+
+    let groups = match input.split_once("::") {
+        Some((head, tail)) => {
+            let mut groups = parse_groups(head)?;
+            let tail = parse_groups(tail)?;
+            if groups.len() + tail.len() > 7 {
+                return None;
+            }
+            groups.resize(8 - tail.len(), 0);
+            groups.extend(tail);
+            groups
+        }
+        None => parse_groups(input)?,
+    };
+    if groups.len() != 8 {
+        return None;
+    }
+
+    Some(
+        groups
+            .iter()
+            .fold(0u128, |address, group| (address << 16) | *group as u128),
+    )
+}
+
 #[derive(
     PartialEq, PartialOrd, Eq, Clone, Encode, Decode, Debug, Copy, TypeInfo, MaxEncodedLen,
 )]